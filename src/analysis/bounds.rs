@@ -1,16 +1,18 @@
 use crate::{
     analysis::{
         function_parameters::{async_param_to_remove, CParameter},
-        functions::{find_function, find_index_to_ignore, finish_function_name},
+        functions::{find_finish_function, find_index_to_ignore},
         imports::Imports,
         out_parameters::use_function_return_for_result,
         ref_mode::RefMode,
-        rust_type::RustType,
+        rust_type::{path_like_type, RustType},
     },
     config,
     consts::TYPE_PARAMETERS_START,
     env::Env,
-    library::{Class, Concurrency, Function, Fundamental, ParameterDirection, Type, TypeId},
+    library::{
+        Class, Concurrency, Function, Fundamental, ParameterDirection, Transfer, Type, TypeId,
+    },
     traits::IntoString,
 };
 use std::{collections::vec_deque::VecDeque, slice::Iter};
@@ -22,6 +24,8 @@ pub enum BoundType {
     IsA(Option<char>),
     // lifetime <- shouldn't be used but just in case...
     AsRef(Option<char>),
+    // slice of elements upcast one by one
+    IsASlice,
 }
 
 impl BoundType {
@@ -102,11 +106,14 @@ impl Bounds {
                     &bound_type,
                     *par.nullable,
                     par.instance_parameter,
+                    (par.transfer == Transfer::Full && par.ref_mode == RefMode::None)
+                        .then(|| type_string.as_str()),
                 ));
                 if r#async && (par.name == "callback" || par.name.ends_with("_callback")) {
                     let func_name = func.c_identifier.as_ref().unwrap();
-                    let finish_func_name = finish_function_name(func_name);
-                    if let Some(function) = find_function(env, &finish_func_name) {
+                    let (_, finish_func) =
+                        find_finish_function(env, func_name, configured_functions);
+                    if let Some(function) = finish_func {
                         // FIXME: This should work completely based on the analysis of the finish() function
                         // but that a) happens afterwards and b) is not accessible from here either.
                         let mut out_parameters =
@@ -181,10 +188,21 @@ impl Bounds {
                 if (!need_is_into_check || !*par.nullable) && par.c_type != "GDestroyNotify" {
                     self.add_parameter(&par.name, &type_string, bound_type, r#async)
                 }
+            } else if let Some(element_type) = Bounds::upcast_element_type(env, par, r#async) {
+                ret = Some(format!(
+                    ".iter().map(|p| p.upcast_ref::<{0}>().clone()).collect::<Vec<{0}>>()",
+                    element_type
+                ));
+                self.add_parameter(&par.name, &element_type, BoundType::IsASlice, r#async);
             }
         } else if par.instance_parameter {
             if let Some(bound_type) = Bounds::type_for(env, par.typ) {
-                ret = Some(Bounds::get_to_glib_extra(&bound_type, *par.nullable, true));
+                ret = Some(Bounds::get_to_glib_extra(
+                    &bound_type,
+                    *par.nullable,
+                    true,
+                    None,
+                ));
             }
         }
 
@@ -195,6 +213,7 @@ impl Bounds {
         use self::BoundType::*;
         match env.library.type_(type_id) {
             Type::Fundamental(Fundamental::Filename | Fundamental::OsString) => Some(AsRef(None)),
+            Type::Alias(alias) if path_like_type(env, alias.typ).is_some() => Some(AsRef(None)),
             Type::Class(Class {
                 final_type: true, ..
             }) => None,
@@ -208,12 +227,41 @@ impl Bounds {
         }
     }
 
+    /// Type of the elements of an array or list of non-final objects, which
+    /// can be taken as a slice of their subclasses
+    fn upcast_element_type(env: &Env, par: &CParameter, r#async: bool) -> Option<String> {
+        if r#async || *par.nullable || par.ref_mode != RefMode::ByRef {
+            return None;
+        }
+        let inner_tid = match env.library.type_(par.typ) {
+            Type::CArray(inner_tid) | Type::List(inner_tid) | Type::SList(inner_tid) => *inner_tid,
+            _ => return None,
+        };
+        if !matches!(Bounds::type_for(env, inner_tid), Some(BoundType::IsA(_))) {
+            return None;
+        }
+        RustType::builder(env, inner_tid)
+            .ref_mode(RefMode::ByRefFake)
+            .try_build()
+            .ok()
+            .map(IntoString::into_string)
+    }
+
     fn get_to_glib_extra(
         bound_type: &BoundType,
         nullable: bool,
         instance_parameter: bool,
+        owned_type: Option<&str>,
     ) -> String {
         use self::BoundType::*;
+        // Values moved into the C function are upcast instead of borrowed
+        if let (IsA(_), Some(owned_type)) = (bound_type, owned_type) {
+            return if nullable {
+                format!(".map(|p| p.upcast::<{}>())", owned_type)
+            } else {
+                format!(".upcast::<{}>()", owned_type)
+            };
+        }
         match bound_type {
             AsRef(_) if nullable => ".as_ref().map(|p| p.as_ref())".to_owned(),
             AsRef(_) => ".as_ref()".to_owned(),
@@ -252,6 +300,35 @@ impl Bounds {
         self.iter().find(move |n| n.parameter_name == name)
     }
 
+    /// Bounds of a variant taking one more parameter `object_name` bound by
+    /// `object_bound`, and passing it first to the callback parameter `name`.
+    /// Returns the type parameter of `object_name` along with them.
+    pub fn with_callback_object(
+        &self,
+        name: &str,
+        object_name: &str,
+        object_bound: &str,
+    ) -> Option<(Bounds, char)> {
+        let mut bounds = self.clone();
+        let alias = bounds.unused.pop_front()?;
+        let callback = bounds.used.iter_mut().find(|b| b.parameter_name == name)?;
+        let pos = callback.type_str.find('(')? + 1;
+        let argument = if callback.type_str[pos..].starts_with(')') {
+            format!("&{}", alias)
+        } else {
+            format!("&{}, ", alias)
+        };
+        callback.type_str.insert_str(pos, &argument);
+        bounds.used.push(Bound {
+            bound_type: BoundType::NoWrapper,
+            parameter_name: object_name.to_owned(),
+            alias: Some(alias),
+            type_str: object_bound.to_owned(),
+            callback_modified: false,
+        });
+        Some((bounds, alias))
+    }
+
     pub fn update_imports(&self, imports: &mut Imports) {
         //TODO: import with versions
         use self::BoundType::*;
@@ -259,6 +336,10 @@ impl Bounds {
             match used.bound_type {
                 NoWrapper => (),
                 IsA(_) => imports.add("glib::object::IsA"),
+                IsASlice => {
+                    imports.add("glib::object::IsA");
+                    imports.add("glib::object::Cast");
+                }
                 AsRef(_) => imports.add_used_type(&used.type_str),
             }
         }
@@ -272,6 +353,12 @@ impl Bounds {
         self.used.iter()
     }
 
+    pub fn add_lifetime(&mut self, lifetime: char) {
+        if !self.lifetimes.contains(&lifetime) {
+            self.lifetimes.push(lifetime);
+        }
+    }
+
     pub fn iter_lifetimes(&self) -> Iter<'_, char> {
         self.lifetimes.iter()
     }
@@ -312,7 +399,7 @@ fn find_out_parameters(
         .filter(|&(index, param)| {
             Some(index) != index_to_ignore
                 && param.direction == ParameterDirection::Out
-                && param.name != "error"
+                && !param.is_error
         })
         .map(|(_, param)| {
             // FIXME: This should work completely based on the analysis of the finish() function
@@ -348,7 +435,7 @@ fn find_error_type(env: &Env, function: &Function) -> String {
     let error_param = function
         .parameters
         .iter()
-        .find(|param| param.direction == ParameterDirection::Out && param.name == "error")
+        .find(|param| param.is_error)
         .expect("error type");
     if let Type::Record(_) = env.type_(error_param.typ) {
         return RustType::builder(env, error_param.typ)
@@ -414,6 +501,39 @@ mod tests {
         assert_eq!(bounds.get_parameter_bound("c"), None);
     }
 
+    #[test]
+    fn with_callback_object() {
+        let mut bounds: Bounds = Default::default();
+        bounds.add_parameter(
+            "func",
+            "Fn(&Widget, i32) + 'static",
+            BoundType::NoWrapper,
+            false,
+        );
+        bounds.add_parameter("done", "FnOnce() + 'static", BoundType::NoWrapper, false);
+        let (weak, alias) = bounds
+            .with_callback_object("func", "object", "glib::ObjectType")
+            .unwrap();
+        assert_eq!(alias, 'R');
+        assert_eq!(
+            weak.get_parameter_bound("func").unwrap().type_str,
+            "Fn(&R, &Widget, i32) + 'static"
+        );
+        let object = weak.get_parameter_bound("object").unwrap();
+        assert_eq!(object.alias, Some('R'));
+        assert_eq!(object.type_str, "glib::ObjectType");
+        let (weak, _) = bounds
+            .with_callback_object("done", "object", "glib::ObjectType")
+            .unwrap();
+        assert_eq!(
+            weak.get_parameter_bound("done").unwrap().type_str,
+            "FnOnce(&R) + 'static"
+        );
+        assert!(bounds
+            .with_callback_object("missing", "object", "glib::ObjectType")
+            .is_none());
+    }
+
     #[test]
     fn impl_bound() {
         let mut bounds: Bounds = Default::default();
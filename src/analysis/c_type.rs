@@ -1,5 +1,5 @@
-use crate::{env::Env, library::TypeId};
-use log::trace;
+use crate::{analysis::is_gconstpointer, env::Env, library::TypeId};
+use log::{trace, warn};
 
 pub fn rustify_pointers(c_type: &str) -> (String, String) {
     let mut input = c_type.trim();
@@ -44,6 +44,30 @@ pub fn is_mut_ptr(c_type: &str) -> bool {
     ptr.find("*mut") == Some(0)
 }
 
+pub fn is_const_ptr(c_type: &str) -> bool {
+    let (ptr, _inner) = rustify_pointers(c_type);
+    ptr.find("*const") == Some(0) || is_gconstpointer(c_type.trim())
+}
+
+/// Pointer to use for an instance of `expected` declared as an untyped
+/// `gpointer` or `gconstpointer`.
+///
+/// The constness of `gconstpointer` is only kept with the `const_pointers`
+/// option, otherwise it is dropped with a warning.
+pub fn untyped_instance_pointer(env: &Env, c_type: &str, expected: &str) -> &'static str {
+    if !is_gconstpointer(c_type) {
+        "*mut"
+    } else if env.config.const_pointers {
+        "*const"
+    } else {
+        warn!(
+            "[c:type `{}` of `{}` loses its constness, enable `const_pointers` to keep it]",
+            c_type, expected
+        );
+        "*mut"
+    }
+}
+
 pub fn implements_c_type(env: &Env, tid: TypeId, c_type: &str) -> bool {
     env.class_hierarchy
         .supertypes(tid)
@@ -53,7 +77,7 @@ pub fn implements_c_type(env: &Env, tid: TypeId, c_type: &str) -> bool {
 
 #[cfg(test)]
 mod tests {
-    use super::rustify_pointers as rustify_ptr;
+    use super::{is_const_ptr, rustify_pointers as rustify_ptr};
 
     fn s(x: &str, y: &str) -> (String, String) {
         (x.into(), y.into())
@@ -81,4 +105,15 @@ mod tests {
 
         assert_eq!(rustify_ptr("GtkWidget*"), s("*mut", "GtkWidget"));
     }
+
+    #[test]
+    fn const_ptr() {
+        assert!(is_const_ptr("const char*"));
+        assert!(is_const_ptr("char const *"));
+        assert!(is_const_ptr("gconstpointer"));
+        assert!(is_const_ptr("char* const*"));
+        assert!(!is_const_ptr("const char**"));
+        assert!(!is_const_ptr("gpointer"));
+        assert!(!is_const_ptr("GtkWidget*"));
+    }
 }
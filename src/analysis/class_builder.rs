@@ -1,15 +1,17 @@
 use crate::{
     analysis::{
         bounds::Bounds,
+        functions,
         imports::Imports,
         properties::{get_property_ref_modes, Property},
         rust_type::RustType,
     },
     config::{self, GObject},
     env::Env,
-    library,
+    library, nameutil,
     traits::*,
 };
+use log::warn;
 use std::collections::HashSet;
 
 pub fn analyze(
@@ -61,6 +63,51 @@ pub fn analyze(
     builder_properties
 }
 
+/// Warns about construct-only properties that can be set neither through
+/// the builder nor through a parameter of a generated constructor.
+///
+/// Only objects generating a builder are checked, the others leave
+/// construction to `glib::Object::new` on purpose.
+pub fn check_construct_only(
+    env: &Env,
+    props: &[library::Property],
+    type_tid: library::TypeId,
+    obj: &GObject,
+    builder_properties: &[(Vec<Property>, library::TypeId)],
+    functions: &[functions::Info],
+) {
+    if !obj.generate_builder {
+        return;
+    }
+    for prop in props.iter().filter(|prop| prop.construct_only) {
+        let configured_properties = obj.properties.matched(&prop.name);
+        if !configured_properties
+            .iter()
+            .all(|f| f.status.need_generate())
+            || env.is_totally_deprecated(Some(type_tid.ns_id), prop.deprecated_version)
+        {
+            continue;
+        }
+
+        let in_builder = builder_properties
+            .iter()
+            .flat_map(|(props, _)| props)
+            .any(|p| p.name == prop.name);
+        let param_name = nameutil::signal_to_snake(&prop.name);
+        let in_constructor = functions
+            .iter()
+            .filter(|f| f.kind == library::FunctionKind::Constructor && f.status.need_generate())
+            .flat_map(|f| &f.parameters.rust_parameters)
+            .any(|p| p.name == param_name);
+        if !in_builder && !in_constructor {
+            warn!(
+                "Construct-only property \"{}.{}\" can't be set by any generated constructor or builder",
+                obj.name, prop.name
+            );
+        }
+    }
+}
+
 fn analyze_properties(
     env: &Env,
     type_tid: library::TypeId,
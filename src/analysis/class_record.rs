@@ -0,0 +1,98 @@
+use crate::{
+    analysis::{ffi_type::ffi_type, rust_type::TypeError},
+    env::Env,
+    library::{self, Type},
+    nameutil,
+    traits::*,
+};
+use log::warn;
+
+/// Read-only wrapper of the class structure of a class or an interface,
+/// generated with `generate_class_record = true`
+#[derive(Debug)]
+pub struct ClassRecord {
+    pub name: String,
+    pub ffi_type: String,
+    /// Accessor names with the type of the virtual function pointers
+    pub vfuncs: Vec<(String, String)>,
+}
+
+pub fn analyze(env: &Env, type_tid: library::TypeId, c_class_type: &str) -> Option<ClassRecord> {
+    let (record_tid, record) = find(env, type_tid, c_class_type)?;
+
+    let ffi_type = match ffi_type(env, record_tid, c_class_type) {
+        Ok(ffi_type) => ffi_type.into_string(),
+        Err(err) => {
+            warn!("Class record {} not generated: {:?}", c_class_type, err);
+            return None;
+        }
+    };
+
+    let mut vfuncs = Vec::new();
+    for field in &record.fields {
+        let func = match env.library.type_(field.typ) {
+            Type::Function(func) if field.c_type.is_none() && !field.private => func,
+            _ => continue,
+        };
+        match vfunc_type(env, func) {
+            Ok(typ) => vfuncs.push((nameutil::mangle_keywords(&*field.name).into_owned(), typ)),
+            Err(err) => warn!(
+                "Virtual function {}.{} not exposed: {:?}",
+                c_class_type, field.name, err
+            ),
+        }
+    }
+
+    Some(ClassRecord {
+        name: record.name.clone(),
+        ffi_type,
+        vfuncs,
+    })
+}
+
+/// Finds the class structure of a class or an interface in its namespace.
+pub fn find<'a>(
+    env: &'a Env,
+    type_tid: library::TypeId,
+    c_class_type: &str,
+) -> Option<(library::TypeId, &'a library::Record)> {
+    env.library
+        .namespace(type_tid.ns_id)
+        .types
+        .iter()
+        .enumerate()
+        .find_map(|(id, typ)| match typ {
+            Some(Type::Record(record)) if record.c_type == c_class_type => Some((
+                library::TypeId {
+                    ns_id: type_tid.ns_id,
+                    id: id as u32,
+                },
+                record,
+            )),
+            _ => None,
+        })
+}
+
+fn vfunc_type(env: &Env, func: &library::Function) -> Result<String, TypeError> {
+    let mut parameters = Vec::with_capacity(func.parameters.len());
+    for par in &func.parameters {
+        if let Type::Fundamental(library::Fundamental::VarArgs) = env.library.type_(par.typ) {
+            parameters.push("...".to_owned());
+        } else {
+            parameters.push(ffi_type(env, par.typ, &par.c_type)?.into_string());
+        }
+    }
+    let ret = if func.ret.typ == Default::default() {
+        String::new()
+    } else {
+        format!(
+            " -> {}",
+            ffi_type(env, func.ret.typ, &func.ret.c_type)?.into_string()
+        )
+    };
+    Ok(format!(
+        "Option<unsafe extern \"C\" fn({}){}>",
+        parameters.join(", "),
+        ret
+    ))
+}
@@ -9,6 +9,8 @@ pub struct Info {
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
     pub cfg_condition: Option<String>,
+    /// Newtype over `&'static str` generated for the value
+    pub newtype: Option<String>,
 }
 
 pub fn analyze<F: Borrow<library::Constant>>(
@@ -48,6 +50,7 @@ pub fn analyze<F: Borrow<library::Constant>>(
         let cfg_condition = configured_constants
             .iter()
             .find_map(|c| c.cfg_condition.clone());
+        let newtype = configured_constants.iter().find_map(|c| c.newtype.clone());
 
         let name = nameutil::mangle_keywords(&*constant.name).into_owned();
 
@@ -58,6 +61,7 @@ pub fn analyze<F: Borrow<library::Constant>>(
             version,
             deprecated_version,
             cfg_condition,
+            newtype,
         });
     }
 
@@ -93,6 +93,7 @@ impl ConversionType {
             List(_) => ConversionType::Pointer,
             SList(_) => ConversionType::Pointer,
             PtrArray(_) => ConversionType::Pointer,
+            HashTable(..) => ConversionType::Pointer,
             Function(super::library::Function { name, .. }) if name == "AsyncReadyCallback" => {
                 ConversionType::Direct
             }
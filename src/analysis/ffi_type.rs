@@ -1,7 +1,7 @@
 use crate::{
     analysis::{
-        c_type::{implements_c_type, rustify_pointers},
-        is_gpointer,
+        c_type::{implements_c_type, rustify_pointers, untyped_instance_pointer},
+        is_gconstpointer, is_gpointer,
         rust_type::{Result, TypeError},
     },
     env::Env,
@@ -24,6 +24,10 @@ pub fn used_ffi_type(env: &Env, type_id: TypeId, c_type: &str) -> Option<String>
 }
 
 pub fn ffi_type(env: &Env, tid: TypeId, c_type: &str) -> Result {
+    // The typedef hides the pointers of the string array
+    if c_type == "GStrv" {
+        return ffi_type(env, tid, "gchar**");
+    }
     let (ptr, inner) = rustify_pointers(c_type);
     let res = if ptr.is_empty() {
         if let Some(c_tid) = env.library.find_type(0, c_type) {
@@ -45,10 +49,14 @@ pub fn ffi_type(env: &Env, tid: TypeId, c_type: &str) -> Result {
                     })
                     | Type::Interface(Interface {
                         c_type: expected, ..
-                    }) if is_gpointer(c_type) => {
-                        info!("[c:type `gpointer` instead of `*mut {}`, fixing]", expected);
+                    }) if is_gpointer(c_type) || is_gconstpointer(c_type) => {
+                        let ptr = untyped_instance_pointer(env, c_type, expected);
+                        info!(
+                            "[c:type `{}` instead of `{} {}`, fixing]",
+                            c_type, ptr, expected
+                        );
                         ffi_inner(env, tid, expected).map_any(|rust_type| {
-                            rust_type.alter_type(|typ_| format!("*mut {}", typ_))
+                            rust_type.alter_type(|typ_| format!("{} {}", ptr, typ_))
                         })
                     }
                     _ => ffi_inner(env, c_tid, c_type),
@@ -127,6 +135,10 @@ fn ffi_inner(env: &Env, tid: TypeId, inner: &str) -> Result {
             fix_name(env, tid, inner)
         }
         Type::CArray(inner_tid) => ffi_inner(env, inner_tid, inner),
+        Type::Custom(Custom {
+            ffi_tid: Some(ffi_tid),
+            ..
+        }) => ffi_inner(env, ffi_tid, inner),
         Type::FixedArray(inner_tid, size, _) => ffi_inner(env, inner_tid, inner)
             .map_any(|rust_type| rust_type.alter_type(|typ_| format!("[{}; {}]", typ_, size))),
         Type::Array(..)
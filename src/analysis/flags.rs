@@ -1,7 +1,7 @@
 use super::{function_parameters::TransformationType, imports::Imports, *};
 use crate::{config::gobjects::GObject, env::Env, nameutil::*, traits::*};
 
-use log::info;
+use log::{error, info};
 
 #[derive(Debug, Default)]
 pub struct Info {
@@ -10,6 +10,8 @@ pub struct Info {
     pub name: String,
     pub functions: Vec<functions::Info>,
     pub specials: special_functions::Infos,
+    /// Enumeration whose values are the bit positions of these flags
+    pub flags_of: Option<library::TypeId>,
 }
 
 impl Info {
@@ -56,6 +58,25 @@ pub fn new(env: &Env, obj: &GObject, imports: &mut Imports) -> Option<Info> {
         }
     }
 
+    let flags_of = obj.flags_of.as_ref().and_then(|enum_name| {
+        let tid = env.library.find_type(0, enum_name);
+        match tid.map(|tid| (tid, env.type_(tid))) {
+            Some((tid, library::Type::Enumeration(enum_))) => {
+                if obj.status.need_generate() {
+                    imports.add_with_version(&format!("crate::{}", enum_.name), enum_.version);
+                }
+                Some(tid)
+            }
+            _ => {
+                error!(
+                    "flags_of `{}` of {} is not a known enumeration",
+                    enum_name, obj.name
+                );
+                None
+            }
+        }
+    });
+
     let mut functions = functions::analyze(
         env,
         &flags.functions,
@@ -111,6 +132,7 @@ pub fn new(env: &Env, obj: &GObject, imports: &mut Imports) -> Option<Info> {
         name: name.to_owned(),
         functions,
         specials,
+        flags_of,
     };
 
     Some(info)
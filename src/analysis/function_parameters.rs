@@ -1,16 +1,29 @@
 use super::{
-    conversion_type::ConversionType, out_parameters::can_as_return,
-    override_string_type::override_string_type_parameter, ref_mode::RefMode, rust_type::RustType,
+    c_type::{is_const_ptr, is_mut_ptr, rustify_pointers},
+    conversion_type::ConversionType,
+    ffi_type::used_ffi_type,
+    functions::is_carray_with_direct_elements,
+    out_parameters::can_as_return,
+    override_string_type::override_string_type_parameter,
+    ref_mode::RefMode,
+    rust_type::RustType,
     try_from_glib::TryFromGlib,
 };
 use crate::{
     analysis,
-    config::{self, parameter_matchable::ParameterMatchable},
+    config::{
+        self,
+        bytes_type::BytesType,
+        functions::{LengthScale, MethodSelf},
+        parameter_matchable::ParameterMatchable,
+    },
+    custom_type_std_time::StdTime,
     env::Env,
     library::{self, Nullable, ParameterScope, TypeId},
     nameutil,
     traits::IntoString,
 };
+use log::{error, warn};
 use std::collections::HashMap;
 
 #[derive(Clone, Debug)]
@@ -72,6 +85,10 @@ pub struct CParameter {
     //analysis fields
     pub ref_mode: RefMode,
     pub try_from_glib: TryFromGlib,
+    /// Lifetime of the reference, when the return value borrows from it
+    pub lifetime: Option<char>,
+    /// Slice of records passed to C as an array of structs
+    pub record_array: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -89,6 +106,8 @@ pub enum TransformationType {
         instance_parameter: bool,
         transfer: library::Transfer,
         ref_mode: RefMode,
+        /// The value is moved into the C function with `into_glib_ptr()`
+        owned: bool,
         //filled by functions
         to_glib_extra: String,
         explicit_target_type: String,
@@ -101,12 +120,84 @@ pub enum TransformationType {
         name: String,
     },
     Length {
-        array_name: String,
+        /// Arrays sharing this length, in order
+        array_names: Vec<String>,
         array_length_name: String,
         array_length_type: String,
+        length_scale: LengthScale,
+        /// Inout length of caller-allocated out arrays, read back after the call
+        in_out: bool,
     },
     IntoRaw(String),
     ToSome(String),
+    /// Fixed-size array of fundamental types, like `&[f64; 4]`
+    ToGlibFixedArray {
+        name: String,
+        mut_ptr: bool,
+    },
+    /// Caller-allocated out array, passed as `&mut Vec<T>` and truncated
+    /// to its inout length after the call
+    ToGlibOutBuffer {
+        name: String,
+    },
+    /// Inout scalar passed as `&mut T`, converted into a temporary and
+    /// written back after the call
+    InOutScalar {
+        name: String,
+    },
+    /// Slice of records passed to C as an array of structs
+    ToGlibRecordArray {
+        name: String,
+        mode: RecordArrayMode,
+        ffi_type: String,
+        mut_ptr: bool,
+        nullable: bool,
+    },
+    /// `HashMap` converted into a new `GHashTable` by the generated
+    /// `hash_tables.rs` helpers
+    ToGlibHashTable {
+        name: String,
+        transfer: library::Transfer,
+        nullable: bool,
+    },
+    /// `SystemTime` converted into a GLib time type by the generated
+    /// `time.rs` helpers, see `options.time_mapping`
+    ToGlibSystemTime {
+        name: String,
+        std_time: StdTime,
+        transfer: library::Transfer,
+        nullable: bool,
+    },
+    /// Value of a configured `variant_type` converted with `ToVariant`
+    ToGlibVariant {
+        name: String,
+        transfer: library::Transfer,
+        nullable: bool,
+    },
+    /// Record or union passed by value, its C struct being copied out of
+    /// the wrapper
+    ToGlibRecordValue {
+        name: String,
+        instance_parameter: bool,
+    },
+    /// Slice copied into a new `GBytes`, or `glib::Bytes` passed as a byte
+    /// array, see `bytes_type`
+    ToGlibBytes {
+        name: String,
+        bytes_type: BytesType,
+        transfer: library::Transfer,
+        nullable: bool,
+    },
+}
+
+/// How a slice of record wrappers is turned into a C array of structs
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RecordArrayMode {
+    /// The wrappers are `#[repr(transparent)]` over the C structs,
+    /// the slice is passed as is
+    PassThrough,
+    /// The C structs are copied out of the wrappers into a temporary `Vec`
+    Copy,
 }
 
 impl TransformationType {
@@ -121,6 +212,15 @@ impl TransformationType {
                 | ToGlibUnknown { .. }
                 | ToSome(_)
                 | IntoRaw(_)
+                | ToGlibFixedArray { .. }
+                | ToGlibOutBuffer { .. }
+                | InOutScalar { .. }
+                | ToGlibRecordArray { .. }
+                | ToGlibHashTable { .. }
+                | ToGlibSystemTime { .. }
+                | ToGlibVariant { .. }
+                | ToGlibBytes { .. }
+                | ToGlibRecordValue { .. }
         )
     }
 
@@ -174,12 +274,127 @@ impl Parameters {
         let transformation = Transformation {
             ind_c,
             ind_rust: None,
-            transformation_type: get_length_type(env, "", &c_par.name, c_par.typ),
+            transformation_type: get_length_type(
+                env,
+                Vec::new(),
+                &c_par.name,
+                c_par.typ,
+                LengthScale::default(),
+                false,
+            ),
         };
         self.transformations.push(transformation);
     }
 }
 
+/// Applies the `method_self` configuration to the instance parameter.
+///
+/// Only types with an owned, mutably borrowable wrapper (boxed records and
+/// unions) can be taken as `&mut self`.
+fn method_self_ref_mode(
+    env: &Env,
+    par: &library::Parameter,
+    method_self: MethodSelf,
+    ref_mode: RefMode,
+) -> RefMode {
+    match method_self {
+        MethodSelf::Mut => {
+            if RefMode::of(env, par.typ, par.direction) == RefMode::ByRefMut {
+                if is_const_ptr(&par.c_type) {
+                    warn!(
+                        "`method_self = \"mut\"` on const instance `{}` of {}, `&self` would do",
+                        par.c_type,
+                        par.typ.full_name(&env.library)
+                    );
+                }
+                RefMode::ByRefMut
+            } else {
+                error!(
+                    "`method_self = \"mut\"` is not supported for instances of type {}",
+                    par.typ.full_name(&env.library)
+                );
+                ref_mode
+            }
+        }
+        MethodSelf::Ref if ref_mode == RefMode::ByRefMut => {
+            if is_mut_ptr(&par.c_type) {
+                RefMode::ByRefImmut
+            } else {
+                RefMode::ByRef
+            }
+        }
+        MethodSelf::Ref => ref_mode,
+    }
+}
+
+/// Objects and records implement `IntoGlibPtr`, unless their reference mode
+/// is configured for a manual type
+fn is_consumable(env: &Env, typ: TypeId) -> bool {
+    if !env.config.move_owned_parameters {
+        return false;
+    }
+    let configured = env
+        .config
+        .objects
+        .get(&typ.full_name(&env.library))
+        .map_or(false, |obj| obj.ref_mode.is_some());
+    !configured
+        && matches!(
+            env.library.type_(typ),
+            library::Type::Class(..) | library::Type::Interface(..) | library::Type::Record(..)
+        )
+}
+
+fn configured_ref_mode(
+    env: &Env,
+    par: &library::Parameter,
+    configured: RefMode,
+    ref_mode: RefMode,
+) -> RefMode {
+    let default = RefMode::of(env, par.typ, par.direction);
+    if par.direction != library::ParameterDirection::In || default == RefMode::None {
+        error!(
+            "`ref_mode` is not supported for parameter `{}` of type {}",
+            par.name,
+            par.typ.full_name(&env.library)
+        );
+        return ref_mode;
+    }
+    match configured {
+        RefMode::ByRefMut => {
+            if default != RefMode::ByRefMut {
+                error!(
+                    "`ref_mode = \"ref-mut\"` is not supported for parameter `{}` of type {}",
+                    par.name,
+                    par.typ.full_name(&env.library)
+                );
+                ref_mode
+            } else {
+                if is_const_ptr(&par.c_type) {
+                    warn!(
+                        "`ref_mode = \"ref-mut\"` on const parameter `{}` of type `{}`",
+                        par.name, par.c_type
+                    );
+                }
+                RefMode::ByRefMut
+            }
+        }
+        // Only a transferred value can be given away by the call
+        RefMode::None if par.transfer == library::Transfer::None => {
+            error!(
+                "`ref_mode = \"none\"` requires the ownership of parameter `{}` to be transferred",
+                par.name
+            );
+            ref_mode
+        }
+        // Borrowing immutably what the C function takes as mutable pointer
+        RefMode::ByRef if is_mut_ptr(&par.c_type) && default == RefMode::ByRefMut => {
+            RefMode::ByRefImmut
+        }
+        configured => configured,
+    }
+}
+
 #[allow(clippy::useless_let_if_seq)]
 pub fn analyze(
     env: &Env,
@@ -191,30 +406,39 @@ pub fn analyze(
 ) -> Parameters {
     let mut parameters = Parameters::new(function_parameters.len());
 
-    // Map: length argument position => array name
-    let array_lengths: HashMap<u32, String> = function_parameters
-        .iter()
-        .filter_map(|p| p.array_length.map(|pos| (pos, p.name.clone())))
-        .collect();
+    // Map: length argument position => names of the arrays
+    let mut array_lengths: HashMap<u32, Vec<String>> = HashMap::new();
+    for p in function_parameters {
+        if let Some(pos) = p.array_length {
+            array_lengths.entry(pos).or_default().push(p.name.clone());
+        }
+    }
 
     for (pos, par) in function_parameters.iter().enumerate() {
-        let name = if par.instance_parameter {
-            par.name.clone()
+        let (name, configured_parameters) = if par.instance_parameter {
+            (
+                par.name.clone(),
+                configured_functions.matched_parameters(&par.name),
+            )
         } else {
-            nameutil::mangle_keywords(&*par.name).into_owned()
+            (
+                parameter_name(par, configured_functions),
+                configured_functions.matched_parameters(&nameutil::mangle_keywords(&*par.name)),
+            )
         };
 
-        let configured_parameters = configured_functions.matched_parameters(&name);
-
         let c_type = par.c_type.clone();
         let typ = override_string_type_parameter(env, par.typ, &configured_parameters);
 
         let ind_c = parameters.c_parameters.len();
         let mut ind_rust = Some(parameters.rust_parameters.len());
 
+        let out_buffer = !async_func && is_out_buffer(env, par, function_parameters);
+
         let mut add_rust_parameter = match par.direction {
             library::ParameterDirection::In | library::ParameterDirection::InOut => true,
             library::ParameterDirection::Return => false,
+            library::ParameterDirection::Out if out_buffer => true,
             library::ParameterDirection::Out => !can_as_return(env, par) && !async_func,
             library::ParameterDirection::None => {
                 panic!("undefined direction for parameter {:?}", par)
@@ -225,23 +449,54 @@ pub fn analyze(
             add_rust_parameter = false;
         }
 
-        let mut array_name = configured_parameters
+        let mut array_names = configured_parameters
             .iter()
-            .find_map(|p| p.length_of.as_ref());
-        if array_name.is_none() {
-            array_name = array_lengths.get(&(pos as u32))
+            .find_map(|p| p.length_of.as_ref())
+            .and_then(|length_of| resolve_length_of(pos, par, length_of, function_parameters))
+            .map(|name| vec![name.to_owned()]);
+        if array_names.is_none() {
+            array_names = array_lengths.get(&(pos as u32)).cloned();
         }
-        if array_name.is_none() && !disable_length_detect {
-            array_name = detect_length(env, pos, par, function_parameters);
+        if array_names.is_none() && !disable_length_detect {
+            array_names =
+                detect_length(env, pos, par, function_parameters).map(|name| vec![name.clone()]);
         }
-        if let Some(array_name) = array_name {
-            let array_name = nameutil::mangle_keywords(&array_name[..]);
+        let mut length_in_out = false;
+        if let Some(array_names) = array_names {
+            length_in_out = !async_func
+                && par.direction == library::ParameterDirection::InOut
+                && array_names.iter().all(|name| {
+                    function_parameters
+                        .iter()
+                        .find(|p| &p.name == name)
+                        .map_or(false, |p| is_out_buffer(env, p, function_parameters))
+                });
+            let array_names = array_names
+                .iter()
+                .map(
+                    |name| match function_parameters.iter().find(|p| &p.name == name) {
+                        Some(array) => parameter_name(array, configured_functions),
+                        None => nameutil::mangle_keywords(&name[..]).into_owned(),
+                    },
+                )
+                .collect();
             add_rust_parameter = false;
+            let length_scale = configured_parameters
+                .iter()
+                .find_map(|p| p.length_scale.clone())
+                .unwrap_or_default();
 
             let transformation = Transformation {
                 ind_c,
                 ind_rust: None,
-                transformation_type: get_length_type(env, &array_name, &par.name, typ),
+                transformation_type: get_length_type(
+                    env,
+                    array_names,
+                    &name,
+                    typ,
+                    length_scale,
+                    length_in_out,
+                ),
             };
             parameters.transformations.push(transformation);
         }
@@ -260,14 +515,38 @@ pub fn analyze(
         }
 
         let immutable = configured_parameters.iter().any(|p| p.constant);
-        let ref_mode =
+        let mut ref_mode =
             RefMode::without_unneeded_mut(env, par, immutable, in_trait && par.instance_parameter);
+        if par.instance_parameter {
+            if let Some(method_self) = configured_functions.iter().find_map(|f| f.method_self) {
+                ref_mode = method_self_ref_mode(env, par, method_self, ref_mode);
+            }
+        } else if let Some(configured) = configured_parameters.iter().find_map(|p| p.ref_mode) {
+            ref_mode = configured_ref_mode(env, par, configured, ref_mode);
+        } else if transfer == library::Transfer::Full
+            && par.direction == library::ParameterDirection::In
+            && conversion == ConversionType::Pointer
+            && is_consumable(env, typ)
+        {
+            // The value is given away to the C function instead of being copied for it
+            ref_mode = RefMode::None;
+        }
+        if out_buffer {
+            ref_mode = RefMode::ByRefMut;
+        }
 
         let nullable_override = configured_parameters.iter().find_map(|p| p.nullable);
         let nullable = nullable_override.unwrap_or(par.nullable);
 
         let try_from_glib = TryFromGlib::from_parameter(env, typ, &configured_parameters);
 
+        let record_array =
+            if par.direction == library::ParameterDirection::In && !par.instance_parameter {
+                record_array_mode(env, typ, &par.c_type)
+            } else {
+                None
+            };
+
         let c_par = CParameter {
             name: name.clone(),
             typ,
@@ -283,6 +562,8 @@ pub fn analyze(
             user_data_index: par.closure,
             destroy_index: par.destroy,
             try_from_glib: try_from_glib.clone(),
+            lifetime: None,
+            record_array: record_array.is_some(),
         };
         parameters.c_parameters.push(c_par);
 
@@ -303,7 +584,11 @@ pub fn analyze(
 
         let transformation_type = match conversion {
             ConversionType::Direct => {
-                if par.c_type != "GLib.Pid" {
+                if length_in_out {
+                    TransformationType::ToGlibDirect {
+                        name: format!("&mut {}", name),
+                    }
+                } else if par.c_type != "GLib.Pid" {
                     TransformationType::ToGlibDirect { name }
                 } else {
                     TransformationType::ToGlibScalar {
@@ -313,6 +598,11 @@ pub fn analyze(
                     }
                 }
             }
+            ConversionType::Scalar
+                if !async_func && par.direction == library::ParameterDirection::InOut =>
+            {
+                TransformationType::InOutScalar { name }
+            }
             ConversionType::Scalar => TransformationType::ToGlibScalar {
                 name,
                 nullable,
@@ -342,16 +632,73 @@ pub fn analyze(
                     needs_into,
                 }
             }
-            ConversionType::Pointer => TransformationType::ToGlibPointer {
-                name,
-                instance_parameter: par.instance_parameter,
-                transfer,
-                ref_mode,
-                to_glib_extra: Default::default(),
-                explicit_target_type: Default::default(),
-                pointer_cast: Default::default(),
-                in_trait,
-                nullable: *nullable,
+            ConversionType::Pointer => match record_array {
+                Some((mode, ffi_type)) => TransformationType::ToGlibRecordArray {
+                    name,
+                    mode,
+                    ffi_type,
+                    mut_ptr: is_mut_ptr(&par.c_type),
+                    nullable: *nullable,
+                },
+                None if out_buffer => TransformationType::ToGlibOutBuffer { name },
+                None if is_hash_table(env, typ) => TransformationType::ToGlibHashTable {
+                    name,
+                    transfer,
+                    nullable: *nullable,
+                },
+                None if env.library.std_time(typ).is_some() => {
+                    TransformationType::ToGlibSystemTime {
+                        name,
+                        std_time: env.library.std_time(typ).unwrap(),
+                        transfer,
+                        nullable: *nullable,
+                    }
+                }
+                None if env.library.variant_type(typ).is_some() => {
+                    TransformationType::ToGlibVariant {
+                        name,
+                        transfer,
+                        nullable: *nullable,
+                    }
+                }
+                None if env.library.bytes_type(typ).is_some() => TransformationType::ToGlibBytes {
+                    name,
+                    bytes_type: env.library.bytes_type(typ).unwrap(),
+                    transfer,
+                    nullable: *nullable,
+                },
+                None if is_record_by_value(env, typ, &par.c_type) => {
+                    TransformationType::ToGlibRecordValue {
+                        name,
+                        instance_parameter: par.instance_parameter,
+                    }
+                }
+                None if is_fixed_array(env, typ) => TransformationType::ToGlibFixedArray {
+                    name,
+                    mut_ptr: is_mut_ptr(&par.c_type),
+                },
+                None => TransformationType::ToGlibPointer {
+                    name,
+                    instance_parameter: par.instance_parameter,
+                    transfer,
+                    ref_mode,
+                    owned: ref_mode == RefMode::None
+                        && transfer == library::Transfer::Full
+                        && is_consumable(env, typ),
+                    to_glib_extra: Default::default(),
+                    explicit_target_type: Default::default(),
+                    // Typed with `pointer_type`
+                    pointer_cast: if matches!(par.c_type.as_str(), "gpointer" | "gconstpointer") {
+                        format!(
+                            " as {}",
+                            nameutil::use_glib_if_needed(env, &format!("ffi::{}", par.c_type))
+                        )
+                    } else {
+                        Default::default()
+                    },
+                    in_trait,
+                    nullable: *nullable,
+                },
             },
             ConversionType::Borrow => TransformationType::ToGlibBorrow,
             ConversionType::Unknown => TransformationType::ToGlibUnknown { name },
@@ -389,18 +736,145 @@ pub fn analyze(
     parameters
 }
 
+fn is_fixed_array(env: &Env, typ: TypeId) -> bool {
+    use crate::library::Type;
+    matches!(
+        env.library.type_(typ),
+        Type::FixedArray(inner_tid, ..)
+            if ConversionType::of(env, *inner_tid) == ConversionType::Direct
+    )
+}
+
+/// Detects records and unions passed by value, like the instance parameter of
+/// `graphene_point_t` methods taking the point itself
+fn is_record_by_value(env: &Env, typ: TypeId, c_type: &str) -> bool {
+    use crate::library::Type;
+    matches!(env.library.type_(typ), Type::Record(..) | Type::Union(..))
+        && rustify_pointers(c_type).0.is_empty()
+        && !c_type.is_empty()
+}
+
+fn is_hash_table(env: &Env, typ: TypeId) -> bool {
+    matches!(env.library.type_(typ), crate::library::Type::HashTable(..))
+}
+
+/// Detects C arrays of records passed by value, like `const GdkRGBA *colors`
+fn record_array_mode(env: &Env, typ: TypeId, c_type: &str) -> Option<(RecordArrayMode, String)> {
+    use crate::library::Type;
+    let inner_tid = match env.library.type_(typ) {
+        Type::CArray(inner_tid) => *inner_tid,
+        _ => return None,
+    };
+    let record = match env.library.type_(inner_tid) {
+        Type::Record(record) => record,
+        _ => return None,
+    };
+    if record.disguised || record.fields.is_empty() {
+        // Opaque structs can only be passed around by pointer
+        return None;
+    }
+    let (ptr, _) = rustify_pointers(c_type);
+    if ptr.matches('*').count() != 1 {
+        return None;
+    }
+    let ffi_type = used_ffi_type(env, inner_tid, c_type)?;
+
+    let boxed_inline = env
+        .config
+        .objects
+        .get(&inner_tid.full_name(&env.library))
+        .map_or(false, |obj| obj.boxed_inline);
+    let mode = if boxed_inline {
+        RecordArrayMode::PassThrough
+    } else {
+        RecordArrayMode::Copy
+    };
+    Some((mode, ffi_type))
+}
+
 fn get_length_type(
     env: &Env,
-    array_name: &str,
+    array_names: Vec<String>,
     length_name: &str,
     length_typ: TypeId,
+    length_scale: LengthScale,
+    in_out: bool,
 ) -> TransformationType {
     let array_length_type = RustType::try_new(env, length_typ).into_string();
     TransformationType::Length {
-        array_name: array_name.to_string(),
+        array_names,
         array_length_name: length_name.to_string(),
         array_length_type,
+        length_scale,
+        in_out,
+    }
+}
+
+/// Detects out arrays of fundamental types filled by the callee up to an
+/// inout length, like `guint8 *buffer` with `gsize *count`.
+///
+/// The length passed in is the `len()` of the `Vec`, not its capacity, so
+/// that the callee only writes initialized items: callers size the buffer
+/// with `vec![0; n]` or `resize` rather than `Vec::with_capacity`.
+pub fn is_out_buffer(
+    env: &Env,
+    par: &library::Parameter,
+    parameters: &[library::Parameter],
+) -> bool {
+    if par.direction != library::ParameterDirection::Out
+        || !is_carray_with_direct_elements(env, par.typ)
+    {
+        return false;
     }
+    let (ptr, _) = rustify_pointers(&par.c_type);
+    if ptr.matches('*').count() != 1 {
+        // The callee allocates the array
+        return false;
+    }
+    par.array_length
+        .and_then(|pos| parameters.get(pos as usize))
+        .map_or(false, |length| {
+            length.direction == library::ParameterDirection::InOut
+        })
+}
+
+/// Resolves the `@previous` and `@next` position hints of `length_of`
+/// Name of a non-instance parameter in the generated code: its configured
+/// `rename`, if any, then keywords mangled. Configuration still matches the
+/// name from the gir.
+pub fn parameter_name(
+    par: &library::Parameter,
+    configured_functions: &[&config::functions::Function],
+) -> String {
+    let name = nameutil::mangle_keywords(&*par.name);
+    match configured_functions
+        .matched_parameters(&name)
+        .iter()
+        .find_map(|p| p.rename.as_ref())
+    {
+        Some(rename) => nameutil::mangle_keywords(&**rename).into_owned(),
+        None => name.into_owned(),
+    }
+}
+
+fn resolve_length_of<'a>(
+    pos: usize,
+    par: &library::Parameter,
+    length_of: &'a str,
+    parameters: &'a [library::Parameter],
+) -> Option<&'a str> {
+    let array = match length_of {
+        "@previous" => pos.checked_sub(1).and_then(|pos| parameters.get(pos)),
+        "@next" => parameters.get(pos + 1),
+        _ => return Some(length_of),
+    };
+    if array.is_none() {
+        error!(
+            "`length_of = \"{}\"` of parameter {} doesn't refer to a parameter",
+            length_of, par.name
+        );
+    }
+    array.map(|p| p.name.as_str())
 }
 
 fn detect_length<'a>(
@@ -409,31 +883,31 @@ fn detect_length<'a>(
     par: &library::Parameter,
     parameters: &'a [library::Parameter],
 ) -> Option<&'a String> {
-    if !is_length(par) {
+    if !is_length(env, par) {
         return None;
     }
 
-    let array = parameters.get(pos - 1).and_then(|p| {
-        if has_length(env, p.typ) {
-            Some(p)
-        } else {
-            None
-        }
-    });
-    array.map(|p| &p.name)
+    // The array usually precedes its length, but some APIs pass the count
+    // first. Arrays with a length annotation are already taken care of.
+    let previous = pos
+        .checked_sub(1)
+        .and_then(|pos| parameters.get(pos))
+        .filter(|p| has_length(env, p.typ));
+    let next = parameters
+        .get(pos + 1)
+        .filter(|p| has_length(env, p.typ) && p.array_length.is_none());
+    previous.or(next).map(|p| &p.name)
 }
 
-fn is_length(par: &library::Parameter) -> bool {
+fn is_length(env: &Env, par: &library::Parameter) -> bool {
     if par.direction != library::ParameterDirection::In {
         return false;
     }
 
-    let len = par.name.len();
-    if len >= 3 && &par.name[len - 3..len] == "len" {
-        return true;
-    }
-
-    par.name.contains("length")
+    env.config
+        .length_name_patterns
+        .iter()
+        .any(|pattern| pattern.is_match(&par.name))
 }
 
 fn has_length(env: &Env, typ: TypeId) -> bool {
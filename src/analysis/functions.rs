@@ -9,7 +9,9 @@ use crate::{
     analysis::{
         self,
         bounds::{Bounds, CallbackInfo},
-        function_parameters::{self, CParameter, Parameters, Transformation, TransformationType},
+        function_parameters::{
+            self, is_out_buffer, CParameter, Parameters, Transformation, TransformationType,
+        },
         imports::Imports,
         is_gpointer,
         out_parameters::{self, use_function_return_for_result},
@@ -20,6 +22,7 @@ use crate::{
         signatures::{Signature, Signatures},
         trampolines::Trampoline,
     },
+    case::CaseExt,
     config::{self, gobjects::GStatus},
     env::Env,
     library::{self, Function, FunctionKind, ParameterDirection, ParameterScope, Transfer, Type},
@@ -27,10 +30,11 @@ use crate::{
     traits::*,
     version::Version,
 };
-use log::warn;
+use log::{error, info, warn};
 use std::{
     borrow::Borrow,
     collections::{HashMap, HashSet},
+    fs,
 };
 
 use super::special_functions;
@@ -71,6 +75,54 @@ pub struct AsyncFuture {
     pub success_parameters: String,
     pub error_parameters: String,
     pub assertion: SafetyAssertionMode,
+    pub request: Option<FutureRequest>,
+}
+
+/// Type implementing `IntoFuture` by calling the `{name}_future` method
+/// with default arguments (`into_future = true`)
+#[derive(Clone, Debug)]
+pub struct FutureRequest {
+    /// Name of the method returning the request
+    pub method_name: String,
+    pub type_name: String,
+}
+
+/// Future wrapping a function reporting its completion through a bare
+/// callback (`async_style = "callback"`).
+#[derive(Clone, Debug)]
+pub struct CallbackFuture {
+    pub is_method: bool,
+    pub name: String,
+    pub callback_name: String,
+    pub output: String,
+    /// Callback arguments with the expression converting them to owned values
+    pub arguments: Vec<(String, String)>,
+    pub assertion: SafetyAssertionMode,
+}
+
+/// `{name}_weak` variant of a method whose notified callback receives the
+/// object it is set on. It holds `object` weakly and passes it first to the
+/// callback, which isn't called anymore once `object` is gone.
+#[derive(Debug)]
+pub struct WeakCallback {
+    pub name: String,
+    pub callback_name: String,
+    pub object_name: String,
+    /// Type parameter of `object_name`
+    pub object_alias: char,
+    /// Names and types of the callback arguments
+    pub arguments: Vec<(String, String)>,
+    pub bounds: Bounds,
+}
+
+/// `{name}_optional` variant of a function with optional out parameters
+/// (`optional_variant = true`), passing `NULL` for the ones not requested.
+#[derive(Debug)]
+pub struct OptionalOuts {
+    pub name: String,
+    /// Optional out parameters, each requested by a `want_{name}: bool`
+    /// parameter and returned as `Option<T>`
+    pub parameters: Vec<String>,
 }
 
 #[derive(Debug)]
@@ -94,13 +146,26 @@ pub struct Info {
     pub assertion: SafetyAssertionMode,
     pub doc_hidden: bool,
     pub doc_ignore_parameters: HashSet<String>,
+    /// Gir names of the parameters renamed with `rename`, to their Rust names
+    pub parameter_renames: HashMap<String, String>,
     pub r#async: bool,
     pub unsafe_: bool,
+    pub safety_doc: Option<String>,
     pub trampoline: Option<AsyncTrampoline>,
     pub callbacks: Vec<Trampoline>,
     pub destroys: Vec<Trampoline>,
     pub remove_params: Vec<usize>,
+    /// Contents of the configured `body_template`
+    pub body_template: Option<String>,
     pub async_future: Option<AsyncFuture>,
+    pub callback_future: Option<CallbackFuture>,
+    /// Notified callback receiving the object the method is called on
+    pub callback_cycle: Option<String>,
+    pub weak_callback: Option<WeakCallback>,
+    pub optional_outs: Option<OptionalOuts>,
+    /// Name of the `with_<property>` variant of this property setter
+    /// (`generate_chainable_setters = true`)
+    pub chainable_setter: Option<String>,
 }
 
 impl Info {
@@ -155,7 +220,17 @@ impl Info {
             .rust_parameters
             .iter()
             .any(|param| param.typ.full_name(&env.library) == "Gio.AsyncResult");
-        self.name.ends_with("_finish") && has_async_result
+        has_async_result
+            && (env
+                .config
+                .finish_function_suffixes
+                .iter()
+                .any(|suffix| self.name.ends_with(suffix.as_str()))
+                || env.config.objects.values().any(|obj| {
+                    obj.functions
+                        .iter()
+                        .any(|f| f.finish_function.as_ref() == Some(&self.glib_name))
+                }))
     }
 }
 
@@ -190,7 +265,18 @@ pub fn analyze<F: Borrow<library::Function>>(
         if env.is_totally_deprecated(Some(type_tid.ns_id), func.deprecated_version) {
             continue;
         }
-        let name = nameutil::mangle_keywords(&*func.name).into_owned();
+        if let Some(ref shadowed_by) = func.shadowed_by {
+            // Only the shadowing function is bound, under the name of this one,
+            // unless this one is configured by name with another name to keep it
+            let kept = configured_functions
+                .iter()
+                .any(|f| matches!(f.ident, config::ident::Ident::Name(_)) && f.rename.is_some());
+            if !kept && functions.iter().any(|f| &f.borrow().name == shadowed_by) {
+                continue;
+            }
+        }
+        let name =
+            nameutil::mangle_keywords(func.shadows.as_ref().unwrap_or(&func.name)).into_owned();
         let signature_params = Signature::new(func);
         let mut not_version = None;
         if func.kind == library::FunctionKind::Method {
@@ -255,6 +341,7 @@ fn fixup_gpointer_parameter(
             instance_parameter,
             transfer: Transfer::None,
             ref_mode: RefMode::ByRef,
+            owned: false,
             to_glib_extra: Default::default(),
             explicit_target_type: format!("{} {}", pointer_type, ffi_name.as_str()),
             pointer_cast: format!(
@@ -331,6 +418,10 @@ fn analyze_callbacks(
     let mut to_replace = Vec::new();
     let mut to_remove = Vec::new();
 
+    // Inverse closures first, as they can carry the `destroy` of the user data
+    link_inverse_closures(env, &mut parameters.c_parameters);
+    link_notified_destroy(env, &mut parameters.c_parameters);
+
     {
         // When closure data and destroy are specified in gir, they don't take into account the
         // actual closure parameter.
@@ -347,7 +438,26 @@ fn analyze_callbacks(
             None => &func.name,
         };
         let mut destructors_to_update = Vec::new();
-        for pos in 0..parameters.c_parameters.len() {
+        let user_data_positions = parameters
+            .c_parameters
+            .iter()
+            .filter(|par| {
+                par.c_type != "GDestroyNotify" && env.library.type_(par.typ).is_function()
+            })
+            .filter_map(|par| c_parameters.get(par.user_data_index?))
+            .map(|&(_, pos)| pos)
+            .collect::<HashSet<_>>();
+        // User data and destroy notifies are analyzed last, once their callback is known
+        let (destroy_positions, positions): (Vec<_>, Vec<_>) = (0..parameters.c_parameters.len())
+            .partition(|&pos| parameters.c_parameters[pos].c_type == "GDestroyNotify");
+        let (user_data_positions, positions): (Vec<_>, Vec<_>) = positions
+            .into_iter()
+            .partition(|pos| user_data_positions.contains(pos));
+        for pos in positions
+            .into_iter()
+            .chain(user_data_positions)
+            .chain(destroy_positions)
+        {
             // If it is a user data parameter, we ignore it.
             if cross_user_data_check.values().any(|p| *p == pos) || user_data_indexes.contains(&pos)
             {
@@ -537,6 +647,71 @@ fn analyze_callbacks(
     }
 }
 
+/// Links callbacks without `closure` annotation to the user data parameter
+/// whose `closure` annotation points back to them, as some girs have it.
+fn link_inverse_closures(env: &Env, c_parameters: &mut [CParameter]) {
+    // Indexes of the annotations don't count the instance parameter
+    let positions = c_parameters
+        .iter()
+        .enumerate()
+        .filter(|(_, par)| !par.instance_parameter)
+        .map(|(pos, _)| pos)
+        .collect::<Vec<_>>();
+    for (index, &pos) in positions.iter().enumerate() {
+        let par = &c_parameters[pos];
+        if !is_gpointer(&par.c_type) {
+            continue;
+        }
+        let callback_pos = match par.user_data_index.and_then(|i| positions.get(i)) {
+            Some(&callback_pos) if callback_pos != pos => callback_pos,
+            _ => continue,
+        };
+        let destroy_index = par.destroy_index;
+        let callback = &mut c_parameters[callback_pos];
+        if callback.user_data_index.is_none()
+            && callback.c_type != "GDestroyNotify"
+            && env.library.type_(callback.typ).is_function()
+        {
+            callback.user_data_index = Some(index);
+            if callback.destroy_index.is_none() {
+                callback.destroy_index = destroy_index;
+            }
+        }
+    }
+}
+
+/// Links a notified callback without `destroy` annotation to the only
+/// `GDestroyNotify` parameter of the function, if not used by another callback.
+fn link_notified_destroy(env: &Env, c_parameters: &mut [CParameter]) {
+    // Indexes of the annotations don't count the instance parameter
+    let destroys = c_parameters
+        .iter()
+        .filter(|par| !par.instance_parameter)
+        .enumerate()
+        .filter(|(_, par)| par.c_type == "GDestroyNotify")
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let destroy_index = match destroys[..] {
+        [destroy_index] => destroy_index,
+        _ => return,
+    };
+    if c_parameters
+        .iter()
+        .any(|par| par.destroy_index == Some(destroy_index))
+    {
+        return;
+    }
+    let mut notified = c_parameters.iter_mut().filter(|par| {
+        par.scope == ParameterScope::Notified
+            && par.c_type != "GDestroyNotify"
+            && par.destroy_index.is_none()
+            && env.library.type_(par.typ).is_function()
+    });
+    if let (Some(par), None) = (notified.next(), notified.next()) {
+        par.destroy_index = Some(destroy_index);
+    }
+}
+
 fn analyze_function(
     env: &Env,
     obj: &config::gobjects::GObject,
@@ -607,9 +782,10 @@ fn analyze_function(
                 );
             }
 
-            if name.starts_with("new_from")
-                || name.starts_with("new_with")
-                || name.starts_with("new_for")
+            if env.config.rename_constructors
+                && ["new_from_", "new_with_", "new_for_"]
+                    .iter()
+                    .any(|prefix| name.starts_with(prefix))
             {
                 new_name = Some(name[4..].to_string());
             }
@@ -628,6 +804,11 @@ fn analyze_function(
         }
     }
 
+    if new_name.is_none() && func.shadows.is_some() {
+        // Keep the name of the shadowed function, for the docs to be matched
+        new_name = Some(name.clone());
+    }
+
     let version = configured_functions
         .iter()
         .filter_map(|f| f.version)
@@ -645,14 +826,37 @@ fn analyze_function(
         .find(|f| !f.doc_ignore_parameters.is_empty())
         .map(|f| f.doc_ignore_parameters.clone())
         .unwrap_or_default();
-    let disable_length_detect = configured_functions.iter().any(|f| f.disable_length_detect);
+    let parameter_renames = func
+        .parameters
+        .iter()
+        .filter(|par| !par.instance_parameter)
+        .filter_map(|par| {
+            let name = function_parameters::parameter_name(par, configured_functions);
+            (name != nameutil::mangle_keywords(&*par.name)).then(|| (par.name.clone(), name))
+        })
+        .collect();
+    let disable_length_detect = configured_functions
+        .iter()
+        .find_map(|f| f.disable_length_detect)
+        .unwrap_or(env.config.disable_length_detect);
     let no_future = configured_functions.iter().any(|f| f.no_future);
     let unsafe_ = configured_functions.iter().any(|f| f.unsafe_);
+    let safety_doc = unsafe_.then(|| {
+        configured_functions
+            .iter()
+            .find_map(|f| f.safety_doc.clone())
+            .unwrap_or_else(|| {
+                format!(
+                    "The caller must uphold the invariants documented for `{}()`.",
+                    func.c_identifier.as_ref().unwrap_or(&func.name)
+                )
+            })
+    });
     let assertion = configured_functions.iter().find_map(|f| f.assertion);
 
     let imports = &mut imports.with_defaults(version, &cfg_condition);
 
-    let ret = return_value::analyze(
+    let mut ret = return_value::analyze(
         env,
         obj,
         func,
@@ -676,7 +880,11 @@ fn analyze_function(
 
     if let Some(ref f) = ret.parameter {
         if let Type::Function(_) = env.library.type_(f.lib_par.typ) {
-            if env.config.work_mode.is_normal() {
+            if env.config.work_mode.is_normal()
+                && env
+                    .type_status(&f.lib_par.typ.full_name(&env.library))
+                    .ignored()
+            {
                 warn!("Function \"{}\" returns callback", func.name);
                 commented = true;
             }
@@ -780,8 +988,11 @@ fn analyze_function(
     for par in &parameters.rust_parameters {
         // Disallow fundamental arrays without length
         let is_len_for_par = |t: &Transformation| {
-            if let TransformationType::Length { ref array_name, .. } = t.transformation_type {
-                array_name == &par.name
+            if let TransformationType::Length {
+                ref array_names, ..
+            } = t.transformation_type
+            {
+                array_names.contains(&par.name)
             } else {
                 false
             }
@@ -809,7 +1020,13 @@ fn analyze_function(
         commented = true;
     } else if status.need_generate() && !commented {
         if !outs.is_empty() {
-            out_parameters::analyze_imports(env, &func.parameters, imports);
+            out_parameters::analyze_imports(
+                env,
+                func.parameters
+                    .iter()
+                    .filter(|par| !is_out_buffer(env, par, &func.parameters)),
+                imports,
+            );
         }
         if let Some(AsyncTrampoline {
             ref output_params, ..
@@ -860,9 +1077,30 @@ fn analyze_function(
                 transformation
                     .transformation_type
                     .set_to_glib_extra(to_glib_extra);
+                // Objects moved into the C function are upcast by value
+                if let TransformationType::ToGlibPointer { owned: true, .. } =
+                    transformation.transformation_type
+                {
+                    if matches!(
+                        env.library
+                            .type_(parameters.c_parameters[transformation.ind_c].typ),
+                        library::Type::Class(..) | library::Type::Interface(..)
+                    ) {
+                        imports.add("glib::object::Cast");
+                    }
+                }
             }
         }
 
+        if parameters.transformations.iter().any(|t| {
+            matches!(
+                t.transformation_type,
+                TransformationType::ToGlibVariant { .. }
+            )
+        }) {
+            imports.add("glib::ToVariant");
+        }
+
         imports.add_used_types(&used_types);
         if ret.base_tid.is_some() {
             imports.add("glib::object::Cast");
@@ -874,6 +1112,89 @@ fn analyze_function(
         bounds.update_imports(imports);
     }
 
+    let callback_future = if configured_functions
+        .iter()
+        .any(|f| f.async_style == config::functions::AsyncStyle::Callback)
+        && !no_future
+        && status.need_generate()
+        && !commented
+    {
+        analyze_callback_future(
+            env,
+            func,
+            type_tid,
+            new_name.as_ref().unwrap_or(&name),
+            &callbacks,
+            &parameters,
+        )
+    } else {
+        None
+    };
+    if callback_future.is_some() {
+        imports.add("std::boxed::Box as Box_");
+        imports.add("std::pin::Pin");
+    }
+
+    let weak_variant = configured_functions.iter().find_map(|f| f.weak_variant);
+    let cycle_callback = if status.need_generate() && !commented {
+        find_cycle_callback(func, type_tid, &callbacks)
+    } else {
+        None
+    };
+    match (cycle_callback, weak_variant) {
+        (Some(callback), None) if !configured_functions.is_empty() => warn_main!(
+            type_tid,
+            "`{}`: notified callback `{}` receives the object it is set on, capturing the object \
+             in it leaks both, set `weak_variant` to generate a variant holding it weakly",
+            func.name,
+            callback.name
+        ),
+        (Some(callback), None) => info!(
+            "`{}`: notified callback `{}` receives the object it is set on",
+            func.name, callback.name
+        ),
+        (None, Some(true)) if status.need_generate() && !commented => warn_main!(
+            type_tid,
+            "`{}`: weak_variant requires a method with a notified callback receiving its object",
+            func.name
+        ),
+        _ => (),
+    }
+    let weak_callback = match cycle_callback {
+        Some(callback) if weak_variant == Some(true) => analyze_weak_callback(
+            env,
+            func,
+            type_tid,
+            new_name.as_ref().unwrap_or(&name),
+            callback,
+            &parameters,
+            &bounds,
+            configured_functions,
+        ),
+        _ => None,
+    };
+    let callback_cycle = cycle_callback.map(|callback| callback.name.clone());
+
+    let optional_variant = configured_functions.iter().any(|f| f.optional_variant);
+    let optional_outs = if optional_variant && status.need_generate() && !commented {
+        analyze_optional_outs(
+            func,
+            type_tid,
+            new_name.as_ref().unwrap_or(&name),
+            &outs,
+            r#async,
+        )
+    } else {
+        None
+    };
+
+    if let Some(name) = configured_functions
+        .iter()
+        .find_map(|f| f.lifetime_bound_to.as_ref())
+    {
+        bind_lifetime(env, func, &mut parameters, &mut ret, &mut bounds, name);
+    }
+
     let visibility = if commented {
         Visibility::Comment
     } else {
@@ -883,6 +1204,26 @@ fn analyze_function(
     let assertion =
         assertion.unwrap_or_else(|| SafetyAssertionMode::of(env, is_method, &parameters));
 
+    let body_template = configured_functions
+        .iter()
+        .find_map(|f| f.body_template.as_ref())
+        .and_then(|path| {
+            let path = match env.config.config_file.parent() {
+                Some(dir) => dir.join(path),
+                None => path.into(),
+            };
+            match fs::read_to_string(&path) {
+                Ok(template) => Some(template),
+                Err(e) => {
+                    error!(
+                        "Can't read body template {:?} of {}: {}",
+                        path, func.name, e
+                    );
+                    None
+                }
+            }
+        });
+
     Info {
         name,
         func_name: func_name.to_string(),
@@ -903,14 +1244,72 @@ fn analyze_function(
         assertion,
         doc_hidden,
         doc_ignore_parameters,
+        parameter_renames,
         r#async,
         unsafe_,
+        safety_doc,
         trampoline,
         async_future,
+        callback_future,
+        callback_cycle,
+        weak_callback,
+        optional_outs,
+        chainable_setter: None,
         callbacks,
         destroys,
         remove_params: cross_user_data_check.values().cloned().collect::<Vec<_>>(),
+        body_template,
+    }
+}
+
+/// Ties the lifetime of the borrowed return value to the parameter `name`
+/// instead of `self`.
+fn bind_lifetime(
+    env: &Env,
+    func: &library::Function,
+    parameters: &mut Parameters,
+    ret: &mut return_value::Info,
+    bounds: &mut Bounds,
+    name: &str,
+) {
+    let is_borrowed_return = ret
+        .parameter
+        .as_ref()
+        .map_or(false, |par| env.is_ref_only_record(par.lib_par.typ));
+    // Owned records are bound through `glib::wrapper!`, which can't carry a
+    // lifetime
+    if !is_borrowed_return {
+        warn!(
+            "Function \"{}\" has lifetime_bound_to but doesn't return a borrowed ref-only record",
+            func.name
+        );
+        return;
     }
+
+    let borrowed_parameter = parameters
+        .rust_parameters
+        .iter()
+        .filter(|par| par.name == name)
+        .map(|par| par.ind_c)
+        .find(|&ind_c| {
+            let c_par = &parameters.c_parameters[ind_c];
+            !c_par.instance_parameter && c_par.ref_mode.is_ref()
+        });
+    let ind_c = match borrowed_parameter {
+        Some(ind_c) => ind_c,
+        None => {
+            warn!(
+                "Function \"{}\" has no borrowed parameter `{}` for lifetime_bound_to",
+                func.name, name
+            );
+            return;
+        }
+    };
+
+    let lifetime = 'a';
+    parameters.c_parameters[ind_c].lifetime = Some(lifetime);
+    ret.lifetime = Some(lifetime);
+    bounds.add_lifetime(lifetime);
 }
 
 pub fn is_carray_with_direct_elements(env: &Env, typ: library::TypeId) -> bool {
@@ -953,10 +1352,11 @@ fn analyze_async(
         // Checks for /*Ignored*/ or other error comments
         *commented |= callback_type.contains("/*");
         let func_name = func.c_identifier.as_ref().unwrap();
-        let finish_func_name = finish_function_name(func_name);
+        let (finish_func_name, finish_func) =
+            find_finish_function(env, func_name, configured_functions);
         let mut output_params = vec![];
         let mut ffi_ret = None;
-        if let Some(function) = find_function(env, &finish_func_name) {
+        if let Some(function) = finish_func {
             if use_function_return_for_result(
                 env,
                 function.ret.typ,
@@ -976,6 +1376,7 @@ fn analyze_async(
                     lib_par.name = nameutil::mangle_keywords(&*param.name).into_owned();
                 }
                 let configured_parameters = configured_functions.matched_parameters(&lib_par.name);
+                lib_par.name = function_parameters::parameter_name(param, configured_functions);
                 output_params.push(analysis::Parameter::from_parameter(
                     env,
                     &lib_par,
@@ -1023,6 +1424,11 @@ fn analyze_async(
         });
 
         if !no_future {
+            let request = if configured_functions.iter().any(|f| f.into_future) {
+                analyze_future_request(env, func, type_tid, codegen_name, parameters)
+            } else {
+                None
+            };
             *async_future = Some(AsyncFuture {
                 is_method,
                 name: format!("{}_future", codegen_name),
@@ -1034,6 +1440,7 @@ fn analyze_async(
                     // need to do it twice.
                     _ => SafetyAssertionMode::Skip,
                 },
+                request,
             });
         }
         true
@@ -1042,6 +1449,342 @@ fn analyze_async(
     }
 }
 
+fn analyze_future_request(
+    env: &Env,
+    func: &library::Function,
+    type_tid: library::TypeId,
+    codegen_name: &str,
+    parameters: &function_parameters::Parameters,
+) -> Option<FutureRequest> {
+    let has_options = parameters
+        .rust_parameters
+        .iter()
+        .filter(|par| !parameters.c_parameters[par.ind_c].instance_parameter)
+        .any(|par| !matches!(&*par.name, "callback" | "cancellable" | "io_priority"));
+    if func.kind != FunctionKind::Method || has_options {
+        warn_main!(
+            type_tid,
+            "`{}`: into_future requires a method without other parameters than `io_priority` and `cancellable`",
+            func.name
+        );
+        return None;
+    }
+
+    let base_name = codegen_name.strip_suffix("_async").unwrap_or(codegen_name);
+    Some(FutureRequest {
+        method_name: format!("{}_request", base_name),
+        type_name: format!(
+            "{}{}Request",
+            env.library.type_(type_tid).get_name(),
+            base_name.to_camel()
+        ),
+    })
+}
+
+fn analyze_callback_future(
+    env: &Env,
+    func: &library::Function,
+    type_tid: library::TypeId,
+    codegen_name: &str,
+    callbacks: &[Trampoline],
+    parameters: &function_parameters::Parameters,
+) -> Option<CallbackFuture> {
+    // The future is a `gio::GioFuture`
+    if !env.library.is_crate("Gio")
+        && !env
+            .config
+            .external_libraries
+            .iter()
+            .any(|lib| lib.namespace == "Gio")
+    {
+        warn_main!(
+            type_tid,
+            "`{}`: async_style = \"callback\" requires Gio in options.external_libraries",
+            func.name
+        );
+        return None;
+    }
+    let callback = match callbacks {
+        // Only async callbacks are bound as `FnOnce`, which the future's
+        // callback moving its sender out needs
+        [callback] if callback.scope.is_async() && callback.ret.typ == Default::default() => {
+            callback
+        }
+        _ => {
+            warn_main!(
+                type_tid,
+                "`{}`: async_style = \"callback\" requires a single async-scoped callback returning nothing",
+                func.name
+            );
+            return None;
+        }
+    };
+
+    let mut outputs = Vec::new();
+    let mut arguments = Vec::new();
+    for transformation in &callback.parameters.transformations {
+        if transformation.name == "this"
+            || callback.parameters.c_parameters[transformation.ind_c].is_real_gpointer(env)
+        {
+            continue;
+        }
+        let par = &callback.parameters.rust_parameters[transformation.ind_rust];
+        let ref_mode = if par.ref_mode == RefMode::ByRefMut {
+            RefMode::ByRef
+        } else {
+            par.ref_mode
+        };
+        let rust_type = match RustType::builder(env, par.typ)
+            .direction(par.direction)
+            .nullable(par.nullable)
+            .ref_mode(ref_mode)
+            .try_build_param()
+        {
+            Ok(rust_type) => rust_type.into_string(),
+            Err(_) => {
+                warn_main!(
+                    type_tid,
+                    "`{}`: unsupported callback argument `{}` for a future",
+                    func.name,
+                    transformation.name
+                );
+                return None;
+            }
+        };
+        let (output, conversion) = owned_callback_argument(&rust_type, &transformation.name);
+        outputs.push(output);
+        arguments.push((transformation.name.clone(), conversion));
+    }
+
+    let output = if outputs.len() == 1 {
+        outputs.remove(0)
+    } else {
+        format!("({})", outputs.join(", "))
+    };
+    let is_method = func.kind == FunctionKind::Method;
+
+    Some(CallbackFuture {
+        is_method,
+        name: format!("{}_future", codegen_name),
+        callback_name: callback.name.clone(),
+        output,
+        arguments,
+        assertion: match SafetyAssertionMode::of(env, is_method, parameters) {
+            SafetyAssertionMode::None => SafetyAssertionMode::None,
+            // the future calls the callback-based function which already has the init check
+            _ => SafetyAssertionMode::Skip,
+        },
+    })
+}
+
+/// Notified callback of a method taking the object the method is called on,
+/// which it keeps alive for as long as it is set.
+fn find_cycle_callback<'a>(
+    func: &library::Function,
+    type_tid: library::TypeId,
+    callbacks: &'a [Trampoline],
+) -> Option<&'a Trampoline> {
+    if func.kind != FunctionKind::Method {
+        return None;
+    }
+    callbacks.iter().find(|callback| {
+        callback.scope == ParameterScope::Notified
+            && callback
+                .parameters
+                .rust_parameters
+                .iter()
+                .any(|par| par.typ == type_tid)
+    })
+}
+
+fn analyze_optional_outs(
+    func: &library::Function,
+    type_tid: library::TypeId,
+    codegen_name: &str,
+    outs: &out_parameters::Info,
+    is_async: bool,
+) -> Option<OptionalOuts> {
+    // Arrays and their lengths are always requested together
+    let is_array_length = |name: &str| {
+        func.parameters.iter().any(|par| {
+            par.array_length
+                .and_then(|pos| func.parameters.get(pos as usize))
+                .map_or(false, |length| {
+                    nameutil::mangle_keywords(&*length.name) == name
+                })
+        })
+    };
+    let parameters = outs
+        .iter()
+        .filter(|out| {
+            out.lib_par.optional
+                && !out.lib_par.is_error
+                && out.lib_par.array_length.is_none()
+                && !is_array_length(&out.lib_par.name)
+        })
+        .map(|out| out.lib_par.name.clone())
+        .collect::<Vec<_>>();
+    if is_async || parameters.is_empty() {
+        warn_main!(
+            type_tid,
+            "`{}`: optional_variant requires optional out parameters returned by the function",
+            func.name
+        );
+        return None;
+    }
+
+    Some(OptionalOuts {
+        name: format!("{}_optional", codegen_name),
+        parameters,
+    })
+}
+
+fn analyze_weak_callback(
+    env: &Env,
+    func: &library::Function,
+    type_tid: library::TypeId,
+    codegen_name: &str,
+    callback: &Trampoline,
+    parameters: &function_parameters::Parameters,
+    bounds: &Bounds,
+    configured_functions: &[&config::functions::Function],
+) -> Option<WeakCallback> {
+    // `WeakRef` isn't `Send`, and there is nothing to return once the object is gone
+    if callback.concurrency != library::Concurrency::None || callback.ret.typ != Default::default()
+    {
+        warn_main!(
+            type_tid,
+            "`{}`: weak_variant requires a callback returning nothing and not sent to other threads",
+            func.name
+        );
+        return None;
+    }
+
+    let names = callback
+        .parameters
+        .transformations
+        .iter()
+        .filter(|transformation| {
+            transformation.name != "this"
+                && !callback.parameters.c_parameters[transformation.ind_c].is_real_gpointer(env)
+        })
+        .map(|transformation| transformation.name.clone())
+        .collect::<Vec<_>>();
+    // Nullable callbacks are boxed instead of bound, but the variant always takes one
+    let mut bounds = bounds.clone();
+    if bounds.get_parameter_bound(&callback.name).is_none() {
+        let mut par = parameters
+            .c_parameters
+            .iter()
+            .find(|par| par.name == callback.name)?
+            .clone();
+        par.nullable = library::Nullable(false);
+        bounds.add_for_parameter(
+            env,
+            func,
+            &par,
+            false,
+            callback.concurrency,
+            configured_functions,
+        );
+    }
+    // The wrapping closure is typed explicitly, as it can't be inferred
+    // from the bound once boxed for nullable callbacks
+    let types = bounds
+        .get_parameter_bound(&callback.name)
+        .map_or_else(Vec::new, |bound| closure_argument_types(&bound.type_str));
+    if names.len() != types.len() {
+        warn_main!(
+            type_tid,
+            "`{}`: unexpected callback arguments for weak_variant",
+            func.name
+        );
+        return None;
+    }
+    let arguments: Vec<(String, String)> = names.into_iter().zip(types).collect();
+    let is_free = |name: &&str| {
+        !arguments.iter().any(|(argument, _)| argument == name)
+            && !parameters
+                .rust_parameters
+                .iter()
+                .any(|par| &par.name == name)
+    };
+    let object_name = ["object", "weak_object"].into_iter().find(is_free)?;
+    let (bounds, object_alias) = bounds.with_callback_object(
+        &callback.name,
+        object_name,
+        &nameutil::use_glib_type(env, "ObjectType"),
+    )?;
+
+    Some(WeakCallback {
+        name: format!("{}_weak", codegen_name),
+        callback_name: callback.name.clone(),
+        object_name: object_name.to_owned(),
+        object_alias,
+        arguments,
+        bounds,
+    })
+}
+
+/// Splits the argument types of a closure bound like `Fn(&Widget, i32) + 'static`
+fn closure_argument_types(bound: &str) -> Vec<String> {
+    let start = match bound.find('(') {
+        Some(pos) => pos + 1,
+        None => return Vec::new(),
+    };
+    let mut types = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0;
+    let mut previous = ' ';
+    for c in bound[start..].chars() {
+        match c {
+            '(' | '<' | '[' => depth += 1,
+            ')' if depth == 0 => break,
+            '>' if previous == '-' => (),
+            ')' | '>' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                types.push(current.trim().to_owned());
+                current.clear();
+                previous = c;
+                continue;
+            }
+            _ => (),
+        }
+        current.push(c);
+        previous = c;
+    }
+    if !current.trim().is_empty() {
+        types.push(current.trim().to_owned());
+    }
+    types
+}
+
+/// Returns the owned type of a callback argument along with the expression
+/// converting the borrowed argument into it.
+fn owned_callback_argument(rust_type: &str, name: &str) -> (String, String) {
+    fn owned(borrowed: &str) -> String {
+        match borrowed {
+            "str" => "String".to_owned(),
+            _ if borrowed.starts_with('[') => format!("Vec<{}>", &borrowed[1..borrowed.len() - 1]),
+            _ => borrowed.to_owned(),
+        }
+    }
+
+    if let Some(inner) = rust_type
+        .strip_prefix("Option<&")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        (
+            format!("Option<{}>", owned(inner)),
+            format!("{}.map(ToOwned::to_owned)", name),
+        )
+    } else if let Some(inner) = rust_type.strip_prefix('&') {
+        (owned(inner), format!("{}.to_owned()", name))
+    } else {
+        (rust_type.to_owned(), name.to_owned())
+    }
+}
+
 fn analyze_callback(
     func_name: &str,
     type_tid: library::TypeId,
@@ -1192,6 +1935,7 @@ fn analyze_callback(
                     name: par.name.to_string(),
                     parameters,
                     ret: func.ret.clone(),
+                    outs: Vec::new(),
                     bound_name: match callback_info {
                         Some(x) => x.bound_name.to_string(),
                         None => match RustType::builder(env, par.typ)
@@ -1222,6 +1966,7 @@ fn analyze_callback(
                     destroy_index: 0,
                     nullable: par.nullable,
                     type_name: env.library.type_(type_tid).get_name(),
+                    is_shared: false,
                 },
                 par.destroy_index
                     .map(|destroy_index| c_parameters[destroy_index].1),
@@ -1265,12 +2010,58 @@ pub fn find_function<'a>(env: &'a Env, c_identifier: &str) -> Option<&'a Functio
 }
 
 /// Given async function name tries to guess the name of finish function.
-pub fn finish_function_name(mut func_name: &str) -> String {
+pub fn finish_function_name(func_name: &str) -> String {
+    finish_function_name_with_suffix(func_name, "_finish")
+}
+
+fn finish_function_name_with_suffix(mut func_name: &str, suffix: &str) -> String {
     if func_name.ends_with("_async") {
         let len = func_name.len() - "_async".len();
         func_name = &func_name[0..len];
     }
-    format!("{}_finish", &func_name)
+    format!("{}{}", &func_name, suffix)
+}
+
+/// Finds the finish function of an async function: the configured
+/// `finish_function` or the first existing one named with one of the
+/// `finish_function_suffixes`.
+///
+/// The name is still returned when no such function exists.
+pub fn find_finish_function<'a>(
+    env: &'a Env,
+    func_name: &str,
+    configured_functions: &[&config::functions::Function],
+) -> (String, Option<&'a Function>) {
+    if let Some(name) = configured_functions
+        .iter()
+        .find_map(|f| f.finish_function.as_ref())
+    {
+        let function = find_function(env, name);
+        if function.is_none() {
+            error!(
+                "Finish function {} configured for {} not found",
+                name, func_name
+            );
+        }
+        return (name.clone(), function);
+    }
+
+    let candidates: Vec<String> = env
+        .config
+        .finish_function_suffixes
+        .iter()
+        .map(|suffix| finish_function_name_with_suffix(func_name, suffix))
+        .collect();
+    candidates
+        .iter()
+        .find_map(|name| find_function(env, name).map(|function| (name.clone(), Some(function))))
+        .unwrap_or_else(|| {
+            let name = candidates
+                .into_iter()
+                .next()
+                .unwrap_or_else(|| finish_function_name(func_name));
+            (name, None)
+        })
 }
 
 pub fn find_index_to_ignore<'a>(
@@ -1288,6 +2079,23 @@ pub fn find_index_to_ignore<'a>(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_closure_argument_types() {
+        assert_eq!(
+            closure_argument_types("Fn(&Widget, i32) + 'static"),
+            vec!["&Widget", "i32"]
+        );
+        assert_eq!(
+            closure_argument_types("FnMut(Option<&str>, &HashMap<String, i32>, &[u8]) + 'static"),
+            vec!["Option<&str>", "&HashMap<String, i32>", "&[u8]"]
+        );
+        assert_eq!(
+            closure_argument_types("Fn(&dyn Fn(i32) -> bool, u8)"),
+            vec!["&dyn Fn(i32) -> bool", "u8"]
+        );
+        assert!(closure_argument_types("Fn() + 'static").is_empty());
+    }
+
     #[test]
     fn test_finish_function_name() {
         assert_eq!(
@@ -1295,5 +2103,32 @@ mod tests {
             &finish_function_name("g_file_copy_async")
         );
         assert_eq!("g_bus_get_finish", &finish_function_name("g_bus_get"));
+        assert_eq!(
+            "foo_load_complete",
+            &finish_function_name_with_suffix("foo_load_async", "_complete")
+        );
+    }
+
+    #[test]
+    fn test_owned_callback_argument() {
+        assert_eq!(
+            owned_callback_argument("&str", "name"),
+            ("String".to_owned(), "name.to_owned()".to_owned())
+        );
+        assert_eq!(
+            owned_callback_argument("Option<&Widget>", "widget"),
+            (
+                "Option<Widget>".to_owned(),
+                "widget.map(ToOwned::to_owned)".to_owned()
+            )
+        );
+        assert_eq!(
+            owned_callback_argument("&[u8]", "data"),
+            ("Vec<u8>".to_owned(), "data.to_owned()".to_owned())
+        );
+        assert_eq!(
+            owned_callback_argument("i32", "count"),
+            ("i32".to_owned(), "count".to_owned())
+        );
     }
 }
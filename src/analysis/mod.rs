@@ -3,7 +3,7 @@ use crate::{
     library::{self, Type, TypeId},
 };
 use imports::Imports;
-use log::error;
+use log::{error, info};
 use std::collections::BTreeMap;
 
 pub mod bounds;
@@ -11,6 +11,7 @@ pub mod c_type;
 pub mod child_properties;
 pub mod class_builder;
 pub mod class_hierarchy;
+pub mod class_record;
 pub mod constants;
 pub mod conversion_type;
 pub mod enums;
@@ -36,18 +37,23 @@ pub mod safety_assertion_mode;
 pub mod signals;
 pub mod signatures;
 pub mod special_functions;
+pub mod strip;
+pub mod subclass;
 pub mod supertypes;
 pub mod symbols;
 pub mod trampoline_parameters;
 pub mod trampolines;
 pub mod try_from_glib;
 pub mod types;
+pub mod varargs;
 
 #[derive(Debug, Default)]
 pub struct Analysis {
     pub objects: BTreeMap<String, object::Info>,
     pub records: BTreeMap<String, record::Info>,
     pub global_functions: Option<info_base::InfoBase>,
+    /// Macros binding the global functions with variadic arguments
+    pub varargs_macros: Vec<varargs::Info>,
     pub constants: Vec<constants::Info>,
 
     pub enumerations: Vec<enums::Info>,
@@ -195,6 +201,40 @@ pub fn run(env: &mut Env) {
 
     // Analyze free functions as the last step once all types are analyzed
     analyze_global_functions(env);
+
+    report_boxed_records(env);
+}
+
+/// Lists the records without GType wrapped through their own copy and free
+/// functions, for review.
+fn report_boxed_records(env: &Env) {
+    let records = env
+        .analysis
+        .records
+        .values()
+        .filter(|record| {
+            record.glib_get_type.is_none()
+                && !record.ref_only
+                && record.specials.has_trait(special_functions::Type::Copy)
+                && record.specials.has_trait(special_functions::Type::Free)
+                && !record.specials.has_trait(special_functions::Type::Ref)
+        })
+        .map(|record| {
+            let traits = record.specials.traits();
+            format!(
+                "{} ({}, {})",
+                record.full_name,
+                traits[&special_functions::Type::Copy].glib_name,
+                traits[&special_functions::Type::Free].glib_name
+            )
+        })
+        .collect::<Vec<_>>();
+    if !records.is_empty() {
+        info!(
+            "Records without GType boxed through their copy and free functions: {}",
+            records.join(", ")
+        );
+    }
 }
 
 fn analyze_enums(env: &mut Env) {
@@ -260,6 +300,8 @@ fn analyze_global_functions(env: &mut Env) {
         return;
     }
 
+    env.analysis.varargs_macros = varargs::analyze(env, &functions, obj);
+
     let mut imports = imports::Imports::new(&env.library);
     imports.add("glib::translate::*");
 
@@ -342,3 +384,7 @@ fn is_all_deps_analyzed(env: &mut Env, deps: &[TypeId]) -> bool {
 pub fn is_gpointer(s: &str) -> bool {
     s == "gpointer" || s == "void*"
 }
+
+pub fn is_gconstpointer(s: &str) -> bool {
+    s == "gconstpointer" || s == "const void*"
+}
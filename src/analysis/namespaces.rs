@@ -12,6 +12,7 @@ pub struct Namespace {
     pub sys_crate_name: String,
     pub higher_crate_name: String,
     pub package_name: Option<String>,
+    pub identifier_prefixes: Vec<String>,
     pub symbol_prefixes: Vec<String>,
     pub shared_libs: Vec<String>,
     pub versions: Vec<Version>,
@@ -56,6 +57,7 @@ pub fn run(gir: &library::Library) -> Info {
             sys_crate_name,
             higher_crate_name,
             package_name: ns.package_name.clone(),
+            identifier_prefixes: ns.identifier_prefixes.clone(),
             symbol_prefixes: ns.symbol_prefixes.clone(),
             shared_libs: ns.shared_library.clone(),
             versions: ns.versions.iter().cloned().collect(),
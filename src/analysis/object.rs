@@ -1,6 +1,6 @@
 use super::{
     child_properties::ChildProperties, imports::Imports, info_base::InfoBase,
-    signatures::Signatures, *,
+    signatures::Signatures, trampolines::Trampolines, *,
 };
 use crate::{
     config::gobjects::{GObject, GStatus},
@@ -8,8 +8,9 @@ use crate::{
     library::{self, FunctionKind},
     nameutil::*,
     traits::*,
+    version::Version,
 };
-use log::info;
+use log::{error, info, warn};
 use std::{borrow::Cow, ops::Deref};
 
 /// The location of an item within the object
@@ -36,11 +37,16 @@ pub struct Info {
     pub has_functions: bool,
     pub signals: Vec<signals::Info>,
     pub notify_signals: Vec<signals::Info>,
+    pub shared_trampolines: Trampolines,
     pub properties: Vec<properties::Property>,
     pub builder_properties: Vec<(Vec<properties::Property>, TypeId)>,
     pub builder_postprocess: Option<String>,
     pub child_properties: ChildProperties,
     pub signatures: Signatures,
+    /// String property (and its version) used for the `Display` implementation
+    pub display_property: Option<(String, Option<Version>)>,
+    pub class_record: Option<class_record::ClassRecord>,
+    pub subclass: Option<subclass::Subclass>,
 }
 
 impl Info {
@@ -156,6 +162,8 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
     }
 
     let supertypes = supertypes::analyze(env, class_tid, version, &mut imports);
+    supertypes::check_hierarchy(env, class_tid, &supertypes);
+    supertypes::check_template_child(env, class_tid, &klass.c_type, &klass.glib_get_type);
 
     let final_type = klass.final_type;
     let trait_name = obj
@@ -191,8 +199,10 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         specials.traits_mut().remove(t);
     }
     special_functions::analyze_imports(&specials, &mut imports);
+    let display_property =
+        analyze_display_property(obj, &klass.properties, &specials, &mut imports);
 
-    let signals = signals::analyze(
+    let mut signals = signals::analyze(
         env,
         &klass.signals,
         class_tid,
@@ -200,7 +210,7 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         obj,
         &mut imports,
     );
-    let (properties, notify_signals) = properties::analyze(
+    let (mut properties, mut notify_signals) = properties::analyze(
         env,
         &klass.properties,
         class_tid,
@@ -210,9 +220,28 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         &signatures,
         deps,
     );
+    properties::resolve_conflicts(env, obj, &mut functions, &mut properties);
+    properties::analyze_chainable_setters(env, &klass.properties, &mut functions);
+    let shared_trampolines = deduplicate_trampolines(&mut signals, &mut notify_signals);
+    let subclass = subclass::analyze(
+        env,
+        obj,
+        class_tid,
+        klass.c_class_type.as_deref(),
+        &signals,
+        &mut imports,
+    );
 
     let builder_properties =
         class_builder::analyze(env, &klass.properties, class_tid, obj, &mut imports);
+    class_builder::check_construct_only(
+        env,
+        &klass.properties,
+        class_tid,
+        obj,
+        &builder_properties,
+        &functions,
+    );
 
     let child_properties =
         child_properties::analyze(env, obj.child_properties.as_ref(), class_tid, &mut imports);
@@ -275,6 +304,8 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         c_type: klass.c_type.clone(),
         c_class_type: klass.c_class_type.clone(),
         get_type: klass.glib_get_type.clone(),
+        class_record: analyze_class_record(env, obj, class_tid, klass.c_class_type.as_deref()),
+        subclass,
         is_interface: false,
         supertypes,
         final_type,
@@ -284,16 +315,75 @@ pub fn class(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info>
         has_functions,
         signals,
         notify_signals,
+        shared_trampolines,
         properties,
         builder_properties,
         builder_postprocess: obj.builder_postprocess.clone(),
         child_properties,
         signatures,
+        display_property,
     };
 
     Some(info)
 }
 
+fn analyze_class_record(
+    env: &Env,
+    obj: &GObject,
+    type_tid: library::TypeId,
+    c_class_type: Option<&str>,
+) -> Option<class_record::ClassRecord> {
+    if !obj.generate_class_record {
+        return None;
+    }
+    let class_record = c_class_type.and_then(|c_type| class_record::analyze(env, type_tid, c_type));
+    if class_record.is_none() {
+        warn!("No class structure to generate for {}", obj.name);
+    }
+    class_record
+}
+
+/// Resolves `display_from` to a string property when no method provides `Display`.
+fn analyze_display_property(
+    obj: &GObject,
+    properties: &[library::Property],
+    specials: &special_functions::Infos,
+    imports: &mut Imports,
+) -> Option<(String, Option<Version>)> {
+    let name = obj.display_from.as_ref()?;
+    if specials.has_trait(special_functions::Type::Display) {
+        return None;
+    }
+    let prop = properties
+        .iter()
+        .find(|p| p.name == *name || p.name == name.replace('_', "-"));
+    match prop {
+        Some(prop) if prop.readable && prop.typ == TypeId::tid_utf8() => {
+            imports.add_with_version("std::fmt", prop.version);
+            Some((prop.name.clone(), prop.version))
+        }
+        _ => {
+            error!(
+                "`display_from` of {} must name a stringifying method or a readable string property, got `{}`",
+                obj.name, name
+            );
+            None
+        }
+    }
+}
+
+fn deduplicate_trampolines(
+    signals: &mut [signals::Info],
+    notify_signals: &mut [signals::Info],
+) -> Trampolines {
+    trampolines::deduplicate(
+        signals
+            .iter_mut()
+            .chain(notify_signals.iter_mut())
+            .filter_map(|s| s.trampoline.as_mut().ok()),
+    )
+}
+
 pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<Info> {
     info!("Analyzing interface {}", obj.name);
     let full_name = obj.name.clone();
@@ -316,6 +406,7 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
     }
 
     let supertypes = supertypes::analyze(env, iface_tid, version, &mut imports);
+    supertypes::check_hierarchy(env, iface_tid, &supertypes);
 
     let trait_name = obj
         .trait_name
@@ -325,7 +416,7 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
 
     let mut signatures = Signatures::with_capacity(iface.functions.len());
 
-    let functions = functions::analyze(
+    let mut functions = functions::analyze(
         env,
         &iface.functions,
         iface_tid,
@@ -337,8 +428,11 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         Some(deps),
     );
 
-    let signals = signals::analyze(env, &iface.signals, iface_tid, true, obj, &mut imports);
-    let (properties, notify_signals) = properties::analyze(
+    let display_property =
+        analyze_display_property(obj, &iface.properties, &Default::default(), &mut imports);
+
+    let mut signals = signals::analyze(env, &iface.signals, iface_tid, true, obj, &mut imports);
+    let (mut properties, mut notify_signals) = properties::analyze(
         env,
         &iface.properties,
         iface_tid,
@@ -348,6 +442,17 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         &signatures,
         deps,
     );
+    properties::resolve_conflicts(env, obj, &mut functions, &mut properties);
+    properties::analyze_chainable_setters(env, &iface.properties, &mut functions);
+    let shared_trampolines = deduplicate_trampolines(&mut signals, &mut notify_signals);
+    let subclass = subclass::analyze(
+        env,
+        obj,
+        iface_tid,
+        iface.c_class_type.as_deref(),
+        &signals,
+        &mut imports,
+    );
 
     if obj.concurrency == library::Concurrency::SendUnique {
         imports.add("glib::ObjectExt");
@@ -373,6 +478,8 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         c_type: iface.c_type.clone(),
         c_class_type: iface.c_class_type.clone(),
         get_type: iface.glib_get_type.clone(),
+        class_record: analyze_class_record(env, obj, iface_tid, iface.c_class_type.as_deref()),
+        subclass,
         is_interface: true,
         supertypes,
         final_type: false,
@@ -381,8 +488,10 @@ pub fn interface(env: &Env, obj: &GObject, deps: &[library::TypeId]) -> Option<I
         has_functions,
         signals,
         notify_signals,
+        shared_trampolines,
         properties,
         signatures,
+        display_property,
         ..Default::default()
     };
 
@@ -1,14 +1,17 @@
 use crate::{
     analysis::{
-        self, conversion_type::ConversionType, function_parameters::CParameter,
-        functions::is_carray_with_direct_elements, imports::Imports, return_value,
+        self,
+        conversion_type::ConversionType,
+        function_parameters::{self, is_out_buffer, CParameter},
+        functions::is_carray_with_direct_elements,
+        imports::Imports,
+        record_type::is_plain_record,
+        return_value,
         rust_type::RustType,
     },
     config::{self, parameter_matchable::ParameterMatchable},
     env::Env,
-    library::{
-        self, Function, Fundamental, Nullable, ParameterDirection, Type, TypeId, INTERNAL_NAMESPACE,
-    },
+    library::{self, Function, Fundamental, ParameterDirection, Type, TypeId, INTERNAL_NAMESPACE},
     nameutil,
 };
 use log::error;
@@ -56,17 +59,16 @@ pub fn analyze(
     let mut info: Info = Default::default();
     let mut unsupported_outs = false;
 
-    let nullable_override = configured_functions.iter().find_map(|f| f.ret.nullable);
     if func.throws {
         let use_ret = use_return_value_for_result(env, func_ret, &func.name, configured_functions);
         info.mode = Mode::Throws(use_ret);
     } else if func.ret.typ == TypeId::tid_none() {
         info.mode = Mode::Normal;
     } else if func.ret.typ == TypeId::tid_bool() || func.ret.typ == TypeId::tid_c_bool() {
-        if nullable_override == Some(Nullable(false)) {
-            info.mode = Mode::Combined;
-        } else {
+        if bool_return_is_option(configured_functions) {
             info.mode = Mode::Optional;
+        } else {
+            info.mode = Mode::Combined;
         }
     } else {
         info.mode = Mode::Combined;
@@ -76,10 +78,15 @@ pub fn analyze(
         if lib_par.direction != ParameterDirection::Out {
             continue;
         }
+        if is_out_buffer(env, lib_par, &func.parameters) {
+            // Passed by the caller as `&mut Vec<T>`
+            continue;
+        }
         if can_as_return(env, lib_par) {
             let mut lib_par = lib_par.clone();
-            lib_par.name = nameutil::mangle_keywords(&lib_par.name).into_owned();
-            let configured_parameters = configured_functions.matched_parameters(&lib_par.name);
+            let configured_parameters =
+                configured_functions.matched_parameters(&nameutil::mangle_keywords(&lib_par.name));
+            lib_par.name = function_parameters::parameter_name(&lib_par, configured_functions);
             let mut out =
                 analysis::Parameter::from_parameter(env, &lib_par, &configured_parameters);
 
@@ -109,7 +116,7 @@ pub fn analyze(
         if let Some(ref par) = func_ret.parameter {
             ret.lib_par.typ = par.lib_par.typ;
         }
-        if let Some(val) = nullable_override {
+        if let Some(val) = configured_functions.iter().find_map(|f| f.ret.nullable) {
             ret.lib_par.nullable = val;
         }
         info.params.insert(0, ret);
@@ -118,6 +125,23 @@ pub fn analyze(
     (info, unsupported_outs)
 }
 
+/// Whether the out parameters of a function returning a success boolean are
+/// returned as `Option<T>`, or `Option<(A, B)>` for several of them, which is
+/// the default, instead of being returned along with the boolean.
+fn bool_return_is_option(configured_functions: &[&config::functions::Function]) -> bool {
+    configured_functions
+        .iter()
+        .find_map(|f| f.ret.bool_return_is_option)
+        // `nullable = false` on the return used to be the only way to opt out
+        .or_else(|| {
+            configured_functions
+                .iter()
+                .find_map(|f| f.ret.nullable)
+                .map(|nullable| *nullable)
+        })
+        .unwrap_or(true)
+}
+
 pub fn analyze_imports<'a>(
     env: &Env,
     parameters: impl IntoIterator<Item = &'a library::Parameter>,
@@ -142,6 +166,9 @@ fn analyze_type_imports(env: &Env, typ: TypeId, caller_allocates: bool, imports:
         {
             imports.add("std::mem")
         }
+        Type::Record(..) if caller_allocates && is_plain_record(env, typ) => {
+            imports.add("std::mem")
+        }
         _ if !caller_allocates => match ConversionType::of(env, typ) {
             ConversionType::Direct
             | ConversionType::Scalar
@@ -162,6 +189,10 @@ pub fn can_as_return(env: &Env, par: &library::Parameter) -> bool {
             if is_carray_with_direct_elements(env, par.typ) && par.array_length.is_none() {
                 return false;
             }
+            // Ref-only records can only be borrowed from a return value
+            if env.is_ref_only_record(par.typ) {
+                return false;
+            }
 
             RustType::builder(env, par.typ)
                 .direction(ParameterDirection::Out)
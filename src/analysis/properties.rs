@@ -1,6 +1,7 @@
 use crate::{
     analysis::{
         bounds::{Bounds, PropertyBound},
+        functions,
         imports::Imports,
         ref_mode::RefMode,
         rust_type::RustType,
@@ -8,7 +9,9 @@ use crate::{
         signatures::{Signature, Signatures},
         trampolines,
     },
-    config::{self, GObject, PropertyGenerateFlags},
+    config::{
+        self, gobjects::GStatus, properties::PropertyConflict, GObject, PropertyGenerateFlags,
+    },
     env::Env,
     library, nameutil,
     traits::*,
@@ -144,7 +147,13 @@ fn analyze_property(
             type_name, name
         );
     }
-    if generate_set && generate.contains(PropertyGenerateFlags::SET) && !writable {
+    if generate_set && generate.contains(PropertyGenerateFlags::SET) && prop.construct_only {
+        warn!(
+            "Attempt to generate setter for construct-only property \"{}.{}\", \
+             it can only be set at construction",
+            type_name, name
+        );
+    } else if generate_set && generate.contains(PropertyGenerateFlags::SET) && !writable {
         warn!(
             "Attempt to generate setter for nonwritable property \"{}.{}\"",
             type_name, name
@@ -301,6 +310,7 @@ fn analyze_property(
                     direction: library::ParameterDirection::Return,
                     transfer: library::Transfer::None,
                     caller_allocates: false,
+                    optional: false,
                     nullable: library::Nullable(false),
                     allow_none: false,
                     array_length: None,
@@ -344,6 +354,7 @@ fn analyze_property(
                 deprecated_version: prop.deprecated_version,
                 doc_hidden: false,
                 is_detailed: false, // see above comment
+                default_handler: None,
             })
         } else {
             None
@@ -355,6 +366,78 @@ fn analyze_property(
     (getter, setter, notify_signal)
 }
 
+/// Resolves the name collisions between property accessors and methods
+/// following the `property_conflict` policy of the object.
+pub fn resolve_conflicts(
+    env: &Env,
+    obj: &GObject,
+    functions: &mut [functions::Info],
+    properties: &mut Vec<Property>,
+) {
+    let policy = obj
+        .property_conflict
+        .unwrap_or(env.config.property_conflict);
+    properties.retain_mut(|prop| {
+        let func = match functions.iter_mut().find(|f| {
+            f.kind == library::FunctionKind::Method
+                && f.status.need_generate()
+                && f.codegen_name() == prop.func_name
+        }) {
+            Some(func) => func,
+            None => return true,
+        };
+        warn!(
+            "Property accessor `{}` of {} has the name of a method, resolved with `{:?}` policy",
+            prop.func_name, obj.name, policy
+        );
+        match policy {
+            PropertyConflict::Method => false,
+            PropertyConflict::Property => {
+                func.status = GStatus::Ignore;
+                true
+            }
+            PropertyConflict::Rename => {
+                prop.func_name = format!("{}_property", prop.func_name);
+                true
+            }
+        }
+    });
+}
+
+/// Adds a chainable `with_<property>` variant to the C setters of the
+/// properties, like the one generated next to the property setters
+pub fn analyze_chainable_setters(
+    env: &Env,
+    props: &[library::Property],
+    functions: &mut [functions::Info],
+) {
+    if !env.config.generate_chainable_setters {
+        return;
+    }
+    for prop in props {
+        if !prop.writable || prop.construct_only {
+            continue;
+        }
+        let name_for_func = nameutil::signal_to_snake(&prop.name);
+        let set_func_name = format!("set_{}", name_for_func);
+        if let Some(func) = functions.iter_mut().find(|f| {
+            f.kind == library::FunctionKind::Method
+                && f.status.need_generate()
+                && f.visibility == functions::Visibility::Public
+                && f.codegen_name() == set_func_name
+                // Only `self` and the value
+                && f.parameters.rust_parameters.len() == 2
+                && f.outs.is_empty()
+                && f.ret.parameter.is_none()
+                && f.ret.bool_return_is_error.is_none()
+                && !f.r#async
+                && !f.unsafe_
+        }) {
+            func.chainable_setter = Some(format!("with_{}", name_for_func));
+        }
+    }
+}
+
 /// Returns (the list of get functions to check, the desired get function name).
 fn get_func_name(prop_name: &str, is_bool_getter: bool) -> (Vec<String>, String) {
     let get_rename_res = getter_rules::try_rename_getter_suffix(prop_name, is_bool_getter);
@@ -1,4 +1,6 @@
-use super::{imports::Imports, info_base::InfoBase, record_type::RecordType, *};
+use super::{
+    imports::Imports, info_base::InfoBase, record_type::RecordType, rust_type::RustType, *,
+};
 use crate::{
     config::{
         derives::{Derive, Derives},
@@ -10,7 +12,7 @@ use crate::{
     traits::*,
     version::Version,
 };
-use log::info;
+use log::{info, warn};
 use std::ops::Deref;
 
 #[derive(Debug, Default)]
@@ -23,6 +25,17 @@ pub struct Info {
     pub init_function_expression: Option<String>,
     pub copy_into_function_expression: Option<String>,
     pub clear_function_expression: Option<String>,
+    pub ref_only: bool,
+    pub field_accessors: Vec<FieldAccessor>,
+}
+
+/// Getter of a field of this record
+#[derive(Debug)]
+pub struct FieldAccessor {
+    pub name: String,
+    pub typ: String,
+    /// Borrows a record embedded by value, instead of copying a callback
+    pub by_ref: bool,
 }
 
 impl Deref for Info {
@@ -84,8 +97,12 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         RecordType::Boxed | RecordType::AutoBoxed
     );
     let boxed_inline = obj.boxed_inline;
+    let ref_only = obj.ref_only;
 
     let mut imports = Imports::with_defined(&env.library, &name);
+    if ref_only {
+        imports.add("glib::translate::*");
+    }
 
     let mut functions = functions::analyze(
         env,
@@ -110,7 +127,10 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         special_functions::unhide(&mut functions, &specials, special_functions::Type::Copy);
     };
 
-    let mut derives = if let Some(ref derives) = obj.derives {
+    let mut derives = if ref_only {
+        // Ref-only records are never owned so there's nothing to derive from
+        vec![]
+    } else if let Some(ref derives) = obj.derives {
         if boxed_inline
             && !derives.is_empty()
             && !derives
@@ -121,15 +141,13 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         }
         derives.clone()
     } else if !boxed_inline {
+        let profile = obj.derive_profile.unwrap_or(env.config.derive_profile);
         let derives = vec![Derive {
-            names: vec![
-                "Debug".into(),
-                "PartialEq".into(),
-                "Eq".into(),
-                "PartialOrd".into(),
-                "Ord".into(),
-                "Hash".into(),
-            ],
+            names: profile
+                .record_derives()
+                .iter()
+                .map(|&name| name.to_owned())
+                .collect(),
             cfg_condition: None,
         }];
 
@@ -172,6 +190,7 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
     // Check if we have to make use of the GType and the generic
     // boxed functions.
     if !is_shared
+        && !ref_only
         && (!specials.has_trait(special_functions::Type::Copy)
             || !specials.has_trait(special_functions::Type::Free))
     {
@@ -195,6 +214,18 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         }
     }
 
+    let field_accessors = if obj.field_ref_accessors && !ref_only {
+        analyze_field_accessors(env, &full_name, record, &functions, &mut imports)
+    } else {
+        Vec::new()
+    };
+
+    let name = if ref_only {
+        format!("{}Ref", name)
+    } else {
+        name
+    };
+
     let base = InfoBase {
         full_name,
         type_id: record_tid,
@@ -217,7 +248,81 @@ pub fn new(env: &Env, obj: &GObject) -> Option<Info> {
         init_function_expression: obj.init_function_expression.clone(),
         copy_into_function_expression: obj.copy_into_function_expression.clone(),
         clear_function_expression: obj.clear_function_expression.clone(),
+        ref_only,
+        field_accessors,
     };
 
     Some(info)
 }
+
+/// Only records generated as `boxed_inline` share the layout of their FFI
+/// struct, so a reference to the embedded struct can be cast to them.
+fn analyze_field_accessors(
+    env: &Env,
+    full_name: &str,
+    record: &library::Record,
+    functions: &[functions::Info],
+    imports: &mut Imports,
+) -> Vec<FieldAccessor> {
+    let mut accessors = Vec::new();
+    for field in &record.fields {
+        if field.private
+            || field.bits.is_some()
+            || field.c_type.as_ref().map_or(true, |c| c.contains('*'))
+        {
+            continue;
+        }
+        let field_type_name = field.typ.full_name(&env.library);
+        let by_ref = match env.library.type_(field.typ) {
+            library::Type::Record(_) => {
+                let field_obj = env.config.objects.get(&field_type_name);
+                if !field_obj.map_or(false, |o| o.boxed_inline && o.status.need_generate()) {
+                    info!(
+                        "Field `{}::{}` is not a generated boxed_inline record, no getter",
+                        full_name, field.name
+                    );
+                    continue;
+                }
+                true
+            }
+            // Named callbacks are copied as their type alias
+            library::Type::Function(_) => {
+                if !env.type_status(&field_type_name).need_generate() {
+                    info!(
+                        "Field `{}::{}` is not a generated callback, no getter",
+                        full_name, field.name
+                    );
+                    continue;
+                }
+                false
+            }
+            _ => continue,
+        };
+        let name = mangle_keywords(&*field.name).into_owned();
+        if functions.iter().any(|f| f.codegen_name() == name) {
+            warn!(
+                "Field `{}::{}` getter clashes with a method of the same name",
+                full_name, field.name
+            );
+            continue;
+        }
+        let rust_type = if by_ref {
+            RustType::try_new(env, field.typ)
+        } else {
+            RustType::builder(env, field.typ)
+                .direction(library::ParameterDirection::Return)
+                .try_build()
+        };
+        let rust_type = match rust_type {
+            Ok(rust_type) => rust_type,
+            Err(_) => continue,
+        };
+        let typ = rust_type.as_str().to_owned();
+        imports.add_used_types(&rust_type.into_used_types());
+        accessors.push(FieldAccessor { name, typ, by_ref });
+    }
+    if !accessors.is_empty() {
+        imports.add("glib::translate::*");
+    }
+    accessors
+}
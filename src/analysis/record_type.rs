@@ -1,4 +1,4 @@
-use crate::library;
+use crate::{env::Env, library};
 
 #[derive(PartialEq, Eq)]
 pub enum RecordType {
@@ -22,7 +22,7 @@ impl RecordType {
         let mut has_destroy = false;
         for func in &record.functions {
             match &func.name[..] {
-                "copy" => has_copy = true,
+                "copy" | "dup" => has_copy = true,
                 "free" => has_free = true,
                 "destroy" => has_destroy = true,
                 "ref" => has_ref = true,
@@ -44,3 +44,50 @@ impl RecordType {
         }
     }
 }
+
+/// Whether the type is a record made only of scalar fields, which can be
+/// allocated on the stack and copied without a `uninitialized()` constructor.
+/// Boxed inline records have one and keep being used in place.
+pub fn is_plain_record(env: &Env, typ: library::TypeId) -> bool {
+    let full_name = typ.full_name(&env.library);
+    if env
+        .config
+        .objects
+        .get(&full_name)
+        .map_or(false, |obj| obj.boxed_inline)
+    {
+        return false;
+    }
+    is_plain_struct(env, typ)
+}
+
+fn is_plain_struct(env: &Env, typ: library::TypeId) -> bool {
+    let record = match env.type_(typ) {
+        library::Type::Record(record) => record,
+        _ => return false,
+    };
+    if record.disguised || record.fields.is_empty() {
+        return false;
+    }
+    record.fields.iter().all(|field| {
+        field
+            .c_type
+            .as_ref()
+            .map_or(false, |c_type| !c_type.contains('*'))
+            && match env.type_(field.typ) {
+                library::Type::Fundamental(fund) => !matches!(
+                    fund,
+                    library::Fundamental::None
+                        | library::Fundamental::Pointer
+                        | library::Fundamental::VarArgs
+                        | library::Fundamental::Utf8
+                        | library::Fundamental::Filename
+                        | library::Fundamental::OsString
+                        | library::Fundamental::Unsupported
+                ),
+                library::Type::Enumeration(_) | library::Type::Bitfield(_) => true,
+                library::Type::Record(_) => is_plain_struct(env, field.typ),
+                _ => false,
+            }
+    })
+}
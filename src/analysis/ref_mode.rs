@@ -44,7 +44,8 @@ impl RefMode {
             | List(..)
             | SList(..)
             | PtrArray(..)
-            | CArray(..) => {
+            | CArray(..)
+            | HashTable(..) => {
                 if direction == library::ParameterDirection::In {
                     RefMode::ByRef
                 } else {
@@ -53,7 +54,10 @@ impl RefMode {
             }
             Record(record) => {
                 if direction == library::ParameterDirection::In {
-                    if let RecordType::Refcounted = RecordType::of(record) {
+                    // `glib::Closure` is shared whatever the gir declares
+                    if record.c_type == "GClosure" {
+                        RefMode::ByRef
+                    } else if let RecordType::Refcounted = RecordType::of(record) {
                         RefMode::ByRef
                     } else {
                         RefMode::ByRefMut
@@ -69,6 +73,13 @@ impl RefMode {
                     RefMode::None
                 }
             }
+            FixedArray(..) => {
+                if direction == library::ParameterDirection::In {
+                    RefMode::ByRef
+                } else {
+                    RefMode::None
+                }
+            }
             Alias(alias) => RefMode::of(env, alias.typ, direction),
             _ => RefMode::None,
         }
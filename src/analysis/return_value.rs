@@ -1,13 +1,23 @@
 use crate::{
     analysis::{
-        self, imports::Imports, namespaces, override_string_type::override_string_type_return,
-        rust_type::RustType,
+        self, conversion_type::ConversionType, imports::Imports, namespaces,
+        override_string_type::override_string_type_return, rust_type::RustType,
     },
     config,
     env::Env,
     library::{self, Nullable, TypeId},
 };
-use log::error;
+use log::{error, warn};
+
+/// How a `gssize` return whose negative values are errors is mapped to
+/// `usize` (`negative_is_error`)
+#[derive(Clone, Debug)]
+pub enum NegativeIsError {
+    /// To a `Result` with a `BoolError` with this message
+    BoolError(String),
+    /// Only cast, the error being reported through the `GError`
+    Throws,
+}
 
 #[derive(Clone, Debug, Default)]
 pub struct Info {
@@ -16,6 +26,11 @@ pub struct Info {
     pub commented: bool,
     pub bool_return_is_error: Option<String>,
     pub nullable_return_is_error: Option<String>,
+    pub negative_is_error: Option<NegativeIsError>,
+    /// Check that the returned pointer isn't `NULL` in debug builds
+    pub debug_assert_not_null: bool,
+    /// Lifetime of the borrowed value, tied to a parameter
+    pub lifetime: Option<char>,
 }
 
 pub fn analyze(
@@ -82,14 +97,27 @@ pub fn analyze(
     let nullable_return_is_error = configured_functions
         .iter()
         .find_map(|f| f.ret.nullable_return_is_error.as_ref());
-    let nullable_return_error_message = nullable_return_is_error.and_then(|m| {
-        if let Some(library::Parameter { nullable: Nullable(false), ..}) = parameter {
+    // A `false` return of functions with out parameters is mapped to the error too,
+    // nullable out parameters are then unwrapped instead of returned as `Option`.
+    let returns_success = (func.ret.typ == TypeId::tid_bool()
+        || func.ret.typ == TypeId::tid_c_bool())
+        && !func.throws
+        && func
+            .parameters
+            .iter()
+            .any(|p| p.direction == library::ParameterDirection::Out);
+    let nullable_return_error_message = nullable_return_is_error.and_then(|m| match parameter {
+        Some(library::Parameter {
+            nullable: Nullable(false),
+            ..
+        }) if !returns_success => {
             error!(
                 "Ignoring nullable_return_is_error configuration for non-none returning function {}",
                 func.name
             );
             None
-        } else {
+        }
+        _ => {
             let ns = if env.namespaces.glib_ns_id == namespaces::MAIN {
                 "crate::BoolError"
             } else {
@@ -101,6 +129,74 @@ pub fn analyze(
         }
     });
 
+    let negative_is_error = if configured_functions.iter().any(|f| f.ret.negative_is_error) {
+        if !matches!(
+            env.library.type_(typ),
+            library::Type::Fundamental(library::Fundamental::SSize)
+        ) {
+            error!(
+                "Ignoring negative_is_error configuration for non-gssize returning function {}",
+                func.name
+            );
+            None
+        } else if func
+            .parameters
+            .iter()
+            .any(|p| p.direction == library::ParameterDirection::Out && !p.is_error)
+        {
+            error!(
+                "Ignoring negative_is_error configuration for function {} with out parameters",
+                func.name
+            );
+            None
+        } else if func.throws {
+            Some(NegativeIsError::Throws)
+        } else {
+            let ns = if env.namespaces.glib_ns_id == namespaces::MAIN {
+                "crate::BoolError"
+            } else {
+                "glib"
+            };
+            imports.add(ns);
+
+            Some(NegativeIsError::BoolError(format!(
+                "Failed to {}",
+                func.name.replace('_', " ")
+            )))
+        }
+    } else {
+        None
+    };
+    if negative_is_error.is_some() {
+        // Only non-negative values are returned
+        let tid_size = env
+            .library
+            .find_type(0, "*.gsize")
+            .expect("No fundamental type *.gsize");
+        if let Some(ref mut par) = parameter {
+            par.typ = tid_size;
+        }
+    }
+
+    let debug_assert_not_null = configured_functions
+        .iter()
+        .any(|f| f.ret.debug_assert_not_null)
+        && match parameter {
+            Some(ref par)
+                if !*par.nullable
+                    && ConversionType::of(env, par.typ) == ConversionType::Pointer =>
+            {
+                true
+            }
+            _ => {
+                error!(
+                    "Ignoring debug_assert_not_null configuration for function {} not returning a non-nullable pointer",
+                    func.name
+                );
+                false
+            }
+        };
+
     let mut base_tid = None;
 
     if func.kind == library::FunctionKind::Constructor {
@@ -133,6 +229,20 @@ pub fn analyze(
             .try_build_param()
             .is_err();
 
+        let is_lifetime_bound = configured_functions
+            .iter()
+            .any(|f| f.lifetime_bound_to.is_some());
+        if env.is_ref_only_record(typ)
+            && (lib_par.transfer != library::Transfer::None
+                || !(is_lifetime_bound || func.parameters.iter().any(|p| p.instance_parameter)))
+        {
+            warn!(
+                "Function \"{}\" returns a ref-only record which it can't borrow from `self`",
+                func.name
+            );
+            commented = true;
+        }
+
         par
     });
 
@@ -142,6 +252,9 @@ pub fn analyze(
         commented,
         bool_return_is_error: bool_return_error_message,
         nullable_return_is_error: nullable_return_error_message,
+        negative_is_error,
+        debug_assert_not_null,
+        lifetime: None,
     }
 }
 
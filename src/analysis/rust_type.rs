@@ -121,10 +121,10 @@ impl RustType {
     }
 
     #[inline]
-    fn apply_ref_mode(self, ref_mode: RefMode) -> Self {
-        match ref_mode.for_rust_type() {
-            "" => self,
-            ref_mode => self.alter_type(|typ_| format!("{}{}", ref_mode, typ_)),
+    fn apply_ref_mode(self, ref_mode: RefMode, lifetime: Option<char>) -> Self {
+        match ref_mode.for_rust_type_with_lifetime(lifetime) {
+            ref_str if ref_str.is_empty() => self,
+            ref_str => self.alter_type(|typ_| format!("{}{}", ref_str, typ_)),
         }
     }
 }
@@ -178,6 +178,57 @@ impl MapAny<RustType> for Result {
     }
 }
 
+/// Rust type of the items of C arrays of fundamental types
+fn array_element_type(env: &Env, type_id: library::TypeId) -> Option<&'static str> {
+    use crate::library::{Fundamental::*, Type::Fundamental};
+    match env.library.type_(type_id) {
+        Fundamental(fund) => match fund {
+            Int8 => Some("i8"),
+            UInt8 => Some("u8"),
+            Int16 => Some("i16"),
+            UInt16 => Some("u16"),
+            Int32 => Some("i32"),
+            UInt32 => Some("u32"),
+            Int64 => Some("i64"),
+            UInt64 => Some("u64"),
+
+            Int => Some("i32"),  //maybe dependent on target system
+            UInt => Some("u32"), //maybe dependent on target system
+
+            Float => Some("f32"),
+            Double => Some("f64"),
+            _ => Option::None,
+        },
+        _ => Option::None,
+    }
+}
+
+/// Keys and values of `GHashTable` in-parameters, either strings or
+/// fundamentals fitting in a pointer. Tables handed out by the library
+/// can only hold strings.
+fn hash_table_item_type(env: &Env, type_id: library::TypeId) -> Option<&'static str> {
+    use crate::library::{Fundamental::*, Type::Fundamental};
+    match env.library.type_(type_id) {
+        Fundamental(Utf8) => Some("String"),
+        Fundamental(Boolean) => Some("bool"),
+        Fundamental(Int8 | UInt8 | Int16 | UInt16 | Int32 | UInt32 | Int | UInt) => {
+            array_element_type(env, type_id)
+        }
+        _ => Option::None,
+    }
+}
+
+/// Returns the borrowed Rust type used for filename and OS string values,
+/// the ones passed around as `impl AsRef<_>` in parameters.
+pub fn path_like_type(env: &Env, type_id: library::TypeId) -> Option<&'static str> {
+    match env.library.type_(type_id) {
+        library::Type::Fundamental(library::Fundamental::Filename) => Some("std::path::Path"),
+        library::Type::Fundamental(library::Fundamental::OsString) => Some("std::ffi::OsStr"),
+        library::Type::Alias(alias) => path_like_type(env, alias.typ),
+        _ => None,
+    }
+}
+
 pub struct RustTypeBuilder<'env> {
     env: &'env Env,
     type_id: library::TypeId,
@@ -188,6 +239,10 @@ pub struct RustTypeBuilder<'env> {
     concurrency: library::Concurrency,
     try_from_glib: TryFromGlib,
     callback_parameters_config: CallbackParameters,
+    /// Lifetime of the references
+    lifetime: Option<char>,
+    /// C array of records passed by value
+    record_array: bool,
 }
 
 impl<'env> RustTypeBuilder<'env> {
@@ -202,6 +257,8 @@ impl<'env> RustTypeBuilder<'env> {
             concurrency: library::Concurrency::None,
             try_from_glib: TryFromGlib::default(),
             callback_parameters_config: Vec::new(),
+            lifetime: None,
+            record_array: false,
         }
     }
 
@@ -235,6 +292,16 @@ impl<'env> RustTypeBuilder<'env> {
         self
     }
 
+    pub fn lifetime(mut self, lifetime: Option<char>) -> Self {
+        self.lifetime = lifetime;
+        self
+    }
+
+    pub fn record_array(mut self, record_array: bool) -> Self {
+        self.record_array = record_array;
+        self
+    }
+
     pub fn callback_parameters_config(
         mut self,
         callback_parameters_config: &[CallbackParameter],
@@ -328,6 +395,22 @@ impl<'env> RustTypeBuilder<'env> {
                 };
                 RustType::try_new_and_use_with_name(self.env, self.type_id, type_name)
             }
+            // `glib::Closure` is bound manually and needs no configuration
+            Record(library::Record { ref c_type, .. }) if c_type == "GClosure" => {
+                Ok(RustType::new_and_use(use_glib_type(self.env, "Closure")))
+            }
+            Record(..) if self.env.is_ref_only_record(self.type_id) => {
+                let type_name = format!("{}Ref", type_.get_name());
+                RustType::try_new_and_use_with_name(self.env, self.type_id, type_name).map(
+                    |rust_type| match self.direction {
+                        // Ref-only records are never owned, hand out borrows
+                        ParameterDirection::Out | ParameterDirection::Return => {
+                            rust_type.apply_ref_mode(RefMode::ByRef, self.lifetime)
+                        }
+                        _ => rust_type,
+                    },
+                )
+            }
             Enumeration(..) | Bitfield(..) | Record(..) | Union(..) | Class(..) | Interface(..) => {
                 RustType::try_new_and_use(self.env, self.type_id).and_then(|rust_type| {
                     if self
@@ -344,9 +427,11 @@ impl<'env> RustTypeBuilder<'env> {
             List(inner_tid) | SList(inner_tid) | CArray(inner_tid) | PtrArray(inner_tid)
                 if ConversionType::of(self.env, inner_tid) == ConversionType::Pointer =>
             {
-                skip_option = true;
+                // A nullable C array of structs is `Option<&[T]>`
+                skip_option = !self.record_array;
                 let inner_ref_mode = match self.env.library.type_(inner_tid) {
                     Class(..) | Interface(..) => RefMode::None,
+                    _ if self.record_array => RefMode::None,
                     _ => self.ref_mode,
                 };
                 RustType::builder(self.env, inner_tid)
@@ -367,46 +452,78 @@ impl<'env> RustTypeBuilder<'env> {
             CArray(inner_tid)
                 if ConversionType::of(self.env, inner_tid) == ConversionType::Direct =>
             {
-                if let Fundamental(fund) = self.env.library.type_(inner_tid) {
-                    let array_type = match fund {
-                        Int8 => Some("i8"),
-                        UInt8 => Some("u8"),
-                        Int16 => Some("i16"),
-                        UInt16 => Some("u16"),
-                        Int32 => Some("i32"),
-                        UInt32 => Some("u32"),
-                        Int64 => Some("i64"),
-                        UInt64 => Some("u64"),
-
-                        Int => Some("i32"),  //maybe dependent on target system
-                        UInt => Some("u32"), //maybe dependent on target system
-
-                        Float => Some("f32"),
-                        Double => Some("f64"),
-                        _ => Option::None,
-                    };
-
-                    if let Some(s) = array_type {
-                        skip_option = true;
-                        if self.ref_mode.is_ref() {
-                            Ok(format!("[{}]", s).into())
-                        } else {
-                            Ok(format!("Vec<{}>", s).into())
-                        }
+                if let Some(s) = array_element_type(self.env, inner_tid) {
+                    skip_option = true;
+                    // Caller-allocated out buffers are resized after the call,
+                    // see `function_parameters::is_out_buffer`
+                    if self.ref_mode.is_ref() && self.direction != ParameterDirection::Out {
+                        Ok(format!("[{}]", s).into())
                     } else {
-                        Err(TypeError::Unimplemented(type_.get_name()))
+                        Ok(format!("Vec<{}>", s).into())
                     }
                 } else {
                     Err(TypeError::Unimplemented(type_.get_name()))
                 }
             }
+            FixedArray(inner_tid, size, _)
+                if self.direction == ParameterDirection::In
+                    && ConversionType::of(self.env, inner_tid) == ConversionType::Direct =>
+            {
+                if let Some(s) = array_element_type(self.env, inner_tid) {
+                    skip_option = true;
+                    Ok(format!("[{}; {}]", s, size).into())
+                } else {
+                    Err(TypeError::Unimplemented(type_.get_name()))
+                }
+            }
+            HashTable(key_tid, value_tid) if self.direction == ParameterDirection::In => {
+                match (
+                    hash_table_item_type(self.env, key_tid),
+                    hash_table_item_type(self.env, value_tid),
+                ) {
+                    (Some(key), Some(value)) => {
+                        ok_and_use(&format!("std::collections::HashMap<{}, {}>", key, value))
+                    }
+                    _ => Err(TypeError::Unimplemented(type_.get_name())),
+                }
+            }
+            // Only string tables can be taken from glib
+            HashTable(key_tid, value_tid)
+                if hash_table_item_type(self.env, key_tid) == Some("String")
+                    && hash_table_item_type(self.env, value_tid) == Some("String") =>
+            {
+                ok_and_use("std::collections::HashMap<String, String>")
+            }
+            // Types standing for `GVariant` and bytes are written as given
+            Custom(library::Custom { ref name, .. })
+                if self.env.library.variant_type(self.type_id).is_some()
+                    || self.env.library.bytes_type(self.type_id).is_some() =>
+            {
+                Ok(RustType {
+                    inner: name.clone(),
+                    used_types: Vec::new(),
+                })
+            }
             Custom(library::Custom { ref name, .. }) => {
                 RustType::try_new_and_use_with_name(self.env, self.type_id, name)
             }
             Function(ref f) => {
+                let full_name = self.type_id.full_name(&self.env.library);
+                if matches!(
+                    self.direction,
+                    ParameterDirection::Out | ParameterDirection::Return
+                ) && !self.env.type_status(&full_name).ignored()
+                {
+                    // Callbacks handed out by the library are exposed through the
+                    // type alias over the raw `ffi` function pointer.
+                    return RustType::try_new_and_use(self.env, self.type_id);
+                }
+
                 let concurrency = match self.concurrency {
                     _ if self.scope.is_call() => "",
                     library::Concurrency::Send | library::Concurrency::SendUnique => " + Send",
+                    // Called only once, so never shared between threads
+                    library::Concurrency::SendSync if self.scope.is_async() => " + Send",
                     // If an object is Sync, it can be shared between threads, and as
                     // such our callback can be called from arbitrary threads and needs
                     // to be Send *AND* Sync
@@ -414,7 +531,6 @@ impl<'env> RustTypeBuilder<'env> {
                     library::Concurrency::None => "",
                 };
 
-                let full_name = self.type_id.full_name(&self.env.library);
                 if full_name == "Gio.AsyncReadyCallback" {
                     // FIXME need to use the result from use_glib_type(&self.env, "Error")?
                     return Ok(format!(
@@ -442,6 +558,16 @@ impl<'env> RustTypeBuilder<'env> {
                         .direction(p.direction)
                         .nullable(nullable)
                         .try_build();
+                    if p.direction == ParameterDirection::In {
+                        if let Some(path_type) = path_like_type(self.env, p.typ) {
+                            if *nullable {
+                                params.push(format!("Option<&{}>", path_type));
+                            } else {
+                                params.push(format!("&{}", path_type));
+                            }
+                            continue;
+                        }
+                    }
                     match p_res {
                         Ok(p_rust_type) => {
                             let is_fundamental = p.typ.is_fundamental_type(self.env);
@@ -553,7 +679,7 @@ impl<'env> RustTypeBuilder<'env> {
 
                             opt
                         })
-                        .apply_ref_mode(self.ref_mode)
+                        .apply_ref_mode(self.ref_mode, self.lifetime)
                 });
             }
             TryFromGlib::Result { ok_type, err_type } => {
@@ -574,11 +700,13 @@ impl<'env> RustTypeBuilder<'env> {
                 }
             }
             TryFromGlib::ResultInfallible { ok_type } => {
-                let new_rust_type = RustType::new_and_use(ok_type).apply_ref_mode(self.ref_mode);
+                let new_rust_type =
+                    RustType::new_and_use(ok_type).apply_ref_mode(self.ref_mode, self.lifetime);
                 rust_type = rust_type.map_any(|_| new_rust_type);
             }
             _ => {
-                rust_type = rust_type.map_any(|rust_type| rust_type.apply_ref_mode(self.ref_mode));
+                rust_type = rust_type
+                    .map_any(|rust_type| rust_type.apply_ref_mode(self.ref_mode, self.lifetime));
             }
         }
 
@@ -610,6 +738,8 @@ impl<'env> RustTypeBuilder<'env> {
             .ref_mode(self.ref_mode)
             .scope(self.scope)
             .try_from_glib(&self.try_from_glib)
+            .lifetime(self.lifetime)
+            .record_array(self.record_array)
             .try_build();
         match type_ {
             Fundamental(
@@ -659,7 +789,9 @@ impl<'env> RustTypeBuilder<'env> {
             },
 
             List(..) | SList(..) => match self.direction {
-                ParameterDirection::In | ParameterDirection::Return => rust_type,
+                ParameterDirection::In | ParameterDirection::Out | ParameterDirection::Return => {
+                    rust_type
+                }
                 _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
             },
             CArray(..) | PtrArray(..) => match self.direction {
@@ -668,6 +800,16 @@ impl<'env> RustTypeBuilder<'env> {
                 }
                 _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
             },
+            FixedArray(..) => match self.direction {
+                ParameterDirection::In => rust_type,
+                _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
+            },
+            HashTable(..) => match self.direction {
+                ParameterDirection::In | ParameterDirection::Out | ParameterDirection::Return => {
+                    rust_type
+                }
+                _ => Err(TypeError::Unimplemented(into_inner(rust_type))),
+            },
             Function(ref func) if func.name == "AsyncReadyCallback" => {
                 Ok("AsyncReadyCallback".into())
             }
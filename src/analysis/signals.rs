@@ -18,6 +18,8 @@ pub struct Info {
     pub deprecated_version: Option<Version>,
     pub doc_hidden: bool,
     pub is_detailed: bool,
+    /// Virtual method run as the default handler of the signal
+    pub default_handler: Option<String>,
 }
 
 pub fn analyze(
@@ -87,7 +89,12 @@ fn analyze_signal(
         version,
     );
 
-    let action_emit_name = if signal.is_action {
+    // Out parameters can't be passed to `emit_by_name()`
+    let has_outs = signal
+        .parameters
+        .iter()
+        .any(|par| par.direction == library::ParameterDirection::Out);
+    let action_emit_name = if signal.is_action && !has_outs {
         imports.add("glib::object::ObjectExt");
         Some(format!("emit_{}", nameutil::signal_to_snake(&signal.name)))
     } else {
@@ -117,7 +124,27 @@ fn analyze_signal(
         deprecated_version,
         doc_hidden,
         is_detailed: signal.is_detailed,
+        default_handler: default_handler(env, type_tid, &signal.name).map(ToOwned::to_owned),
     };
 
     info
 }
+
+/// Finds the virtual method with the same name as the signal, which the class
+/// or interface struct declares to run as its default handler.
+fn default_handler<'a>(
+    env: &'a Env,
+    type_tid: library::TypeId,
+    signal_name: &str,
+) -> Option<&'a str> {
+    let virtual_methods = match env.library.type_(type_tid) {
+        library::Type::Class(class) => &class.virtual_methods,
+        library::Type::Interface(iface) => &iface.virtual_methods,
+        _ => return None,
+    };
+    let name = nameutil::signal_to_snake(signal_name);
+    virtual_methods
+        .iter()
+        .find(|vfunc| **vfunc == name)
+        .map(String::as_str)
+}
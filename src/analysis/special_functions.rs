@@ -130,11 +130,22 @@ fn update_func(func: &mut FuncInfo, type_: Type) -> bool {
     true
 }
 
+/// Records without a `copy` function can be duplicated with their `dup` one,
+/// which stays visible as it isn't named like a special function.
+fn parse_type(func: &FuncInfo, dup_as_copy: bool) -> Result<Type, String> {
+    if dup_as_copy && func.name == "dup" {
+        return Ok(Type::Copy);
+    }
+    func.name.parse()
+}
+
 pub fn extract(functions: &mut Vec<FuncInfo>, parent_type: &LibType, obj: &GObject) -> Infos {
     let mut specials = Infos::default();
     let mut has_copy = false;
     let mut has_free = false;
     let mut destroy = None;
+    let dup_as_copy = matches!(parent_type, LibType::Record(_))
+        && !functions.iter().any(|func| func.name == "copy");
 
     for (pos, func) in functions.iter_mut().enumerate() {
         if is_stringify(func, parent_type, obj) {
@@ -162,10 +173,14 @@ pub fn extract(functions: &mut Vec<FuncInfo>, parent_type: &LibType, obj: &GObje
             }
 
             // Some stringifying functions can serve as Display implementation
-            if matches!(
-                func.name.as_str(),
-                "to_string" | "to_str" | "name" | "get_name"
-            ) {
+            let is_display = match obj.display_from {
+                Some(ref name) => func.name == *name,
+                None => matches!(
+                    func.name.as_str(),
+                    "to_string" | "to_str" | "name" | "get_name"
+                ),
+            };
+            if is_display {
                 // FUTURE: Decide which function gets precedence if multiple Display prospects exist.
                 specials.traits.insert(
                     Type::Display,
@@ -176,15 +191,16 @@ pub fn extract(functions: &mut Vec<FuncInfo>, parent_type: &LibType, obj: &GObje
                     },
                 );
             }
-        } else if let Ok(type_) = func.name.parse() {
+        } else if let Ok(type_) = parse_type(func, dup_as_copy) {
             if func.name == "destroy" {
                 destroy = Some((func.glib_name.clone(), pos));
                 continue;
             }
-            if !update_func(func, type_) {
+            let is_dup = type_ == Type::Copy && func.name == "dup";
+            if !is_dup && !update_func(func, type_) {
                 continue;
             }
-            if func.name == "copy" {
+            if type_ == Type::Copy {
                 has_copy = true;
             } else if func.name == "free" {
                 has_free = true;
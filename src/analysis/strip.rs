@@ -0,0 +1,186 @@
+use crate::{
+    analysis::{functions, info_base::InfoBase, special_functions},
+    env::Env,
+    library::{Type, TypeId, MAIN_NAMESPACE},
+};
+use std::collections::HashMap;
+
+/// Checks that nothing generated references the objects and functions of
+/// `options.strip`, returning the list of such references otherwise.
+pub fn check(env: &Env) -> Result<(), String> {
+    if env.config.strip.is_empty() {
+        return Ok(());
+    }
+
+    let mut types = HashMap::new();
+    let mut references = Vec::new();
+    for name in &env.config.strip {
+        if env.config.objects.contains_key(name) {
+            if let Some(tid) = env.library.find_type(MAIN_NAMESPACE, name) {
+                types.insert(tid, name.as_str());
+            }
+            continue;
+        }
+        // Functions used for trait implementations are dropped from them by
+        // `ignore`, so they don't show up in the analysis
+        let (object_name, function_name) = match name.rsplit_once('.') {
+            Some(names) => names,
+            None => continue,
+        };
+        let generated = env
+            .config
+            .objects
+            .get(object_name)
+            .map_or(false, |object| object.status.need_generate());
+        if !generated {
+            continue;
+        }
+        if let Some(special) = record_special(env, object_name, function_name) {
+            references.push(format!(
+                "`{}` needs `{}` for its {:?} implementation",
+                object_name, name, special
+            ));
+        }
+    }
+
+    for info in env.analysis.objects.values() {
+        if !env.config.objects[&info.full_name].status.need_generate() {
+            continue;
+        }
+        for supertype in &info.supertypes {
+            if let Some(stripped) = types.get(&supertype.type_id) {
+                references.push(format!("`{}` derives from `{}`", info.full_name, stripped));
+            }
+        }
+        for property in &info.properties {
+            if let Some(stripped) = stripped_type(env, property.typ, &types) {
+                references.push(format!(
+                    "`{}` property `{}` is of type `{}`",
+                    info.full_name, property.name, stripped
+                ));
+            }
+        }
+        for signal in info.signals.iter().chain(&info.notify_signals) {
+            if let Ok(ref trampoline) = signal.trampoline {
+                let stripped = trampoline
+                    .parameters
+                    .c_parameters
+                    .iter()
+                    .map(|par| par.typ)
+                    .chain(Some(trampoline.ret.typ))
+                    .find_map(|typ| stripped_type(env, typ, &types));
+                if let Some(stripped) = stripped {
+                    references.push(format!(
+                        "`{}` signal `{}` uses `{}`",
+                        info.full_name, signal.signal_name, stripped
+                    ));
+                }
+            }
+        }
+        check_functions(env, &info.base, &types, &mut references);
+    }
+    for info in env.analysis.records.values() {
+        if env.config.objects[&info.full_name].status.need_generate() {
+            check_functions(env, &info.base, &types, &mut references);
+        }
+    }
+    if let Some(ref info) = env.analysis.global_functions {
+        check_functions(env, info, &types, &mut references);
+    }
+
+    if references.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Items of options.strip are still referenced: {}",
+            references.join(", ")
+        ))
+    }
+}
+
+/// The trait implementation of a record the function would be used for,
+/// following `special_functions::extract()`
+fn record_special(
+    env: &Env,
+    record_name: &str,
+    function_name: &str,
+) -> Option<special_functions::Type> {
+    let record = match env
+        .library
+        .find_type(MAIN_NAMESPACE, record_name)
+        .map(|tid| env.library.type_(tid))
+    {
+        Some(Type::Record(record)) => record,
+        _ => return None,
+    };
+    let has_function = |name: &str| record.functions.iter().any(|f| f.name == name);
+    match function_name {
+        "dup" if !has_function("copy") => Some(special_functions::Type::Copy),
+        "destroy" if !has_function("free") && (has_function("copy") || has_function("dup")) => {
+            Some(special_functions::Type::Free)
+        }
+        "destroy" => None,
+        _ => function_name.parse().ok(),
+    }
+}
+
+fn check_functions(
+    env: &Env,
+    info: &InfoBase,
+    types: &HashMap<TypeId, &str>,
+    references: &mut Vec<String>,
+) {
+    // Commented out functions don't generate any code
+    for function in info
+        .functions
+        .iter()
+        .filter(|f| f.status.need_generate() && f.visibility.code_visible())
+    {
+        if let Some(stripped) = function_type(env, function, types) {
+            references.push(format!(
+                "`{}::{}` uses `{}`",
+                info.full_name,
+                function.codegen_name(),
+                stripped
+            ));
+        }
+    }
+}
+
+fn function_type<'a>(
+    env: &Env,
+    function: &functions::Info,
+    types: &HashMap<TypeId, &'a str>,
+) -> Option<&'a str> {
+    function
+        .parameters
+        .c_parameters
+        .iter()
+        .map(|par| par.typ)
+        .chain(function.ret.parameter.iter().map(|par| par.lib_par.typ))
+        .chain(function.outs.iter().map(|par| par.lib_par.typ))
+        .chain(
+            function
+                .callbacks
+                .iter()
+                .flat_map(|callback| callback.parameters.c_parameters.iter().map(|par| par.typ)),
+        )
+        .find_map(|typ| stripped_type(env, typ, types))
+}
+
+/// Looks through the containers the type is made of
+fn stripped_type<'a>(env: &Env, typ: TypeId, types: &HashMap<TypeId, &'a str>) -> Option<&'a str> {
+    use crate::library::Type::*;
+    if let Some(stripped) = types.get(&typ) {
+        return Some(stripped);
+    }
+    match env.library.type_(typ) {
+        CArray(typ) | FixedArray(typ, ..) | PtrArray(typ) | List(typ) | SList(typ) => {
+            stripped_type(env, *typ, types)
+        }
+        HashTable(key, value) => {
+            stripped_type(env, *key, types).or_else(|| stripped_type(env, *value, types))
+        }
+        _ => None,
+    }
+}
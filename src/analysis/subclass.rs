@@ -0,0 +1,420 @@
+use crate::{
+    analysis::{
+        class_record, conversion_type::ConversionType, ffi_type::ffi_type, imports::Imports,
+        ref_mode::RefMode, rust_type::RustType, signals,
+    },
+    config::gobjects::GObject,
+    env::Env,
+    library::{self, Nullable, ParameterDirection, Transfer, Type, TypeId},
+    nameutil,
+    traits::*,
+};
+use log::{info, warn};
+
+/// `Impl` traits overriding the virtual functions of a class or an
+/// interface, generated with `generate_subclass = true`
+#[derive(Debug)]
+pub struct Subclass {
+    pub impl_trait: String,
+    pub ext_trait: String,
+    /// `Impl` trait of the parent class the `Impl` trait extends
+    pub parent_impl: String,
+    /// Type of the C class or interface structure
+    pub ffi_type: String,
+    pub vfuncs: Vec<Vfunc>,
+    pub async_vfuncs: Vec<AsyncVfunc>,
+}
+
+#[derive(Debug)]
+pub struct Vfunc {
+    pub name: String,
+    /// Signal this virtual function is the default handler of
+    pub signal_name: Option<String>,
+    /// Type of the C instance passed first
+    pub ffi_instance: String,
+    pub parameters: Vec<Parameter>,
+    pub ret: Option<Return>,
+}
+
+/// `{name}_async` and `{name}_finish` virtual functions, overridden together
+/// by a method returning a future
+#[derive(Debug)]
+pub struct AsyncVfunc {
+    pub name: String,
+    pub async_name: String,
+    pub finish_name: String,
+    /// Type of the C instance passed first
+    pub ffi_instance: String,
+    pub parameters: Vec<Parameter>,
+    /// Whether the `_async` function takes a `GCancellable`
+    pub cancellable: bool,
+    pub ret: AsyncReturn,
+}
+
+/// Result of the `_finish` virtual function
+#[derive(Debug)]
+pub enum AsyncReturn {
+    None,
+    /// `TRUE` on success
+    Bool,
+    /// Value returned with transfer full
+    Value {
+        typ: String,
+        ffi_type: String,
+    },
+}
+
+impl AsyncReturn {
+    /// Type of the value the future resolves to
+    pub fn typ(&self) -> &str {
+        match self {
+            AsyncReturn::None | AsyncReturn::Bool => "()",
+            AsyncReturn::Value { typ, .. } => typ,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Parameter {
+    pub name: String,
+    pub typ: String,
+    pub ffi_type: String,
+    /// Whether the argument is borrowed, and so copied into the future
+    /// calling the parent implementation
+    pub by_ref: bool,
+    /// Expression converting the C argument in the trampoline
+    pub from_glib: String,
+    /// Expression converting the argument for the parent implementation
+    pub to_glib: String,
+}
+
+#[derive(Debug)]
+pub struct Return {
+    pub typ: String,
+    pub ffi_type: String,
+    /// Suffix converting the value returned to C by the trampoline
+    pub to_glib: &'static str,
+    /// Function converting the value returned by the parent implementation
+    pub from_glib: &'static str,
+}
+
+pub fn analyze(
+    env: &Env,
+    obj: &GObject,
+    type_tid: TypeId,
+    c_class_type: Option<&str>,
+    signals: &[signals::Info],
+    imports: &mut Imports,
+) -> Option<Subclass> {
+    if !obj.generate_subclass {
+        return None;
+    }
+    let name = nameutil::split_namespace_name(&obj.name).1;
+    let (record_tid, record) =
+        match c_class_type.and_then(|c_type| class_record::find(env, type_tid, c_type)) {
+            Some(found) => found,
+            None => {
+                warn!(
+                    "No class structure to generate the subclass of {}",
+                    obj.name
+                );
+                return None;
+            }
+        };
+    let parent_impl = match parent_impl(env, type_tid) {
+        Some(parent_impl) => parent_impl,
+        None => {
+            warn!(
+                "Subclass of {} not generated: its parent has no `Impl` trait",
+                obj.name
+            );
+            return None;
+        }
+    };
+    let ffi_type = ffi_type(env, record_tid, &record.c_type)
+        .ok()?
+        .into_string();
+
+    let vfunc_fields = record
+        .fields
+        .iter()
+        .filter(|field| field.c_type.is_none() && !field.private)
+        .filter_map(|field| match env.library.type_(field.typ) {
+            Type::Function(func) => Some((field, func)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut async_vfuncs = Vec::new();
+    let mut async_names = Vec::new();
+    for (field, func) in &vfunc_fields {
+        let vfunc_name = match field.name.strip_suffix("_async") {
+            Some(vfunc_name) => vfunc_name,
+            None => continue,
+        };
+        let finish_name = format!("{}_finish", vfunc_name);
+        let finish = match vfunc_fields
+            .iter()
+            .find(|(field, _)| field.name == finish_name)
+        {
+            Some((_, finish)) => finish,
+            None => continue,
+        };
+        async_names.push(field.name.clone());
+        match analyze_async_vfunc(env, vfunc_name, func, finish, imports) {
+            Some(vfunc) => async_vfuncs.push(vfunc),
+            None => info!(
+                "Virtual functions {}.{} and {} can't be overridden",
+                record.c_type, field.name, finish_name
+            ),
+        }
+        async_names.push(finish_name);
+    }
+
+    let mut vfuncs = Vec::new();
+    for (field, func) in vfunc_fields {
+        if async_names.contains(&field.name) {
+            continue;
+        }
+        let signal_name = signals
+            .iter()
+            .find(|signal| signal.default_handler.as_deref() == Some(&*field.name))
+            .map(|signal| signal.signal_name.clone());
+        match analyze_vfunc(env, field, func, signal_name, imports) {
+            Some(vfunc) => vfuncs.push(vfunc),
+            None => info!(
+                "Virtual function {}.{} can't be overridden",
+                record.c_type, field.name
+            ),
+        }
+    }
+
+    imports.add("glib::subclass::prelude::*");
+    imports.add("glib::translate::*");
+    imports.add("glib::object::Cast");
+    if !async_vfuncs.is_empty() {
+        imports.add("std::boxed::Box as Box_");
+        imports.add("std::pin::Pin");
+        imports.add("std::ptr");
+    }
+
+    Some(Subclass {
+        impl_trait: format!("{}Impl", name),
+        ext_trait: format!("{}ImplExt", name),
+        parent_impl,
+        ffi_type,
+        vfuncs,
+        async_vfuncs,
+    })
+}
+
+/// Interfaces and direct subclasses of `GObject` extend `ObjectImpl`, other
+/// classes the `Impl` trait of their parent.
+fn parent_impl(env: &Env, type_tid: TypeId) -> Option<String> {
+    let parent_tid = match env.library.type_(type_tid) {
+        Type::Class(library::Class {
+            parent: Some(parent_tid),
+            ..
+        }) => *parent_tid,
+        _ => return Some("ObjectImpl".to_owned()),
+    };
+    let parent_name = env.library.type_(parent_tid).get_name();
+    if parent_tid.full_name(&env.library) == "GObject.Object" {
+        Some("ObjectImpl".to_owned())
+    } else if parent_tid.ns_id == type_tid.ns_id {
+        let parent = env
+            .config
+            .objects
+            .get(&parent_tid.full_name(&env.library))?;
+        if !parent.generate_subclass {
+            return None;
+        }
+        let module_name = parent
+            .module_name
+            .clone()
+            .unwrap_or_else(|| nameutil::module_name(&parent_name));
+        Some(format!("super::{}::{}Impl", module_name, parent_name))
+    } else {
+        Some(format!(
+            "{}::subclass::prelude::{}Impl",
+            env.namespaces[parent_tid.ns_id].higher_crate_name, parent_name
+        ))
+    }
+}
+
+fn analyze_vfunc(
+    env: &Env,
+    field: &library::Field,
+    func: &library::Function,
+    signal_name: Option<String>,
+    imports: &mut Imports,
+) -> Option<Vfunc> {
+    if func.throws {
+        return None;
+    }
+    let (instance, pars) = func.parameters.split_first()?;
+    let ffi_instance = ffi_type(env, instance.typ, &instance.c_type)
+        .ok()?
+        .into_string();
+
+    let parameters = pars
+        .iter()
+        .map(|par| analyze_parameter(env, par, imports))
+        .collect::<Option<Vec<_>>>()?;
+
+    let ret = if func.ret.typ == TypeId::tid_none() {
+        None
+    } else {
+        if *func.ret.nullable {
+            return None;
+        }
+        let (to_glib, from_glib) = match (ConversionType::of(env, func.ret.typ), func.ret.transfer)
+        {
+            (ConversionType::Direct, _) => ("", ""),
+            (ConversionType::Scalar, _) => (".into_glib()", "from_glib"),
+            (ConversionType::Pointer, Transfer::Full) => (".to_glib_full()", "from_glib_full"),
+            _ => return None,
+        };
+        let typ = RustType::builder(env, func.ret.typ)
+            .direction(ParameterDirection::Return)
+            .nullable(Nullable(false))
+            .try_build()
+            .ok()?;
+        imports.add_used_types(typ.used_types());
+        Some(Return {
+            typ: typ.into_string(),
+            ffi_type: ffi_type(env, func.ret.typ, &func.ret.c_type)
+                .ok()?
+                .into_string(),
+            to_glib,
+            from_glib,
+        })
+    };
+
+    Some(Vfunc {
+        name: nameutil::mangle_keywords(&*field.name).into_owned(),
+        signal_name,
+        ffi_instance,
+        parameters,
+        ret,
+    })
+}
+
+/// Analyzes the `{name}_async` virtual function, taking the arguments then an
+/// optional `GCancellable`, the `GAsyncReadyCallback` and its user data, with
+/// the `{name}_finish` one, throwing and taking the `GAsyncResult`.
+fn analyze_async_vfunc(
+    env: &Env,
+    name: &str,
+    func: &library::Function,
+    finish: &library::Function,
+    imports: &mut Imports,
+) -> Option<AsyncVfunc> {
+    let (instance, pars) = func.parameters.split_first()?;
+    let ffi_instance = ffi_type(env, instance.typ, &instance.c_type)
+        .ok()?
+        .into_string();
+    let pars = match pars {
+        [pars @ .., callback, user_data]
+            if callback.c_type == "GAsyncReadyCallback" && user_data.c_type == "gpointer" =>
+        {
+            pars
+        }
+        _ => return None,
+    };
+    let (pars, cancellable) = match pars {
+        [pars @ .., cancellable] if cancellable.c_type == "GCancellable*" => (pars, true),
+        _ => (pars, false),
+    };
+    let parameters = pars
+        .iter()
+        .map(|par| analyze_parameter(env, par, imports))
+        .collect::<Option<Vec<_>>>()?;
+
+    if !finish.throws
+        || !matches!(&finish.parameters[..], [_, result, _] if result.c_type == "GAsyncResult*")
+    {
+        return None;
+    }
+    let ret = match env.library.type_(finish.ret.typ) {
+        Type::Fundamental(library::Fundamental::None) => AsyncReturn::None,
+        Type::Fundamental(library::Fundamental::Boolean) => AsyncReturn::Bool,
+        _ if ConversionType::of(env, finish.ret.typ) == ConversionType::Pointer
+            && finish.ret.transfer == Transfer::Full =>
+        {
+            let typ = RustType::builder(env, finish.ret.typ)
+                .direction(ParameterDirection::Return)
+                .nullable(Nullable(false))
+                .try_build()
+                .ok()?;
+            imports.add_used_types(typ.used_types());
+            AsyncReturn::Value {
+                typ: typ.into_string(),
+                ffi_type: ffi_type(env, finish.ret.typ, &finish.ret.c_type)
+                    .ok()?
+                    .into_string(),
+            }
+        }
+        _ => return None,
+    };
+
+    Some(AsyncVfunc {
+        name: nameutil::mangle_keywords(name).into_owned(),
+        async_name: format!("{}_async", name),
+        finish_name: format!("{}_finish", name),
+        ffi_instance,
+        parameters,
+        cancellable,
+        ret,
+    })
+}
+
+/// Non-nullable input parameter of a virtual function
+fn analyze_parameter(
+    env: &Env,
+    par: &library::Parameter,
+    imports: &mut Imports,
+) -> Option<Parameter> {
+    if par.direction != ParameterDirection::In || *par.nullable {
+        return None;
+    }
+    let name = nameutil::mangle_keywords(&*par.name).into_owned();
+    let (from_glib, to_glib, by_ref) = match ConversionType::of(env, par.typ) {
+        ConversionType::Direct => (name.clone(), name.clone(), false),
+        ConversionType::Scalar => (
+            format!("from_glib({})", name),
+            format!("{}.into_glib()", name),
+            false,
+        ),
+        ConversionType::Pointer | ConversionType::Borrow if par.transfer == Transfer::None => {
+            let from_glib = match env.library.type_(par.typ) {
+                Type::Fundamental(library::Fundamental::Utf8) => format!(
+                    "&{}::from_glib_borrow({})",
+                    nameutil::use_glib_type(env, "GString"),
+                    name
+                ),
+                Type::Class(_) | Type::Interface(_) | Type::Record(_) => {
+                    format!("&from_glib_borrow({})", name)
+                }
+                _ => return None,
+            };
+            (from_glib, format!("{}.to_glib_none().0", name), true)
+        }
+        _ => return None,
+    };
+    let typ = RustType::builder(env, par.typ)
+        .direction(ParameterDirection::In)
+        .nullable(Nullable(false))
+        .ref_mode(RefMode::of(env, par.typ, ParameterDirection::In))
+        .try_build_param()
+        .ok()?;
+    imports.add_used_types(typ.used_types());
+    Some(Parameter {
+        name,
+        typ: typ.into_string(),
+        ffi_type: ffi_type(env, par.typ, &par.c_type).ok()?.into_string(),
+        by_ref,
+        from_glib,
+        to_glib,
+    })
+}
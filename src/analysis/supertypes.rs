@@ -5,6 +5,7 @@ use crate::{
     library::TypeId,
     version::Version,
 };
+use log::{error, warn};
 
 pub fn analyze(
     env: &Env,
@@ -63,6 +64,92 @@ pub fn analyze(
     parents
 }
 
+/// Checks that every non-ignored supertype has all of its own supertypes
+/// available, otherwise the `IsA` path required by the `wrapper!` macro
+/// can't be generated.
+pub fn check_hierarchy(env: &Env, type_id: TypeId, parents: &[StatusedTypeId]) {
+    let gobject_id = match env.library.find_type(0, "GObject.Object") {
+        Some(gobject_id) => gobject_id,
+        None => return,
+    };
+    let full_name = type_id.full_name(&env.library);
+
+    for parent in parents.iter().filter(|p| !p.status.ignored()) {
+        if RustType::try_new(env, parent.type_id).is_err() {
+            error!(
+                "`{}`: supertype `{}` has no Rust type, `IsA<{}>` can't be generated",
+                full_name,
+                parent.type_id.full_name(&env.library),
+                parent.name,
+            );
+            continue;
+        }
+
+        for &required_tid in env.class_hierarchy.supertypes(parent.type_id) {
+            if required_tid == gobject_id {
+                continue;
+            }
+            let is_available = parents
+                .iter()
+                .any(|p| p.type_id == required_tid && !p.status.ignored());
+            if !is_available {
+                error!(
+                    "`{}`: supertype `{}` requires `{}` which is ignored, add it to the `generate` or `manual` list",
+                    full_name,
+                    parent.type_id.full_name(&env.library),
+                    required_tid.full_name(&env.library),
+                );
+            }
+        }
+    }
+}
+
+/// Checks that `GtkBuilder` finds the type of a widget used as template
+/// child by itself, through the `get_type` function it guesses from the
+/// type name. The other widgets must be registered beforehand with
+/// `StaticTypeExt::ensure_type`.
+pub fn check_template_child(env: &Env, type_id: TypeId, c_type: &str, get_type: &str) {
+    let widget_id = match env.library.find_type(0, "Gtk.Widget") {
+        Some(widget_id) => widget_id,
+        None => return,
+    };
+    if !env.class_hierarchy.supertypes(type_id).contains(&widget_id) {
+        return;
+    }
+    if ![false, true]
+        .iter()
+        .any(|&split_first_cap| builder_get_type(c_type, split_first_cap) == get_type)
+    {
+        warn!(
+            "`{}`: GtkBuilder can't guess `{}` from `{}`, call `ensure_type()` before using it as template child",
+            type_id.full_name(&env.library),
+            get_type,
+            c_type,
+        );
+    }
+}
+
+/// Name of the `get_type` function `GtkBuilder` looks up for `type_name`,
+/// like `type_name_mangle()` of GTK
+fn builder_get_type(type_name: &str, split_first_cap: bool) -> String {
+    let chars = type_name.chars().collect::<Vec<_>>();
+    // Like `g_ascii_toupper (c) == c`, true for digits too
+    let is_upper = |i: usize| !chars[i].is_ascii_lowercase();
+    let mut symbol = String::with_capacity(type_name.len() + 10);
+    for (i, c) in chars.iter().enumerate() {
+        let split = is_upper(i)
+            && ((i > 0 && !is_upper(i - 1))
+                || (i == 1 && is_upper(0) && split_first_cap)
+                || (i > 2 && is_upper(i - 1) && is_upper(i - 2)));
+        if split {
+            symbol.push('_');
+        }
+        symbol.push(c.to_ascii_lowercase());
+    }
+    symbol.push_str("_get_type");
+    symbol
+}
+
 pub fn dependencies(env: &Env, type_id: TypeId) -> Vec<TypeId> {
     let mut parents = Vec::new();
     let gobject_id = match env.library.find_type(0, "GObject.Object") {
@@ -85,3 +172,26 @@ pub fn dependencies(env: &Env, type_id: TypeId) -> Vec<TypeId> {
 
     parents
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_get_type() {
+        assert_eq!(builder_get_type("GtkButton", false), "gtk_button_get_type");
+        assert_eq!(
+            builder_get_type("GtkHeaderBar", false),
+            "gtk_header_bar_get_type"
+        );
+        assert_eq!(builder_get_type("GtkGLArea", false), "gtk_gl_area_get_type");
+        assert_eq!(
+            builder_get_type("GSimpleAction", false),
+            "gsimple_action_get_type"
+        );
+        assert_eq!(
+            builder_get_type("GSimpleAction", true),
+            "g_simple_action_get_type"
+        );
+    }
+}
@@ -213,6 +213,26 @@ pub fn run(library: &Library, namespaces: &namespaces::Info) -> Info {
                 _ => {}
             }
         }
+
+        // Global functions and constants are re-exported at the crate root
+        for func in &ns.functions {
+            if let Some(ref c_identifier) = func.c_identifier {
+                let symbol = Symbol {
+                    crate_name: crate_name.cloned(),
+                    name: func.name.clone(),
+                    ..Default::default()
+                };
+                info.insert(c_identifier, symbol, None);
+            }
+        }
+        for constant in &ns.constants {
+            let symbol = Symbol {
+                crate_name: crate_name.cloned(),
+                name: constant.name.clone(),
+                ..Default::default()
+            };
+            info.insert(&constant.c_identifier, symbol, None);
+        }
     }
 
     info
@@ -139,7 +139,16 @@ pub fn analyze(
     for par in signal_parameters {
         let name = nameutil::mangle_keywords(&*par.name).into_owned();
 
-        let ref_mode = RefMode::without_unneeded_mut(env, par, false, false);
+        // Transfer-full arguments are consumed by the handler, so hand them
+        // over as owned values instead of borrows.
+        let is_owned = par.transfer == library::Transfer::Full
+            && ConversionType::of(env, par.typ) == ConversionType::Pointer;
+
+        let ref_mode = if is_owned {
+            RefMode::None
+        } else {
+            RefMode::without_unneeded_mut(env, par, false, false)
+        };
 
         let nullable_override = configured_signals
             .matched_parameters(&name)
@@ -154,7 +163,9 @@ pub fn analyze(
             });
         let nullable = nullable_override.unwrap_or(par.nullable);
 
-        let conversion_type = {
+        let conversion_type = if is_owned {
+            ConversionType::Pointer
+        } else {
             match env.library.type_(par.typ) {
                 library::Type::Fundamental(library::Fundamental::Utf8)
                 | library::Type::Record(..)
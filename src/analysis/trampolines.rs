@@ -10,7 +10,7 @@ use crate::{
     config::{self, gobjects::GObject},
     env::Env,
     library,
-    nameutil::signal_to_snake,
+    nameutil::{mangle_keywords, signal_to_snake},
     parser::is_empty_c_type,
     traits::IntoString,
     version::Version,
@@ -22,6 +22,9 @@ pub struct Trampoline {
     pub name: String,
     pub parameters: Parameters,
     pub ret: library::Parameter,
+    /// Out parameters of signals, returned by the handler after the return
+    /// value and written back by the trampoline
+    pub outs: Vec<library::Parameter>,
     // This field is used for user callbacks in `codegen::function_body_chunk` when generating
     // inner C functions. We need to have the bound name in order to create variables and also to
     // pass to the C function bounds (otherwise it won't compile because it doesn't know how to
@@ -39,6 +42,9 @@ pub struct Trampoline {
     pub nullable: library::Nullable,
     /// This field is used to give the type name when generating the "IsA<X>" part.
     pub type_name: String,
+    /// Set when this trampoline is generated once at module level and shared
+    /// with other signals instead of being nested in its `connect_*` function.
+    pub is_shared: bool,
 }
 
 pub type Trampolines = Vec<Trampoline>;
@@ -68,8 +74,10 @@ pub fn analyze(
 
     let name = format!("{}_trampoline", signal_to_snake(&signal.name));
 
-    //TODO: move to object.signal.return config
-    let inhibit = configured_signals.iter().any(|f| f.inhibit);
+    let inhibit = configured_signals
+        .iter()
+        .find_map(|f| f.inhibit)
+        .unwrap_or_else(|| is_event_signal(signal));
     if inhibit && signal.ret.typ != library::TypeId::tid_bool() {
         error!("Wrong return type for Inhibit for signal '{}'", signal.name);
     }
@@ -170,10 +178,30 @@ pub fn analyze(
         ..signal.ret.clone()
     };
 
+    let outs = signal
+        .parameters
+        .iter()
+        .filter(|par| par.direction == library::ParameterDirection::Out)
+        .map(|par| {
+            let name = mangle_keywords(&*par.name).into_owned();
+            let nullable = parameters
+                .rust_parameters
+                .iter()
+                .find(|rust_par| rust_par.name == name)
+                .map_or(par.nullable, |rust_par| rust_par.nullable);
+            library::Parameter {
+                name,
+                nullable,
+                ..par.clone()
+            }
+        })
+        .collect();
+
     let trampoline = Trampoline {
         name,
         parameters,
         ret,
+        outs,
         bounds,
         version,
         inhibit,
@@ -185,14 +213,90 @@ pub fn analyze(
         destroy_index: 0,
         nullable: library::Nullable(false),
         type_name: env.library.type_(type_tid).get_name(),
+        is_shared: false,
     };
     Ok(trampoline)
 }
 
+/// Key identifying the code generated for a signal trampoline, except for its name.
+fn dedup_key(trampoline: &Trampoline) -> String {
+    let ret = &trampoline.ret;
+    format!(
+        "{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{:?}|{}|{:?}|{}",
+        trampoline.parameters.rust_parameters,
+        trampoline.parameters.c_parameters,
+        trampoline.parameters.transformations,
+        ret.typ,
+        ret.c_type,
+        ret.nullable,
+        ret.transfer,
+        trampoline.bounds,
+        trampoline.concurrency,
+        trampoline.inhibit,
+        trampoline.is_notify,
+        trampoline.type_name,
+    )
+}
+
+/// Makes signals with identical trampolines share a single one.
+///
+/// Returns the shared trampolines, which have to be generated once at module
+/// level, while the trampolines of the signals using them are renamed and
+/// flagged as `is_shared`.
+pub fn deduplicate<'a>(trampolines: impl Iterator<Item = &'a mut Trampoline>) -> Trampolines {
+    let mut groups: Vec<(String, Vec<&'a mut Trampoline>)> = Vec::new();
+    for trampoline in trampolines {
+        let key = dedup_key(trampoline);
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, group)) => group.push(trampoline),
+            None => groups.push((key, vec![trampoline])),
+        }
+    }
+
+    let mut shared = Trampolines::new();
+    for (_, group) in groups {
+        if group.len() < 2 {
+            continue;
+        }
+        let mut trampoline = group[0].clone();
+        if trampoline.is_notify {
+            trampoline.name = "notify_trampoline".to_owned();
+        }
+        if shared.iter().any(|t| t.name == trampoline.name) {
+            trampoline.name = format!("{}_{}", trampoline.name, shared.len());
+        }
+        trampoline.is_shared = true;
+        // The shared trampoline is needed as soon as one of its users is available
+        trampoline.version = group.iter().map(|t| t.version).min().flatten();
+        for t in group {
+            t.name = trampoline.name.clone();
+            t.is_shared = true;
+        }
+        shared.push(trampoline);
+    }
+    shared
+}
+
+/// Whether the signal is an event signal whose `gboolean` return value tells
+/// if the event propagation has to be stopped, like `GtkWidget::key-press-event`.
+fn is_event_signal(signal: &library::Signal) -> bool {
+    signal.name.ends_with("-event")
+        && signal.ret.typ == library::TypeId::tid_bool()
+        && signal
+            .parameters
+            .iter()
+            .any(|p| p.c_type.starts_with("GdkEvent"))
+}
+
 fn closure_errors(env: &Env, signal: &library::Signal) -> Vec<String> {
     let mut errors: Vec<String> = Vec::new();
     for par in &signal.parameters {
-        if let Some(error) = type_error(env, par) {
+        let error = if par.direction == library::ParameterDirection::Out {
+            out_type_error(env, par)
+        } else {
+            type_error(env, par)
+        };
+        if let Some(error) = error {
             errors.push(format!(
                 "{} {}: {}",
                 error,
@@ -213,6 +317,24 @@ fn closure_errors(env: &Env, signal: &library::Signal) -> Vec<String> {
     errors
 }
 
+/// Out parameters of signals are returned by the handler, so they can't borrow
+/// from it: only scalars and values transferred in full can be written back
+fn out_type_error(env: &Env, par: &library::Parameter) -> Option<&'static str> {
+    use super::conversion_type::ConversionType::*;
+    match ConversionType::of(env, par.typ) {
+        Direct | Scalar | Option | Result { .. } => (),
+        Pointer if par.transfer == library::Transfer::Full => (),
+        _ => return Some("Out"),
+    }
+    type_error(
+        env,
+        &library::Parameter {
+            direction: library::ParameterDirection::In,
+            ..par.clone()
+        },
+    )
+}
+
 pub fn type_error(env: &Env, par: &library::Parameter) -> Option<&'static str> {
     use super::rust_type::TypeError::*;
     if par.direction == library::ParameterDirection::Out {
@@ -0,0 +1,212 @@
+use crate::{
+    config,
+    env::Env,
+    library::{self, Fundamental, ParameterDirection, Transfer, Type, TypeId},
+    nameutil,
+    traits::*,
+    version::Version,
+};
+use log::info;
+
+/// Conversion of a macro argument into its C value
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Conversion {
+    /// Passed as is
+    Direct,
+    /// `bool` converted with `IntoGlib`
+    Bool,
+    /// Anything implementing `AsRef<str>`
+    Str,
+    /// Anything implementing `AsRef<Path>`
+    Path,
+}
+
+#[derive(Debug)]
+pub enum Return {
+    None,
+    Direct,
+    Bool,
+    /// Owned string returned as `GString` or `PathBuf`
+    String {
+        path: bool,
+        nullable: bool,
+    },
+}
+
+/// Declarative macro binding a function taking a `NULL`-terminated list of
+/// strings as variadic arguments
+#[derive(Debug)]
+pub struct Info {
+    pub name: String,
+    pub glib_name: String,
+    /// Fixed parameters, in order
+    pub parameters: Vec<(String, Conversion)>,
+    /// Conversion of the variadic arguments
+    pub varargs: Conversion,
+    pub ret: Return,
+    pub version: Option<Version>,
+}
+
+pub fn analyze(
+    env: &Env,
+    functions: &[&library::Function],
+    obj: &config::gobjects::GObject,
+) -> Vec<Info> {
+    let ns = env.library.namespace(library::MAIN_NAMESPACE);
+    let mut infos = Vec::new();
+
+    for func in functions {
+        if !func
+            .parameters
+            .last()
+            .map_or(false, |par| is_varargs(env, par.typ))
+        {
+            continue;
+        }
+        if obj
+            .functions
+            .matched(&func.name)
+            .iter()
+            .any(|f| f.status.ignored())
+        {
+            continue;
+        }
+        if env.is_totally_deprecated(None, func.deprecated_version) {
+            continue;
+        }
+        let glib_name = match func.c_identifier {
+            Some(ref c_identifier) => c_identifier,
+            None => continue,
+        };
+
+        // The `va_list` twin often carries the documentation of the arguments
+        let twin = ns.functions.iter().find(|f| {
+            f.c_identifier.as_ref().map_or(false, |c| {
+                c.strip_prefix(glib_name.as_str()).map_or(false, |suffix| {
+                    matches!(suffix, "_valist" | "_va_list" | "_va")
+                })
+            }) && f.parameters.iter().any(|par| par.c_type == "va_list")
+        });
+        let null_terminated = func
+            .parameters
+            .iter()
+            .chain(twin.iter().flat_map(|twin| twin.parameters.iter()))
+            .filter_map(|par| par.doc.as_ref())
+            .chain(func.doc.iter())
+            .any(|doc| is_null_terminated(doc));
+        if !null_terminated {
+            if let Some(twin) = twin {
+                info!(
+                    "Variadic function {} (with va_list twin {}) isn't NULL-terminated, not generating a macro",
+                    glib_name,
+                    twin.c_identifier.as_deref().unwrap_or(&twin.name)
+                );
+            }
+            continue;
+        }
+
+        let fixed = &func.parameters[..func.parameters.len() - 1];
+        // The variadic arguments are of the same kind as the last fixed string
+        let varargs = match fixed.last().and_then(|par| conversion(env, par)) {
+            Some(conversion @ (Conversion::Str | Conversion::Path)) => conversion,
+            _ => continue,
+        };
+        let parameters = match fixed
+            .iter()
+            .map(|par| {
+                conversion(env, par).map(|conversion| {
+                    (
+                        nameutil::mangle_keywords(&par.name).into_owned(),
+                        conversion,
+                    )
+                })
+            })
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(parameters) => parameters,
+            None => continue,
+        };
+        let ret = match analyze_return(env, &func.ret) {
+            Some(ret) => ret,
+            None => continue,
+        };
+
+        infos.push(Info {
+            name: nameutil::mangle_keywords(&func.name).into_owned(),
+            glib_name: glib_name.clone(),
+            parameters,
+            varargs,
+            ret,
+            version: func.version,
+        });
+    }
+
+    infos
+}
+
+fn is_varargs(env: &Env, typ: TypeId) -> bool {
+    matches!(
+        env.library.type_(typ),
+        Type::Fundamental(Fundamental::VarArgs)
+    )
+}
+
+fn is_null_terminated(doc: &str) -> bool {
+    let doc = doc.to_lowercase().replace('%', "");
+    doc.contains("null-terminated")
+        || doc.contains("null terminated")
+        || doc.contains("terminated by null")
+        || doc.contains("terminated with null")
+}
+
+fn conversion(env: &Env, par: &library::Parameter) -> Option<Conversion> {
+    if par.direction != ParameterDirection::In || *par.nullable {
+        return None;
+    }
+    match env.library.type_(par.typ) {
+        Type::Fundamental(Fundamental::Utf8) => Some(Conversion::Str),
+        Type::Fundamental(Fundamental::Filename) => Some(Conversion::Path),
+        Type::Fundamental(Fundamental::Boolean) => Some(Conversion::Bool),
+        Type::Fundamental(fund) if is_direct(*fund) => Some(Conversion::Direct),
+        _ => None,
+    }
+}
+
+fn analyze_return(env: &Env, ret: &library::Parameter) -> Option<Return> {
+    match env.library.type_(ret.typ) {
+        Type::Fundamental(Fundamental::None) => Some(Return::None),
+        Type::Fundamental(Fundamental::Utf8 | Fundamental::Filename)
+            if ret.transfer == Transfer::Full =>
+        {
+            Some(Return::String {
+                path: ret.typ == TypeId::tid_filename(),
+                nullable: *ret.nullable,
+            })
+        }
+        Type::Fundamental(Fundamental::Boolean) => Some(Return::Bool),
+        Type::Fundamental(fund) if is_direct(*fund) => Some(Return::Direct),
+        _ => None,
+    }
+}
+
+fn is_direct(fund: Fundamental) -> bool {
+    use crate::library::Fundamental::*;
+    matches!(
+        fund,
+        Int8 | UInt8
+            | Int16
+            | UInt16
+            | Int32
+            | UInt32
+            | Int64
+            | UInt64
+            | Int
+            | UInt
+            | Long
+            | ULong
+            | Size
+            | SSize
+            | Float
+            | Double
+    )
+}
@@ -54,6 +54,22 @@ pub enum Chunk {
     ErrorResultReturn {
         value: Box<Chunk>,
     },
+    BoolErrorReturn {
+        condition: String,
+        message: String,
+        value: Box<Chunk>,
+    },
+    NoneIsError {
+        message: String,
+        value: Box<Chunk>,
+    },
+    /// `Some(value)` if `flag` is set, `None` otherwise, or `value` as is
+    /// when it is already optional
+    Requested {
+        flag: String,
+        nullable: bool,
+        value: Box<Chunk>,
+    },
     AssertInit(SafetyAssertionMode),
     Connect {
         signal: String,
@@ -12,6 +12,10 @@ pub struct Parameter {
     pub is_error: bool,
     pub is_uninitialized: bool,
     pub try_from_glib: TryFromGlib,
+    pub nullable: library::Nullable,
+    /// Boolean deciding whether the optional out parameter is requested,
+    /// `NULL` being passed otherwise
+    pub requested_by: Option<String>,
 }
 
 impl Parameter {
@@ -27,6 +31,8 @@ impl Parameter {
             is_error: orig.is_error,
             is_uninitialized,
             try_from_glib: orig.try_from_glib.clone(),
+            nullable: orig.nullable,
+            requested_by: None,
         }
     }
 }
@@ -41,6 +47,8 @@ impl From<&analysis::Parameter> for Parameter {
             is_error: orig.lib_par.is_error,
             is_uninitialized: false,
             try_from_glib: orig.try_from_glib.clone(),
+            nullable: orig.lib_par.nullable,
+            requested_by: None,
         }
     }
 }
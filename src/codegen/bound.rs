@@ -14,14 +14,15 @@ impl Bound {
     }
 
     /// Returns the type parameter reference, with [`BoundType::IsA`] wrapped
-    /// in `ref_mode` and `nullable` as appropriate.
+    /// in `ref_mode` (borrowed for `lifetime`) and `nullable` as appropriate.
     pub(super) fn full_type_parameter_reference(
         &self,
         ref_mode: RefMode,
+        lifetime: Option<char>,
         nullable: Nullable,
         r#async: bool,
     ) -> String {
-        let ref_str = ref_mode.for_rust_type();
+        let ref_str = ref_mode.for_rust_type_with_lifetime(lifetime);
 
         // Generate `impl Trait` if this bound does not have an alias
         let trait_bound = match self.type_parameter_reference() {
@@ -52,6 +53,7 @@ impl Bound {
                 format!("Option<{}{}>", ref_str, trait_bound)
             }
             BoundType::IsA(_) => format!("{}{}", ref_str, trait_bound),
+            BoundType::IsASlice => format!("{}[{}]", ref_str, trait_bound),
             BoundType::AsRef(_) if *nullable => {
                 format!("Option<{}>", trait_bound)
             }
@@ -86,6 +88,7 @@ impl Bound {
             }
             BoundType::AsRef(Some(_ /*lifetime*/)) => panic!("AsRef cannot have a lifetime"),
             BoundType::AsRef(None) => format!("AsRef<{}>", self.type_str),
+            BoundType::IsASlice => format!("IsA<{}>", self.type_str),
         }
     }
 }
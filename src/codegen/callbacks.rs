@@ -0,0 +1,302 @@
+use crate::{
+    analysis::{
+        conversion_type::ConversionType, ffi_type::ffi_type, imports::Imports, is_gpointer,
+        namespaces, ref_mode::RefMode, rust_type::RustType,
+    },
+    codegen::general,
+    config::gobjects::GObject,
+    env::Env,
+    file_saver,
+    library::*,
+    nameutil::{self, use_glib_if_needed},
+    writer::primitives::tabs,
+};
+use std::{
+    io::{prelude::*, Result},
+    path::Path,
+};
+
+/// Safe wrapper calling a callback handed out by C
+struct Adapter {
+    name: String,
+    /// Type of the raw function pointer
+    ffi_func: String,
+    /// Type of the user data, when the callback takes some
+    user_data: Option<String>,
+    /// Name, type and conversion of the arguments of `call()`
+    parameters: Vec<(String, String, String)>,
+    ret: Option<String>,
+    ret_conversion: &'static str,
+}
+
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    let configs: Vec<&GObject> = env
+        .config
+        .objects
+        .values()
+        .filter(|c| {
+            c.status.need_generate() && c.type_id.map_or(false, |tid| tid.ns_id == namespaces::MAIN)
+        })
+        .collect();
+    let has_any = configs
+        .iter()
+        .any(|c| matches!(env.library.type_(c.type_id.unwrap()), Type::Function(_)));
+
+    if !has_any {
+        return;
+    }
+
+    let mut imports = Imports::new(&env.library);
+    let mut callbacks = Vec::new();
+    for config in &configs {
+        if let Type::Function(func) = env.library.type_(config.type_id.unwrap()) {
+            let adapter = analyze_adapter(env, func, &mut imports);
+            callbacks.push((func, adapter));
+        }
+    }
+
+    let path = root_path.join("callbacks.rs");
+    file_saver::save_to_file(path, env, |w| {
+        general::start_comments(w, &env.config)?;
+        general::uses(w, env, &imports, None)?;
+        writeln!(w)?;
+
+        mod_rs.push("\nmod callbacks;".into());
+        for (func, _) in &callbacks {
+            mod_rs.push(format!("pub use self::callbacks::{};", func.name));
+            generate_callback(w, func)?;
+        }
+        for (func, adapter) in &callbacks {
+            if let Some(adapter) = adapter {
+                mod_rs.push(format!("pub use self::callbacks::{};", adapter.name));
+                generate_adapter(w, func, adapter)?;
+            }
+        }
+
+        Ok(())
+    });
+}
+
+fn generate_callback(w: &mut dyn Write, func: &Function) -> Result<()> {
+    let c_type = func.c_identifier.as_ref().unwrap_or(&func.name);
+    writeln!(w, "pub type {} = ffi::{};", func.name, c_type)?;
+
+    Ok(())
+}
+
+/// Callbacks can be called from Rust when the arguments other than the user
+/// data can be passed in and the return value converted back
+fn analyze_adapter(env: &Env, func: &Function, imports: &mut Imports) -> Option<Adapter> {
+    if func.throws {
+        return None;
+    }
+    let mut user_data = None;
+    let mut ffi_parameters = Vec::new();
+    let mut parameters = Vec::new();
+    for (pos, par) in func.parameters.iter().enumerate() {
+        if is_gpointer(&par.c_type) && user_data.is_none() {
+            if par.closure != Some(pos) && !par.name.ends_with("data") {
+                return None;
+            }
+            let ffi_user_data = use_glib_if_needed(env, "ffi::gpointer");
+            ffi_parameters.push(ffi_user_data.clone());
+            user_data = Some(ffi_user_data);
+            continue;
+        }
+        ffi_parameters.push(
+            ffi_type(env, par.typ, &par.c_type)
+                .ok()?
+                .as_str()
+                .to_owned(),
+        );
+        if par.direction != ParameterDirection::In || !is_adaptable(env, par.typ) {
+            return None;
+        }
+        let conversion = match (ConversionType::of(env, par.typ), par.transfer) {
+            (ConversionType::Direct, _) => "",
+            (ConversionType::Scalar, _) => ".into_glib()",
+            (ConversionType::Pointer | ConversionType::Borrow, Transfer::None) => {
+                ".to_glib_none().0"
+            }
+            (ConversionType::Pointer | ConversionType::Borrow, Transfer::Full) => ".to_glib_full()",
+            _ => return None,
+        };
+        let rust_type = RustType::builder(env, par.typ)
+            .direction(ParameterDirection::In)
+            .nullable(par.nullable)
+            .ref_mode(RefMode::of(env, par.typ, ParameterDirection::In))
+            .try_build_param()
+            .ok()?;
+        let name = nameutil::mangle_keywords(&*par.name).into_owned();
+        parameters.push((
+            name.clone(),
+            rust_type.as_str().to_owned(),
+            format!("{}{}", name, conversion),
+        ));
+        imports.add_used_types(rust_type.used_types());
+    }
+
+    let (ret, ret_conversion) = if func.ret.typ == TypeId::tid_none() {
+        (None, "")
+    } else {
+        if !is_adaptable(env, func.ret.typ) {
+            return None;
+        }
+        let conversion = match (ConversionType::of(env, func.ret.typ), func.ret.transfer) {
+            (ConversionType::Direct, _) => "",
+            (ConversionType::Scalar, _) => "from_glib",
+            (ConversionType::Pointer, Transfer::None) => "from_glib_none",
+            (ConversionType::Pointer, Transfer::Full) => "from_glib_full",
+            _ => return None,
+        };
+        let rust_type = RustType::builder(env, func.ret.typ)
+            .direction(ParameterDirection::Return)
+            .nullable(func.ret.nullable)
+            .try_build()
+            .ok()?;
+        imports.add_used_types(rust_type.used_types());
+        (Some(rust_type.as_str().to_owned()), conversion)
+    };
+
+    let ffi_ret = if ret.is_some() {
+        format!(
+            " -> {}",
+            ffi_type(env, func.ret.typ, &func.ret.c_type).ok()?.as_str()
+        )
+    } else {
+        String::new()
+    };
+    if parameters
+        .iter()
+        .any(|(_, _, conversion)| conversion.contains('.'))
+        || !ret_conversion.is_empty()
+    {
+        imports.add("glib::translate::*");
+    }
+
+    Some(Adapter {
+        name: format!("{}Handle", func.name),
+        ffi_func: format!(
+            "unsafe extern \"C\" fn({}){}",
+            ffi_parameters.join(", "),
+            ffi_ret
+        ),
+        user_data,
+        parameters,
+        ret,
+        ret_conversion,
+    })
+}
+
+/// Types passed by value or through a pointer to a single instance
+fn is_adaptable(env: &Env, tid: TypeId) -> bool {
+    match env.library.type_(tid) {
+        Type::Fundamental(fund) => !matches!(
+            fund,
+            Fundamental::Pointer
+                | Fundamental::VarArgs
+                | Fundamental::Unsupported
+                | Fundamental::None
+        ),
+        Type::Enumeration(_)
+        | Type::Bitfield(_)
+        | Type::Class(_)
+        | Type::Interface(_)
+        | Type::Record(_) => true,
+        _ => false,
+    }
+}
+
+fn generate_adapter(w: &mut dyn Write, func: &Function, adapter: &Adapter) -> Result<()> {
+    writeln!(w)?;
+    if adapter.user_data.is_some() {
+        writeln!(
+            w,
+            "/// [`{}`] handed out by C, along with its user data",
+            func.name
+        )?;
+    } else {
+        writeln!(w, "/// [`{}`] handed out by C", func.name)?;
+    }
+    writeln!(w, "#[derive(Clone, Copy)]")?;
+    writeln!(w, "pub struct {} {{", adapter.name)?;
+    writeln!(w, "{}func: {},", tabs(1), adapter.ffi_func)?;
+    if let Some(ref user_data) = adapter.user_data {
+        writeln!(w, "{}user_data: {},", tabs(1), user_data)?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "impl {} {{", adapter.name)?;
+    writeln!(w, "{}/// Wraps `func`, `None` if it's `NULL`.", tabs(1))?;
+    writeln!(w, "{}///", tabs(1))?;
+    writeln!(w, "{}/// # Safety", tabs(1))?;
+    writeln!(w, "{}///", tabs(1))?;
+    if let Some(ref user_data) = adapter.user_data {
+        writeln!(
+            w,
+            "{}/// `func` and `user_data` must be handed out together by C, and stay valid",
+            tabs(1)
+        )?;
+        writeln!(w, "{}/// as long as the handle is used.", tabs(1))?;
+        writeln!(
+            w,
+            "{}pub unsafe fn from_raw(func: {}, user_data: {}) -> Option<Self> {{",
+            tabs(1),
+            func.name,
+            user_data
+        )?;
+        writeln!(w, "{}func.map(|func| Self {{ func, user_data }})", tabs(2))?;
+    } else {
+        writeln!(
+            w,
+            "{}/// `func` must stay valid as long as the handle is used.",
+            tabs(1)
+        )?;
+        writeln!(
+            w,
+            "{}pub unsafe fn from_raw(func: {}) -> Option<Self> {{",
+            tabs(1),
+            func.name
+        )?;
+        writeln!(w, "{}func.map(|func| Self {{ func }})", tabs(2))?;
+    }
+    writeln!(w, "{}}}", tabs(1))?;
+    writeln!(w)?;
+
+    let parameters = adapter
+        .parameters
+        .iter()
+        .map(|(name, typ, _)| format!(", {}: {}", name, typ))
+        .collect::<String>();
+    let ret = adapter
+        .ret
+        .as_ref()
+        .map(|ret| format!(" -> {}", ret))
+        .unwrap_or_default();
+    writeln!(w, "{}pub fn call(&self{}){} {{", tabs(1), parameters, ret)?;
+    let mut arguments = adapter
+        .parameters
+        .iter()
+        .map(|(_, _, conversion)| conversion.as_str())
+        .collect::<Vec<_>>();
+    if adapter.user_data.is_some() {
+        let pos = func
+            .parameters
+            .iter()
+            .position(|par| is_gpointer(&par.c_type))
+            .unwrap();
+        arguments.insert(pos, "self.user_data");
+    }
+    let call = format!("(self.func)({})", arguments.join(", "));
+    writeln!(w, "{}unsafe {{", tabs(2))?;
+    if adapter.ret_conversion.is_empty() {
+        writeln!(w, "{}{}", tabs(3), call)?;
+    } else {
+        writeln!(w, "{}{}({})", tabs(3), adapter.ret_conversion, call)?;
+    }
+    writeln!(w, "{}}}", tabs(2))?;
+    writeln!(w, "{}}}", tabs(1))?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
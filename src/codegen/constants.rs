@@ -6,7 +6,11 @@ use crate::{
     env::Env,
     file_saver, library,
 };
-use std::path::Path;
+use std::{
+    collections::BTreeSet,
+    io::{Result, Write},
+    path::Path,
+};
 
 pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     let path = root_path.join("constants.rs");
@@ -19,13 +23,24 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     let sys_crate_name = env.main_sys_crate_name();
     imports.add("std::ffi::CStr");
 
-    file_saver::save_to_file(path, env.config.make_backup, |w| {
+    file_saver::save_to_file(path, env, |w| {
         general::start_comments(w, &env.config)?;
         general::uses(w, env, &imports, None)?;
         writeln!(w)?;
 
         mod_rs.push("\nmod constants;".into());
 
+        let newtypes = env
+            .analysis
+            .constants
+            .iter()
+            .filter_map(|constant| constant.newtype.as_deref())
+            .collect::<BTreeSet<_>>();
+        for newtype in newtypes {
+            generate_newtype(w, newtype)?;
+            mod_rs.push(format!("pub use self::constants::{};", newtype));
+        }
+
         for constant in &env.analysis.constants {
             let type_ = env.type_(constant.typ);
             if let library::Type::Fundamental(library::Fundamental::Utf8) = type_ {
@@ -33,13 +48,22 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
                 cfg_condition(w, constant.cfg_condition.as_ref(), false, 0)?;
                 version_condition(w, env, None, constant.version, false, 0)?;
                 doc_alias(w, &constant.glib_name, "", 0)?;
+                let value = format!(
+                    "unsafe{{CStr::from_ptr({sys_crate_name}::{c_id}).to_str().unwrap()}}",
+                    sys_crate_name = sys_crate_name,
+                    c_id = constant.glib_name
+                );
+                let (type_, value) = match constant.newtype {
+                    Some(ref newtype) => (newtype.as_str(), format!("{}({})", newtype, value)),
+                    None => ("&'static str", value),
+                };
                 writeln!(
                     w,
-                    "pub static {name}: once_cell::sync::Lazy<&'static str> = once_cell::sync::Lazy::new(|| \
-                     unsafe{{CStr::from_ptr({sys_crate_name}::{c_id}).to_str().unwrap()}});",
-                    sys_crate_name = sys_crate_name,
+                    "pub static {name}: once_cell::sync::Lazy<{type_}> = once_cell::sync::Lazy::new(|| \
+                     {value});",
                     name = constant.name,
-                    c_id = constant.glib_name
+                    type_ = type_,
+                    value = value
                 )?;
                 if let Some(cfg) = version_condition_string(env, None, constant.version, false, 0) {
                     mod_rs.push(cfg);
@@ -51,3 +75,33 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
         Ok(())
     });
 }
+
+fn generate_newtype(w: &mut dyn Write, name: &str) -> Result<()> {
+    writeln!(
+        w,
+        "// rustdoc-stripper-ignore-next
+/// Name given by one of the string constants of this kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct {name}(&'static str);
+
+impl {name} {{
+    pub fn as_str(&self) -> &'static str {{
+        self.0
+    }}
+}}
+
+impl AsRef<str> for {name} {{
+    fn as_ref(&self) -> &str {{
+        self.0
+    }}
+}}
+
+impl std::fmt::Display for {name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+        f.write_str(self.0)
+    }}
+}}
+",
+        name = name
+    )
+}
@@ -0,0 +1,110 @@
+use crate::{
+    analysis::{self, namespaces::MAIN},
+    env::Env,
+    file_saver::save_to_file,
+    library::Type as LType,
+    version::Version,
+};
+use log::info;
+use std::{
+    collections::BTreeMap,
+    io::{Result, Write},
+    path::PathBuf,
+};
+
+/// Path of the features index, next to the documentation target:
+/// `docs.md` gives `docs.features.json`.
+fn index_path(env: &Env) -> PathBuf {
+    let doc_target_path = &env.config.doc_target_path;
+    let stem = doc_target_path
+        .file_stem()
+        .map_or_else(|| "docs".into(), |s| s.to_string_lossy());
+    doc_target_path.with_file_name(format!("{}.features.json", stem))
+}
+
+pub fn generate(env: &Env) {
+    let path = index_path(env);
+    info!("Generating documentation features index {:?}", path);
+    let index = collect(env);
+    save_to_file(&path, env, |w| write_index(w, &index));
+}
+
+fn collect(env: &Env) -> BTreeMap<String, Option<Version>> {
+    let mut index = BTreeMap::new();
+
+    for info in env.analysis.objects.values() {
+        if info.type_id.ns_id != MAIN || env.is_totally_deprecated(None, info.deprecated_version) {
+            continue;
+        }
+        add_type(env, &mut index, &info.name, info.version, &info.functions);
+        for signal in &info.signals {
+            index.insert(
+                format!("{}::{}", info.name, signal.connect_name),
+                env.config.filter_version(signal.version.max(info.version)),
+            );
+        }
+    }
+
+    for info in env.analysis.records.values() {
+        if info.type_id.ns_id != MAIN || env.is_totally_deprecated(None, info.deprecated_version) {
+            continue;
+        }
+        add_type(env, &mut index, &info.name, info.version, &info.functions);
+    }
+
+    for (tid, type_) in env.library.namespace_types(MAIN) {
+        let (name, version) = match type_ {
+            LType::Enumeration(enum_) => (&enum_.name, enum_.version),
+            LType::Bitfield(bitfield) => (&bitfield.name, bitfield.version),
+            _ => continue,
+        };
+        let obj = match env.config.objects.get(&tid.full_name(&env.library)) {
+            Some(obj) if !obj.status.ignored() => obj,
+            _ => continue,
+        };
+        index.insert(
+            name.clone(),
+            env.config.filter_version(obj.version.or(version)),
+        );
+    }
+
+    if let Some(ref global_functions) = env.analysis.global_functions {
+        for func in &global_functions.functions {
+            if func.should_be_doc_linked(env) {
+                index.insert(func.codegen_name().to_owned(), func.version);
+            }
+        }
+    }
+
+    index
+}
+
+fn add_type(
+    env: &Env,
+    index: &mut BTreeMap<String, Option<Version>>,
+    name: &str,
+    version: Option<Version>,
+    functions: &[analysis::functions::Info],
+) {
+    let version = env.config.filter_version(version);
+    index.insert(name.to_owned(), version);
+    for func in functions {
+        if func.should_be_doc_linked(env) {
+            index.insert(
+                format!("{}::{}", name, func.codegen_name()),
+                func.version.max(version),
+            );
+        }
+    }
+}
+
+fn write_index(w: &mut dyn Write, index: &BTreeMap<String, Option<Version>>) -> Result<()> {
+    writeln!(w, "{{")?;
+    for (i, (name, version)) in index.iter().enumerate() {
+        let feature =
+            version.map_or_else(|| "null".to_owned(), |v| format!("\"{}\"", v.to_feature()));
+        let separator = if i + 1 < index.len() { "," } else { "" };
+        writeln!(w, "  \"{}\": {}{}", name, feature, separator)?;
+    }
+    writeln!(w, "}}")
+}
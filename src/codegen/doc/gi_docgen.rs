@@ -1,10 +1,10 @@
 use crate::{
-    analysis::object::LocationInObject,
+    analysis::{object::LocationInObject, symbols::Symbol},
     codegen::doc::format::{
         gen_alias_doc_link, gen_callback_doc_link, gen_const_doc_link, gen_object_fn_doc_link,
         gen_property_doc_link, gen_signal_doc_link, gen_symbol_doc_link, gen_vfunc_doc_link,
     },
-    library::TypeId,
+    library::{TypeId, MAIN_NAMESPACE},
     nameutil::mangle_keywords,
     Env,
 };
@@ -196,13 +196,90 @@ fn ns_type_to_doc(namespace: &Option<String>, type_: &str) -> String {
     }
 }
 
+/// Whether the link targets the namespace of the generated crate.
+fn is_main_namespace(env: &Env, namespace: &Option<String>) -> bool {
+    namespace.as_ref().map_or(true, |ns| {
+        env.library.find_namespace(ns) == Some(MAIN_NAMESPACE)
+    })
+}
+
+/// Finds a type by its GIR name, looking in the main namespace by default.
+fn find_type(env: &Env, namespace: &Option<String>, type_: &str) -> Option<TypeId> {
+    let ns_id = match namespace {
+        Some(ns) => env.library.find_namespace(ns)?,
+        None => MAIN_NAMESPACE,
+    };
+    env.library.find_type(ns_id, type_)
+}
+
+/// Rust path of a type, which may belong to a dependency.
+fn rust_type_name(env: &Env, namespace: &Option<String>, type_: &str) -> String {
+    find_type(env, namespace, type_)
+        .and_then(|tid| env.symbols.borrow().by_tid(tid).map(Symbol::full_rust_name))
+        .unwrap_or_else(|| ns_type_to_doc(namespace, type_))
+}
+
+/// Links a type, `local` being the matching type analyzed in the current crate.
+///
+/// Types of dependencies link into their crate, types which aren't generated
+/// fall back to their C name.
+fn type_doc_link(
+    env: &Env,
+    namespace: &Option<String>,
+    type_: &str,
+    local: Option<TypeId>,
+) -> String {
+    if let Some(tid) = local.filter(|_| is_main_namespace(env, namespace)) {
+        return gen_symbol_doc_link(tid, env);
+    }
+    match find_type(env, namespace, type_) {
+        Some(tid) if tid.ns_id != MAIN_NAMESPACE && env.symbols.borrow().by_tid(tid).is_some() => {
+            gen_symbol_doc_link(tid, env)
+        }
+        Some(tid) => env.type_(tid).get_glib_name().map_or_else(
+            || format!("`{}`", ns_type_to_doc(namespace, type_)),
+            |c_name| format!("`{}`", c_name),
+        ),
+        None => format!("`{}`", ns_type_to_doc(namespace, type_)),
+    }
+}
+
+/// C name of a function which couldn't be linked, `type_` being `None` for
+/// global functions.
+fn c_function_name(
+    env: &Env,
+    namespace: &Option<String>,
+    type_: Option<&str>,
+    name: &str,
+) -> Option<String> {
+    let functions = match type_ {
+        Some(type_) => env.type_(find_type(env, namespace, type_)?).functions(),
+        None => {
+            let ns_id = match namespace {
+                Some(ns) => env.library.find_namespace(ns)?,
+                None => MAIN_NAMESPACE,
+            };
+            &env.library.namespace(ns_id).functions[..]
+        }
+    };
+    functions
+        .iter()
+        .find(|f| f.name == name)
+        .and_then(|f| f.c_identifier.as_ref())
+        .map(|c_name| format!("`{}()`", c_name))
+}
+
 fn find_method_or_function_by_name(
+    namespace: &Option<String>,
     type_: Option<&str>,
     name: &str,
     env: &Env,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
     is_class_method: bool,
 ) -> Option<String> {
+    if !is_main_namespace(env, namespace) {
+        return None;
+    }
     find_method_or_function(
         name,
         env,
@@ -224,33 +301,33 @@ impl GiDocgen {
     ) -> String {
         let symbols = env.symbols.borrow();
         match self {
-            GiDocgen::Enum { type_, namespace } | GiDocgen::Error { type_, namespace } => env
-                .analysis
-                .enumerations
-                .iter()
-                .find(|e| &e.name == type_)
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
-            GiDocgen::Class { type_, namespace } | GiDocgen::Interface { type_, namespace } => env
-                .analysis
-                .objects
-                .values()
-                .find(|o| &o.name == type_)
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
-            GiDocgen::Flag { type_, namespace } => env
-                .analysis
-                .flags
-                .iter()
-                .find(|e| &e.name == type_)
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+            GiDocgen::Enum { type_, namespace } | GiDocgen::Error { type_, namespace } => {
+                let local = env
+                    .analysis
+                    .enumerations
+                    .iter()
+                    .find(|e| &e.name == type_)
+                    .map(|info| info.type_id);
+                type_doc_link(env, namespace, type_, local)
+            }
+            GiDocgen::Class { type_, namespace } | GiDocgen::Interface { type_, namespace } => {
+                let local = env
+                    .analysis
+                    .objects
+                    .values()
+                    .find(|o| &o.name == type_)
+                    .map(|info| info.type_id);
+                type_doc_link(env, namespace, type_, local)
+            }
+            GiDocgen::Flag { type_, namespace } => {
+                let local = env
+                    .analysis
+                    .flags
+                    .iter()
+                    .find(|e| &e.name == type_)
+                    .map(|info| info.type_id);
+                type_doc_link(env, namespace, type_, local)
+            }
             GiDocgen::Const { type_, namespace } => env
                 .analysis
                 .constants
@@ -268,9 +345,9 @@ impl GiDocgen {
                 .analysis
                 .objects
                 .values()
-                .find(|o| &o.name == type_)
+                .find(|o| &o.name == type_ && is_main_namespace(env, namespace))
                 .map_or_else(
-                    || gen_property_doc_link(&ns_type_to_doc(namespace, type_), name),
+                    || gen_property_doc_link(&rust_type_name(env, namespace, type_), name),
                     |info| {
                         let sym = symbols.by_tid(info.type_id).unwrap();
                         gen_property_doc_link(&sym.full_rust_name(), name)
@@ -284,9 +361,9 @@ impl GiDocgen {
                 .analysis
                 .objects
                 .values()
-                .find(|o| &o.name == type_)
+                .find(|o| &o.name == type_ && is_main_namespace(env, namespace))
                 .map_or_else(
-                    || gen_signal_doc_link(&ns_type_to_doc(namespace, type_), name),
+                    || gen_signal_doc_link(&rust_type_name(env, namespace, type_), name),
                     |info| {
                         let sym = symbols.by_tid(info.type_id).unwrap();
                         gen_signal_doc_link(&sym.full_rust_name(), name)
@@ -296,15 +373,15 @@ impl GiDocgen {
                 || format!("`{}`", c_name),
                 |sym| format!("[`{n}`][crate::{n}]", n = sym.full_rust_name()),
             ),
-            GiDocgen::Struct { namespace, type_ } => env
-                .analysis
-                .records
-                .values()
-                .find(|r| &r.name == type_)
-                .map_or_else(
-                    || format!("`{}`", ns_type_to_doc(namespace, type_)),
-                    |info| gen_symbol_doc_link(info.type_id, env),
-                ),
+            GiDocgen::Struct { namespace, type_ } => {
+                let local = env
+                    .analysis
+                    .records
+                    .values()
+                    .find(|r| &r.name == type_)
+                    .map(|info| info.type_id);
+                type_doc_link(env, namespace, type_, local)
+            }
             GiDocgen::Constructor {
                 namespace,
                 type_,
@@ -313,35 +390,50 @@ impl GiDocgen {
                 .analysis
                 .find_object_by_function(
                     env,
-                    |o| &o.name == type_,
+                    |o| &o.name == type_ && is_main_namespace(env, namespace),
                     |f| f.name == mangle_keywords(name),
                 )
-                .map_or_else(
-                    || format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name),
-                    |(obj_info, fn_info)| {
-                        gen_object_fn_doc_link(obj_info, fn_info, env, in_type, type_)
-                    },
-                ),
+                .map(|(obj_info, fn_info)| {
+                    gen_object_fn_doc_link(obj_info, fn_info, env, in_type, type_)
+                })
+                .or_else(|| c_function_name(env, namespace, Some(type_), name))
+                .unwrap_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
             GiDocgen::Func {
                 namespace,
                 type_,
                 name,
-            } => find_method_or_function_by_name(type_.as_deref(), name, env, in_type, false)
-                .unwrap_or_else(|| {
-                    if let Some(ty) = type_ {
-                        format!("`{}::{}()`", ns_type_to_doc(namespace, ty), name)
-                    } else {
-                        format!("`{}()`", name)
-                    }
-                }),
+            } => find_method_or_function_by_name(
+                namespace,
+                type_.as_deref(),
+                name,
+                env,
+                in_type,
+                false,
+            )
+            .or_else(|| c_function_name(env, namespace, type_.as_deref(), name))
+            .unwrap_or_else(|| {
+                if let Some(ty) = type_ {
+                    format!("`{}::{}()`", ns_type_to_doc(namespace, ty), name)
+                } else {
+                    format!("`{}()`", name)
+                }
+            }),
             GiDocgen::Alias(alias) => gen_alias_doc_link(alias),
             GiDocgen::Method {
                 namespace,
                 type_,
                 name,
                 is_class_method,
-            } => find_method_or_function_by_name(Some(type_), name, env, in_type, *is_class_method)
-                .unwrap_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
+            } => find_method_or_function_by_name(
+                namespace,
+                Some(type_),
+                name,
+                env,
+                in_type,
+                *is_class_method,
+            )
+            .or_else(|| c_function_name(env, namespace, Some(type_), name))
+            .unwrap_or_else(|| format!("`{}::{}()`", ns_type_to_doc(namespace, type_), name)),
             GiDocgen::Callback { namespace, name } => {
                 gen_callback_doc_link(&ns_type_to_doc(namespace, name))
             }
@@ -1,4 +1,4 @@
-use self::format::reformat_doc;
+use self::format::{gen_property_doc_link, gen_signal_doc_link, gen_vfunc_doc_link, reformat_doc};
 use crate::{
     analysis::{self, namespaces::MAIN, object::LocationInObject},
     config::gobjects::GObject,
@@ -14,11 +14,12 @@ use once_cell::sync::Lazy;
 use regex::{Captures, Regex};
 use std::{
     borrow::Cow,
-    collections::{BTreeSet, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     io::{Result, Write},
 };
 use stripper_lib::{write_file_name, write_item_doc, Type as SType, TypeStruct};
 
+mod features;
 mod format;
 mod gi_docgen;
 
@@ -102,9 +103,8 @@ impl_function_like_type!(Signal);
 
 pub fn generate(env: &Env) {
     info!("Generating documentation {:?}", env.config.doc_target_path);
-    save_to_file(&env.config.doc_target_path, env.config.make_backup, |w| {
-        generate_doc(w, env)
-    });
+    save_to_file(&env.config.doc_target_path, env, |w| generate_doc(w, env));
+    features::generate(env);
 }
 
 #[allow(clippy::type_complexity)]
@@ -170,16 +170,19 @@ fn generate_doc(w: &mut dyn Write, env: &Env) -> Result<()> {
 
         for function in functions {
             if let Some(ref c_identifier) = function.c_identifier {
-                let fn_new_name = (&global_functions.functions)
-                    .iter()
-                    .find(move |f| &f.glib_name == c_identifier)
-                    .and_then(|analysed_f| analysed_f.new_name.clone());
-
-                let doc_ignored_parameters = (&global_functions.functions)
+                let analyzed_f = global_functions
+                    .functions
                     .iter()
-                    .find(|f| &f.glib_name == c_identifier)
-                    .map(|analyzed_f| analyzed_f.doc_ignore_parameters.clone())
+                    .find(|f| &f.glib_name == c_identifier);
+                let fn_new_name = analyzed_f.and_then(|f| f.new_name.clone());
+                let doc_ignored_parameters = analyzed_f
+                    .map(|f| f.doc_ignore_parameters.clone())
                     .unwrap_or_default();
+                let parameter_renames = analyzed_f
+                    .map(|f| f.parameter_renames.clone())
+                    .unwrap_or_default();
+                let safety_doc = analyzed_f.and_then(|f| f.safety_doc.clone());
+                let cycle_note = analyzed_f.and_then(callback_cycle_note);
                 create_fn_doc(
                     w,
                     env,
@@ -187,6 +190,10 @@ fn generate_doc(w: &mut dyn Write, env: &Env) -> Result<()> {
                     None,
                     fn_new_name,
                     doc_ignored_parameters,
+                    parameter_renames,
+                    safety_doc,
+                    cycle_note,
+                    Vec::new(),
                     None,
                 )?;
             }
@@ -290,6 +297,7 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
                 "\nThis is an Abstract Base Class, you cannot instantiate it."
             )?;
         }
+        write_runtime_feature_note(w, env, info.version)?;
 
         let impl_self = if has_trait { Some(info.type_id) } else { None };
         let mut implements = impl_self
@@ -338,7 +346,7 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
                         w,
                         "{}",
                         reformat_doc(
-                            &fix_param_names(doc, &None),
+                            &fix_param_names(doc, &None, &HashMap::new()),
                             env,
                             Some((&info.type_id, Some(LocationInObject::Builder)))
                         )
@@ -349,7 +357,7 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
                         w,
                         "{}",
                         reformat_doc(
-                            &fix_param_names(doc, &None),
+                            &fix_param_names(doc, &None, &HashMap::new()),
                             env,
                             Some((&info.type_id, Some(LocationInObject::Builder)))
                         )
@@ -378,6 +386,27 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
 
             writeln!(w, "\n# Implementors\n")?;
             writeln!(w, "{}", implementors.join(", "))?;
+
+            // Point to the manually implemented methods of the same type, traits
+            // from other crates belong to supertypes
+            let local_manual_traits = obj
+                .manual_traits
+                .iter()
+                .filter(|name| !name.contains("::"))
+                .collect::<Vec<_>>();
+            if !local_manual_traits.is_empty() {
+                let implementations = std::iter::once(&info.trait_name)
+                    .chain(local_manual_traits)
+                    .map(|name| format!("[`{0}`][trait@crate::prelude::{0}]", name))
+                    .collect::<Vec<_>>();
+                writeln!(w, "\n# Implementations\n")?;
+                writeln!(
+                    w,
+                    "The methods of [`struct@{}`] are split between {}.",
+                    ty.name,
+                    implementations.join(", ")
+                )?;
+            }
             Ok(())
         })?;
     }
@@ -432,6 +461,23 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
                 .find(|f| &f.glib_name == c_identifier)
                 .map(|analyzed_f| analyzed_f.doc_ignore_parameters.clone())
                 .unwrap_or_default();
+            let parameter_renames = info
+                .functions
+                .iter()
+                .find(|f| &f.glib_name == c_identifier)
+                .map(|analyzed_f| analyzed_f.parameter_renames.clone())
+                .unwrap_or_default();
+            let safety_doc = info
+                .functions
+                .iter()
+                .find(|f| &f.glib_name == c_identifier)
+                .and_then(|analyzed_f| analyzed_f.safety_doc.clone());
+            let cycle_note = info
+                .functions
+                .iter()
+                .find(|f| &f.glib_name == c_identifier)
+                .and_then(callback_cycle_note);
+            let see_also = see_also_links(env, info, function, properties, signals);
             create_fn_doc(
                 w,
                 env,
@@ -439,6 +485,10 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
                 Some(Box::new(ty)),
                 fn_new_name,
                 doc_ignored_parameters,
+                parameter_renames,
+                safety_doc,
+                cycle_note,
+                see_also,
                 Some((&info.type_id, object_location)),
             )?;
         }
@@ -457,6 +507,17 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
         } else {
             (ty.clone(), Some(LocationInObject::Impl))
         };
+        let see_also = info
+            .signals
+            .iter()
+            .find(|s| s.signal_name == signal.name)
+            .and_then(|s| s.default_handler.as_deref())
+            .and_then(|vfunc| {
+                let symbols = env.symbols.borrow();
+                let symbol = symbols.by_tid(info.type_id)?.full_rust_name();
+                Some(vec![gen_vfunc_doc_link(&symbol, vfunc)])
+            })
+            .unwrap_or_default();
         create_fn_doc(
             w,
             env,
@@ -464,6 +525,10 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
             Some(Box::new(ty)),
             None,
             HashSet::new(),
+            HashMap::new(),
+            None,
+            None,
+            see_also,
             Some((&info.type_id, object_location)),
         )?;
     }
@@ -508,6 +573,45 @@ fn create_object_doc(w: &mut dyn Write, env: &Env, info: &analysis::object::Info
     Ok(())
 }
 
+/// Links a property accessor or a signal emitter to the corresponding
+/// property or signal of the object.
+fn see_also_links(
+    env: &Env,
+    info: &analysis::object::Info,
+    function: &Function,
+    properties: &[Property],
+    signals: &[Signal],
+) -> Vec<String> {
+    if !function.parameters.iter().any(|p| p.instance_parameter) {
+        return Vec::new();
+    }
+    let symbols = env.symbols.borrow();
+    let symbol = match symbols.by_tid(info.type_id) {
+        Some(symbol) => symbol.full_rust_name(),
+        None => return Vec::new(),
+    };
+    let fn_name = function.name.as_str();
+
+    let mut links = Vec::new();
+    for property in properties {
+        let name = nameutil::signal_to_snake(&property.name);
+        let is_accessor = fn_name == name
+            || ["get_", "set_", "is_"]
+                .iter()
+                .any(|prefix| fn_name.strip_prefix(prefix) == Some(name.as_str()));
+        if is_accessor {
+            links.push(gen_property_doc_link(&symbol, &property.name));
+        }
+    }
+    for signal in signals {
+        let name = nameutil::signal_to_snake(&signal.name);
+        if fn_name == name || fn_name.strip_prefix("emit_") == Some(name.as_str()) {
+            links.push(gen_signal_doc_link(&symbol, &signal.name));
+        }
+    }
+    links
+}
+
 fn create_record_doc(w: &mut dyn Write, env: &Env, info: &analysis::record::Info) -> Result<()> {
     let record: &Record = env.library.type_(info.type_id).to_ref_as();
     let ty = record.to_stripper_type();
@@ -538,6 +642,16 @@ fn create_record_doc(w: &mut dyn Write, env: &Env, info: &analysis::record::Info
                 .iter()
                 .find(|f| &f.glib_name == c_identifier)
                 .and_then(|analysed_f| analysed_f.new_name.clone());
+            let safety_doc = info
+                .functions
+                .iter()
+                .find(|f| &f.glib_name == c_identifier)
+                .and_then(|analyzed_f| analyzed_f.safety_doc.clone());
+            let cycle_note = info
+                .functions
+                .iter()
+                .find(|f| &f.glib_name == c_identifier)
+                .and_then(callback_cycle_note);
             create_fn_doc(
                 w,
                 env,
@@ -545,6 +659,10 @@ fn create_record_doc(w: &mut dyn Write, env: &Env, info: &analysis::record::Info
                 Some(Box::new(ty.clone())),
                 fn_new_name,
                 HashSet::new(),
+                HashMap::new(),
+                safety_doc,
+                cycle_note,
+                Vec::new(),
                 Some((&info.type_id, None)),
             )?;
         }
@@ -635,17 +753,47 @@ fn create_bitfield_doc(
 
 static PARAM_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"@(\w+)\b").unwrap());
 
-fn fix_param_names<'a>(doc: &'a str, self_name: &Option<String>) -> Cow<'a, str> {
+fn fix_param_names<'a>(
+    doc: &'a str,
+    self_name: &Option<String>,
+    parameter_renames: &HashMap<String, String>,
+) -> Cow<'a, str> {
     PARAM_NAME.replace_all(doc, |caps: &Captures<'_>| {
         if let Some(self_name) = self_name {
             if &caps[1] == self_name {
                 return "@self".into();
             }
         }
-        format!("@{}", nameutil::mangle_keywords(&caps[1]))
+        format!("@{}", param_name(&caps[1], parameter_renames))
     })
 }
 
+fn param_name<'a>(name: &'a str, parameter_renames: &'a HashMap<String, String>) -> Cow<'a, str> {
+    match parameter_renames.get(name) {
+        Some(rename) => Cow::Borrowed(rename),
+        None => nameutil::mangle_keywords(name),
+    }
+}
+
+/// Warns that the notified callback found by the analysis keeps the object
+/// alive, along with the `_weak` variant when there is one
+fn callback_cycle_note(analyzed_f: &analysis::functions::Info) -> Option<String> {
+    let callback = analyzed_f.callback_cycle.as_ref()?;
+    let mut note = format!(
+        "`{}` is kept until it is replaced or the object is finalized, and it receives \
+         the object: capturing a strong reference to the object in it, directly or through \
+         another object, creates a reference cycle and neither is ever freed.",
+        callback
+    );
+    if let Some(ref weak_callback) = analyzed_f.weak_callback {
+        note.push_str(&format!(
+            " [`{0}()`][Self::{0}()] holds such an object weakly.",
+            weak_callback.name
+        ));
+    }
+    Some(note)
+}
+
 fn create_fn_doc<T>(
     w: &mut dyn Write,
     env: &Env,
@@ -653,6 +801,10 @@ fn create_fn_doc<T>(
     parent: Option<Box<TypeStruct>>,
     name_override: Option<String>,
     doc_ignored_parameters: HashSet<String>,
+    parameter_renames: HashMap<String, String>,
+    safety_doc: Option<String>,
+    cycle_note: Option<String>,
+    see_also: Vec<String>,
     in_type: Option<(&TypeId, Option<LocationInObject>)>,
 ) -> Result<()>
 where
@@ -661,7 +813,10 @@ where
     if env.is_totally_deprecated(None, *fn_.deprecated_version()) {
         return Ok(());
     }
-    if fn_.doc().is_none()
+    if safety_doc.is_none()
+        && cycle_note.is_none()
+        && see_also.is_empty()
+        && fn_.doc().is_none()
         && fn_.doc_deprecated().is_none()
         && fn_.ret().doc.is_none()
         && fn_.parameters().iter().all(|p| p.doc.is_none())
@@ -685,7 +840,11 @@ where
             writeln!(
                 w,
                 "{}",
-                reformat_doc(&fix_param_names(doc, &self_name), env, in_type)
+                reformat_doc(
+                    &fix_param_names(doc, &self_name, &parameter_renames),
+                    env,
+                    in_type
+                )
             )?;
         }
         if let Some(ver) = fn_.deprecated_version() {
@@ -697,9 +856,22 @@ where
             writeln!(
                 w,
                 "{}",
-                reformat_doc(&fix_param_names(doc, &self_name), env, in_type)
+                reformat_doc(
+                    &fix_param_names(doc, &self_name, &parameter_renames),
+                    env,
+                    in_type
+                )
             )?;
         }
+        write_runtime_feature_note(w, env, *fn_.version())?;
+        if let Some(ref doc) = safety_doc {
+            writeln!(w, "\n# Safety\n")?;
+            writeln!(w, "{}", doc)?;
+        }
+        if let Some(ref note) = cycle_note {
+            writeln!(w, "\n# Reference cycles\n")?;
+            writeln!(w, "{}", note)?;
+        }
 
         // A list of parameter positions to filter out
         let mut indices_to_ignore: BTreeSet<_> = fn_
@@ -727,7 +899,7 @@ where
                 || doc_ignored_parameters.contains(&param.name)
                 || param.direction == ParameterDirection::Out
                 // special case error pointer as it's transformed to a Result
-                || (param.name == "error" && param.c_type == "GError**")
+                || param.is_error
                 // special case `data` with explicit `gpointer` type as it could be something else (unlike `user_data`)
                 || (param.name == "data" && param.c_type == "gpointer");
             !ignore
@@ -738,11 +910,19 @@ where
                 continue;
             }
             if let Some(ref doc) = parameter.doc {
-                writeln!(w, "## `{}`", nameutil::mangle_keywords(&parameter.name[..]))?;
+                writeln!(
+                    w,
+                    "## `{}`",
+                    param_name(&parameter.name, &parameter_renames)
+                )?;
                 writeln!(
                     w,
                     "{}",
-                    reformat_doc(&fix_param_names(doc, &self_name), env, in_type)
+                    reformat_doc(
+                        &fix_param_names(doc, &self_name, &parameter_renames),
+                        env,
+                        in_type
+                    )
                 )?;
             }
         }
@@ -752,7 +932,7 @@ where
             .filter(|param| {
                 param.direction == ParameterDirection::Out
                     && !doc_ignored_parameters.contains(&param.name)
-                    && !(param.name == "error" && param.c_type == "GError**")
+                    && !param.is_error
             })
             .collect();
 
@@ -764,7 +944,11 @@ where
             writeln!(
                 w,
                 "{}",
-                reformat_doc(&fix_param_names(doc, &self_name), env, in_type)
+                reformat_doc(
+                    &fix_param_names(doc, &self_name, &parameter_renames),
+                    env,
+                    in_type
+                )
             )?;
         }
         // document OUT parameters as part of the function's Return
@@ -773,15 +957,22 @@ where
                 writeln!(
                     w,
                     "\n## `{}`",
-                    nameutil::mangle_keywords(&parameter.name[..])
+                    param_name(&parameter.name, &parameter_renames)
                 )?;
                 writeln!(
                     w,
                     "{}",
-                    reformat_doc(&fix_param_names(doc, &self_name), env, in_type),
+                    reformat_doc(
+                        &fix_param_names(doc, &self_name, &parameter_renames),
+                        env,
+                        in_type
+                    ),
                 )?;
             }
         }
+        if !see_also.is_empty() {
+            writeln!(w, "\nSee also: {}", see_also.join(", "))?;
+        }
         Ok(())
     })
 }
@@ -837,7 +1028,11 @@ fn create_property_doc(
                 writeln!(
                     w,
                     "{}",
-                    reformat_doc(&fix_param_names(doc, &None), env, Some(in_type))
+                    reformat_doc(
+                        &fix_param_names(doc, &None, &HashMap::new()),
+                        env,
+                        Some(in_type)
+                    )
                 )?;
             }
             if let Some(ver) = property.deprecated_version {
@@ -849,7 +1044,11 @@ fn create_property_doc(
                 writeln!(
                     w,
                     "{}",
-                    reformat_doc(&fix_param_names(doc, &None), env, Some(in_type))
+                    reformat_doc(
+                        &fix_param_names(doc, &None, &HashMap::new()),
+                        env,
+                        Some(in_type)
+                    )
                 )?;
             }
             Ok(())
@@ -903,3 +1102,25 @@ pub fn get_type_manual_traits_for_implements(
         .map(|name| format!("[`{0}`][trait@crate::prelude::{0}]", name))
         .collect()
 }
+
+/// Points items gated behind a version feature to the generated runtime check,
+/// as the library loaded at runtime can be older than the enabled features.
+fn write_runtime_feature_note(
+    w: &mut dyn Write,
+    env: &Env,
+    version: Option<Version>,
+) -> Result<()> {
+    if env.config.runtime_version_functions.is_none() {
+        return Ok(());
+    }
+    match version {
+        Some(version) if version > env.config.min_cfg_version => writeln!(
+            w,
+            "\nRequires the `{feature}` feature, use \
+             [`has_{feature}()`][crate::features::has_{feature}] to check that the library \
+             loaded at runtime provides it.",
+            feature = version.to_feature()
+        ),
+        _ => Ok(()),
+    }
+}
@@ -1,4 +1,4 @@
-use super::{function, trait_impls};
+use super::{function, sys::EnumRepr, trait_impls};
 use crate::{
     analysis::enums::Info,
     analysis::special_functions::Type,
@@ -31,7 +31,7 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     }
 
     let path = root_path.join("enums.rs");
-    file_saver::save_to_file(path, env.config.make_backup, |w| {
+    file_saver::save_to_file(path, env, |w| {
         general::start_comments(w, &env.config)?;
         general::uses(w, env, &env.analysis.enum_imports, None)?;
         writeln!(w)?;
@@ -51,6 +51,9 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
             if let Some(cfg) = cfg_condition_string(config.cfg_condition.as_ref(), false, 0) {
                 mod_rs.push(cfg);
             }
+            if config.doc_hidden {
+                mod_rs.push("#[doc(hidden)]".into());
+            }
             mod_rs.push(format!("pub use self::enums::{};", enum_.name));
             generate_enum(env, w, enum_, config, enum_analysis)?;
         }
@@ -78,6 +81,17 @@ fn generate_enum(
     let mut members: Vec<Member<'_>> = Vec::new();
     let mut vals: HashSet<String> = HashSet::new();
     let sys_crate_name = env.main_sys_crate_name();
+    // The -sys crate picks a wider type than `c_int` for large values, which
+    // `glib::Value` and `glib::Error` still see as `c_int`, as does MSVC
+    let repr = EnumRepr::of(enum_);
+    let (int_cast, ffi_cast) = if repr == EnumRepr::Int {
+        (String::new(), String::new())
+    } else {
+        (
+            " as _".to_owned(),
+            format!(" as {}::{}", sys_crate_name, enum_.c_type),
+        )
+    };
 
     for member in &enum_.members {
         let member_config = config.members.matched(&member.name);
@@ -121,7 +135,8 @@ fn generate_enum(
     if let Some(ref d) = config.derives {
         derives(w, d, 1)?;
     } else {
-        writeln!(w, "#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]")?;
+        let profile = config.derive_profile.unwrap_or(env.config.derive_profile);
+        writeln!(w, "#[derive({})]", profile.enum_derives().join(", "))?;
     }
     writeln!(w, "#[derive(Clone, Copy)]")?;
     writeln!(w, "#[non_exhaustive]")?;
@@ -149,8 +164,9 @@ fn generate_enum(
         w,
         "\
     #[doc(hidden)]
-    __Unknown(i32),
-}}"
+    __Unknown({}),
+}}",
+        repr.rust_type()
     )?;
 
     let functions = analysis
@@ -179,6 +195,34 @@ fn generate_enum(
         writeln!(w, "}}")?;
     }
 
+    if config.generate_enum_iter {
+        writeln!(w)?;
+        version_condition(w, env, None, enum_.version, false, 0)?;
+        cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
+        writeln!(w, "impl {} {{", analysis.name)?;
+        writeln!(w, "\t/// All the known members of this enumeration.")?;
+        writeln!(w, "\t#[allow(deprecated)]")?;
+        writeln!(w, "\tpub const ALL: &'static [Self] = &[")?;
+        for member in &members {
+            version_condition_no_doc(w, env, None, member.version, false, 2)?;
+            cfg_condition_no_doc(w, member.cfg_condition.as_ref(), false, 2)?;
+            writeln!(w, "\t\tSelf::{},", member.name)?;
+        }
+        writeln!(w, "\t];")?;
+        writeln!(w)?;
+        writeln!(
+            w,
+            "\t/// Returns an iterator over all the known members of this enumeration."
+        )?;
+        writeln!(
+            w,
+            "\tpub fn iter() -> impl Iterator<Item = Self> {{
+\t\tSelf::ALL.iter().copied()
+\t}}"
+        )?;
+        writeln!(w, "}}")?;
+    }
+
     trait_impls::generate(
         w,
         env,
@@ -241,7 +285,7 @@ impl IntoGlib for {name} {{
             member.name, sys_crate_name, member.c_name
         )?;
     }
-    writeln!(w, "\t\t\tSelf::__Unknown(value) => value,")?;
+    writeln!(w, "\t\t\tSelf::__Unknown(value) => value{},", int_cast)?;
     writeln!(
         w,
         "\
@@ -280,7 +324,7 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
             sys_crate_name, member.c_name, member.name
         )?;
     }
-    writeln!(w, "\t\t\tvalue => Self::__Unknown(value),")?;
+    writeln!(w, "\t\t\tvalue => Self::__Unknown(value{}),", int_cast)?;
     writeln!(
         w,
         "\
@@ -332,12 +376,14 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
             "    }}
 
     fn code(self) -> i32 {{
-        self.into_glib()
+        self.into_glib(){int_cast}
     }}
 
     fn from(code: i32) -> Option<Self> {{
-        {assert}match code {{",
-            assert = assert
+        {assert}match code{code_cast} {{",
+            assert = assert,
+            int_cast = int_cast,
+            code_cast = ffi_cast,
         )?;
 
         for member in &members {
@@ -352,7 +398,11 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
         if has_failed_member {
             writeln!(w, "\t\t\t_ => Some(Self::Failed),")?;
         } else {
-            writeln!(w, "\t\t\tvalue => Some(Self::__Unknown(value)),")?;
+            writeln!(
+                w,
+                "\t\t\tvalue => Some(Self::__Unknown(value{})),",
+                int_cast
+            )?;
         }
 
         writeln!(
@@ -388,6 +438,28 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
         )?;
         writeln!(w)?;
 
+        if env.config.param_spec_impls {
+            version_condition(w, env, None, version, false, 0)?;
+            cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
+            writeln!(
+                w,
+                "impl {has_param_spec} for {name} {{
+    type ParamSpec = {param_spec};
+    type SetValue = Self;
+    type BuilderFn = fn(&str, Self) -> {builder}<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {{
+        |name, default_value| Self::ParamSpec::builder_with_default(name, default_value)
+    }}
+}}",
+                name = enum_.name,
+                has_param_spec = use_glib_type(env, "HasParamSpec"),
+                param_spec = use_glib_type(env, "ParamSpecEnum"),
+                builder = use_glib_type(env, "ParamSpecEnumBuilder"),
+            )?;
+            writeln!(w)?;
+        }
+
         version_condition(w, env, None, version, false, 0)?;
         cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
         writeln!(
@@ -408,10 +480,11 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
     type Checker = {genericwrongvaluetypechecker}<Self>;
 
     unsafe fn from_value(value: &'a {gvalue}) -> Self {{
-        {assert}from_glib({glib}(value.to_glib_none().0))
+        {assert}from_glib({glib}(value.to_glib_none().0){ffi_cast})
     }}
 }}",
             name = enum_.name,
+            ffi_cast = ffi_cast,
             glib = use_glib_type(env, "gobject_ffi::g_value_get_enum"),
             gvalue = use_glib_type(env, "Value"),
             genericwrongvaluetypechecker = use_glib_type(env, "value::GenericValueTypeChecker"),
@@ -427,7 +500,7 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
     fn to_value(&self) -> {gvalue} {{
         let mut value = {gvalue}::for_value_type::<Self>();
         unsafe {{
-            {glib}(value.to_glib_none_mut().0, self.into_glib());
+            {glib}(value.to_glib_none_mut().0, self.into_glib(){int_cast});
         }}
         value
     }}
@@ -440,6 +513,7 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
             glib = use_glib_type(env, "gobject_ffi::g_value_set_enum"),
             gvalue = use_glib_type(env, "Value"),
             gtype = use_glib_type(env, "Type"),
+            int_cast = int_cast,
         )?;
         writeln!(w)?;
     }
@@ -0,0 +1,64 @@
+use crate::{
+    codegen::{general, sys::collect_versions},
+    env::Env,
+    file_saver,
+    version::Version,
+};
+use log::info;
+use std::path::Path;
+
+/// Generates `features.rs` with a `has_<feature>()` function per version
+/// feature, checking the version of the library loaded at runtime.
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    let functions = match env.config.runtime_version_functions {
+        Some(ref functions) => functions,
+        None => return,
+    };
+    let versions = collect_versions(env);
+    if versions.is_empty() {
+        return;
+    }
+
+    info!("Generate runtime feature detection");
+
+    let path = root_path.join("features.rs");
+    let sys_crate_name = env.main_sys_crate_name();
+
+    file_saver::save_to_file(path, env, |w| {
+        general::start_comments(w, &env.config)?;
+        writeln!(w)?;
+
+        mod_rs.push("\npub mod features;".into());
+
+        writeln!(w, "fn runtime_version() -> (u32, u32, u32) {{")?;
+        writeln!(w, "\tunsafe {{")?;
+        writeln!(w, "\t\t(")?;
+        for function in functions {
+            writeln!(w, "\t\t\t{}::{}() as u32,", sys_crate_name, function)?;
+        }
+        writeln!(w, "\t\t)")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        for (version, lib_version) in &versions {
+            let Version(major, minor, micro) = *lib_version;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "/// Whether the library loaded at runtime is at least version {}, \
+                 as required by the API of the `{}` feature.",
+                lib_version,
+                version.to_feature()
+            )?;
+            writeln!(w, "pub fn has_{}() -> bool {{", version.to_feature())?;
+            writeln!(
+                w,
+                "\truntime_version() >= ({}, {}, {})",
+                major, minor, micro
+            )?;
+            writeln!(w, "}}")?;
+        }
+
+        Ok(())
+    });
+}
@@ -4,8 +4,8 @@ use crate::{
     analysis::special_functions::Type,
     codegen::general::{
         self, cfg_condition, cfg_condition_doc, cfg_condition_no_doc, cfg_condition_string,
-        cfg_deprecated, derives, doc_alias, version_condition, version_condition_doc,
-        version_condition_no_doc, version_condition_string,
+        cfg_deprecated, derives, doc_alias, not_version_condition_no_dox, version_condition,
+        version_condition_doc, version_condition_no_doc, version_condition_string,
     },
     config::gobjects::GObject,
     env::Env,
@@ -13,8 +13,10 @@ use crate::{
     library::*,
     nameutil::{bitfield_member_name, use_glib_type},
     traits::*,
+    version::Version,
 };
 use std::{
+    collections::BTreeMap,
     io::{prelude::*, Result},
     path::Path,
 };
@@ -30,7 +32,7 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     }
 
     let path = root_path.join("flags.rs");
-    file_saver::save_to_file(path, env.config.make_backup, |w| {
+    file_saver::save_to_file(path, env, |w| {
         general::start_comments(w, &env.config)?;
         general::uses(w, env, &env.analysis.flags_imports, None)?;
         writeln!(w)?;
@@ -49,6 +51,9 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
             if let Some(cfg) = cfg_condition_string(config.cfg_condition.as_ref(), false, 0) {
                 mod_rs.push(cfg);
             }
+            if config.doc_hidden {
+                mod_rs.push("#[doc(hidden)]".into());
+            }
             mod_rs.push(format!("pub use self::flags::{};", flags.name));
             generate_flags(env, w, flags, config, flags_analysis)?;
         }
@@ -57,6 +62,46 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     });
 }
 
+struct FlagsMember<'a> {
+    name: String,
+    c_identifier: &'a str,
+    version: Option<Version>,
+    deprecated_version: Option<Version>,
+    cfg_condition: Option<&'a String>,
+    bits: u32,
+}
+
+/// Returns, by version, the bits of `member` that are only defined by members
+/// requiring a newer version than `member` itself.
+fn newer_bits(
+    member: &FlagsMember<'_>,
+    members: &[FlagsMember<'_>],
+    min_version: Option<Version>,
+) -> BTreeMap<Version, u32> {
+    let mut masks = BTreeMap::new();
+    if member.bits.count_ones() < 2 {
+        return masks;
+    }
+
+    let older_bits = members
+        .iter()
+        .filter(|m| m.bits.is_power_of_two() && m.version <= member.version)
+        .fold(0, |bits, m| bits | m.bits);
+    for other in members {
+        let version = match other.version {
+            Some(version) if other.version > member.version && other.version > min_version => {
+                version
+            }
+            _ => continue,
+        };
+        let bits = other.bits & member.bits & !older_bits;
+        if bits != 0 {
+            *masks.entry(version).or_insert(0) |= bits;
+        }
+    }
+    masks
+}
+
 #[allow(clippy::write_literal)]
 fn generate_flags(
     env: &Env,
@@ -85,17 +130,24 @@ fn generate_flags(
 
     if let Some(ref d) = config.derives {
         derives(w, d, 1)?;
+    } else {
+        let profile = config.derive_profile.unwrap_or(env.config.derive_profile);
+        let profile_derives = profile.flags_derives();
+        if !profile_derives.is_empty() {
+            writeln!(w, "    #[derive({})]", profile_derives.join(", "))?;
+        }
     }
 
     doc_alias(w, &flags.c_type, "", 1)?;
     writeln!(w, "    pub struct {}: u32 {{", flags.name)?;
+
+    let mut members = Vec::new();
     for member in &flags.members {
         let member_config = config.members.matched(&member.name);
         if member.status.ignored() {
             continue;
         }
 
-        let name = bitfield_member_name(&member.name);
         let deprecated_version = member_config
             .iter()
             .find_map(|m| m.deprecated_version)
@@ -104,18 +156,56 @@ fn generate_flags(
             .iter()
             .find_map(|m| m.version)
             .or(member.version);
-        let cfg_cond = member_config.iter().find_map(|m| m.cfg_condition.as_ref());
-        cfg_deprecated(w, env, Some(analysis.type_id), deprecated_version, false, 2)?;
-        version_condition(w, env, None, version, false, 2)?;
-        cfg_condition(w, cfg_cond, false, 2)?;
-        if member.c_identifier != member.name {
-            doc_alias(w, &member.c_identifier, "", 2)?;
-        }
-        writeln!(
+        let cfg_condition = member_config.iter().find_map(|m| m.cfg_condition.as_ref());
+        members.push(FlagsMember {
+            name: bitfield_member_name(&member.name),
+            c_identifier: &member.c_identifier,
+            version,
+            deprecated_version,
+            cfg_condition,
+            bits: member.value.parse::<i64>().map_or(0, |v| v as u32),
+        });
+    }
+
+    let min_version = env.config.min_required_version(env, None);
+    for member in &members {
+        cfg_deprecated(
             w,
-            "\t\tconst {} = {}::{} as u32;",
-            name, sys_crate_name, member.c_identifier,
+            env,
+            Some(analysis.type_id),
+            member.deprecated_version,
+            false,
+            2,
         )?;
+        version_condition(w, env, None, member.version, false, 2)?;
+        cfg_condition(w, member.cfg_condition, false, 2)?;
+        if member.c_identifier != member.name {
+            doc_alias(w, member.c_identifier, "", 2)?;
+        }
+
+        // Bits only available in later versions must not leak into the
+        // value of this member when the matching feature is disabled.
+        let masks = newer_bits(member, &members, min_version);
+        if masks.is_empty() {
+            writeln!(
+                w,
+                "\t\tconst {} = {}::{} as u32;",
+                member.name, sys_crate_name, member.c_identifier,
+            )?;
+        } else {
+            writeln!(w, "\t\tconst {} = {{", member.name)?;
+            writeln!(
+                w,
+                "\t\t\tlet bits = {}::{} as u32;",
+                sys_crate_name, member.c_identifier
+            )?;
+            for (version, mask) in masks {
+                not_version_condition_no_dox(w, env, None, Some(version), false, 3)?;
+                writeln!(w, "\t\t\tlet bits = bits & !{:#x};", mask)?;
+            }
+            writeln!(w, "\t\t\tbits")?;
+            writeln!(w, "\t\t}};")?;
+        }
     }
 
     writeln!(
@@ -219,6 +309,42 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
         assert = assert
     )?;
 
+    if let Some(enum_tid) = analysis.flags_of {
+        let enum_ = env.type_(enum_tid).maybe_ref_as::<Enumeration>().unwrap();
+        let version = std::cmp::max(flags.version, enum_.version);
+
+        version_condition(w, env, None, version, false, 0)?;
+        cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
+        writeln!(
+            w,
+            "impl From<{enum_name}> for {name} {{
+    fn from(value: {enum_name}) -> Self {{
+        {assert}Self::from_bits_truncate(1u32.checked_shl(value.into_glib() as u32).unwrap_or(0))
+    }}
+}}
+",
+            name = flags.name,
+            enum_name = enum_.name,
+            assert = assert,
+        )?;
+
+        version_condition(w, env, None, version, false, 0)?;
+        cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
+        writeln!(
+            w,
+            "impl {name} {{
+    /// Returns `true` if the flag matching `value` is set.
+    pub fn contains_value(&self, value: {enum_name}) -> bool {{
+        let flag = Self::from(value);
+        !flag.is_empty() && self.contains(flag)
+    }}
+}}
+",
+            name = flags.name,
+            enum_name = enum_.name,
+        )?;
+    }
+
     if let Some(ref get_type) = flags.glib_get_type {
         let configured_functions = config.functions.matched("get_type");
         let version = std::iter::once(flags.version)
@@ -241,6 +367,28 @@ impl FromGlib<{sys_crate_name}::{ffi_name}> for {name} {{
         )?;
         writeln!(w)?;
 
+        if env.config.param_spec_impls {
+            version_condition(w, env, None, version, false, 0)?;
+            cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
+            writeln!(
+                w,
+                "impl {has_param_spec} for {name} {{
+    type ParamSpec = {param_spec};
+    type SetValue = Self;
+    type BuilderFn = fn(&str) -> {builder}<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {{
+        |name| Self::ParamSpec::builder(name)
+    }}
+}}",
+                name = flags.name,
+                has_param_spec = use_glib_type(env, "HasParamSpec"),
+                param_spec = use_glib_type(env, "ParamSpecFlags"),
+                builder = use_glib_type(env, "ParamSpecFlagsBuilder"),
+            )?;
+            writeln!(w)?;
+        }
+
         version_condition(w, env, None, version, false, 0)?;
         cfg_condition_no_doc(w, config.cfg_condition.as_ref(), false, 0)?;
         writeln!(
@@ -10,21 +10,29 @@ use super::{
 };
 use crate::{
     analysis::{
-        self, bounds::Bounds, functions::Visibility, namespaces, try_from_glib::TryFromGlib,
+        self, bounds::Bounds, functions::Visibility, namespaces, return_value::NegativeIsError,
+        rust_type::RustType, try_from_glib::TryFromGlib,
     },
     chunk::{ffi_function_todo, Chunk},
+    config::functions::Instrument,
     env::Env,
     library,
+    nameutil::{use_gio_type, use_glib_type},
+    traits::*,
     version::Version,
     writer::{primitives::tabs, safety_assertion_mode_to_str, ToCode},
 };
-use log::warn;
+use log::{error, warn};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
 use std::{
     fmt,
     io::{Result, Write},
     result::Result as StdResult,
 };
 
+static PLACEHOLDER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\{\{\s*([\w:]+)\s*\}\}").unwrap());
+
 pub fn generate(
     w: &mut dyn Write,
     env: &Env,
@@ -102,14 +110,43 @@ pub fn generate(
     )?;
 
     if !only_declaration {
-        let body = body_chunk(env, analysis).to_code(env);
-        for s in body {
-            writeln!(w, "{}{}", tabs(indent), s)?;
+        if !commented && env.config.instrument == Instrument::Tracing {
+            writeln!(w, "{}#[cfg(feature = \"tracing\")]", tabs(indent + 1))?;
+            writeln!(
+                w,
+                "{}let _span = tracing::trace_span!(\"{}\").entered();",
+                tabs(indent + 1),
+                analysis.glib_name
+            )?;
+        }
+        match analysis.body_template {
+            Some(ref template) if !commented => {
+                for s in body_from_template(env, analysis, template).lines() {
+                    if !s.is_empty() {
+                        writeln!(w, "{}{}", tabs(indent + 1), s)?;
+                    } else {
+                        writeln!(w)?;
+                    }
+                }
+                writeln!(w, "{}}}", tabs(indent))?;
+            }
+            _ => {
+                let body = body_chunk(env, analysis).to_code(env);
+                for s in body {
+                    writeln!(w, "{}{}", tabs(indent), s)?;
+                }
+            }
         }
     }
 
-    if analysis.async_future.is_some() {
-        let declaration = declaration_futures(env, analysis);
+    let future_declaration = if analysis.async_future.is_some() {
+        Some(declaration_futures(env, analysis))
+    } else if analysis.callback_future.is_some() {
+        Some(declaration_callback_future(env, analysis))
+    } else {
+        None
+    };
+    if let Some(declaration) = future_declaration {
         let suffix = if only_declaration { ";" } else { " {" };
 
         writeln!(w)?;
@@ -133,7 +170,12 @@ pub fn generate(
         )?;
 
         if !only_declaration {
-            let body = body_chunk_futures(env, analysis).unwrap();
+            let body = if analysis.async_future.is_some() {
+                body_chunk_futures(env, analysis)
+            } else {
+                body_chunk_callback_future(env, analysis)
+            }
+            .unwrap();
             for s in body.lines() {
                 if !s.is_empty() {
                     writeln!(w, "{}{}{}", tabs(indent + 1), comment_prefix, s)?;
@@ -145,19 +187,343 @@ pub fn generate(
         }
     }
 
+    if let Some(ref weak_callback) = analysis.weak_callback {
+        writeln!(w)?;
+        if !in_trait || only_declaration {
+            cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        }
+        cfg_condition(w, analysis.cfg_condition.as_ref(), commented, indent)?;
+        version_condition(w, env, None, version, commented, indent)?;
+        not_version_condition(w, analysis.not_version, commented, indent)?;
+        doc_hidden(w, analysis.doc_hidden, comment_prefix, indent)?;
+        if !in_trait || only_declaration {
+            writeln!(
+                w,
+                "{}{}// rustdoc-stripper-ignore-next",
+                tabs(indent),
+                comment_prefix
+            )?;
+            writeln!(
+                w,
+                "{0}{1}/// Like [`{2}()`][Self::{2}()], but holding `{3}` weakly instead of \
+                 capturing it in `{4}`: it is passed first to `{4}`, which isn't called \
+                 anymore once `{3}` is gone.",
+                tabs(indent),
+                comment_prefix,
+                analysis.codegen_name(),
+                weak_callback.object_name,
+                weak_callback.callback_name,
+            )?;
+        }
+        writeln!(
+            w,
+            "{}{}{}{}{}{}",
+            tabs(indent),
+            comment_prefix,
+            pub_prefix,
+            unsafe_,
+            declaration_weak_callback(env, analysis),
+            suffix
+        )?;
+
+        if !only_declaration {
+            for s in body_weak_callback(env, analysis).lines() {
+                writeln!(w, "{}{}\t{}", tabs(indent), comment_prefix, s)?;
+            }
+            writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+        }
+    }
+
+    if let Some(ref chainable_setter) = analysis.chainable_setter {
+        writeln!(w)?;
+        if !in_trait || only_declaration {
+            cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        }
+        cfg_condition(w, analysis.cfg_condition.as_ref(), commented, indent)?;
+        version_condition(w, env, None, version, commented, indent)?;
+        not_version_condition(w, analysis.not_version, commented, indent)?;
+        doc_hidden(w, analysis.doc_hidden, comment_prefix, indent)?;
+        writeln!(
+            w,
+            "{}{}{}{}{}",
+            tabs(indent),
+            comment_prefix,
+            pub_prefix,
+            declaration_chainable_setter(env, analysis, chainable_setter),
+            suffix
+        )?;
+
+        if !only_declaration {
+            let value = analysis
+                .parameters
+                .rust_parameters
+                .iter()
+                .find(|par| !analysis.parameters.c_parameters[par.ind_c].instance_parameter)
+                .map_or("", |par| &par.name);
+            writeln!(
+                w,
+                "{}{}\tself.{}({});",
+                tabs(indent),
+                comment_prefix,
+                analysis.codegen_name(),
+                value
+            )?;
+            writeln!(w, "{}{}\tself", tabs(indent), comment_prefix)?;
+            writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+        }
+    }
+
+    if let Some(ref optional_outs) = analysis.optional_outs {
+        writeln!(w)?;
+        if !in_trait || only_declaration {
+            cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        }
+        cfg_condition(w, analysis.cfg_condition.as_ref(), commented, indent)?;
+        version_condition(w, env, None, version, commented, indent)?;
+        not_version_condition(w, analysis.not_version, commented, indent)?;
+        doc_hidden(w, analysis.doc_hidden, comment_prefix, indent)?;
+        if !in_trait || only_declaration {
+            writeln!(
+                w,
+                "{}{}// rustdoc-stripper-ignore-next",
+                tabs(indent),
+                comment_prefix
+            )?;
+            writeln!(
+                w,
+                "{}{}/// Like `{}()`, but only requesting the optional out parameters whose \
+                 `want_` argument is `true`, the others being returned as `None`.",
+                tabs(indent),
+                comment_prefix,
+                analysis.codegen_name(),
+            )?;
+        }
+        writeln!(
+            w,
+            "{}{}{}{}{}{}",
+            tabs(indent),
+            comment_prefix,
+            pub_prefix,
+            unsafe_,
+            declaration_optional_outs(env, analysis),
+            suffix
+        )?;
+
+        if !only_declaration {
+            let body = body_chunk_requesting(env, analysis, &optional_outs.parameters).to_code(env);
+            for s in body {
+                writeln!(w, "{}{}", tabs(indent), s)?;
+            }
+        }
+    }
+
+    if let Some(request) = analysis
+        .async_future
+        .as_ref()
+        .and_then(|f| f.request.as_ref())
+        .filter(|_| analysis.visibility != Visibility::Private)
+    {
+        writeln!(w)?;
+        if !in_trait || only_declaration {
+            cfg_deprecated(w, env, None, analysis.deprecated_version, commented, indent)?;
+        }
+        cfg_condition(w, analysis.cfg_condition.as_ref(), commented, indent)?;
+        version_condition(w, env, None, version, commented, indent)?;
+        not_version_condition(w, analysis.not_version, commented, indent)?;
+        doc_hidden(w, analysis.doc_hidden, comment_prefix, indent)?;
+        if !in_trait || only_declaration {
+            writeln!(
+                w,
+                "{}{}// rustdoc-stripper-ignore-next",
+                tabs(indent),
+                comment_prefix
+            )?;
+            writeln!(
+                w,
+                "{}{}/// Returns a request calling `{}()` with default arguments once `.await`ed.",
+                tabs(indent),
+                comment_prefix,
+                analysis.async_future.as_ref().unwrap().name
+            )?;
+        }
+        writeln!(
+            w,
+            "{}{}{}fn {}(&self) -> {}{}",
+            tabs(indent),
+            comment_prefix,
+            pub_prefix,
+            request.method_name,
+            request.type_name,
+            suffix
+        )?;
+
+        if !only_declaration {
+            writeln!(
+                w,
+                "{}{}\t{} {{ obj: {} }}",
+                tabs(indent),
+                comment_prefix,
+                request.type_name,
+                if in_trait {
+                    "self.as_ref().clone()"
+                } else {
+                    "self.clone()"
+                }
+            )?;
+            writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+        }
+    }
+
     Ok(())
 }
 
+/// Generates the `IntoFuture` request type of an `into_future = true` method.
+///
+/// `path` is the trait or type the `{name}_future` method is called through.
+pub fn generate_future_request(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::functions::Info,
+    object_name: &str,
+    path: &str,
+    scope_version: Option<Version>,
+) -> Result<()> {
+    let async_future = match analysis.async_future {
+        Some(ref async_future) => async_future,
+        None => return Ok(()),
+    };
+    let request = match async_future.request {
+        Some(ref request) => request,
+        None => return Ok(()),
+    };
+    if !analysis.status.need_generate() || analysis.visibility != Visibility::Public {
+        return Ok(());
+    }
+
+    let version = Version::if_stricter_than(analysis.version, scope_version);
+    let mut arguments = String::new();
+    for par in &analysis.parameters.rust_parameters {
+        if par.name == "io_priority" {
+            arguments.push_str(", ");
+            arguments.push_str(&use_glib_type(env, "Priority::default()"));
+        }
+    }
+
+    writeln!(w)?;
+    cfg_condition(w, analysis.cfg_condition.as_ref(), false, 0)?;
+    version_condition(w, env, None, version, false, 0)?;
+    not_version_condition(w, analysis.not_version, false, 0)?;
+    writeln!(w, "// rustdoc-stripper-ignore-next")?;
+    writeln!(
+        w,
+        "/// Request returned by [`{path}::{method}()`], calling [`{path}::{future}()`] \
+         with default arguments once `.await`ed.",
+        path = path,
+        method = request.method_name,
+        future = async_future.name,
+    )?;
+    writeln!(w, "#[derive(Clone, Debug)]")?;
+    writeln!(w, "#[must_use = \"requests do nothing unless `.await`ed\"]")?;
+    writeln!(w, "pub struct {} {{", request.type_name)?;
+    writeln!(w, "\tobj: {},", object_name)?;
+    writeln!(w, "}}")?;
+
+    writeln!(w)?;
+    cfg_condition(w, analysis.cfg_condition.as_ref(), false, 0)?;
+    version_condition(w, env, None, version, false, 0)?;
+    not_version_condition(w, analysis.not_version, false, 0)?;
+    writeln!(
+        w,
+        "impl std::future::IntoFuture for {} {{",
+        request.type_name
+    )?;
+    writeln!(
+        w,
+        "\ttype Output = Result<{}, {}>;",
+        async_future.success_parameters, async_future.error_parameters
+    )?;
+    writeln!(
+        w,
+        "\ttype IntoFuture = Pin<Box_<dyn std::future::Future<Output = Self::Output> + 'static>>;"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "\tfn into_future(self) -> Self::IntoFuture {{")?;
+    writeln!(
+        w,
+        "\t\t{}::{}(&self.obj{})",
+        path, async_future.name, arguments
+    )?;
+    writeln!(w, "\t}}")?;
+    writeln!(w, "}}")
+}
+
 pub fn declaration(env: &Env, analysis: &analysis::functions::Info) -> String {
+    let return_str = return_str(env, analysis);
+    let mut param_str = String::with_capacity(100);
+
+    let (bounds, _) = bounds(&analysis.bounds, &[], false, false);
+
+    for par in analysis.parameters.rust_parameters.iter() {
+        if !param_str.is_empty() {
+            param_str.push_str(", ")
+        }
+        let c_par = &analysis.parameters.c_parameters[par.ind_c];
+        let s = c_par.to_parameter(env, &analysis.bounds, false);
+        param_str.push_str(&s);
+    }
+
+    format!(
+        "fn {}{}({}){}",
+        analysis.codegen_name(),
+        bounds,
+        param_str,
+        return_str,
+    )
+}
+
+/// Declaration of the `{name}_optional` variant, taking a `want_{name}`
+/// boolean for each optional out parameter
+pub fn declaration_optional_outs(env: &Env, analysis: &analysis::functions::Info) -> String {
+    let optional_outs = analysis.optional_outs.as_ref().unwrap();
+
+    let mut params = analysis
+        .parameters
+        .rust_parameters
+        .iter()
+        .map(|par| {
+            analysis.parameters.c_parameters[par.ind_c].to_parameter(env, &analysis.bounds, false)
+        })
+        .collect::<Vec<_>>();
+    params.extend(
+        optional_outs
+            .parameters
+            .iter()
+            .map(|name| format!("want_{}: bool", name)),
+    );
+
+    let (bounds, _) = bounds(&analysis.bounds, &[], false, false);
+
+    format!(
+        "fn {}{}({}){}",
+        optional_outs.name,
+        bounds,
+        params.join(", "),
+        out_parameters_as_return(env, analysis, &optional_outs.parameters),
+    )
+}
+
+fn return_str(env: &Env, analysis: &analysis::functions::Info) -> String {
     let outs_as_return = !analysis.outs.is_empty();
-    let return_str = if outs_as_return {
-        out_parameters_as_return(env, analysis)
+    if outs_as_return {
+        out_parameters_as_return(env, analysis, &[])
     } else if analysis.ret.bool_return_is_error.is_some() {
         if env.namespaces.glib_ns_id == namespaces::MAIN {
             " -> Result<(), error::BoolError>".into()
         } else {
             " -> Result<(), glib::error::BoolError>".into()
         }
+    } else if let Some(NegativeIsError::BoolError(_)) = analysis.ret.negative_is_error {
+        format!(" -> Result<usize, {}>", use_glib_type(env, "BoolError"))
     } else if let Some(return_type) = analysis.ret.to_return_value(
         env,
         analysis
@@ -170,29 +536,68 @@ pub fn declaration(env: &Env, analysis: &analysis::functions::Info) -> String {
         format!(" -> {}", return_type)
     } else {
         String::new()
-    };
-    let mut param_str = String::with_capacity(100);
+    }
+}
 
-    let (bounds, _) = bounds(&analysis.bounds, &[], false, false);
+pub fn declaration_weak_callback(env: &Env, analysis: &analysis::functions::Info) -> String {
+    let weak_callback = analysis.weak_callback.as_ref().unwrap();
 
+    let mut param_str = String::with_capacity(100);
     for par in analysis.parameters.rust_parameters.iter() {
         if !param_str.is_empty() {
             param_str.push_str(", ")
         }
-        let c_par = &analysis.parameters.c_parameters[par.ind_c];
-        let s = c_par.to_parameter(env, &analysis.bounds, false);
-        param_str.push_str(&s);
+        let mut c_par = analysis.parameters.c_parameters[par.ind_c].clone();
+        // Unsetting the callback is left to the original function
+        if c_par.name == weak_callback.callback_name {
+            c_par.nullable = library::Nullable(false);
+        }
+        param_str.push_str(&c_par.to_parameter(env, &weak_callback.bounds, false));
+        if c_par.instance_parameter {
+            param_str.push_str(&format!(
+                ", {}: &{}",
+                weak_callback.object_name, weak_callback.object_alias
+            ));
+        }
     }
 
+    let (bounds, _) = bounds(&weak_callback.bounds, &[], false, false);
+
     format!(
         "fn {}{}({}){}",
-        analysis.codegen_name(),
+        weak_callback.name,
         bounds,
         param_str,
-        return_str,
+        return_str(env, analysis),
     )
 }
 
+/// Declaration of the `with_<property>` variant of a property setter,
+/// consuming and returning `self`
+pub fn declaration_chainable_setter(
+    env: &Env,
+    analysis: &analysis::functions::Info,
+    name: &str,
+) -> String {
+    let params = analysis
+        .parameters
+        .rust_parameters
+        .iter()
+        .map(|par| {
+            let c_par = &analysis.parameters.c_parameters[par.ind_c];
+            if c_par.instance_parameter {
+                "self".to_owned()
+            } else {
+                c_par.to_parameter(env, &analysis.bounds, false)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let (bounds, _) = bounds(&analysis.bounds, &[], false, false);
+
+    format!("fn {}{}({}) -> Self", name, bounds, params.join(", "))
+}
+
 pub fn declaration_futures(env: &Env, analysis: &analysis::functions::Info) -> String {
     let async_future = analysis.async_future.as_ref().unwrap();
 
@@ -236,6 +641,47 @@ pub fn declaration_futures(env: &Env, analysis: &analysis::functions::Info) -> S
     )
 }
 
+pub fn declaration_callback_future(env: &Env, analysis: &analysis::functions::Info) -> String {
+    let callback_future = analysis.callback_future.as_ref().unwrap();
+
+    let return_str = format!(
+        " -> Pin<Box_<dyn std::future::Future<Output = {}> + 'static>>",
+        callback_future.output
+    );
+
+    let mut param_str = String::with_capacity(100);
+
+    let mut skipped_bounds = vec![];
+    for par in analysis.parameters.rust_parameters.iter() {
+        let c_par = &analysis.parameters.c_parameters[par.ind_c];
+
+        if c_par.name == callback_future.callback_name || c_par.name == "cancellable" {
+            if let Some(alias) = analysis
+                .bounds
+                .get_parameter_bound(&c_par.name)
+                .and_then(|bound| bound.type_parameter_reference())
+            {
+                skipped_bounds.push(alias);
+            }
+            continue;
+        }
+
+        if !param_str.is_empty() {
+            param_str.push_str(", ")
+        }
+
+        let s = c_par.to_parameter(env, &analysis.bounds, true);
+        param_str.push_str(&s);
+    }
+
+    let (bounds, _) = bounds(&analysis.bounds, skipped_bounds.as_ref(), true, false);
+
+    format!(
+        "fn {}{}({}){}",
+        callback_future.name, bounds, param_str, return_str,
+    )
+}
+
 pub fn bounds(
     bounds: &Bounds,
     skip: &[char],
@@ -244,7 +690,7 @@ pub fn bounds(
 ) -> (String, Vec<String>) {
     use crate::analysis::bounds::BoundType::*;
 
-    if bounds.is_empty() {
+    if bounds.is_empty() && bounds.iter_lifetimes().len() == 0 {
         return (String::new(), Vec::new());
     }
 
@@ -295,7 +741,70 @@ pub fn bounds(
     (type_names, bounds)
 }
 
+/// Fills in the placeholders of a configured `body_template`: `{{sys_fn}}`,
+/// `{{fn_name}}`, `{{params}}` and `{{type:<parameter name>}}`.
+fn body_from_template(env: &Env, analysis: &analysis::functions::Info, template: &str) -> String {
+    let params: Vec<(&str, String)> = analysis
+        .parameters
+        .rust_parameters
+        .iter()
+        .map(|par| {
+            let c_par = &analysis.parameters.c_parameters[par.ind_c];
+            let typ = if c_par.instance_parameter {
+                format!("{}Self", c_par.ref_mode.for_rust_type())
+            } else {
+                RustType::builder(env, c_par.typ)
+                    .direction(c_par.direction)
+                    .nullable(c_par.nullable)
+                    .ref_mode(c_par.ref_mode)
+                    .lifetime(c_par.lifetime)
+                    .scope(c_par.scope)
+                    .try_from_glib(&c_par.try_from_glib)
+                    .record_array(c_par.record_array)
+                    .try_build_param()
+                    .into_string()
+            };
+            (par.name.as_str(), typ)
+        })
+        .collect();
+
+    PLACEHOLDER
+        .replace_all(template, |caps: &Captures<'_>| match &caps[1] {
+            "sys_fn" => format!("{}::{}", env.main_sys_crate_name(), analysis.glib_name),
+            "fn_name" => analysis.codegen_name().to_owned(),
+            "params" => params
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", "),
+            placeholder => match placeholder
+                .strip_prefix("type:")
+                .and_then(|name| params.iter().find(|(n, _)| *n == name))
+            {
+                Some((_, typ)) => typ.clone(),
+                None => {
+                    error!(
+                        "Unknown placeholder `{}` in body template of {}",
+                        placeholder, analysis.glib_name
+                    );
+                    caps[0].to_owned()
+                }
+            },
+        })
+        .into_owned()
+}
+
 pub fn body_chunk(env: &Env, analysis: &analysis::functions::Info) -> Chunk {
+    body_chunk_requesting(env, analysis, &[])
+}
+
+/// Body passing `NULL` for the `optional_outs` whose `want_{name}` boolean
+/// isn't set
+fn body_chunk_requesting(
+    env: &Env,
+    analysis: &analysis::functions::Info,
+    optional_outs: &[String],
+) -> Chunk {
     if analysis.visibility == Visibility::Comment {
         return ffi_function_todo(env, &analysis.glib_name);
     }
@@ -334,7 +843,9 @@ pub fn body_chunk(env: &Env, analysis: &analysis::functions::Info) -> Chunk {
     }
 
     for par in &analysis.parameters.c_parameters {
-        if outs_as_return && analysis.outs.iter().any(|out| out.lib_par.name == par.name) {
+        if outs_as_return && optional_outs.contains(&par.name) {
+            builder.optional_out_parameter(env, par, format!("want_{}", par.name));
+        } else if outs_as_return && analysis.outs.iter().any(|out| out.lib_par.name == par.name) {
             builder.out_parameter(env, par);
         } else {
             builder.parameter();
@@ -453,3 +964,186 @@ pub fn body_chunk_futures(
 
     Ok(body)
 }
+
+pub fn body_weak_callback(env: &Env, analysis: &analysis::functions::Info) -> String {
+    let weak_callback = analysis.weak_callback.as_ref().unwrap();
+    let object = &weak_callback.object_name;
+    let arguments = weak_callback
+        .arguments
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let typed_arguments = weak_callback
+        .arguments
+        .iter()
+        .map(|(name, typ)| format!("{}: {}", name, typ))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let mut body = String::new();
+    body.push_str(&format!(
+        "let {0} = {1}::downgrade({0});\n",
+        object,
+        use_glib_type(env, "object::ObjectExt")
+    ));
+    body.push_str(&format!("self.{}(\n", analysis.codegen_name()));
+    // Skip the instance parameter
+    for par in analysis.parameters.rust_parameters.iter().skip(1) {
+        if par.name != weak_callback.callback_name {
+            body.push_str(&format!("\t{},\n", par.name));
+            continue;
+        }
+        let c_par = &analysis.parameters.c_parameters[par.ind_c];
+        // Nullable callbacks are taken boxed by the original function
+        let (open, close) = if *c_par.nullable {
+            ("Some(Box_::new(", "))")
+        } else {
+            ("", "")
+        };
+        body.push_str(&format!("\t{}move |{}| {{\n", open, typed_arguments));
+        body.push_str(&format!(
+            "\t\tif let Some({0}) = {0}.upgrade() {{\n",
+            object
+        ));
+        body.push_str(&format!(
+            "\t\t\t{}(&{}{}{});\n",
+            par.name,
+            object,
+            if arguments.is_empty() { "" } else { ", " },
+            arguments
+        ));
+        body.push_str("\t\t}\n");
+        body.push_str(&format!("\t}}{},\n", close));
+    }
+    body.push(')');
+    body
+}
+
+pub fn body_chunk_callback_future(
+    env: &Env,
+    analysis: &analysis::functions::Info,
+) -> StdResult<String, fmt::Error> {
+    use crate::analysis::ref_mode::RefMode;
+    use std::fmt::Write;
+
+    let callback_future = analysis.callback_future.as_ref().unwrap();
+
+    let mut body = String::new();
+
+    let gio_future_name = use_gio_type(env, "GioFuture");
+    writeln!(body)?;
+
+    if !callback_future.assertion.is_none() {
+        writeln!(
+            body,
+            "{}",
+            safety_assertion_mode_to_str(callback_future.assertion)
+        )?;
+    }
+    let skip = if callback_future.is_method { 1 } else { 0 };
+
+    // Skip the instance parameter
+    for par in analysis.parameters.rust_parameters.iter().skip(skip) {
+        if par.name == "cancellable" || par.name == callback_future.callback_name {
+            continue;
+        }
+
+        let c_par = &analysis.parameters.c_parameters[par.ind_c];
+
+        let type_ = env.type_(par.typ);
+        let is_str = matches!(
+            *type_,
+            library::Type::Fundamental(library::Fundamental::Utf8)
+        );
+
+        if *c_par.nullable {
+            writeln!(
+                body,
+                "let {} = {}.map(ToOwned::to_owned);",
+                par.name, par.name
+            )?;
+        } else if is_str {
+            writeln!(body, "let {} = String::from({});", par.name, par.name)?;
+        } else if c_par.ref_mode != RefMode::None {
+            writeln!(body, "let {} = {}.clone();", par.name, par.name)?;
+        }
+    }
+
+    let has_cancellable = analysis
+        .parameters
+        .rust_parameters
+        .iter()
+        .any(|par| par.name == "cancellable");
+    let cancellable = if has_cancellable {
+        "cancellable"
+    } else {
+        "_cancellable"
+    };
+
+    if callback_future.is_method {
+        writeln!(
+            body,
+            "Box_::pin({}::new(self, move |obj, {}, send| {{",
+            gio_future_name, cancellable
+        )?;
+        writeln!(body, "\tobj.{}(", analysis.codegen_name())?;
+    } else {
+        writeln!(
+            body,
+            "Box_::pin({}::new(&(), move |_obj, {}, send| {{",
+            gio_future_name, cancellable
+        )?;
+        if analysis.type_name.is_ok() {
+            writeln!(body, "\tSelf::{}(", analysis.codegen_name())?;
+        } else {
+            writeln!(body, "\t{}(", analysis.codegen_name())?;
+        }
+    }
+
+    let names: Vec<&str> = callback_future
+        .arguments
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    let conversions: Vec<&str> = callback_future
+        .arguments
+        .iter()
+        .map(|(_, conversion)| conversion.as_str())
+        .collect();
+    let output = if conversions.len() == 1 {
+        conversions[0].to_owned()
+    } else {
+        format!("({})", conversions.join(", "))
+    };
+
+    // Skip the instance parameter
+    for par in analysis.parameters.rust_parameters.iter().skip(skip) {
+        if par.name == "cancellable" {
+            writeln!(body, "\t\tSome(cancellable),")?;
+        } else if par.name == callback_future.callback_name {
+            writeln!(body, "\t\tmove |{}| {{", names.join(", "))?;
+            writeln!(body, "\t\t\tsend.resolve({});", output)?;
+            writeln!(body, "\t\t}},")?;
+        } else {
+            let c_par = &analysis.parameters.c_parameters[par.ind_c];
+
+            if *c_par.nullable {
+                writeln!(
+                    body,
+                    "\t\t{}.as_ref().map(::std::borrow::Borrow::borrow),",
+                    par.name
+                )?;
+            } else if c_par.ref_mode != RefMode::None {
+                writeln!(body, "\t\t&{},", par.name)?;
+            } else {
+                writeln!(body, "\t\t{},", par.name)?;
+            }
+        }
+    }
+
+    writeln!(body, "\t);")?;
+    writeln!(body, "}}))")?;
+
+    Ok(body)
+}
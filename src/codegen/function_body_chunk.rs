@@ -3,24 +3,30 @@ use crate::{
         self,
         conversion_type::ConversionType,
         function_parameters::{
-            CParameter as AnalysisCParameter, Transformation, TransformationType,
+            CParameter as AnalysisCParameter, RecordArrayMode, Transformation, TransformationType,
         },
         functions::{find_index_to_ignore, AsyncTrampoline},
         out_parameters::Mode,
-        return_value,
-        rust_type::RustType,
+        record_type, return_value,
+        rust_type::{path_like_type, RustType},
         safety_assertion_mode::SafetyAssertionMode,
         trampoline_parameters,
         trampolines::Trampoline,
     },
     chunk::{parameter_ffi_call_out, Chunk, Param, TupleMode},
+    codegen::panics,
+    config::functions::LengthScale,
     env::Env,
     library::{self, ParameterDirection, TypeId},
     nameutil::{is_gstring, use_gio_type, use_glib_if_needed, use_glib_type},
     traits::*,
 };
+use once_cell::sync::Lazy;
+use regex::Regex;
 use std::collections::{hash_map::Entry, BTreeMap, HashMap};
 
+static LEN_WORD: Lazy<Regex> = Lazy::new(|| Regex::new(r"\blen\b").unwrap());
+
 #[derive(Clone, Debug)]
 enum Parameter {
     //Used to separate in and out parameters in `add_in_array_lengths`
@@ -37,13 +43,18 @@ use self::Parameter::*;
 enum OutMemMode {
     Uninitialized,
     UninitializedNamed(String),
+    /// Caller-allocated plain struct on the stack, copied into its wrapper
+    UninitializedStruct,
     NullPtr,
     NullMutPtr,
 }
 
 impl OutMemMode {
     fn is_uninitialized(&self) -> bool {
-        matches!(*self, OutMemMode::Uninitialized)
+        matches!(
+            *self,
+            OutMemMode::Uninitialized | OutMemMode::UninitializedStruct
+        )
     }
 }
 
@@ -122,6 +133,20 @@ impl Builder {
         self
     }
 
+    /// Out parameter only passed if `flag` is set, see `optional_variant`
+    pub fn optional_out_parameter(
+        &mut self,
+        env: &Env,
+        parameter: &AnalysisCParameter,
+        flag: String,
+    ) -> &mut Builder {
+        self.out_parameter(env, parameter);
+        if let Some(Out { parameter, .. }) = self.parameters.last_mut() {
+            parameter.requested_by = Some(flag);
+        }
+        self
+    }
+
     pub fn transformations(&mut self, transformations: &[Transformation]) -> &mut Builder {
         self.transformations = transformations.to_owned();
         self
@@ -166,10 +191,10 @@ impl Builder {
                             if calls.iter().all(|c| c.scope.is_call()) {
                                 Some((
                                     format!(
-                                        "&({})",
+                                        "&mut ({})",
                                         calls
                                             .iter()
-                                            .map(|c| format!("&{}", c.bound_name))
+                                            .map(|c| format!("&mut {}", c.bound_name))
                                             .collect::<Vec<_>>()
                                             .join(", ")
                                     ),
@@ -204,11 +229,28 @@ impl Builder {
 
         let call = self.generate_call(&group_by_user_data);
         let call = self.generate_call_conversion(call, &mut uninitialized_vars);
-        let ret = self.generate_out_return(&mut uninitialized_vars);
+        let ret = self.generate_out_return(env, &mut uninitialized_vars);
         let (call, ret) = self.apply_outs_mode(call, ret, &mut uninitialized_vars);
 
+        let mut write_backs = self.out_buffer_lengths();
+        write_backs.extend(self.in_out_scalars());
+        let (call, ret) =
+            if !write_backs.is_empty() && ret.is_none() && self.ret.ret.parameter.is_some() {
+                // The values are written back between the call and the return
+                let call = Chunk::Let {
+                    name: "ret".into(),
+                    is_mut: false,
+                    value: Box::new(call),
+                    type_: None,
+                };
+                (call, Some(Chunk::Custom("ret".into())))
+            } else {
+                (call, ret)
+            };
+
         body.push(call);
         self.write_out_uninitialized(&mut body, uninitialized_vars);
+        body.extend(write_backs);
         if let Some(chunk) = ret {
             body.push(chunk);
         }
@@ -216,6 +258,8 @@ impl Builder {
         let mut chunks = Vec::new();
 
         self.add_in_array_lengths(&mut chunks);
+        self.add_in_out_scalars(&mut chunks);
+        self.add_in_record_arrays(&mut chunks);
         self.add_assertion(&mut chunks);
 
         if !self.callbacks.is_empty() || !self.destroys.is_empty() {
@@ -285,10 +329,10 @@ impl Builder {
                             )
                         } else if calls.iter().all(|c| c.scope.is_call()) {
                             format!(
-                                "&({})",
+                                "&mut ({})",
                                 calls
                                     .iter()
-                                    .map(|c| format!("{}_data", c.name))
+                                    .map(|c| format!("&mut {}_data", c.name))
                                     .collect::<Vec<_>>()
                                     .join(", ")
                             )
@@ -312,11 +356,15 @@ impl Builder {
                         is_mut: false,
                         value: Box::new(Chunk::Custom(format!(
                             "{}{}_data",
-                            if calls[0].scope.is_call() { "&" } else { "" },
+                            if calls[0].scope.is_call() {
+                                "&mut "
+                            } else {
+                                ""
+                            },
                             calls[0].name
                         ))),
                         type_: Some(Box::new(Chunk::Custom(if calls[0].scope.is_call() {
-                            format!("&{}", calls[0].bound_name)
+                            format!("&mut {}", calls[0].bound_name)
                         } else {
                             format!("Box_<{}>", calls[0].bound_name)
                         }))),
@@ -382,7 +430,7 @@ impl Builder {
             if full_type.is_none() {
                 if trampoline.scope.is_call() {
                     chunks.push(Chunk::Custom(format!(
-                        "let {0}_data: {1} = {0};",
+                        "let mut {0}_data: {1} = {0};",
                         trampoline.name, trampoline.bound_name
                     )));
                 } else {
@@ -393,7 +441,7 @@ impl Builder {
                 }
             } else if trampoline.scope.is_call() {
                 chunks.push(Chunk::Custom(format!(
-                    "let {0}_data: &{1} = &{0};",
+                    "let mut {0}_data: {1} = {0};",
                     trampoline.name, trampoline.bound_name
                 )));
             } else {
@@ -428,6 +476,14 @@ impl Builder {
                 }
                 continue;
             }
+            if path_like_type(env, par.typ).is_some() {
+                if *nullable {
+                    arguments.push(Chunk::Name(format!("{}.as_deref()", par.name)));
+                } else {
+                    arguments.push(Chunk::Name(format!("&*{}", par.name)));
+                }
+                continue;
+            }
             if *nullable && !is_fundamental {
                 arguments.push(Chunk::Name(format!("{}.as_ref().as_ref()", par.name)));
                 continue;
@@ -487,7 +543,7 @@ impl Builder {
                             String::new()
                         },
                         if *trampoline.nullable {
-                            ".expect(\"cannot get closure...\")"
+                            panics::missing_closure_expect(env)
                         } else {
                             ""
                         }
@@ -545,6 +601,12 @@ impl Builder {
                             }
                         )));
                     } else {
+                        if let Some(pos) = pos {
+                            body.push(Chunk::Custom(format!(
+                                "let callback = &mut callback.{};",
+                                pos
+                            )));
+                        }
                         body.push(Chunk::Custom(add.to_owned()));
                     }
                 }
@@ -577,9 +639,10 @@ impl Builder {
             });
             if !is_destroy && *trampoline.nullable {
                 if trampoline.scope.is_async() {
-                    body.push(Chunk::Custom(
-                        "let callback = (*callback).expect(\"cannot get closure...\");".to_owned(),
-                    ));
+                    body.push(Chunk::Custom(format!(
+                        "let callback = (*callback){};",
+                        panics::missing_closure_expect(env)
+                    )));
                     if trampoline.ret.c_type != "void" {
                         extra_before_call = "let res = ";
                     }
@@ -632,9 +695,7 @@ impl Builder {
             )));
             if !trampoline.scope.is_async() && *trampoline.nullable {
                 body.push(Chunk::Custom("} else {".to_owned()));
-                body.push(Chunk::Custom(
-                    "\tpanic!(\"cannot get closure...\")".to_owned(),
-                ));
+                body.push(Chunk::Custom(format!("\t{}", panics::missing_closure(env))));
                 body.push(Chunk::Custom("};".to_owned()));
             }
             if trampoline.ret.c_type != "void" {
@@ -702,6 +763,12 @@ impl Builder {
                     trampoline.name, bounds_str
                 )));
             }
+        } else if let Some(callback) = self.single_nullable_callback(trampoline.user_data_index) {
+            // No data to destroy without a callback
+            chunks.push(Chunk::Custom(format!(
+                "let destroy_call{} = if {}_data.is_some() {{ Some({}_func{} as _) }} else {{ None }};",
+                trampoline.destroy_index, callback.name, trampoline.name, bounds_str
+            )));
         } else {
             chunks.push(Chunk::Custom(format!(
                 "let destroy_call{} = Some({}_func{} as _);",
@@ -710,6 +777,18 @@ impl Builder {
         }
     }
 
+    /// The callback alone with its user data, if it's nullable.
+    fn single_nullable_callback(&self, user_data_index: usize) -> Option<&Trampoline> {
+        let mut callbacks = self
+            .callbacks
+            .iter()
+            .filter(|c| c.user_data_index == user_data_index);
+        match (callbacks.next(), callbacks.next()) {
+            (Some(callback), None) if *callback.nullable => Some(callback),
+            _ => None,
+        }
+    }
+
     fn add_async_trampoline(
         &self,
         env: &Env,
@@ -774,7 +853,7 @@ impl Builder {
             .enumerate()
             .filter(|&(index, out)| {
                 out.lib_par.direction == ParameterDirection::Out
-                    && out.lib_par.name != "error"
+                    && !out.lib_par.is_error
                     && Some(index) != index_to_ignore
             })
             .map(|(_, out)| {
@@ -833,9 +912,7 @@ impl Builder {
         let output_vars = trampoline
             .output_params
             .iter()
-            .filter(|out| {
-                out.lib_par.direction == ParameterDirection::Out && out.lib_par.name != "error"
-            })
+            .filter(|out| out.lib_par.direction == ParameterDirection::Out && !out.lib_par.is_error)
             .map(|out| Chunk::Let {
                 name: out.lib_par.name.clone(),
                 is_mut: true,
@@ -938,17 +1015,47 @@ impl Builder {
     fn add_in_array_lengths(&self, chunks: &mut Vec<Chunk>) {
         for trans in &self.transformations {
             if let TransformationType::Length {
-                ref array_name,
+                ref array_names,
                 ref array_length_name,
                 ref array_length_type,
+                ref length_scale,
+                in_out,
             } = trans.transformation_type
             {
                 if let In = self.parameters[trans.ind_c] {
-                    let value =
-                        Chunk::Custom(format!("{}.len() as {}", array_name, array_length_type));
+                    let array_name = &array_names[0];
+                    let measure = |name: &str| {
+                        let measure_slice = |slice: &str| match length_scale {
+                            LengthScale::Bytes => format!("std::mem::size_of_val({})", slice),
+                            _ => format!("{}.len()", slice),
+                        };
+                        if self.is_nullable_record_array(name) {
+                            format!("{}.map_or(0, |s| {})", name, measure_slice("s"))
+                        } else {
+                            measure_slice(name)
+                        }
+                    };
+                    // All the arrays must match the single length passed to C
+                    for other_name in &array_names[1..] {
+                        chunks.push(Chunk::Custom(format!(
+                            "assert_eq!({}, {}, \"`{}` and `{}` must have the same length\");",
+                            measure(array_name),
+                            measure(other_name),
+                            array_name,
+                            other_name
+                        )));
+                    }
+                    let value = match length_scale {
+                        LengthScale::Elements | LengthScale::Bytes => measure(array_name),
+                        LengthScale::Expression(expr) => format!(
+                            "({})",
+                            LEN_WORD.replace_all(expr, measure(array_name).as_str())
+                        ),
+                    };
+                    let value = Chunk::Custom(format!("{} as {}", value, array_length_type));
                     chunks.push(Chunk::Let {
                         name: array_length_name.clone(),
-                        is_mut: false,
+                        is_mut: in_out,
                         value: Box::new(value),
                         type_: None,
                     });
@@ -957,6 +1064,102 @@ impl Builder {
         }
     }
 
+    /// Truncates the caller-allocated out arrays to the length written back
+    /// by the C function.
+    fn out_buffer_lengths(&self) -> Vec<Chunk> {
+        let mut chunks = Vec::new();
+        for trans in &self.transformations {
+            if let TransformationType::Length {
+                ref array_names,
+                ref array_length_name,
+                in_out: true,
+                ..
+            } = trans.transformation_type
+            {
+                for array_name in array_names {
+                    chunks.push(Chunk::Custom(format!(
+                        "{}.truncate({} as usize);",
+                        array_name, array_length_name
+                    )));
+                }
+            }
+        }
+        chunks
+    }
+
+    fn add_in_out_scalars(&self, chunks: &mut Vec<Chunk>) {
+        for trans in &self.transformations {
+            if let TransformationType::InOutScalar { ref name } = trans.transformation_type {
+                chunks.push(Chunk::Let {
+                    name: format!("{}_glib", name),
+                    is_mut: true,
+                    value: Box::new(Chunk::Custom(format!("(*{}).into_glib()", name))),
+                    type_: None,
+                });
+            }
+        }
+    }
+
+    /// Writes the values of the inout scalars back into the references of
+    /// the caller.
+    fn in_out_scalars(&self) -> Vec<Chunk> {
+        self.transformations
+            .iter()
+            .filter_map(|trans| match trans.transformation_type {
+                TransformationType::InOutScalar { ref name } => Some(Chunk::Custom(format!(
+                    "*{} = from_glib({}_glib);",
+                    name, name
+                ))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Whether `name` is passed as an `Option<&[T]>` of records
+    fn is_nullable_record_array(&self, name: &str) -> bool {
+        self.transformations.iter().any(|trans| {
+            matches!(
+                trans.transformation_type,
+                TransformationType::ToGlibRecordArray {
+                    name: ref array_name,
+                    nullable: true,
+                    ..
+                } if array_name == name
+            )
+        })
+    }
+
+    fn add_in_record_arrays(&self, chunks: &mut Vec<Chunk>) {
+        for trans in &self.transformations {
+            if let TransformationType::ToGlibRecordArray {
+                ref name,
+                mode: RecordArrayMode::Copy,
+                ref ffi_type,
+                nullable,
+                ..
+            } = trans.transformation_type
+            {
+                let copy = |slice: &str| {
+                    format!(
+                        "{}.iter().map(|r| unsafe {{ *ToGlibPtr::<*const {}>::to_glib_none(r).0 }}).collect::<Vec<_>>()",
+                        slice, ffi_type
+                    )
+                };
+                let value = Chunk::Custom(if nullable {
+                    format!("{}.map(|s| {})", name, copy("s"))
+                } else {
+                    copy(name)
+                });
+                chunks.push(Chunk::Let {
+                    name: name.clone(),
+                    is_mut: false,
+                    value: Box::new(value),
+                    type_: None,
+                });
+            }
+        }
+    }
+
     fn generate_call(&self, calls: &FuncParameters<'_>) -> Chunk {
         let params = self.generate_func_parameters(calls);
         let func = Chunk::FfiCall {
@@ -998,16 +1201,23 @@ impl Builder {
         let mut to_insert = Vec::new();
         for (user_data_index, FuncParameter { pos, callbacks, .. }) in calls.iter() {
             let all_call = callbacks.iter().all(|c| c.scope.is_call());
+            let mut name = if all_call {
+                // Derived from a unique borrow as the trampolines call it mutably
+                format!("super_callback{} as *mut _ as usize as *mut _", pos)
+            } else {
+                format!("Box_::into_raw(super_callback{}) as *mut _", pos)
+            };
+            if self.single_nullable_callback(*user_data_index).is_some() {
+                // `None` is passed with NULL user data, which is then never freed
+                name = format!(
+                    "if super_callback{}.is_some() {{ {} }} else {{ std::ptr::null_mut() }}",
+                    pos, name
+                );
+            }
             to_insert.push((
                 *user_data_index,
                 Chunk::FfiCallParameter {
-                    transformation_type: TransformationType::ToGlibDirect {
-                        name: if all_call {
-                            format!("super_callback{} as *const _ as usize as *mut _", pos)
-                        } else {
-                            format!("Box_::into_raw(super_callback{}) as *mut _", pos)
-                        },
-                    },
+                    transformation_type: TransformationType::ToGlibDirect { name },
                 },
             ));
         }
@@ -1063,7 +1273,8 @@ impl Builder {
             } = par
             {
                 let val = self.get_uninitialized(mem_mode);
-                if val.is_uninitialized() {
+                // Optional ones are only initialized if requested
+                if val.is_uninitialized() && parameter.requested_by.is_none() {
                     ret.push((
                         parameter.name.clone(),
                         self.check_if_need_glib_conversion(env, parameter.typ),
@@ -1083,7 +1294,7 @@ impl Builder {
     fn get_uninitialized(&self, mem_mode: &OutMemMode) -> Chunk {
         use self::OutMemMode::*;
         match mem_mode {
-            Uninitialized => Chunk::Uninitialized,
+            Uninitialized | UninitializedStruct => Chunk::Uninitialized,
             UninitializedNamed(ref name) => Chunk::UninitializedNamed { name: name.clone() },
             NullPtr => Chunk::NullPtr,
             NullMutPtr => Chunk::NullMutPtr,
@@ -1091,7 +1302,11 @@ impl Builder {
     }
 
     #[allow(clippy::blocks_in_if_conditions)]
-    fn generate_out_return(&self, uninitialized_vars: &mut Vec<(String, bool)>) -> Option<Chunk> {
+    fn generate_out_return(
+        &self,
+        env: &Env,
+        uninitialized_vars: &mut Vec<(String, bool)>,
+    ) -> Option<Chunk> {
         if !self.outs_as_return {
             return None;
         }
@@ -1115,7 +1330,23 @@ impl Builder {
                     continue;
                 }
 
-                chs.push(self.out_parameter_to_return(parameter, mem_mode, uninitialized_vars));
+                let value =
+                    self.out_parameter_to_return(env, parameter, mem_mode, uninitialized_vars);
+                if let Some(ref flag) = parameter.requested_by {
+                    chs.push(Chunk::Requested {
+                        flag: flag.clone(),
+                        nullable: *parameter.nullable,
+                        value: Box::new(value),
+                    });
+                    continue;
+                }
+                match self.nullable_out_error() {
+                    Some(message) if *parameter.nullable => chs.push(Chunk::NoneIsError {
+                        message: message.clone(),
+                        value: Box::new(value),
+                    }),
+                    _ => chs.push(value),
+                }
             }
         }
         let chunk = Chunk::Tuple(chs, TupleMode::Auto);
@@ -1123,13 +1354,27 @@ impl Builder {
     }
     fn out_parameter_to_return(
         &self,
+        env: &Env,
         parameter: &parameter_ffi_call_out::Parameter,
         mem_mode: &OutMemMode,
         uninitialized_vars: &mut Vec<(String, bool)>,
     ) -> Chunk {
-        let value = Chunk::Custom(parameter.name.clone());
+        let name = if parameter.requested_by.is_some() && mem_mode.is_uninitialized() {
+            let need_from_glib = self.check_if_need_glib_conversion(env, parameter.typ);
+            format!(
+                "{}{}.assume_init(){}",
+                if need_from_glib { "from_glib(" } else { "" },
+                parameter.name,
+                if need_from_glib { ")" } else { "" },
+            )
+        } else {
+            parameter.name.clone()
+        };
+        let value = Chunk::Custom(name.clone());
         if let OutMemMode::UninitializedNamed(_) = mem_mode {
             value
+        } else if let OutMemMode::UninitializedStruct = mem_mode {
+            Chunk::Custom(format!("from_glib_none(&{} as *const _)", name))
         } else {
             let array_length_name = self.find_array_length_name(&parameter.name);
             self.remove_extra_assume_init(&array_length_name, uninitialized_vars);
@@ -1158,9 +1403,16 @@ impl Builder {
                     type_: Option::None,
                 };
                 let ret = ret.expect("No return in optional outs mode");
-                let ret = Chunk::OptionalReturn {
-                    condition: "ret".into(),
-                    value: Box::new(ret),
+                let ret = match self.nullable_out_error() {
+                    Some(message) => Chunk::BoolErrorReturn {
+                        condition: "ret".into(),
+                        message: message.clone(),
+                        value: Box::new(ret),
+                    },
+                    Option::None => Chunk::OptionalReturn {
+                        condition: "ret".into(),
+                        value: Box::new(ret),
+                    },
                 };
                 (call, Some(ret))
             }
@@ -1228,15 +1480,25 @@ impl Builder {
         }
     }
 
+    /// Error message used when a success boolean or a nullable out parameter
+    /// is mapped to an error.
+    fn nullable_out_error(&self) -> Option<&String> {
+        if self.outs_mode == Mode::Optional {
+            self.ret.ret.nullable_return_is_error.as_ref()
+        } else {
+            None
+        }
+    }
+
     fn find_array_length_name(&self, array_name_: &str) -> Option<String> {
         self.transformations.iter().find_map(|tr| {
             if let TransformationType::Length {
-                ref array_name,
+                ref array_names,
                 ref array_length_name,
                 ..
             } = tr.transformation_type
             {
-                if array_name == array_name_ {
+                if array_names.iter().any(|name| name == array_name_) {
                     Some(array_length_name.clone())
                 } else {
                     None
@@ -1283,6 +1545,9 @@ fn c_type_mem_mode_lib(
 }
 
 fn c_type_mem_mode(env: &Env, parameter: &AnalysisCParameter) -> OutMemMode {
+    if parameter.caller_allocates && record_type::is_plain_record(env, parameter.typ) {
+        return OutMemMode::UninitializedStruct;
+    }
     c_type_mem_mode_lib(
         env,
         parameter.typ,
@@ -1341,9 +1606,9 @@ fn add_chunk_for_type(
         }
         library::Type::Fundamental(library::Fundamental::UniChar) => {
             body.push(Chunk::Custom(format!(
-                "let {0} = std::convert::TryFrom::try_from({0})\
-                     .expect(\"conversion from an invalid Unicode value attempted\");",
-                par.name
+                "let {0} = std::convert::TryFrom::try_from({0}){1};",
+                par.name,
+                panics::invalid_unichar(env)
             )));
             true
         }
@@ -1355,6 +1620,20 @@ fn add_chunk_for_type(
             true
         }
         library::Type::Alias(x) => add_chunk_for_type(env, x.typ, par, body, ty_name, nullable),
+        library::Type::Fundamental(
+            library::Fundamental::Filename | library::Fundamental::OsString,
+        ) => {
+            let type_name = if *nullable {
+                format!("Option<{}>", ty_name)
+            } else {
+                ty_name.to_owned()
+            };
+            body.push(Chunk::Custom(format!(
+                "let {0}: {1} = from_glib_none({0});",
+                par.name, type_name
+            )));
+            false
+        }
         x => {
             let (begin, end) =
                 crate::codegen::trampoline_from_glib::from_glib_xxx(par.transfer, true);
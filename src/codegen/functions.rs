@@ -1,10 +1,14 @@
 use crate::{
+    analysis::varargs::{self, Conversion},
     codegen::{function, general},
     env::Env,
     file_saver,
 };
 use log::info;
-use std::path::Path;
+use std::{
+    io::{Result, Write},
+    path::Path,
+};
 
 pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     info!("Generate global functions");
@@ -19,7 +23,7 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
     }
 
     let path = root_path.join("functions.rs");
-    file_saver::save_to_file(path, env.config.make_backup, |w| {
+    file_saver::save_to_file(path, env, |w| {
         general::start_comments(w, &env.config)?;
         general::uses(w, env, &functions.imports, None)?;
 
@@ -31,6 +35,89 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
             function::generate(w, env, func_analysis, None, None, false, false, 0)?;
         }
 
+        for macro_analysis in &env.analysis.varargs_macros {
+            writeln!(w)?;
+            generate_varargs_macro(w, env, macro_analysis)?;
+        }
+
         Ok(())
     });
 }
+
+fn generate_varargs_macro(w: &mut dyn Write, env: &Env, analysis: &varargs::Info) -> Result<()> {
+    let glib = if env.namespaces.is_glib_crate {
+        "$crate"
+    } else {
+        "$crate::glib"
+    };
+    let convert = |arg: &str, conversion: Conversion| {
+        match conversion {
+        Conversion::Direct => arg.to_owned(),
+        Conversion::Bool => format!("{}::translate::IntoGlib::into_glib({})", glib, arg),
+        Conversion::Str | Conversion::Path => format!(
+            "{}::translate::ToGlibPtr::<*const std::os::raw::c_char>::to_glib_none(AsRef::<{}>::as_ref(&{})).0",
+            glib,
+            if conversion == Conversion::Str {
+                "str"
+            } else {
+                "std::path::Path"
+            },
+            arg
+        ),
+    }
+    };
+
+    let pattern = analysis
+        .parameters
+        .iter()
+        .map(|(name, _)| format!("${}:expr", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let mut call = format!("$crate::ffi::{}(", analysis.glib_name);
+    for (name, conversion) in &analysis.parameters {
+        call.push_str(&format!(
+            "\n\t\t\t\t{},",
+            convert(&format!("${}", name), *conversion)
+        ));
+    }
+    call.push_str(&format!(
+        "\n\t\t\t\t$({},)*\n\t\t\t\tstd::ptr::null::<std::os::raw::c_char>(),\n\t\t\t)",
+        convert("$args", analysis.varargs)
+    ));
+    let body = match analysis.ret {
+        varargs::Return::None | varargs::Return::Direct => call,
+        varargs::Return::Bool => format!("{}::translate::from_glib::<_, bool>({})", glib, call),
+        varargs::Return::String { path, nullable } => {
+            let typ = if path {
+                "std::path::PathBuf".to_owned()
+            } else {
+                format!("{}::GString", glib)
+            };
+            let typ = if nullable {
+                format!("Option<{}>", typ)
+            } else {
+                typ
+            };
+            format!(
+                "{}::translate::from_glib_full::<_, {}>({})",
+                glib, typ, call
+            )
+        }
+    };
+
+    writeln!(w, "// rustdoc-stripper-ignore-next")?;
+    writeln!(
+        w,
+        "/// Calls [`ffi::{}`] with the given arguments followed by `NULL`.",
+        analysis.glib_name
+    )?;
+    general::version_condition(w, env, None, analysis.version, false, 0)?;
+    writeln!(w, "#[macro_export]")?;
+    writeln!(w, "macro_rules! {} {{", analysis.name)?;
+    writeln!(
+        w,
+        "\t({} $(, $args:expr)* $(,)?) => {{\n\t\tunsafe {{\n\t\t\t{}\n\t\t}}\n\t}};",
+        pattern, body
+    )?;
+    writeln!(w, "}}")
+}
@@ -2,6 +2,7 @@ use crate::{
     analysis::{
         self, general::StatusedTypeId, imports::Imports, namespaces, special_functions::TraitInfo,
     },
+    codegen::panics,
     config::{derives::Derive, Config},
     env::Env,
     gir_version::VERSION,
@@ -20,12 +21,14 @@ pub fn start_comments(w: &mut dyn Write, conf: &Config) -> Result<()> {
     if conf.single_version_file.is_some() {
         start_comments_no_version(w, conf)
     } else {
+        file_header(w, conf)?;
         single_version_file(w, conf, "// ")?;
         writeln!(w, "// DO NOT EDIT")
     }
 }
 
 pub fn start_comments_no_version(w: &mut dyn Write, conf: &Config) -> Result<()> {
+    file_header(w, conf)?;
     writeln!(
         w,
         "// This file was generated by gir (https://github.com/gtk-rs/gir)
@@ -44,6 +47,37 @@ pub fn start_comments_no_version(w: &mut dyn Write, conf: &Config) -> Result<()>
     )
 }
 
+/// Starts the crate-internal helpers module `{name}.rs`, whose items the
+/// generated functions reach as `crate::*` through the usual `pub use auto::*;`.
+pub fn start_helpers_module(
+    w: &mut dyn Write,
+    env: &Env,
+    name: &str,
+    mod_rs: &mut Vec<String>,
+) -> Result<()> {
+    start_comments(w, &env.config)?;
+    writeln!(w)?;
+    writeln!(w, "#![allow(dead_code)]")?;
+
+    mod_rs.push(format!("\nmod {};", name));
+    mod_rs.push("#[allow(unused_imports)]".into());
+    mod_rs.push(format!("pub(crate) use self::{}::*;", name));
+    Ok(())
+}
+
+/// Writes the configured `file_header`, with `{gir_version}` replaced by the
+/// version of gir.
+fn file_header(w: &mut dyn Write, conf: &Config) -> Result<()> {
+    if let Some(ref header) = conf.file_header {
+        let header = header.replace("{gir_version}", VERSION);
+        write!(w, "{}", header)?;
+        if !header.ends_with('\n') {
+            writeln!(w)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn single_version_file(w: &mut dyn Write, conf: &Config, prefix: &str) -> Result<()> {
     write!(
         w,
@@ -72,30 +106,47 @@ pub fn single_version_file(w: &mut dyn Write, conf: &Config, prefix: &str) -> Re
     )
 }
 
+/// Imports sharing a version and the same cfg constraints
+type ImportBlock<'a> = ((Option<Version>, &'a [String]), Vec<&'a String>);
+
 pub fn uses(
     w: &mut dyn Write,
     env: &Env,
     imports: &Imports,
     outer_version: Option<Version>,
 ) -> Result<()> {
-    writeln!(w)?;
+    // Imports sharing the same conditions are emitted together, ungated ones first,
+    // so that each cfg block only pulls in what the matching code needs.
+    let mut blocks: Vec<ImportBlock<'_>> = Vec::new();
     for (name, scope) in imports.iter() {
-        if !scope.constraints.is_empty() {
-            writeln!(
-                w,
-                "#[cfg(any({},feature = \"dox\"))]",
-                scope.constraints.join(", ")
-            )?;
-            writeln!(
-                w,
-                "#[cfg_attr(feature = \"dox\", doc(cfg({})))]",
-                scope.constraints.join(", ")
-            )?;
+        let version = Version::if_stricter_than(scope.version, outer_version)
+            .filter(|_| version_condition_string(env, None, scope.version, false, 0).is_some());
+        let key = (version, scope.constraints.as_slice());
+        match blocks.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, names)) => names.push(name),
+            None => blocks.push((key, vec![name])),
+        }
+    }
+    blocks.sort_by_key(|(key, _)| *key);
+
+    for ((version, constraints), names) in blocks {
+        writeln!(w)?;
+        for name in names {
+            if !constraints.is_empty() {
+                writeln!(
+                    w,
+                    "#[cfg(any({},feature = \"dox\"))]",
+                    constraints.join(", ")
+                )?;
+                writeln!(
+                    w,
+                    "#[cfg_attr(feature = \"dox\", doc(cfg({})))]",
+                    constraints.join(", ")
+                )?;
+            }
+            version_condition(w, env, None, version, false, 0)?;
+            writeln!(w, "use {};", name)?;
         }
-        let version = Version::if_stricter_than(scope.version, outer_version);
-
-        version_condition(w, env, None, version, false, 0)?;
-        writeln!(w, "use {};", name)?;
     }
 
     Ok(())
@@ -517,6 +568,46 @@ pub fn define_shared_type(
     Ok(())
 }
 
+pub fn define_ref_only_type(
+    w: &mut dyn Write,
+    env: &Env,
+    type_name: &str,
+    glib_name: &str,
+) -> Result<()> {
+    let sys_crate_name = env.main_sys_crate_name();
+    writeln!(w)?;
+    writeln!(w, "#[repr(transparent)]")?;
+    doc_alias(w, glib_name, "", 0)?;
+    writeln!(
+        w,
+        "pub struct {}({}::{});",
+        type_name, sys_crate_name, glib_name
+    )?;
+
+    for (ptr, cast) in &[("*const", ""), ("*mut", " as *const _ as *mut _")] {
+        writeln!(w)?;
+        writeln!(w, "#[doc(hidden)]")?;
+        writeln!(
+            w,
+            "impl<'a> ToGlibPtr<'a, {ptr} {sys}::{glib}> for {ty} {{
+\ttype Storage = &'a Self;
+
+\t#[inline]
+\tfn to_glib_none(&'a self) -> Stash<'a, {ptr} {sys}::{glib}, Self> {{
+\t\tStash(&self.0{cast}, self)
+\t}}
+}}",
+            ptr = ptr,
+            sys = sys_crate_name,
+            glib = glib_name,
+            ty = type_name,
+            cast = cast,
+        )?;
+    }
+
+    Ok(())
+}
+
 pub fn cfg_deprecated(
     w: &mut dyn Write,
     env: &Env,
@@ -855,10 +946,11 @@ pub fn declare_default_from_new(
                 "impl Default for {0} {{
                      fn default() -> Self {{
                          glib::object::Object::new::<Self>(&[])
-                            .expect(\"Can't construct {0} object with default parameters\")
+                            {1}
                      }}
                  }}",
-                name
+                name,
+                panics::default_construct(env, name)
             )?;
         }
     }
@@ -0,0 +1,187 @@
+use crate::{
+    analysis::{
+        function_parameters::TransformationType, functions::Visibility, info_base::InfoBase,
+    },
+    codegen::general,
+    env::Env,
+    file_saver,
+    nameutil::{use_glib_if_needed, use_glib_type},
+};
+use log::info;
+use std::path::Path;
+
+/// Generates `hash_tables.rs` with the helpers turning the `HashMap`s of
+/// `GHashTable` in-parameters into tables, when a generated function has one.
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if !uses_hash_tables(env) {
+        return;
+    }
+
+    info!("Generate hash table helpers");
+
+    let path = root_path.join("hash_tables.rs");
+    let ffi = use_glib_if_needed(env, "ffi");
+
+    file_saver::save_to_file(path, env, |w| {
+        general::start_helpers_module(w, env, "hash_tables", mod_rs)?;
+        writeln!(w)?;
+        writeln!(w, "use {};", use_glib_type(env, "translate::*"))?;
+        writeln!(w, "use std::collections::HashMap;")?;
+
+        writeln!(w)?;
+        writeln!(w, "pub(crate) trait HashTableItem {{")?;
+        writeln!(w, "\tfn hash_func() -> {}::GHashFunc;", ffi)?;
+        writeln!(w, "\tfn equal_func() -> {}::GEqualFunc;", ffi)?;
+        writeln!(w, "\tfn destroy_func() -> {}::GDestroyNotify;", ffi)?;
+        writeln!(w, "\tfn to_glib_item(&self) -> {}::gpointer;", ffi)?;
+        writeln!(w, "}}")?;
+
+        let items = [
+            (
+                "String",
+                "g_str_hash",
+                "g_str_equal",
+                format!("Some({}::g_free)", ffi),
+                "ToGlibPtr::<*mut std::os::raw::c_char>::to_glib_full(self) as _",
+            ),
+            (
+                "bool",
+                "g_direct_hash",
+                "g_direct_equal",
+                "None".to_owned(),
+                "self.into_glib() as isize as _",
+            ),
+        ];
+        let integers = [
+            ("i8", "isize"),
+            ("u8", "usize"),
+            ("i16", "isize"),
+            ("u16", "usize"),
+            ("i32", "isize"),
+            ("u32", "usize"),
+        ];
+        let integers = integers.iter().map(|(typ, pointer)| {
+            (
+                *typ,
+                "g_direct_hash",
+                "g_direct_equal",
+                "None".to_owned(),
+                if *pointer == "isize" {
+                    "*self as isize as _"
+                } else {
+                    "*self as usize as _"
+                },
+            )
+        });
+        for (typ, hash, equal, destroy, to_glib) in items.into_iter().chain(integers) {
+            writeln!(w)?;
+            writeln!(w, "impl HashTableItem for {} {{", typ)?;
+            writeln!(w, "\tfn hash_func() -> {}::GHashFunc {{", ffi)?;
+            writeln!(w, "\t\tSome({}::{})", ffi, hash)?;
+            writeln!(w, "\t}}")?;
+            writeln!(w, "\tfn equal_func() -> {}::GEqualFunc {{", ffi)?;
+            writeln!(w, "\t\tSome({}::{})", ffi, equal)?;
+            writeln!(w, "\t}}")?;
+            writeln!(w, "\tfn destroy_func() -> {}::GDestroyNotify {{", ffi)?;
+            writeln!(w, "\t\t{}", destroy)?;
+            writeln!(w, "\t}}")?;
+            writeln!(w, "\tfn to_glib_item(&self) -> {}::gpointer {{", ffi)?;
+            writeln!(w, "\t\t{}", to_glib)?;
+            writeln!(w, "\t}}")?;
+            writeln!(w, "}}")?;
+        }
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Reference on a table, released once the call is done"
+        )?;
+        writeln!(
+            w,
+            "pub(crate) struct HashTableStash(pub *mut {}::GHashTable);",
+            ffi
+        )?;
+        writeln!(w)?;
+        writeln!(w, "impl Drop for HashTableStash {{")?;
+        writeln!(w, "\tfn drop(&mut self) {{")?;
+        writeln!(w, "\t\tif !self.0.is_null() {{")?;
+        writeln!(w, "\t\t\tunsafe {{ {}::g_hash_table_unref(self.0) }}", ffi)?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) fn hash_table_to_glib_none<K: HashTableItem, V: HashTableItem>(\
+             map: Option<&HashMap<K, V>>) -> HashTableStash {{"
+        )?;
+        writeln!(w, "\tHashTableStash(hash_table_to_glib_full(map))")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) fn hash_table_to_glib_full<K: HashTableItem, V: HashTableItem>(\
+             map: Option<&HashMap<K, V>>) -> *mut {}::GHashTable {{",
+            ffi
+        )?;
+        writeln!(w, "\tlet map = match map {{")?;
+        writeln!(w, "\t\tSome(map) => map,")?;
+        writeln!(w, "\t\tNone => return std::ptr::null_mut(),")?;
+        writeln!(w, "\t}};")?;
+        writeln!(w, "\tunsafe {{")?;
+        writeln!(
+            w,
+            "\t\tlet table = {}::g_hash_table_new_full(K::hash_func(), K::equal_func(), \
+             K::destroy_func(), V::destroy_func());",
+            ffi
+        )?;
+        writeln!(w, "\t\tfor (key, value) in map {{")?;
+        writeln!(
+            w,
+            "\t\t\t{}::g_hash_table_insert(table, key.to_glib_item(), value.to_glib_item());",
+            ffi
+        )?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t\ttable")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        Ok(())
+    });
+}
+
+fn uses_hash_tables(env: &Env) -> bool {
+    let objects = env
+        .analysis
+        .objects
+        .values()
+        .filter(|info| env.config.objects[&info.full_name].status.need_generate())
+        .map(|info| &info.base);
+    let records = env
+        .analysis
+        .records
+        .values()
+        .filter(|info| env.config.objects[&info.full_name].status.need_generate())
+        .map(|info| &info.base);
+    objects
+        .chain(records)
+        .chain(env.analysis.global_functions.iter())
+        .any(takes_hash_table)
+}
+
+fn takes_hash_table(info: &InfoBase) -> bool {
+    info.functions
+        .iter()
+        .filter(|function| {
+            function.status.need_generate() && function.visibility != Visibility::Comment
+        })
+        .flat_map(|function| &function.parameters.transformations)
+        .any(|trans| {
+            matches!(
+                trans.transformation_type,
+                TransformationType::ToGlibHashTable { .. }
+            )
+        })
+}
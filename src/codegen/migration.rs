@@ -0,0 +1,310 @@
+//! Map of the C symbols removed or replaced between two runs on different
+//! versions of a library.
+//!
+//! The symbols of the main namespace are saved along with the map, so the next
+//! run against a newer `.gir` file can tell which ones went away.
+
+use crate::{
+    analysis::namespaces::MAIN,
+    env::Env,
+    file_saver::save_to_file,
+    library::{Function, Type},
+};
+use log::{info, warn};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{Result, Write},
+    path::Path,
+};
+
+static GI_DOCGEN_LINK: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[(?:ctor|method|func|vfunc|class|iface|struct|enum|flags|const)@([\w.]+)\]")
+        .unwrap()
+});
+static C_IDENTIFIER: Lazy<Regex> = Lazy::new(|| Regex::new(r"\w+").unwrap());
+
+struct Migration {
+    replacement: Option<String>,
+    reason: &'static str,
+}
+
+pub fn generate(env: &Env) {
+    let path = match env.config.migration_file {
+        Some(ref path) => path,
+        None => return,
+    };
+    info!("Generating migration map {:?}", path);
+
+    let symbols = collect_symbols(env);
+    let previous = read_previous_symbols(path);
+    let mut migrations = BTreeMap::new();
+
+    for (c_identifier, moved_to) in &env.library.namespace(MAIN).moved_functions {
+        if let Some(replacement) = resolve_path(env, moved_to) {
+            if replacement != *c_identifier {
+                migrations.insert(
+                    c_identifier.clone(),
+                    Migration {
+                        replacement: Some(replacement),
+                        reason: "moved-to",
+                    },
+                );
+            }
+        }
+    }
+
+    for (c_identifier, doc_deprecated) in deprecated_notes(env) {
+        if migrations.contains_key(&c_identifier) {
+            continue;
+        }
+        if let Some(replacement) =
+            replacement_from_note(env, &symbols, &c_identifier, doc_deprecated)
+        {
+            migrations.insert(
+                c_identifier,
+                Migration {
+                    replacement: Some(replacement),
+                    reason: "deprecated",
+                },
+            );
+        }
+    }
+
+    let added: Vec<&String> = symbols.iter().filter(|s| !previous.contains(*s)).collect();
+    for removed in previous.iter().filter(|s| !symbols.contains(*s)) {
+        let replacement = most_similar(removed, &added).cloned();
+        let reason = if replacement.is_some() {
+            "renamed"
+        } else {
+            "removed"
+        };
+        migrations.insert(
+            removed.clone(),
+            Migration {
+                replacement,
+                reason,
+            },
+        );
+    }
+
+    save_to_file(path, env, |w| write_migration(w, &migrations, &symbols));
+}
+
+fn collect_symbols(env: &Env) -> BTreeSet<String> {
+    let mut symbols = BTreeSet::new();
+    let ns = env.library.namespace(MAIN);
+
+    for (_, typ) in env.library.namespace_types(MAIN) {
+        if let Some(glib_name) = typ.get_glib_name() {
+            symbols.insert(glib_name.to_owned());
+        }
+        let members = match typ {
+            Type::Enumeration(enum_) => &enum_.members[..],
+            Type::Bitfield(bitfield) => &bitfield.members[..],
+            _ => &[][..],
+        };
+        symbols.extend(members.iter().map(|m| m.c_identifier.clone()));
+        symbols.extend(
+            typ.functions()
+                .iter()
+                .filter_map(|f| f.c_identifier.clone()),
+        );
+    }
+    symbols.extend(ns.functions.iter().filter_map(|f| f.c_identifier.clone()));
+    symbols.extend(ns.constants.iter().map(|c| c.c_identifier.clone()));
+    symbols.extend(ns.moved_functions.keys().cloned());
+
+    symbols
+}
+
+fn deprecated_notes(env: &Env) -> Vec<(String, &str)> {
+    let ns = env.library.namespace(MAIN);
+    let functions = env
+        .library
+        .namespace_types(MAIN)
+        .flat_map(|(_, typ)| typ.functions())
+        .chain(&ns.functions);
+    fn notes(f: &Function) -> Option<(String, &str)> {
+        match (&f.c_identifier, &f.doc_deprecated) {
+            (Some(c_identifier), Some(doc)) if f.deprecated_version.is_some() => {
+                Some((c_identifier.clone(), doc.as_str()))
+            }
+            _ => None,
+        }
+    }
+
+    functions
+        .filter_map(notes)
+        .chain(ns.constants.iter().filter_map(|c| match c.doc_deprecated {
+            Some(ref doc) if c.deprecated_version.is_some() => {
+                Some((c.c_identifier.clone(), doc.as_str()))
+            }
+            _ => None,
+        }))
+        .collect()
+}
+
+/// Finds the replacement mentioned in a deprecation note, either as a gi-docgen
+/// link or as a plain C identifier.
+fn replacement_from_note(
+    env: &Env,
+    symbols: &BTreeSet<String>,
+    c_identifier: &str,
+    doc_deprecated: &str,
+) -> Option<String> {
+    GI_DOCGEN_LINK
+        .captures_iter(doc_deprecated)
+        .find_map(|caps| resolve_path(env, &caps[1]))
+        .or_else(|| {
+            C_IDENTIFIER
+                .find_iter(doc_deprecated)
+                .map(|m| m.as_str())
+                .find(|s| *s != c_identifier && symbols.contains(*s))
+                .map(ToOwned::to_owned)
+        })
+        .filter(|s| s != c_identifier)
+}
+
+/// Resolves a GIR path like `Widget.show` or `Gtk.init` to a C identifier.
+fn resolve_path(env: &Env, path: &str) -> Option<String> {
+    let ns = env.library.namespace(MAIN);
+    let path = path
+        .strip_prefix(&ns.name)
+        .and_then(|p| p.strip_prefix('.'))
+        .unwrap_or(path);
+
+    match path.rsplit_once('.') {
+        Some((type_name, func_name)) => {
+            let typ = env.library.type_(env.library.find_type(MAIN, type_name)?);
+            typ.functions()
+                .iter()
+                .find(|f| f.name == func_name)
+                .and_then(|f| f.c_identifier.clone())
+        }
+        None => ns
+            .functions
+            .iter()
+            .find(|f| f.name == path)
+            .and_then(|f| f.c_identifier.clone())
+            .or_else(|| {
+                let tid = env.library.find_type(MAIN, path)?;
+                env.library
+                    .type_(tid)
+                    .get_glib_name()
+                    .map(ToOwned::to_owned)
+            }),
+    }
+}
+
+fn read_previous_symbols(path: &Path) -> BTreeSet<String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => parse_symbols(&contents),
+        Err(_) => {
+            warn!(
+                "No previous migration map {:?}, only deprecations are mapped",
+                path
+            );
+            BTreeSet::new()
+        }
+    }
+}
+
+/// Reads back the `symbols` list written by `write_migration`.
+fn parse_symbols(contents: &str) -> BTreeSet<String> {
+    contents
+        .lines()
+        .skip_while(|line| line.trim() != "\"symbols\": [")
+        .skip(1)
+        .map(str::trim)
+        .take_while(|line| !line.starts_with(']'))
+        .map(|line| line.trim_end_matches(',').trim_matches('"').to_owned())
+        .collect()
+}
+
+/// Picks the added symbol closest to `removed`, if any is close enough to be
+/// considered a rename.
+fn most_similar<'a>(removed: &str, added: &[&'a String]) -> Option<&'a String> {
+    added
+        .iter()
+        .map(|s| (edit_distance(removed, s), *s))
+        .filter(|(distance, s)| distance * 3 <= removed.len().max(s.len()))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, s)| s)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(previous + 1);
+        }
+    }
+    row[b.len()]
+}
+
+fn write_migration(
+    w: &mut dyn Write,
+    migrations: &BTreeMap<String, Migration>,
+    symbols: &BTreeSet<String>,
+) -> Result<()> {
+    writeln!(w, "{{")?;
+    writeln!(w, "  \"migrations\": {{")?;
+    for (i, (name, migration)) in migrations.iter().enumerate() {
+        let replacement = migration
+            .replacement
+            .as_ref()
+            .map_or_else(|| "null".to_owned(), |r| format!("\"{}\"", r));
+        let separator = if i + 1 < migrations.len() { "," } else { "" };
+        writeln!(
+            w,
+            "    \"{}\": {{ \"replacement\": {}, \"reason\": \"{}\" }}{}",
+            name, replacement, migration.reason, separator
+        )?;
+    }
+    writeln!(w, "  }},")?;
+    writeln!(w, "  \"symbols\": [")?;
+    for (i, symbol) in symbols.iter().enumerate() {
+        let separator = if i + 1 < symbols.len() { "," } else { "" };
+        writeln!(w, "    \"{}\"{}", symbol, separator)?;
+    }
+    writeln!(w, "  ]")?;
+    writeln!(w, "}}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn symbols_round_trip() {
+        let symbols: BTreeSet<String> = ["gtk_widget_show", "gtk_widget_hide"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let mut out = Vec::new();
+        write_migration(&mut out, &BTreeMap::new(), &symbols).unwrap();
+        assert_eq!(parse_symbols(&String::from_utf8(out).unwrap()), symbols);
+    }
+
+    #[test]
+    fn similar_names() {
+        let added = [
+            "gtk_widget_set_visible".to_owned(),
+            "gdk_surface_new".to_owned(),
+        ];
+        let added: Vec<&String> = added.iter().collect();
+        assert_eq!(
+            most_similar("gtk_widget_set_visibility", &added),
+            Some(added[0])
+        );
+        assert_eq!(most_similar("gdk_window_new", &added), None);
+    }
+}
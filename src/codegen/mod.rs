@@ -3,17 +3,22 @@ use std::path::Path;
 
 mod alias;
 mod bound;
+mod callbacks;
 mod child_properties;
 mod constants;
 mod doc;
 mod enums;
+mod features;
 mod flags;
 pub mod function;
 mod function_body_chunk;
 mod functions;
 mod general;
+mod hash_tables;
+mod migration;
 mod object;
 mod objects;
+mod panics;
 mod parameter;
 mod properties;
 mod property_body;
@@ -24,7 +29,10 @@ mod return_value;
 mod signal;
 mod signal_body;
 mod special_functions;
-mod sys;
+mod stale_check;
+mod subclass;
+pub mod sys;
+mod time;
 mod trait_impls;
 mod trampoline;
 mod trampoline_from_glib;
@@ -37,7 +45,7 @@ pub fn generate(env: &Env) {
         WorkMode::Normal => normal_generate(env),
         WorkMode::Sys => sys::generate(env),
         WorkMode::Doc => doc::generate(env),
-        WorkMode::DisplayNotBound => {}
+        WorkMode::DisplayNotBound | WorkMode::ConsistencyCheck => {}
     }
 }
 
@@ -45,18 +53,37 @@ fn normal_generate(env: &Env) {
     let mut mod_rs: Vec<String> = Vec::new();
     let mut traits: Vec<String> = Vec::new();
     let mut builders: Vec<String> = Vec::new();
+    let mut subclass: Vec<String> = Vec::new();
+    let mut prelude: Vec<String> = Vec::new();
     let root_path = env.config.auto_path.as_path();
 
     generate_single_version_file(env);
-    objects::generate(env, root_path, &mut mod_rs, &mut traits, &mut builders);
+    objects::generate(
+        env,
+        root_path,
+        &mut mod_rs,
+        &mut traits,
+        &mut builders,
+        &mut subclass,
+        &mut prelude,
+    );
     records::generate(env, root_path, &mut mod_rs);
     enums::generate(env, root_path, &mut mod_rs);
     flags::generate(env, root_path, &mut mod_rs);
     alias::generate(env, root_path, &mut mod_rs);
+    callbacks::generate(env, root_path, &mut mod_rs);
     functions::generate(env, root_path, &mut mod_rs);
     constants::generate(env, root_path, &mut mod_rs);
+    features::generate(env, root_path, &mut mod_rs);
+    hash_tables::generate(env, root_path, &mut mod_rs);
+    panics::generate(env, root_path, &mut mod_rs);
+    time::generate(env, root_path, &mut mod_rs);
+    migration::generate(env);
 
-    generate_mod_rs(env, root_path, &mod_rs, &traits, &builders);
+    if env.config.generate_prelude {
+        generate_prelude_rs(env, root_path, &prelude);
+    }
+    generate_mod_rs(env, root_path, &mod_rs, &traits, &builders, &subclass);
 }
 
 pub fn generate_mod_rs(
@@ -65,9 +92,10 @@ pub fn generate_mod_rs(
     mod_rs: &[String],
     traits: &[String],
     builders: &[String],
+    subclass: &[String],
 ) {
     let path = root_path.join("mod.rs");
-    save_to_file(path, env.config.make_backup, |w| {
+    save_to_file(path, env, |w| {
         general::start_comments(w, &env.config)?;
         general::write_vec(w, mod_rs)?;
         writeln!(w)?;
@@ -84,13 +112,36 @@ pub fn generate_mod_rs(
             general::write_vec(w, builders)?;
             writeln!(w, "}}")?;
         }
+
+        if !subclass.is_empty() {
+            writeln!(w, "#[doc(hidden)]")?;
+            writeln!(w, "pub mod subclass {{")?;
+            general::write_vec(w, subclass)?;
+            writeln!(w, "}}")?;
+        }
+
+        if env.config.generate_prelude {
+            writeln!(w, "pub mod prelude;")?;
+        }
+        Ok(())
+    });
+}
+
+/// Generates `prelude.rs`, re-exporting all the generated traits
+/// not listed in `options.prelude_exclude`.
+pub fn generate_prelude_rs(env: &Env, root_path: &Path, prelude: &[String]) {
+    let path = root_path.join("prelude.rs");
+    save_to_file(path, env, |w| {
+        general::start_comments(w, &env.config)?;
+        writeln!(w)?;
+        general::write_vec(w, prelude)?;
         Ok(())
     });
 }
 
 pub fn generate_single_version_file(env: &Env) {
     if let Some(ref path) = env.config.single_version_file {
-        save_to_file(path, env.config.make_backup, |w| {
+        save_to_file(path, env, |w| {
             general::single_version_file(w, &env.config, "")
         });
     }
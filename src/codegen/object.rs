@@ -4,17 +4,18 @@ use super::{
         cfg_deprecated_string, not_version_condition_no_dox, version_condition,
         version_condition_no_doc, version_condition_string,
     },
-    properties, signal, trait_impls,
+    panics, properties, signal, subclass, trait_impls, trampoline,
 };
 use crate::{
     analysis::{
-        self, object::has_builder_properties, ref_mode::RefMode, rust_type::RustType,
-        special_functions::Type,
+        self, class_record::ClassRecord, object::has_builder_properties, ref_mode::RefMode,
+        rust_type::RustType, special_functions::Type,
     },
     env::Env,
     library::{self, Nullable},
     nameutil,
     traits::IntoString,
+    version::Version,
 };
 use std::collections::{BTreeMap, HashSet};
 use std::io::{Result, Write};
@@ -139,8 +140,13 @@ pub fn generate(
         )?;
     }
 
+    let generate_class_accessor = env.config.generate_class_accessors
+        && !analysis.is_interface
+        && analysis.c_class_type.is_some();
+
     if (analysis.need_generate_inherent() && analysis.should_generate_impl_block())
         || !analysis.final_type
+        || generate_class_accessor
     {
         writeln!(w)?;
         write!(w, "impl {} {{", analysis.name)?;
@@ -187,6 +193,25 @@ pub fn generate(
             )?;
         }
 
+        if generate_class_accessor {
+            writeln!(
+                w,
+                "
+            /// Returns the C class structure of the type of this object.
+            pub fn class_struct(&self) -> &{sys_crate_name}::{class_name} {{
+                unsafe {{
+                    let instance = {object_type}::as_ptr(self) as *const {type_instance};
+                    &*((*instance).g_class as *const {sys_crate_name}::{class_name})
+                }}
+            }}
+        ",
+                sys_crate_name = env.main_sys_crate_name(),
+                class_name = analysis.c_class_type.as_ref().unwrap(),
+                object_type = nameutil::use_glib_type(env, "ObjectType"),
+                type_instance = nameutil::use_glib_type(env, "gobject_ffi::GTypeInstance"),
+            )?;
+        }
+
         if !analysis.need_generate_trait() {
             for func_analysis in &analysis.methods() {
                 function::generate(
@@ -297,7 +322,56 @@ pub fn generate(
         generate_trait(w, env, analysis)?;
     }
 
-    if generate_display_trait && !analysis.specials.has_trait(Type::Display) {
+    if let Some(ref class_record) = analysis.class_record {
+        writeln!(w)?;
+        generate_class_record(w, analysis, class_record)?;
+    }
+
+    if let Some(ref info) = analysis.subclass {
+        writeln!(w)?;
+        subclass::generate(w, env, analysis, info)?;
+    }
+
+    let request_path = if analysis.need_generate_trait() {
+        &analysis.trait_name
+    } else {
+        &analysis.name
+    };
+    for func_analysis in &analysis.methods() {
+        function::generate_future_request(
+            w,
+            env,
+            func_analysis,
+            &analysis.name,
+            request_path,
+            analysis.version,
+        )?;
+    }
+
+    for trampoline in &analysis.shared_trampolines {
+        writeln!(w)?;
+        let version = Version::if_stricter_than(trampoline.version, analysis.version);
+        version_condition(w, env, None, version, false, 0)?;
+        trampoline::generate(w, env, trampoline, analysis.need_generate_trait(), 0)?;
+    }
+
+    if let Some((ref prop_name, prop_version)) = analysis.display_property {
+        writeln!(w)?;
+        let version = Version::if_stricter_than(prop_version, analysis.version);
+        version_condition(w, env, None, version, false, 0)?;
+        writeln!(w, "impl fmt::Display for {} {{", analysis.name)?;
+        writeln!(
+            w,
+            "\tfn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {{\n\
+             \t\tlet value = {}::property::<Option<{}>>(self, \"{}\");\n\
+             \t\tf.write_str(value.as_deref().unwrap_or_default())\n\
+             \t}}\n\
+             }}",
+            nameutil::use_glib_type(env, "ObjectExt"),
+            nameutil::use_glib_type(env, "GString"),
+            prop_name
+        )?;
+    } else if generate_display_trait && !analysis.specials.has_trait(Type::Display) {
         writeln!(w, "\nimpl fmt::Display for {} {{", analysis.name,)?;
         // Generate Display trait implementation.
         writeln!(
@@ -313,6 +387,40 @@ pub fn generate(
     Ok(())
 }
 
+fn generate_class_record(
+    w: &mut dyn Write,
+    analysis: &analysis::object::Info,
+    class_record: &ClassRecord,
+) -> Result<()> {
+    writeln!(
+        w,
+        "// rustdoc-stripper-ignore-next
+/// Class structure of [`{name}`], giving read-only access to its virtual functions.
+#[repr(transparent)]
+pub struct {class_name}({ffi_type});
+
+impl {class_name} {{
+    /// Wraps a borrowed C class structure.
+    pub fn from_ffi(class: &{ffi_type}) -> &Self {{
+        unsafe {{ &*(class as *const {ffi_type} as *const Self) }}
+    }}
+
+    pub fn as_ptr(&self) -> *const {ffi_type} {{
+        &self.0
+    }}",
+        name = analysis.name,
+        class_name = class_record.name,
+        ffi_type = class_record.ffi_type,
+    )?;
+    for (name, typ) in &class_record.vfuncs {
+        writeln!(w)?;
+        writeln!(w, "\tpub fn {}(&self) -> {} {{", name, typ)?;
+        writeln!(w, "\t\tself.0.{}", name)?;
+        writeln!(w, "\t}}")?;
+    }
+    writeln!(w, "}}")
+}
+
 // TODO: instead create a Vec<> inside the Builder instead of Options.
 fn generate_builder(w: &mut dyn Write, env: &Env, analysis: &analysis::object::Info) -> Result<()> {
     let mut methods = vec![];
@@ -361,6 +469,7 @@ fn generate_builder(w: &mut dyn Write, env: &Env, analysis: &analysis::object::I
                                     .map(|bound| {
                                         bound.full_type_parameter_reference(
                                             RefMode::ByRef,
+                                            None,
                                             Nullable(false),
                                             false,
                                         )
@@ -470,8 +579,10 @@ impl {name}Builder {{
         writeln!(
             w,
             r#"        let ret = {}::Object::new::<{}>(&properties)
-                .expect("Failed to create an instance of {}");"#,
-            glib_crate_name, analysis.name, analysis.name,
+                {};"#,
+            glib_crate_name,
+            analysis.name,
+            panics::build_object(env, &analysis.name),
         )?;
         writeln!(w, "        {{\n            {}\n        }}", code)?;
         writeln!(w, "    ret\n    }}")?;
@@ -479,8 +590,10 @@ impl {name}Builder {{
         writeln!(
             w,
             r#"        {}::Object::new::<{}>(&properties)
-                .expect("Failed to create an instance of {}")"#,
-            glib_crate_name, analysis.name, analysis.name,
+                {}"#,
+            glib_crate_name,
+            analysis.name,
+            panics::build_object(env, &analysis.name),
         )?;
         writeln!(w, "\n    }}")?;
     }
@@ -565,6 +678,8 @@ pub fn generate_reexports(
     contents: &mut Vec<String>,
     traits: &mut Vec<String>,
     builders: &mut Vec<String>,
+    subclass: &mut Vec<String>,
+    prelude: &mut Vec<String>,
 ) {
     let mut cfgs: Vec<String> = Vec::new();
     if let Some(cfg) = general::cfg_condition_string(analysis.cfg_condition.as_ref(), false, 0) {
@@ -586,9 +701,42 @@ pub fn generate_reexports(
     contents.push("".to_owned());
     contents.extend_from_slice(&cfgs);
     contents.push(format!("mod {};", module_name));
+    if env.config.objects[&analysis.full_name].doc_hidden {
+        cfgs.push("#[doc(hidden)]".to_owned());
+    }
     contents.extend_from_slice(&cfgs);
 
     contents.push(format!("pub use self::{}::{};", module_name, analysis.name,));
+    if let Some(ref class_record) = analysis.class_record {
+        contents.extend_from_slice(&cfgs);
+        contents.push(format!(
+            "pub use self::{}::{};",
+            module_name, class_record.name
+        ));
+    }
+
+    for func_analysis in &analysis.methods() {
+        let request = match func_analysis.async_future {
+            Some(analysis::functions::AsyncFuture {
+                request: Some(ref request),
+                ..
+            }) if func_analysis.status.need_generate()
+                && func_analysis.visibility == analysis::functions::Visibility::Public =>
+            {
+                request
+            }
+            _ => continue,
+        };
+        contents.extend_from_slice(&cfgs);
+        let version = Version::if_stricter_than(func_analysis.version, analysis.version);
+        if let Some(cfg) = general::version_condition_string(env, None, version, false, 0) {
+            contents.push(cfg);
+        }
+        contents.push(format!(
+            "pub use self::{}::{};",
+            module_name, request.type_name
+        ));
+    }
 
     if analysis.need_generate_trait() {
         for cfg in &cfgs {
@@ -598,6 +746,14 @@ pub fn generate_reexports(
             "\tpub use super::{}::{};",
             module_name, analysis.trait_name
         ));
+
+        if !env.config.prelude_exclude.contains(&analysis.trait_name) {
+            prelude.extend_from_slice(&cfgs);
+            prelude.push(format!(
+                "pub use super::{}::{};",
+                module_name, analysis.trait_name
+            ));
+        }
     }
 
     if has_builder_properties(&analysis.builder_properties) {
@@ -609,4 +765,14 @@ pub fn generate_reexports(
             module_name, analysis.name
         ));
     }
+
+    if let Some(ref info) = analysis.subclass {
+        for cfg in &cfgs {
+            subclass.push(format!("\t{}", cfg));
+        }
+        subclass.push(format!(
+            "\tpub use super::{}::{{{}, {}}};",
+            module_name, info.impl_trait, info.ext_trait
+        ));
+    }
 }
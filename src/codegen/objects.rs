@@ -8,6 +8,8 @@ pub fn generate(
     mod_rs: &mut Vec<String>,
     traits: &mut Vec<String>,
     builders: &mut Vec<String>,
+    subclass: &mut Vec<String>,
+    prelude: &mut Vec<String>,
 ) {
     info!("Generate objects");
     for class_analysis in env.analysis.objects.values() {
@@ -26,10 +28,19 @@ pub fn generate(
         path.set_extension("rs");
         info!("Generating file {:?}", path);
 
-        save_to_file(path, env.config.make_backup, |w| {
+        save_to_file(path, env, |w| {
             super::object::generate(w, env, class_analysis, generate_display_trait)
         });
 
-        super::object::generate_reexports(env, class_analysis, &mod_name, mod_rs, traits, builders);
+        super::object::generate_reexports(
+            env,
+            class_analysis,
+            &mod_name,
+            mod_rs,
+            traits,
+            builders,
+            subclass,
+            prelude,
+        );
     }
 }
@@ -0,0 +1,225 @@
+use crate::{codegen::general, env::Env, file_saver};
+use log::info;
+use std::path::Path;
+
+/// Generates `panics.rs` with the `#[cold]` functions through which the
+/// generated code panics when `options.cold_panics` is set, keeping the
+/// formatting machinery out of every function.
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if !env.config.cold_panics {
+        return;
+    }
+
+    info!("Generate panic helpers");
+
+    let path = root_path.join("panics.rs");
+
+    file_saver::save_to_file(path, env, |w| {
+        general::start_helpers_module(w, env, "panics", mod_rs)?;
+
+        let helpers = [
+            ("mandatory_none", "", "\"mandatory glib value is None\""),
+            (
+                "invalid_unichar",
+                "",
+                "\"conversion from an invalid Unicode value attempted\"",
+            ),
+            (
+                "infallible",
+                "err: &dyn std::fmt::Display",
+                "\"infallible {}\", err",
+            ),
+            ("missing_closure", "", "\"cannot get closure...\""),
+            (
+                "property_value",
+                "name: &str, err: &dyn std::fmt::Debug",
+                "\"Return Value for property `{}` getter: {:?}\", name, err",
+            ),
+            ("returned_null", "name: &str", "\"{} returned NULL\", name"),
+            (
+                "invalid_string",
+                "name: &str",
+                "\"{} returned an invalid string\", name",
+            ),
+            (
+                "default_construct",
+                "name: &str",
+                "\"Can't construct {} object with default parameters\", name",
+            ),
+            (
+                "build_object",
+                "name: &str",
+                "\"Failed to create an instance of {}\", name",
+            ),
+            (
+                "wrong_variant_type",
+                "name: &str",
+                "\"Wrong variant type for `{}`\", name",
+            ),
+            (
+                "missing_parent_vfunc",
+                "name: &str",
+                "\"No parent implementation of `{}`\", name",
+            ),
+        ];
+        for (name, params, args) in helpers {
+            writeln!(w)?;
+            writeln!(w, "#[cold]")?;
+            writeln!(w, "#[inline(never)]")?;
+            writeln!(w, "#[track_caller]")?;
+            writeln!(w, "pub(crate) fn panic_{}({}) -> ! {{", name, params)?;
+            writeln!(w, "\tpanic!({})", args)?;
+            writeln!(w, "}}")?;
+        }
+
+        Ok(())
+    });
+}
+
+/// Conversion suffix unwrapping the `Result` of a fallible `try_from_glib`
+/// of a mandatory value.
+pub fn mandatory_none(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        ".unwrap_or_else(|_| crate::panic_mandatory_none())"
+    } else {
+        ".expect(\"mandatory glib value is None\")"
+    }
+}
+
+/// Suffix unwrapping the `Option` of a mandatory value.
+pub fn mandatory_none_option(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        ".unwrap_or_else(|| crate::panic_mandatory_none())"
+    } else {
+        ".expect(\"mandatory glib value is None\")"
+    }
+}
+
+/// Conversion suffix unwrapping the `Result` of `char::try_from`.
+pub fn invalid_unichar(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        ".unwrap_or_else(|_| crate::panic_invalid_unichar())"
+    } else {
+        ".expect(\"conversion from an invalid Unicode value attempted\")"
+    }
+}
+
+/// Conversion suffix unwrapping the `Result` of an infallible `try_from_glib`.
+pub fn infallible(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        ".unwrap_or_else(|err| crate::panic_infallible(&err))"
+    } else {
+        ".unwrap_or_else(|err| panic!(\"infallible {}\", err))"
+    }
+}
+
+/// Suffix unwrapping the `Option` of a nullable callback of a trampoline.
+pub fn missing_closure_expect(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        ".unwrap_or_else(|| crate::panic_missing_closure())"
+    } else {
+        ".expect(\"cannot get closure...\")"
+    }
+}
+
+/// Statement panicking on a missing nullable callback in a trampoline.
+pub fn missing_closure(env: &Env) -> &'static str {
+    if env.config.cold_panics {
+        "crate::panic_missing_closure()"
+    } else {
+        "panic!(\"cannot get closure...\")"
+    }
+}
+
+/// Expression getting the value of the property `name` out of `value`.
+pub fn property_value(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            "value.get().unwrap_or_else(|err| crate::panic_property_value(\"{}\", &err))",
+            name
+        )
+    } else {
+        format!(
+            "value.get().expect(\"Return Value for property `{}` getter\")",
+            name
+        )
+    }
+}
+
+/// Suffix unwrapping the `Option` of a string pointer returned by `name`.
+pub fn returned_null(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|| crate::panic_returned_null(\"{}\"))",
+            name
+        )
+    } else {
+        format!(".expect(\"{} returned NULL\")", name)
+    }
+}
+
+/// Suffix unwrapping the `Result` of `CStr::to_str` on a string returned by `name`.
+pub fn invalid_string(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|_| crate::panic_invalid_string(\"{}\"))",
+            name
+        )
+    } else {
+        format!(".expect(\"{} returned an invalid string\")", name)
+    }
+}
+
+/// Suffix unwrapping the `Result` of constructing the object `name` with
+/// default properties.
+pub fn default_construct(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|_| crate::panic_default_construct(\"{}\"))",
+            name
+        )
+    } else {
+        format!(
+            ".expect(\"Can't construct {} object with default parameters\")",
+            name
+        )
+    }
+}
+
+/// Suffix unwrapping the `Result` of building the object `name`.
+pub fn build_object(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|_| crate::panic_build_object(\"{}\"))",
+            name
+        )
+    } else {
+        format!(".expect(\"Failed to create an instance of {}\")", name)
+    }
+}
+
+/// Suffix unwrapping the `Option` of getting the parameter `name` out of its
+/// `Variant`.
+pub fn wrong_variant_type(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|| crate::panic_wrong_variant_type(\"{}\"))",
+            name
+        )
+    } else {
+        format!(".expect(\"Wrong variant type for `{}`\")", name)
+    }
+}
+
+/// Suffix unwrapping the `Option` of the parent implementation of the
+/// virtual function `name`.
+pub fn missing_parent_vfunc(env: &Env, name: &str) -> String {
+    if env.config.cold_panics {
+        format!(
+            ".unwrap_or_else(|| crate::panic_missing_parent_vfunc(\"{}\"))",
+            name
+        )
+    } else {
+        format!(".expect(\"No parent implementation of `{}`\")", name)
+    }
+}
@@ -17,16 +17,21 @@ impl ToParameter for CParameter {
             format!("{}self", self.ref_mode.for_rust_type())
         } else {
             let type_str = match bounds.get_parameter_bound(&self.name) {
-                Some(bound) => {
-                    bound.full_type_parameter_reference(self.ref_mode, self.nullable, r#async)
-                }
+                Some(bound) => bound.full_type_parameter_reference(
+                    self.ref_mode,
+                    self.lifetime,
+                    self.nullable,
+                    r#async,
+                ),
                 None => {
                     let type_name = RustType::builder(env, self.typ)
                         .direction(self.direction)
                         .nullable(self.nullable)
                         .ref_mode(self.ref_mode)
+                        .lifetime(self.lifetime)
                         .scope(self.scope)
                         .try_from_glib(&self.try_from_glib)
+                        .record_array(self.record_array)
                         .try_build_param()
                         .into_string();
                     match ConversionType::of(env, self.typ) {
@@ -7,6 +7,7 @@ use crate::{
     chunk::Chunk,
     env::Env,
     library,
+    nameutil::{self, use_gio_type, use_glib_type},
     traits::IntoString,
     writer::{primitives::tabs, ToCode},
 };
@@ -21,6 +22,193 @@ pub fn generate(
     indent: usize,
 ) -> Result<()> {
     generate_prop_func(w, env, prop, in_trait, only_declaration, indent)?;
+    if env.config.generate_settings_binding && !prop.is_get {
+        generate_settings_bind_func(w, env, prop, in_trait, only_declaration, indent)?;
+    }
+    if env.config.generate_try_property_getters && prop.is_get {
+        generate_try_getter_func(w, env, prop, in_trait, only_declaration, indent)?;
+    }
+    if env.config.generate_chainable_setters && !prop.is_get {
+        generate_chainable_setter_func(w, env, prop, in_trait, only_declaration, indent)?;
+    }
+
+    Ok(())
+}
+
+fn generate_settings_bind_func(
+    w: &mut dyn Write,
+    env: &Env,
+    prop: &Property,
+    in_trait: bool,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let pub_prefix = if in_trait { "" } else { "pub " };
+    let decl_suffix = if only_declaration { ";" } else { " {" };
+    let commented = RustType::try_new(env, prop.typ).is_err();
+    let comment_prefix = if commented { "//" } else { "" };
+
+    writeln!(w)?;
+
+    if !in_trait || only_declaration {
+        cfg_deprecated(
+            w,
+            env,
+            Some(prop.typ),
+            prop.deprecated_version,
+            commented,
+            indent,
+        )?;
+    }
+    version_condition(w, env, None, prop.version, commented, indent)?;
+    writeln!(
+        w,
+        "{}{}{}fn bind_{}_to_settings(&self, settings: &{}, key: &str, flags: {}){}",
+        tabs(indent),
+        comment_prefix,
+        pub_prefix,
+        nameutil::signal_to_snake(&prop.name),
+        use_gio_type(env, "Settings"),
+        use_gio_type(env, "SettingsBindFlags"),
+        decl_suffix
+    )?;
+
+    if !only_declaration {
+        let self_ = if in_trait { "self.as_ref()" } else { "self" };
+        writeln!(
+            w,
+            "{}{}\t{}::bind(settings, key, {}, \"{}\").flags(flags).build();",
+            tabs(indent),
+            comment_prefix,
+            use_gio_type(env, "prelude::SettingsExtManual"),
+            self_,
+            prop.name
+        )?;
+        writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+    }
+
+    Ok(())
+}
+
+fn generate_chainable_setter_func(
+    w: &mut dyn Write,
+    env: &Env,
+    prop: &Property,
+    in_trait: bool,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let pub_prefix = if in_trait { "" } else { "pub " };
+    let decl_suffix = if only_declaration { ";" } else { " {" };
+    let commented = RustType::try_new(env, prop.typ).is_err();
+    let comment_prefix = if commented { "//" } else { "" };
+
+    writeln!(w)?;
+
+    if !in_trait || only_declaration {
+        cfg_deprecated(
+            w,
+            env,
+            Some(prop.typ),
+            prop.deprecated_version,
+            commented,
+            indent,
+        )?;
+    }
+    version_condition(w, env, None, prop.version, commented, indent)?;
+    let (bound, set_param) = set_parameter(env, prop);
+    let name = prop
+        .func_name
+        .strip_prefix("set_")
+        .unwrap_or(&prop.func_name);
+    writeln!(
+        w,
+        "{}{}{}fn with_{}{}(self{}) -> Self{}",
+        tabs(indent),
+        comment_prefix,
+        pub_prefix,
+        name,
+        bound,
+        set_param,
+        decl_suffix
+    )?;
+
+    if !only_declaration {
+        writeln!(
+            w,
+            "{}{}\tself.{}({});",
+            tabs(indent),
+            comment_prefix,
+            prop.func_name,
+            prop.var_name
+        )?;
+        writeln!(w, "{}{}\tself", tabs(indent), comment_prefix)?;
+        writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+    }
+
+    Ok(())
+}
+
+fn generate_try_getter_func(
+    w: &mut dyn Write,
+    env: &Env,
+    prop: &Property,
+    in_trait: bool,
+    only_declaration: bool,
+    indent: usize,
+) -> Result<()> {
+    let pub_prefix = if in_trait { "" } else { "pub " };
+    let decl_suffix = if only_declaration { ";" } else { " {" };
+    let commented = RustType::try_new(env, prop.typ).is_err();
+    let comment_prefix = if commented { "//" } else { "" };
+
+    writeln!(w)?;
+
+    if !in_trait || only_declaration {
+        cfg_deprecated(
+            w,
+            env,
+            Some(prop.typ),
+            prop.deprecated_version,
+            commented,
+            indent,
+        )?;
+    }
+    version_condition(w, env, None, prop.version, commented, indent)?;
+    let ret_type = RustType::builder(env, prop.typ)
+        .direction(library::ParameterDirection::Return)
+        .nullable(prop.nullable)
+        .ref_mode(prop.get_out_ref_mode)
+        .try_build_param()
+        .into_string();
+    writeln!(
+        w,
+        "{}{}{}fn try_{}(&self) -> Result<{}, {}<{}>>{}",
+        tabs(indent),
+        comment_prefix,
+        pub_prefix,
+        prop.func_name,
+        ret_type,
+        use_glib_type(env, "value::ValueTypeMismatchOrNoneError"),
+        use_glib_type(env, "value::ValueTypeMismatchError"),
+        decl_suffix
+    )?;
+
+    if !only_declaration {
+        let self_ = if in_trait { "self.as_ref()" } else { "self" };
+        // Nullable values fail with a `ValueTypeMismatchError`, which gets
+        // wrapped
+        writeln!(
+            w,
+            "{}{}\t{}::property_value({}, \"{}\").get().map_err(Into::into)",
+            tabs(indent),
+            comment_prefix,
+            use_glib_type(env, "ObjectExt"),
+            self_,
+            prop.name,
+        )?;
+        writeln!(w, "{}{}}}", tabs(indent), comment_prefix)?;
+    }
 
     Ok(())
 }
@@ -84,16 +272,14 @@ fn generate_prop_func(
     Ok(())
 }
 
-fn declaration(env: &Env, prop: &Property) -> String {
-    let bound: String;
-    let set_param = if prop.is_get {
-        bound = String::new();
-        String::new()
-    } else if let Some(ref set_bound) = prop.set_bound {
-        bound = format!("<{}: IsA<{}>>", set_bound.alias, set_bound.type_str);
-        format!(", {}: Option<&{}>", prop.var_name, set_bound.alias)
+/// Returns the generic bounds and the value parameter of a property setter
+fn set_parameter(env: &Env, prop: &Property) -> (String, String) {
+    if let Some(ref set_bound) = prop.set_bound {
+        (
+            format!("<{}: IsA<{}>>", set_bound.alias, set_bound.type_str),
+            format!(", {}: Option<&{}>", prop.var_name, set_bound.alias),
+        )
     } else {
-        bound = String::new();
         let dir = library::ParameterDirection::In;
         let param_type = RustType::builder(env, prop.typ)
             .direction(dir)
@@ -101,7 +287,18 @@ fn declaration(env: &Env, prop: &Property) -> String {
             .ref_mode(prop.set_in_ref_mode)
             .try_build_param()
             .into_string();
-        format!(", {}: {}", prop.var_name, param_type)
+        (
+            String::new(),
+            format!(", {}: {}", prop.var_name, param_type),
+        )
+    }
+}
+
+fn declaration(env: &Env, prop: &Property) -> String {
+    let (bound, set_param) = if prop.is_get {
+        (String::new(), String::new())
+    } else {
+        set_parameter(env, prop)
     };
     let return_str = if prop.is_get {
         let dir = library::ParameterDirection::Return;
@@ -1,6 +1,7 @@
 use crate::{
     analysis,
     chunk::Chunk,
+    codegen::panics,
     env::Env,
     nameutil::{use_glib_type, use_gtk_type},
 };
@@ -161,10 +162,7 @@ impl<'a> Builder<'a> {
             call: Box::new(ffi_call),
         });
 
-        body.push(Chunk::Custom(format!(
-            "value.get().expect(\"Return Value for property `{}` getter\")",
-            self.name,
-        )));
+        body.push(Chunk::Custom(panics::property_value(self.env, &self.name)));
         vec![Chunk::Unsafe(body)]
     }
 
@@ -4,6 +4,7 @@ use crate::{
     env::Env,
     library,
     traits::MaybeRef,
+    writer::primitives::tabs,
 };
 use std::io::{Result, Write};
 
@@ -13,7 +14,11 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::record::Info)
     general::start_comments(w, &env.config)?;
     general::uses(w, env, &analysis.imports, type_.version)?;
 
-    if RecordType::of(env.type_(analysis.type_id).maybe_ref().unwrap()) == RecordType::AutoBoxed {
+    if analysis.ref_only {
+        general::define_ref_only_type(w, env, &analysis.name, &type_.c_type)?;
+    } else if RecordType::of(env.type_(analysis.type_id).maybe_ref().unwrap())
+        == RecordType::AutoBoxed
+    {
         if let Some((ref glib_get_type, _)) = analysis.glib_get_type {
             general::define_auto_boxed_type(
                 w,
@@ -88,6 +93,7 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::record::Info)
         .functions
         .iter()
         .any(|f| f.status.need_generate() && !f.visibility.hidden())
+        || !analysis.field_accessors.is_empty()
     {
         writeln!(w)?;
         write!(w, "impl {} {{", analysis.name)?;
@@ -105,6 +111,10 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::record::Info)
             )?;
         }
 
+        for accessor in &analysis.field_accessors {
+            generate_field_accessor(w, env, &type_.c_type, accessor)?;
+        }
+
         writeln!(w, "}}")?;
     }
 
@@ -142,6 +152,45 @@ pub fn generate(w: &mut dyn Write, env: &Env, analysis: &analysis::record::Info)
     Ok(())
 }
 
+fn generate_field_accessor(
+    w: &mut dyn Write,
+    env: &Env,
+    c_type: &str,
+    accessor: &analysis::record::FieldAccessor,
+) -> Result<()> {
+    let reference = if accessor.by_ref { "&" } else { "" };
+    writeln!(w)?;
+    writeln!(
+        w,
+        "{}pub fn {}(&self) -> {}{} {{",
+        tabs(1),
+        accessor.name,
+        reference,
+        accessor.typ
+    )?;
+    writeln!(w, "{}unsafe {{", tabs(2))?;
+    writeln!(
+        w,
+        "{}let ptr: *const {}::{} = self.to_glib_none().0;",
+        tabs(3),
+        env.main_sys_crate_name(),
+        c_type
+    )?;
+    if accessor.by_ref {
+        writeln!(
+            w,
+            "{}&*(&(*ptr).{} as *const _ as *const {})",
+            tabs(3),
+            accessor.name,
+            accessor.typ
+        )?;
+    } else {
+        writeln!(w, "{}(*ptr).{}", tabs(3), accessor.name)?;
+    }
+    writeln!(w, "{}}}", tabs(2))?;
+    writeln!(w, "{}}}", tabs(1))
+}
+
 pub fn generate_reexports(
     env: &Env,
     analysis: &analysis::record::Info,
@@ -167,6 +216,9 @@ pub fn generate_reexports(
     };
     contents.push("".to_owned());
     contents.push(format!("{}mod {};", cfg, module_name));
+    if env.config.objects[&analysis.full_name].doc_hidden {
+        cfg.push_str("#[doc(hidden)]\n");
+    }
     contents.push(format!(
         "{}pub use self::{}::{};",
         cfg, module_name, analysis.name
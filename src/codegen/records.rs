@@ -19,7 +19,7 @@ pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
         path.set_extension("rs");
         info!("Generating file {:?}", path);
 
-        save_to_file(path, env.config.make_backup, |w| {
+        save_to_file(path, env, |w| {
             super::record::generate(w, env, record_analysis)
         });
 
@@ -1,4 +1,5 @@
 use crate::analysis::ref_mode::RefMode;
+use std::borrow::Cow;
 
 impl RefMode {
     pub(crate) fn for_rust_type(self) -> &'static str {
@@ -8,4 +9,12 @@ impl RefMode {
             RefMode::ByRefMut => "&mut ",
         }
     }
+
+    /// Same as `for_rust_type()`, with the reference borrowing for `lifetime`.
+    pub(crate) fn for_rust_type_with_lifetime(self, lifetime: Option<char>) -> Cow<'static, str> {
+        match (self.for_rust_type(), lifetime) {
+            ("", _) | (_, None) => Cow::Borrowed(self.for_rust_type()),
+            (ref_str, Some(lifetime)) => Cow::Owned(format!("&'{} {}", lifetime, &ref_str[1..])),
+        }
+    }
 }
@@ -1,10 +1,10 @@
 use crate::{
     analysis::{
-        self, conversion_type::ConversionType, namespaces, rust_type::RustType,
+        self, conversion_type::ConversionType, namespaces, out_parameters, rust_type::RustType,
         try_from_glib::TryFromGlib,
     },
     env::Env,
-    library::{self, ParameterDirection},
+    library::{self, Nullable, ParameterDirection},
     nameutil::{is_gstring, mangle_keywords, use_glib_type},
     traits::*,
 };
@@ -26,26 +26,32 @@ impl ToReturnValue for library::Parameter {
         try_from_glib: &TryFromGlib,
         is_trampoline: bool,
     ) -> Option<String> {
-        let mut name = RustType::builder(env, self.typ)
-            .direction(self.direction)
-            .nullable(self.nullable)
-            .scope(self.scope)
-            .try_from_glib(try_from_glib)
-            .try_build_param()
-            .into_string();
-        if is_trampoline
-            && self.direction == library::ParameterDirection::Return
-            && is_gstring(&name)
-        {
-            name = "String".to_owned();
-        }
-        let type_str = match ConversionType::of(env, self.typ) {
-            ConversionType::Unknown => format!("/*Unknown conversion*/{}", name),
-            //TODO: records as in gtk_container_get_path_for_child
-            _ => name,
-        };
+        Some(return_type(env, self, None, try_from_glib, is_trampoline))
+    }
+}
 
-        Some(type_str)
+fn return_type(
+    env: &Env,
+    par: &library::Parameter,
+    lifetime: Option<char>,
+    try_from_glib: &TryFromGlib,
+    is_trampoline: bool,
+) -> String {
+    let mut name = RustType::builder(env, par.typ)
+        .direction(par.direction)
+        .nullable(par.nullable)
+        .scope(par.scope)
+        .lifetime(lifetime)
+        .try_from_glib(try_from_glib)
+        .try_build_param()
+        .into_string();
+    if is_trampoline && par.direction == library::ParameterDirection::Return && is_gstring(&name) {
+        name = "String".to_owned();
+    }
+    match ConversionType::of(env, par.typ) {
+        ConversionType::Unknown => format!("/*Unknown conversion*/{}", name),
+        //TODO: records as in gtk_container_get_path_for_child
+        _ => name,
     }
 }
 
@@ -57,24 +63,27 @@ impl ToReturnValue for analysis::return_value::Info {
         is_trampoline: bool,
     ) -> Option<String> {
         let par = self.parameter.as_ref()?;
-        par.lib_par
-            .to_return_value(env, try_from_glib, is_trampoline)
-            .map(|type_name| {
-                if self.nullable_return_is_error.is_some() && type_name.starts_with("Option<") {
-                    // Change `Option<T>` to `Result<T, glib::BoolError>`
-                    format!(
-                        "Result<{}, {}BoolError>",
-                        &type_name[7..(type_name.len() - 1)],
-                        if env.namespaces.glib_ns_id == namespaces::MAIN {
-                            ""
-                        } else {
-                            "glib::"
-                        }
-                    )
+        let type_name = return_type(
+            env,
+            &par.lib_par,
+            self.lifetime,
+            try_from_glib,
+            is_trampoline,
+        );
+        if self.nullable_return_is_error.is_some() && type_name.starts_with("Option<") {
+            // Change `Option<T>` to `Result<T, glib::BoolError>`
+            Some(format!(
+                "Result<{}, {}BoolError>",
+                &type_name[7..(type_name.len() - 1)],
+                if env.namespaces.glib_ns_id == namespaces::MAIN {
+                    ""
                 } else {
-                    type_name
+                    "glib::"
                 }
-            })
+            ))
+        } else {
+            Some(type_name)
+        }
     }
 }
 
@@ -134,7 +143,13 @@ fn out_parameter_as_return_parts(
     }
 }
 
-pub fn out_parameters_as_return(env: &Env, analysis: &analysis::functions::Info) -> String {
+/// Returned tuple of the out parameters, the `optional_outs` ones being
+/// returned as `Option<T>`
+pub fn out_parameters_as_return(
+    env: &Env,
+    analysis: &analysis::functions::Info,
+    optional_outs: &[String],
+) -> String {
     let (prefix, suffix) = out_parameter_as_return_parts(analysis, env);
     let mut return_str = String::with_capacity(100);
     return_str.push_str(" -> ");
@@ -147,6 +162,10 @@ pub fn out_parameters_as_return(env: &Env, analysis: &analysis::functions::Info)
         .filter_map(|out| out.lib_par.array_length)
         .collect();
 
+    // Nullable out parameters are unwrapped into the error of the returned `Result`
+    let nullable_is_error = analysis.outs.mode == out_parameters::Mode::Optional
+        && analysis.ret.nullable_return_is_error.is_some();
+
     let mut skip = 0;
     for (pos, out) in analysis
         .outs
@@ -179,18 +198,33 @@ pub fn out_parameters_as_return(env: &Env, analysis: &analysis::functions::Info)
         if pos > skip {
             return_str.push_str(", ")
         }
-        let s = out_parameter_as_return(out, env);
+        let s = if !optional_outs.contains(&out.lib_par.name) {
+            out_parameter_as_return(out, env, nullable_is_error)
+        } else if *out.lib_par.nullable {
+            out_parameter_as_return(out, env, false)
+        } else {
+            format!("Option<{}>", out_parameter_as_return(out, env, false))
+        };
         return_str.push_str(&s);
     }
     return_str.push_str(&suffix);
     return_str
 }
 
-fn out_parameter_as_return(out: &analysis::Parameter, env: &Env) -> String {
+fn out_parameter_as_return(
+    out: &analysis::Parameter,
+    env: &Env,
+    nullable_is_error: bool,
+) -> String {
     //TODO: upcasts?
+    let nullable = if nullable_is_error {
+        Nullable(false)
+    } else {
+        out.lib_par.nullable
+    };
     let name = RustType::builder(env, out.lib_par.typ)
         .direction(ParameterDirection::Return)
-        .nullable(out.lib_par.nullable)
+        .nullable(nullable)
         .scope(out.lib_par.scope)
         .try_from_glib(&out.try_from_glib)
         .try_build_param()
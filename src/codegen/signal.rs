@@ -56,7 +56,9 @@ pub fn generate(
     if !only_declaration {
         if !commented {
             if let Ok(ref trampoline) = analysis.trampoline {
-                trampoline::generate(w, env, trampoline, in_trait, 2)?;
+                if !trampoline.is_shared {
+                    trampoline::generate(w, env, trampoline, in_trait, 2)?;
+                }
             }
         }
         match function_type {
@@ -6,7 +6,7 @@ use crate::{
     Env,
 };
 
-use super::general::version_condition;
+use super::{general::version_condition, panics};
 
 pub(super) fn generate(
     w: &mut dyn Write,
@@ -50,16 +50,18 @@ pub(super) fn generate_static_to_str(
 \t\t\tCStr::from_ptr(
 \t\t\t\t{ns}::{glib_fn_name}(self.into_glib())
 \t\t\t\t\t.as_ref()
-\t\t\t\t\t.expect(\"{glib_fn_name} returned NULL\"),
+\t\t\t\t\t{returned_null},
 \t\t\t)
 \t\t\t.to_str()
-\t\t\t.expect(\"{glib_fn_name} returned an invalid string\")
+\t\t\t{invalid_string}
 \t\t}}
 \t}}",
         visibility = visibility,
         rust_fn_name = function.codegen_name(),
         ns = env.main_sys_crate_name(),
         glib_fn_name = function.glib_name,
+        returned_null = panics::returned_null(env, &function.glib_name),
+        invalid_string = panics::invalid_string(env, &function.glib_name),
     )?;
 
     Ok(())
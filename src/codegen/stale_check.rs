@@ -0,0 +1,115 @@
+use crate::{codegen::general, config::config::normalize_path, env::Env, file_saver::save_to_file};
+use log::{info, warn};
+use std::{
+    fs,
+    io::{Result, Write},
+    path::{Component, Path, PathBuf},
+};
+
+/// Generates `build_stale_check.rs`, for build scripts to warn when the .gir
+/// files or the configuration changed since the code was generated
+pub fn generate(env: &Env) {
+    if !env.config.generate_stale_check {
+        return;
+    }
+
+    let inputs = env
+        .library
+        .gir_files
+        .iter()
+        .chain(std::iter::once(&env.config.config_file))
+        .filter_map(|path| match fs::read(path) {
+            Ok(content) => Some((
+                relative_path(path, &env.config.target_path),
+                fnv1a(&content),
+            )),
+            Err(e) => {
+                warn!("Can't hash {} for the stale check: {}", path.display(), e);
+                None
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let path = env.config.target_path.join("build_stale_check.rs");
+    info!("Generating file {:?}", path);
+    save_to_file(&path, env, |w| generate_stale_check(w, env, &inputs));
+}
+
+fn generate_stale_check(w: &mut dyn Write, env: &Env, inputs: &[(PathBuf, u64)]) -> Result<()> {
+    general::start_comments(w, &env.config)?;
+    writeln!(w)?;
+    writeln!(w, "const INPUTS: &[(&str, u64)] = &[")?;
+    for (path, hash) in inputs {
+        // Written with `/` to be the same on all platforms
+        let path = path
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        writeln!(w, "    ({:?}, 0x{:016x}),", path, hash)?;
+    }
+    writeln!(w, "];")?;
+    write!(
+        w,
+        "{}",
+        r##"
+/// Warns when the files the code was generated from changed since then.
+/// Files which can't be read, like in a published crate, are skipped.
+///
+/// The build script is rerun when these files change. Like any
+/// `cargo:rerun-if-changed` line, this stops cargo from rerunning it when any
+/// other file of the package changes.
+pub fn check() {
+    let mut stale = Vec::new();
+    for (path, hash) in INPUTS {
+        if let Ok(content) = std::fs::read(path) {
+            println!("cargo:rerun-if-changed={}", path);
+            if fnv1a(&content) != *hash {
+                stale.push(*path);
+            }
+        }
+    }
+    if !stale.is_empty() {
+        println!(
+            "cargo:warning=The generated code is out of date, regenerate it with gir: {} changed",
+            stale.join(", ")
+        );
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+"##
+    )
+}
+
+/// Same hash as the `fnv1a` function of the generated file
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Path of `path` relative to the directory `base`, both being relative to
+/// the current directory unless absolute
+fn relative_path(path: &Path, base: &Path) -> PathBuf {
+    let absolute = |path: &Path| match std::env::current_dir() {
+        Ok(dir) => normalize_path(dir.join(path)),
+        Err(_) => normalize_path(path),
+    };
+    let path = absolute(path);
+    let base = absolute(base);
+    let common = path
+        .components()
+        .zip(base.components())
+        .take_while(|(a, b)| a == b)
+        .count();
+    base.components()
+        .skip(common)
+        .map(|_| Component::ParentDir)
+        .chain(path.components().skip(common))
+        .collect()
+}
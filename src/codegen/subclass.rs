@@ -0,0 +1,573 @@
+use super::panics;
+use crate::{
+    analysis::{
+        self,
+        subclass::{AsyncReturn, AsyncVfunc, Parameter, Subclass, Vfunc},
+    },
+    env::Env,
+    nameutil,
+};
+use std::io::{Result, Write};
+
+pub fn generate(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+) -> Result<()> {
+    let obj = nameutil::module_name(&analysis.name);
+
+    writeln!(w, "// rustdoc-stripper-ignore-next")?;
+    writeln!(
+        w,
+        "/// Trait to implement in subclasses of [`{}`], overriding its virtual functions.",
+        analysis.name
+    )?;
+    writeln!(
+        w,
+        "pub trait {}: {} + {} {{",
+        subclass.impl_trait, subclass.ext_trait, subclass.parent_impl
+    )?;
+    for (pos, vfunc) in subclass.vfuncs.iter().enumerate() {
+        if pos > 0 {
+            writeln!(w)?;
+        }
+        if let Some(ref signal_name) = vfunc.signal_name {
+            writeln!(w, "\t// rustdoc-stripper-ignore-next")?;
+            writeln!(
+                w,
+                "\t/// Default handler of the `{}` signal, run when it's emitted.",
+                signal_name
+            )?;
+        }
+        writeln!(w, "\t{} {{", signature(&obj, vfunc, ""))?;
+        writeln!(
+            w,
+            "\t\tself.parent_{}({}{})",
+            vfunc.name,
+            obj,
+            arguments(&vfunc.parameters, |par| &par.name)
+        )?;
+        writeln!(w, "\t}}")?;
+    }
+    for (pos, vfunc) in subclass.async_vfuncs.iter().enumerate() {
+        if pos > 0 || !subclass.vfuncs.is_empty() {
+            writeln!(w)?;
+        }
+        writeln!(w, "\t// rustdoc-stripper-ignore-next")?;
+        writeln!(
+            w,
+            "\t/// Overrides `{}` and `{}`, running the future on the thread-default main context.",
+            vfunc.async_name, vfunc.finish_name
+        )?;
+        writeln!(w, "\t{} {{", async_signature(env, &obj, vfunc, ""))?;
+        writeln!(
+            w,
+            "\t\tself.parent_{}_future({}{})",
+            vfunc.name,
+            obj,
+            arguments(&vfunc.parameters, |par| &par.name)
+        )?;
+        writeln!(w, "\t}}")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "// rustdoc-stripper-ignore-next")?;
+    writeln!(
+        w,
+        "/// Calls of the parent implementations of the virtual functions of [`{}`].",
+        analysis.name
+    )?;
+    writeln!(w, "pub trait {}: ObjectSubclass {{", subclass.ext_trait)?;
+    for vfunc in &subclass.vfuncs {
+        writeln!(w, "\t{};", signature(&obj, vfunc, "parent_"))?;
+    }
+    for vfunc in &subclass.async_vfuncs {
+        writeln!(w, "\t{};", async_signature(env, &obj, vfunc, "parent_"))?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "impl<T: {}> {} for T {{",
+        subclass.impl_trait, subclass.ext_trait
+    )?;
+    for (pos, vfunc) in subclass.vfuncs.iter().enumerate() {
+        if pos > 0 {
+            writeln!(w)?;
+        }
+        generate_parent_call(w, env, analysis, subclass, &obj, vfunc)?;
+    }
+    for (pos, vfunc) in subclass.async_vfuncs.iter().enumerate() {
+        if pos > 0 || !subclass.vfuncs.is_empty() {
+            writeln!(w)?;
+        }
+        generate_parent_future(w, env, analysis, subclass, &obj, vfunc)?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    if analysis.is_interface {
+        writeln!(
+            w,
+            "unsafe impl<T: {}> IsImplementable<T> for {}",
+            subclass.impl_trait, analysis.name
+        )?;
+        writeln!(
+            w,
+            "where\n\t<T as ObjectSubclass>::Type: IsA<{}>,\n{{",
+            nameutil::use_glib_type(env, "Object")
+        )?;
+        writeln!(
+            w,
+            "\tfn interface_init(iface: &mut {}<Self>) {{",
+            nameutil::use_glib_type(env, "Interface")
+        )?;
+        writeln!(w, "\t\tlet iface = iface.as_mut();")?;
+        for name in vfunc_names(subclass) {
+            writeln!(
+                w,
+                "\t\tiface.{name} = Some({obj}_{name}::<T>);",
+                name = name,
+                obj = obj
+            )?;
+        }
+    } else {
+        writeln!(
+            w,
+            "unsafe impl<T: {}> IsSubclassable<T> for {} {{",
+            subclass.impl_trait, analysis.name
+        )?;
+        writeln!(
+            w,
+            "\tfn class_init(class: &mut {}<Self>) {{",
+            nameutil::use_glib_type(env, "Class")
+        )?;
+        writeln!(w, "\t\tSelf::parent_class_init::<T>(class);")?;
+        if !subclass.vfuncs.is_empty() || !subclass.async_vfuncs.is_empty() {
+            writeln!(w)?;
+            writeln!(w, "\t\tlet klass = class.as_mut();")?;
+        }
+        for name in vfunc_names(subclass) {
+            writeln!(
+                w,
+                "\t\tklass.{name} = Some({obj}_{name}::<T>);",
+                name = name,
+                obj = obj
+            )?;
+        }
+    }
+    writeln!(w, "\t}}")?;
+    writeln!(w, "}}")?;
+
+    for vfunc in &subclass.vfuncs {
+        writeln!(w)?;
+        generate_trampoline(w, analysis, subclass, &obj, vfunc)?;
+    }
+    for vfunc in &subclass.async_vfuncs {
+        writeln!(w)?;
+        generate_async_trampolines(w, env, analysis, subclass, &obj, vfunc)?;
+    }
+
+    Ok(())
+}
+
+/// Fields of the class or interface structure set to the trampolines
+fn vfunc_names(subclass: &Subclass) -> impl Iterator<Item = &str> {
+    subclass
+        .vfuncs
+        .iter()
+        .map(|vfunc| vfunc.name.as_str())
+        .chain(
+            subclass
+                .async_vfuncs
+                .iter()
+                .flat_map(|vfunc| [vfunc.async_name.as_str(), vfunc.finish_name.as_str()]),
+        )
+}
+
+fn signature(obj: &str, vfunc: &Vfunc, prefix: &str) -> String {
+    let parameters = typed_parameters(&vfunc.parameters);
+    let ret = vfunc
+        .ret
+        .as_ref()
+        .map(|ret| format!(" -> {}", ret.typ))
+        .unwrap_or_default();
+    format!(
+        "fn {}{}(&self, {}: &Self::Type{}){}",
+        prefix, vfunc.name, obj, parameters, ret
+    )
+}
+
+fn async_signature(env: &Env, obj: &str, vfunc: &AsyncVfunc, prefix: &str) -> String {
+    format!(
+        "fn {}{}_future(&self, {}: &Self::Type{}) -> Pin<Box_<dyn std::future::Future<Output = Result<{}, {}>> + 'static>>",
+        prefix,
+        vfunc.name,
+        obj,
+        typed_parameters(&vfunc.parameters),
+        vfunc.ret.typ(),
+        nameutil::use_glib_type(env, "Error")
+    )
+}
+
+fn typed_parameters(parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|par| format!(", {}: {}", par.name, par.typ))
+        .collect()
+}
+
+fn arguments<'a>(
+    parameters: &'a [Parameter],
+    argument: impl Fn(&'a Parameter) -> &'a str,
+) -> String {
+    parameters
+        .iter()
+        .map(|par| format!(", {}", argument(par)))
+        .collect()
+}
+
+/// Writes the `let` of the pointer to the structure of the parent class or
+/// interface, returning its name.
+fn parent_struct(
+    w: &mut dyn Write,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+    indent: &str,
+) -> Result<&'static str> {
+    writeln!(w, "{}let data = T::type_data();", indent)?;
+    let (parent, parent_ptr) = if analysis.is_interface {
+        (
+            "parent_iface",
+            format!("parent_interface::<{}>()", analysis.name),
+        )
+    } else {
+        ("parent_class", "parent_class()".to_owned())
+    };
+    writeln!(
+        w,
+        "{}let {} = data.as_ref().{} as *const {};",
+        indent, parent, parent_ptr, subclass.ffi_type
+    )?;
+    Ok(parent)
+}
+
+fn generate_parent_call(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+    obj: &str,
+    vfunc: &Vfunc,
+) -> Result<()> {
+    writeln!(w, "\t{} {{", signature(obj, vfunc, "parent_"))?;
+    writeln!(w, "\t\tunsafe {{")?;
+    let parent = parent_struct(w, analysis, subclass, "\t\t\t")?;
+    let call = format!(
+        "f({}.unsafe_cast_ref::<{}>().to_glib_none().0{})",
+        obj,
+        analysis.name,
+        arguments(&vfunc.parameters, |par| &par.to_glib)
+    );
+    match vfunc.ret {
+        None => {
+            writeln!(w, "\t\t\tif let Some(f) = (*{}).{} {{", parent, vfunc.name)?;
+            writeln!(w, "\t\t\t\t{}", call)?;
+            writeln!(w, "\t\t\t}}")?;
+        }
+        Some(ref ret) => {
+            writeln!(
+                w,
+                "\t\t\tlet f = (*{}).{}{};",
+                parent,
+                vfunc.name,
+                panics::missing_parent_vfunc(env, &vfunc.name)
+            )?;
+            if ret.from_glib.is_empty() {
+                writeln!(w, "\t\t\t{}", call)?;
+            } else {
+                writeln!(w, "\t\t\t{}({})", ret.from_glib, call)?;
+            }
+        }
+    }
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")
+}
+
+fn generate_trampoline(
+    w: &mut dyn Write,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+    obj: &str,
+    vfunc: &Vfunc,
+) -> Result<()> {
+    let parameters = ffi_parameters(&vfunc.parameters);
+    let ret = vfunc
+        .ret
+        .as_ref()
+        .map(|ret| format!(" -> {}", ret.ffi_type))
+        .unwrap_or_default();
+    writeln!(
+        w,
+        "unsafe extern \"C\" fn {}_{}<T: {}>(this: {}{}){} {{",
+        obj, vfunc.name, subclass.impl_trait, vfunc.ffi_instance, parameters, ret
+    )?;
+    writeln!(w, "\tlet instance = &*(this as *mut T::Instance);")?;
+    writeln!(w, "\tlet imp = instance.imp();")?;
+    writeln!(
+        w,
+        "\timp.{}(from_glib_borrow::<_, {}>(this).unsafe_cast_ref(){}){}",
+        vfunc.name,
+        analysis.name,
+        arguments(&vfunc.parameters, |par| &par.from_glib),
+        vfunc.ret.as_ref().map_or("", |ret| ret.to_glib)
+    )?;
+    writeln!(w, "}}")
+}
+
+fn ffi_parameters(parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|par| format!(", {}: {}", par.name, par.ffi_type))
+        .collect()
+}
+
+/// Calls the parent `_async` function in a `GioFuture`, resolved by a
+/// trampoline calling the parent `_finish` function.
+fn generate_parent_future(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+    obj: &str,
+    vfunc: &AsyncVfunc,
+) -> Result<()> {
+    writeln!(w, "\t{} {{", async_signature(env, obj, vfunc, "parent_"))?;
+    writeln!(w, "\t\tunsafe {{")?;
+    let parent = parent_struct(w, analysis, subclass, "\t\t\t")?;
+    writeln!(
+        w,
+        "\t\t\tlet f = (*{}).{}{};",
+        parent,
+        vfunc.async_name,
+        panics::missing_parent_vfunc(env, &vfunc.async_name)
+    )?;
+    for par in vfunc.parameters.iter().filter(|par| par.by_ref) {
+        writeln!(w, "\t\t\tlet {0} = {0}.to_owned();", par.name)?;
+    }
+    writeln!(
+        w,
+        "\t\t\tBox_::pin({}::new({}, move |obj, {}cancellable, send| {{",
+        nameutil::use_gio_type(env, "GioFuture"),
+        obj,
+        if vfunc.cancellable { "" } else { "_" }
+    )?;
+    writeln!(
+        w,
+        "\t\t\t\tf(obj.unsafe_cast_ref::<{}>().to_glib_none().0{}{}, Some({}_parent_{}_ready::<T>), Box_::into_raw(Box_::new(send)) as *mut _);",
+        analysis.name,
+        arguments(&vfunc.parameters, |par| &par.to_glib),
+        if vfunc.cancellable {
+            ", cancellable.to_glib_none().0"
+        } else {
+            ""
+        },
+        obj,
+        vfunc.name
+    )?;
+    writeln!(w, "\t\t\t}}))")?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t}}")
+}
+
+/// Trampolines of the `_async` function, creating a `GTask` returning the
+/// result of the future, of the `_finish` one, propagating it, and of the
+/// callback of the parent `_async` function.
+fn generate_async_trampolines(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &analysis::object::Info,
+    subclass: &Subclass,
+    obj: &str,
+    vfunc: &AsyncVfunc,
+) -> Result<()> {
+    let gio_ffi = |name| nameutil::use_gio_type(env, &format!("ffi::{}", name));
+    let glib_ffi = |name| nameutil::use_glib_type(env, &format!("ffi::{}", name));
+    let gobject_ffi = |name| nameutil::use_glib_type(env, &format!("gobject_ffi::{}", name));
+    let error = nameutil::use_glib_type(env, "Error");
+
+    writeln!(
+        w,
+        "unsafe extern \"C\" fn {}_{}<T: {}>(this: {}{}{}, callback: {}, user_data: {}) {{",
+        obj,
+        vfunc.async_name,
+        subclass.impl_trait,
+        vfunc.ffi_instance,
+        ffi_parameters(&vfunc.parameters),
+        if vfunc.cancellable {
+            format!(", cancellable: *mut {}", gio_ffi("GCancellable"))
+        } else {
+            String::new()
+        },
+        gio_ffi("GAsyncReadyCallback"),
+        glib_ffi("gpointer")
+    )?;
+    writeln!(w, "\tlet instance = &*(this as *mut T::Instance);")?;
+    writeln!(w, "\tlet imp = instance.imp();")?;
+    writeln!(
+        w,
+        "\tlet future = imp.{}_future(from_glib_borrow::<_, {}>(this).unsafe_cast_ref(){});",
+        vfunc.name,
+        analysis.name,
+        arguments(&vfunc.parameters, |par| &par.from_glib)
+    )?;
+    writeln!(
+        w,
+        "\tlet task = {}(this as *mut _, {}, callback, user_data);",
+        gio_ffi("g_task_new"),
+        if vfunc.cancellable {
+            "cancellable"
+        } else {
+            "ptr::null_mut()"
+        }
+    )?;
+    writeln!(
+        w,
+        "\t{}::ref_thread_default().spawn_local(async move {{",
+        nameutil::use_glib_type(env, "MainContext")
+    )?;
+    writeln!(w, "\t\tmatch future.await {{")?;
+    match vfunc.ret {
+        AsyncReturn::None | AsyncReturn::Bool => {
+            writeln!(
+                w,
+                "\t\t\tOk(()) => {}(task, {}),",
+                gio_ffi("g_task_return_boolean"),
+                glib_ffi("GTRUE")
+            )?;
+        }
+        AsyncReturn::Value { ref typ, .. } => {
+            writeln!(w, "\t\t\tOk(value) => {{")?;
+            writeln!(
+                w,
+                "\t\t\t\tunsafe extern \"C\" fn drop_value(value: {}) {{",
+                glib_ffi("gpointer")
+            )?;
+            writeln!(
+                w,
+                "\t\t\t\t\tlet _ = Box_::from_raw(value as *mut {});",
+                typ
+            )?;
+            writeln!(w, "\t\t\t\t}}")?;
+            writeln!(
+                w,
+                "\t\t\t\t{}(task, Box_::into_raw(Box_::new(value)) as *mut _, Some(drop_value));",
+                gio_ffi("g_task_return_pointer")
+            )?;
+            writeln!(w, "\t\t\t}}")?;
+        }
+    }
+    writeln!(
+        w,
+        "\t\t\tErr(error) => {}(task, error.into_raw()),",
+        gio_ffi("g_task_return_error")
+    )?;
+    writeln!(w, "\t\t}}")?;
+    writeln!(w, "\t\t{}(task as *mut _);", gobject_ffi("g_object_unref"))?;
+    writeln!(w, "\t}});")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    let ret = match vfunc.ret {
+        AsyncReturn::None => String::new(),
+        AsyncReturn::Bool => format!(" -> {}", glib_ffi("gboolean")),
+        AsyncReturn::Value { ref ffi_type, .. } => format!(" -> {}", ffi_type),
+    };
+    writeln!(
+        w,
+        "unsafe extern \"C\" fn {}_{}<T: {}>(_this: {}, result: *mut {}, error: *mut *mut {}){} {{",
+        obj,
+        vfunc.finish_name,
+        subclass.impl_trait,
+        vfunc.ffi_instance,
+        gio_ffi("GAsyncResult"),
+        glib_ffi("GError"),
+        ret
+    )?;
+    match vfunc.ret {
+        AsyncReturn::None => writeln!(
+            w,
+            "\t{}(result as *mut _, error);",
+            gio_ffi("g_task_propagate_boolean")
+        )?,
+        AsyncReturn::Bool => writeln!(
+            w,
+            "\t{}(result as *mut _, error)",
+            gio_ffi("g_task_propagate_boolean")
+        )?,
+        AsyncReturn::Value { ref typ, .. } => {
+            writeln!(
+                w,
+                "\tlet value = {}(result as *mut _, error) as *mut {};",
+                gio_ffi("g_task_propagate_pointer"),
+                typ
+            )?;
+            writeln!(w, "\tif value.is_null() {{")?;
+            writeln!(w, "\t\treturn ptr::null_mut();")?;
+            writeln!(w, "\t}}")?;
+            writeln!(w, "\tBox_::from_raw(value).to_glib_full()")?;
+        }
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "unsafe extern \"C\" fn {}_parent_{}_ready<T: {}>(source_object: *mut {}, res: *mut {}, user_data: {}) {{",
+        obj,
+        vfunc.name,
+        subclass.impl_trait,
+        gobject_ffi("GObject"),
+        gio_ffi("GAsyncResult"),
+        glib_ffi("gpointer")
+    )?;
+    let parent = parent_struct(w, analysis, subclass, "\t")?;
+    writeln!(
+        w,
+        "\tlet f = (*{}).{}{};",
+        parent,
+        vfunc.finish_name,
+        panics::missing_parent_vfunc(env, &vfunc.finish_name)
+    )?;
+    writeln!(w, "\tlet mut error = ptr::null_mut();")?;
+    let value = match vfunc.ret {
+        AsyncReturn::None | AsyncReturn::Bool => {
+            writeln!(w, "\tf(source_object as *mut _, res, &mut error);")?;
+            "()"
+        }
+        AsyncReturn::Value { .. } => {
+            writeln!(
+                w,
+                "\tlet ret = f(source_object as *mut _, res, &mut error);"
+            )?;
+            "from_glib_full(ret)"
+        }
+    };
+    writeln!(
+        w,
+        "\tlet result = if error.is_null() {{ Ok({}) }} else {{ Err(from_glib_full(error)) }};",
+        value
+    )?;
+    writeln!(
+        w,
+        "\tlet send: Box_<{}<{}, {}>> = Box_::from_raw(user_data as *mut _);",
+        nameutil::use_gio_type(env, "GioFutureResult"),
+        vfunc.ret.typ(),
+        error
+    )?;
+    writeln!(w, "\tsend.resolve(result);")?;
+    writeln!(w, "}}")
+}
@@ -14,7 +14,7 @@ pub fn generate(env: &Env) {
 
     if !split_build_rs || !path.exists() {
         info!("Generating file {:?}", path);
-        save_to_file(&path, env.config.make_backup, |w| {
+        save_to_file(&path, env, |w| {
             generate_build_script(w, env, split_build_rs)
         });
     }
@@ -22,9 +22,7 @@ pub fn generate(env: &Env) {
     if split_build_rs {
         let path = env.config.target_path.join("build_version.rs");
         info!("Generating file {:?}", path);
-        save_to_file(&path, env.config.make_backup, |w| {
-            generate_build_version(w, env)
-        });
+        save_to_file(&path, env, |w| generate_build_version(w, env));
     }
 }
 
@@ -45,22 +43,34 @@ use std::process;"##
         writeln!(w)?;
         writeln!(w, "mod build_version;")?;
     }
+    let (dox_check, check) = if env.config.generate_stale_check {
+        if !split_build_rs {
+            writeln!(w)?;
+        }
+        writeln!(w, "mod build_stale_check;")?;
+        (
+            "\n    build_stale_check::check();\n",
+            "    build_stale_check::check();\n",
+        )
+    } else {
+        ("", "")
+    };
 
     write!(
         w,
-        "{}",
         r##"
 #[cfg(feature = "dox")]
-fn main() {} // prevent linking libraries to avoid documentation failure
+fn main() {{{}}} // prevent linking libraries to avoid documentation failure
 
 #[cfg(not(feature = "dox"))]
-fn main() {
-    if let Err(s) = system_deps::Config::new().probe() {
-        println!("cargo:warning={}", s);
+fn main() {{
+{}    if let Err(s) = system_deps::Config::new().probe() {{
+        println!("cargo:warning={{}}", s);
         process::exit(1);
-    }
-}
-"##
+    }}
+}}
+"##,
+        dox_check, check
     )
 }
 
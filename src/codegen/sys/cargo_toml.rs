@@ -22,7 +22,7 @@ pub fn generate(env: &Env) -> String {
     }
     fill_in(&mut root_table, env);
 
-    save_to_file(&path, env.config.make_backup, |w| {
+    save_to_file(&path, env, |w| {
         w.write_all(toml::to_string(&root_table).unwrap().as_bytes())
     });
 
@@ -67,7 +67,16 @@ fn fill_in(root: &mut Table, env: &Env) {
 
     {
         let deps = upsert_table(root, "dependencies");
-        set_string(deps, "libc", "0.2");
+        if env.config.sys_no_std {
+            if !matches!(deps.get("libc"), Some(Value::Table(_))) {
+                unset(deps, "libc");
+            }
+            let libc = upsert_table(deps, "libc");
+            set_string(libc, "version", "0.2");
+            libc.insert("default-features".into(), Value::Boolean(false));
+        } else {
+            set_string(deps, "libc", "0.2");
+        }
     }
 
     {
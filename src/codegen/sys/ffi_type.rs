@@ -1,7 +1,7 @@
 use crate::{
     analysis::{
-        c_type::{implements_c_type, rustify_pointers},
-        namespaces,
+        c_type::{implements_c_type, rustify_pointers, untyped_instance_pointer},
+        is_gconstpointer, namespaces,
         rust_type::{Result, TypeError},
     },
     env::Env,
@@ -41,10 +41,14 @@ pub fn ffi_type(env: &Env, tid: library::TypeId, c_type: &str) -> Result {
                     | Type::Interface(Interface {
                         c_type: ref expected,
                         ..
-                    }) if c_type == "gpointer" => {
-                        info!("[c:type `gpointer` instead of `*mut {}`, fixing]", expected);
+                    }) if c_type == "gpointer" || is_gconstpointer(c_type) => {
+                        let ptr = untyped_instance_pointer(env, c_type, expected);
+                        info!(
+                            "[c:type `{}` instead of `{} {}`, fixing]",
+                            c_type, ptr, expected
+                        );
                         ffi_inner(env, tid, expected.clone()).map_any(|rust_type| {
-                            rust_type.alter_type(|typ_| format!("*mut {}", typ_))
+                            rust_type.alter_type(|typ_| format!("{} {}", ptr, typ_))
                         })
                     }
                     _ => ffi_inner(env, c_tid, c_type.into()),
@@ -153,9 +157,10 @@ fn ffi_inner(env: &Env, tid: library::TypeId, mut inner: String) -> Result {
         _ => {
             if let Some(glib_name) = env.library.type_(tid).get_glib_name() {
                 if inner != glib_name {
-                    if inner == "gpointer" {
+                    if inner == "gpointer" || is_gconstpointer(&inner) {
+                        let ptr = untyped_instance_pointer(env, &inner, glib_name);
                         fix_name(env, tid, glib_name).map_any(|rust_type| {
-                            rust_type.alter_type(|typ_| format!("*mut {}", typ_))
+                            rust_type.alter_type(|typ_| format!("{} {}", ptr, typ_))
                         })
                     } else if implements_c_type(env, tid, &inner) {
                         info!(
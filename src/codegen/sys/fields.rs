@@ -3,7 +3,7 @@ use crate::{
     codegen::sys::{ffi_type::ffi_type, functions::function_signature},
     env::Env,
     library::*,
-    traits::{IntoString, MaybeRefAs},
+    traits::{IntoString, MapAny, MaybeRefAs},
 };
 
 pub struct Fields {
@@ -30,6 +30,8 @@ pub struct FieldInfo {
     unsafe_access: bool,
     /// Include this field in Debug impl?
     pub debug: bool,
+    /// Is this a C99 flexible array member, like `guint8 data[]`?
+    pub flexible_array: bool,
 }
 
 impl Fields {
@@ -106,7 +108,7 @@ fn analyze_fields(
     let mut infos = Vec::with_capacity(fields.len());
 
     let mut is_bitfield = false;
-    for field in fields {
+    for (i, field) in fields.iter().enumerate() {
         // See IsIncomplete for &[Field].
         if is_bitfield && field.bits.is_some() {
             truncated = Some(format!("field {} has incomplete type", &field.name));
@@ -114,7 +116,13 @@ fn analyze_fields(
         }
         is_bitfield = field.bits.is_some();
 
-        let typ = match field_ffi_type(env, field) {
+        let flexible_array = i + 1 == fields.len() && is_flexible_array(env, field);
+        let typ = if flexible_array {
+            flexible_array_ffi_type(env, field)
+        } else {
+            field_ffi_type(env, field)
+        };
+        let typ = match typ {
             e @ Err(..) => {
                 truncated = Some(e.into_string());
                 break;
@@ -124,13 +132,18 @@ fn analyze_fields(
         // Skip private fields from Debug impl. Ignore volatile as well,
         // they are usually used as synchronization primites,
         // so we wouldn't want to introduce additional reads.
-        let debug = !field.private && !field.is_volatile() && field.implements_debug(&env.library);
+        // Flexible arrays have no elements of their own to show.
+        let debug = !field.private
+            && !field.is_volatile()
+            && !flexible_array
+            && field.implements_debug(&env.library);
 
         infos.push(FieldInfo {
             name: field.name.clone(),
             typ: typ.into_string(),
             debug,
             unsafe_access,
+            flexible_array,
         });
     }
 
@@ -162,6 +175,28 @@ fn field_ffi_type(env: &Env, field: &Field) -> Result {
     }
 }
 
+/// A C array with neither a pointer nor a size, which can only be the last
+/// field of a struct.
+fn is_flexible_array(env: &Env, field: &Field) -> bool {
+    matches!(env.library.type_(field.typ), Type::CArray(..))
+        && field
+            .c_type
+            .as_ref()
+            .map_or(false, |c_type| !c_type.contains('*'))
+}
+
+/// Flexible arrays are zero-length arrays, taking no room in the struct but
+/// aligning it like C does.
+fn flexible_array_ffi_type(env: &Env, field: &Field) -> Result {
+    let inner_tid = match env.library.type_(field.typ) {
+        Type::CArray(inner_tid) => *inner_tid,
+        _ => unreachable!(),
+    };
+    let c_type = field.c_type.as_ref().unwrap();
+    ffi_type(env, inner_tid, c_type)
+        .map_any(|rust_type| rust_type.alter_type(|typ_| format!("[{}; 0]", typ_)))
+}
+
 fn get_gobject_cfg_condition(env: &Env, name: &str) -> Option<String> {
     let full_name = format!("{}.{}", env.namespaces.main().name, name);
     if let Some(obj) = env.config.objects.get(&full_name) {
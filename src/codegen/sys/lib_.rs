@@ -1,4 +1,4 @@
-use super::{ffi_type::ffi_type, fields, functions, statics};
+use super::{ffi_type::ffi_type, fields, functions, statics, EnumRepr};
 use crate::{
     codegen::general::{self, cfg_condition, version_condition},
     config::constants,
@@ -20,7 +20,7 @@ pub fn generate(env: &Env) {
     let path = env.config.auto_path.join(file_name_sys("lib"));
 
     info!("Generating file {:?}", path);
-    save_to_file(&path, env.config.make_backup, |w| generate_lib(w, env));
+    save_to_file(&path, env, |w| generate_lib(w, env));
 }
 
 fn write_link_attr(w: &mut dyn Write, shared_libs: &[String]) -> Result<()> {
@@ -37,7 +37,7 @@ fn write_link_attr(w: &mut dyn Write, shared_libs: &[String]) -> Result<()> {
 
 fn generate_lib(w: &mut dyn Write, env: &Env) -> Result<()> {
     general::start_comments(w, &env.config)?;
-    statics::begin(w)?;
+    statics::begin(w, env.config.sys_no_std)?;
 
     include_custom_modules(w, env)?;
     statics::after_extern_crates(w)?;
@@ -48,7 +48,7 @@ fn generate_lib(w: &mut dyn Write, env: &Env) -> Result<()> {
     match &*env.config.library_name {
         "GLib" => statics::only_for_glib(w)?,
         "GObject" => statics::only_for_gobject(w)?,
-        "Gtk" => statics::only_for_gtk(w)?,
+        "Gtk" => statics::only_for_gtk(w, std_crate(env))?,
         _ => (),
     }
     writeln!(w)?;
@@ -104,6 +104,10 @@ fn include_custom_modules(w: &mut dyn Write, env: &Env) -> Result<()> {
 
 fn find_modules(env: &Env) -> Result<Vec<String>> {
     let mut vec = Vec::<String>::new();
+    // Not created yet when the files are kept in memory
+    if !env.config.auto_path.is_dir() {
+        return Ok(vec);
+    }
     for entry in fs::read_dir(&env.config.auto_path)? {
         let path = entry?.path();
         let ext = match path.extension() {
@@ -277,7 +281,15 @@ fn generate_enums(w: &mut dyn Write, env: &Env, items: &[&Enumeration]) -> Resul
         if let Some(false) = config.map(|c| c.status.need_generate()) {
             continue;
         }
-        writeln!(w, "pub type {} = c_int;", item.c_type)?;
+        let repr = EnumRepr::of(item);
+        if repr == EnumRepr::Int {
+            writeln!(w, "pub type {} = c_int;", item.c_type)?;
+        } else {
+            writeln!(w, "#[cfg(target_env = \"msvc\")]")?;
+            writeln!(w, "pub type {} = c_int;", item.c_type)?;
+            writeln!(w, "#[cfg(not(target_env = \"msvc\"))]")?;
+            writeln!(w, "pub type {} = {};", item.c_type, repr.ffi_type())?;
+        }
         for member in &item.members {
             let member_config = config
                 .as_ref()
@@ -294,11 +306,20 @@ fn generate_enums(w: &mut dyn Write, env: &Env, items: &[&Enumeration]) -> Resul
             }
 
             version_condition(w, env, None, version, false, 0)?;
-            writeln!(
-                w,
-                "pub const {}: {} = {};",
-                member.c_identifier, item.c_type, member.value,
-            )?;
+            if repr == EnumRepr::Int {
+                writeln!(
+                    w,
+                    "pub const {}: {} = {};",
+                    member.c_identifier, item.c_type, member.value,
+                )?;
+            } else {
+                // Out of `c_int` range values wrap around with MSVC
+                writeln!(
+                    w,
+                    "pub const {}: {} = {}_i64 as {};",
+                    member.c_identifier, item.c_type, member.value, item.c_type,
+                )?;
+            }
         }
         writeln!(w)?;
     }
@@ -323,20 +344,31 @@ fn generate_unions(w: &mut dyn Write, env: &Env, unions: &[&Union]) -> Result<()
 
         let align = config.and_then(|c| c.align);
         let fields = fields::from_union(env, union);
-        generate_from_fields(w, &fields, align)?;
+        generate_from_fields(w, env, &fields, align)?;
     }
     Ok(())
 }
 
-fn generate_debug_impl(w: &mut dyn Write, name: &str, impl_content: &str) -> Result<()> {
+/// Crate providing `fmt` and friends to the generated code
+fn std_crate(env: &Env) -> &'static str {
+    if env.config.sys_no_std {
+        "core"
+    } else {
+        "std"
+    }
+}
+
+fn generate_debug_impl(w: &mut dyn Write, env: &Env, name: &str, impl_content: &str) -> Result<()> {
     writeln!(
         w,
-        "impl ::std::fmt::Debug for {} {{\n\
-         \tfn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{\n\
+        "impl ::{std}::fmt::Debug for {} {{\n\
+         \tfn fmt(&self, f: &mut ::{std}::fmt::Formatter) -> ::{std}::fmt::Result {{\n\
          \t\t{}\n\
          \t}}\n\
          }}\n",
-        name, impl_content
+        name,
+        impl_content,
+        std = std_crate(env)
     )
 }
 
@@ -354,7 +386,7 @@ fn generate_classes_structs(w: &mut dyn Write, env: &Env, classes: &[&Class]) ->
 
         let align = config.and_then(|c| c.align);
         let fields = fields::from_class(env, class);
-        generate_from_fields(w, &fields, align)?;
+        generate_from_fields(w, env, &fields, align)?;
     }
     Ok(())
 }
@@ -388,6 +420,7 @@ fn generate_interfaces_structs(
         generate_opaque_type(w, &interface.c_type)?;
         generate_debug_impl(
             w,
+            env,
             &interface.c_type,
             &format!(
                 "write!(f, \"{name} @ {{:p}}\", self)",
@@ -421,21 +454,20 @@ fn generate_records(w: &mut dyn Write, env: &Env, records: &[&Record]) -> Result
             // 4. ...
             // 5. Thus, we use custom generated GHookList.
             //    Hopefully someone will profit from all this.
-            generate_ghooklist(w)?;
+            generate_ghooklist(w, env)?;
         } else if record.disguised {
             generate_disguised(w, record)?;
         } else {
             let align = config.and_then(|c| c.align);
             let fields = fields::from_record(env, record);
-            generate_from_fields(w, &fields, align)?;
+            generate_from_fields(w, env, &fields, align)?;
         }
     }
     Ok(())
 }
 
-fn generate_ghooklist(w: &mut dyn Write) -> Result<()> {
-    w.write_all(
-        br#"#[repr(C)]
+fn generate_ghooklist(w: &mut dyn Write, env: &Env) -> Result<()> {
+    let ghooklist = r#"#[repr(C)]
 #[derive(Copy, Clone)]
 pub struct GHookList {
     pub seq_id: c_ulong,
@@ -455,7 +487,11 @@ impl ::std::fmt::Debug for GHookList {
     }
 }
 
-"#,
+"#;
+    w.write_all(
+        ghooklist
+            .replace("::std::", &format!("::{}::", std_crate(env)))
+            .as_bytes(),
     )
 }
 
@@ -467,6 +503,7 @@ fn generate_disguised(w: &mut dyn Write, record: &Record) -> Result<()> {
 
 fn generate_from_fields(
     w: &mut dyn Write,
+    env: &Env,
     fields: &fields::Fields,
     align: Option<u32>,
 ) -> Result<()> {
@@ -492,6 +529,12 @@ fn generate_from_fields(
             name = &fields.name
         )?;
         for field in &fields.fields {
+            if field.flexible_array {
+                writeln!(
+                    w,
+                    "\t/// Flexible array member, its elements follow the struct in memory"
+                )?;
+            }
             writeln!(
                 w,
                 "\tpub {field_name}: {field_type},",
@@ -509,18 +552,25 @@ fn generate_from_fields(
     cfg_condition(w, fields.cfg_condition.as_ref(), false, 0)?;
     writeln!(
         w,
-        "impl ::std::fmt::Debug for {name} {{",
+        "impl ::{std}::fmt::Debug for {name} {{",
+        std = std_crate(env),
         name = &fields.name
     )?;
     writeln!(
         w,
-        "\tfn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {{"
-    )?;
-    writeln!(
-        w,
-        "\t\tf.debug_struct(&format!(\"{name} @ {{:p}}\", self))",
-        name = &fields.name
+        "\tfn fmt(&self, f: &mut ::{std}::fmt::Formatter) -> ::{std}::fmt::Result {{",
+        std = std_crate(env)
     )?;
+    if env.config.sys_no_std {
+        // `format!` needs an allocator
+        writeln!(w, "\t\tf.debug_struct(\"{name}\")", name = &fields.name)?;
+    } else {
+        writeln!(
+            w,
+            "\t\tf.debug_struct(&format!(\"{name} @ {{:p}}\", self))",
+            name = &fields.name
+        )?;
+    }
     for field in fields.fields.iter().filter(|f| f.debug) {
         // TODO: We should generate debug for field manually if automatic one is not available.
         writeln!(
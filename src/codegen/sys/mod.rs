@@ -1,11 +1,16 @@
-use crate::{codegen::generate_single_version_file, env::Env, version::Version};
+use crate::{
+    codegen::{generate_single_version_file, stale_check},
+    env::Env,
+    library::Enumeration,
+    version::Version,
+};
 use std::collections::BTreeMap;
 
 mod build;
 mod cargo_toml;
 pub mod ffi_type;
 mod fields;
-mod functions;
+pub mod functions;
 mod lib_;
 mod statics;
 mod tests;
@@ -14,6 +19,7 @@ pub fn generate(env: &Env) {
     generate_single_version_file(env);
     lib_::generate(env);
     build::generate(env);
+    stale_check::generate(env);
     let crate_name = cargo_toml::generate(env);
     tests::generate(env, &crate_name);
 }
@@ -38,3 +44,60 @@ pub fn collect_versions(env: &Env) -> BTreeMap<Version, Version> {
 
     versions
 }
+
+/// Integer type picked by GCC-like compilers for a C enumeration.
+///
+/// MSVC always uses `int`, whatever the values are.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EnumRepr {
+    Int,
+    UInt,
+    Int64,
+}
+
+impl EnumRepr {
+    pub fn of(enum_: &Enumeration) -> Self {
+        Self::of_values(
+            enum_
+                .members
+                .iter()
+                .filter_map(|m| m.value.parse::<i64>().ok()),
+        )
+    }
+
+    fn of_values(values: impl Iterator<Item = i64>) -> Self {
+        let (min, max) = values.fold((0, 0), |(min, max), v| (min.min(v), max.max(v)));
+        if min >= i64::from(i32::MIN) && max <= i64::from(i32::MAX) {
+            EnumRepr::Int
+        } else if min >= 0 && max <= i64::from(u32::MAX) {
+            EnumRepr::UInt
+        } else {
+            EnumRepr::Int64
+        }
+    }
+
+    pub fn ffi_type(self) -> &'static str {
+        match self {
+            EnumRepr::Int => "c_int",
+            EnumRepr::UInt => "c_uint",
+            EnumRepr::Int64 => "i64",
+        }
+    }
+
+    /// Rust integer of the same size and signedness as `ffi_type()`
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            EnumRepr::Int => "i32",
+            EnumRepr::UInt => "u32",
+            EnumRepr::Int64 => "i64",
+        }
+    }
+
+    pub fn c_type(self) -> &'static str {
+        match self {
+            EnumRepr::Int => "gint",
+            EnumRepr::UInt => "guint",
+            EnumRepr::Int64 => "gint64",
+        }
+    }
+}
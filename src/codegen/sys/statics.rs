@@ -1,14 +1,17 @@
 use super::super::general::write_vec;
 use std::io::{Result, Write};
 
-pub fn begin(w: &mut dyn Write) -> Result<()> {
-    let v = vec![
-        "",
+pub fn begin(w: &mut dyn Write, no_std: bool) -> Result<()> {
+    let mut v = vec![""];
+    if no_std {
+        v.push("#![no_std]");
+    }
+    v.extend_from_slice(&[
         "#![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]",
         "#![allow(clippy::approx_constant, clippy::type_complexity, clippy::unreadable_literal, clippy::upper_case_acronyms)]",
         "#![cfg_attr(feature = \"dox\", feature(doc_cfg))]",
         "",
-    ];
+    ]);
 
     write_vec(w, &v)
 }
@@ -80,10 +83,13 @@ pub fn only_for_gobject(w: &mut dyn Write) -> Result<()> {
     write_vec(w, &v)
 }
 
-pub fn only_for_gtk(w: &mut dyn Write) -> Result<()> {
+pub fn only_for_gtk(w: &mut dyn Write, std_crate: &str) -> Result<()> {
     let v = vec![
-        "",
-        "pub const GTK_ENTRY_BUFFER_MAX_SIZE: u16 = ::std::u16::MAX;",
+        "".to_owned(),
+        format!(
+            "pub const GTK_ENTRY_BUFFER_MAX_SIZE: u16 = ::{}::u16::MAX;",
+            std_crate
+        ),
     ];
 
     write_vec(w, &v)
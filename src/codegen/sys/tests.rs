@@ -1,12 +1,14 @@
+use super::EnumRepr;
 use crate::{
     analysis::types::IsIncomplete,
     codegen::general,
     env::Env,
     file_saver::save_to_file,
-    library::{self, Bitfield, Enumeration, Namespace, Type, MAIN_NAMESPACE},
+    library::{self, Bitfield, Namespace, Type, MAIN_NAMESPACE},
 };
 use log::info;
 use std::{
+    collections::{HashMap, HashSet},
     io::{self, prelude::*},
     path::Path,
 };
@@ -15,6 +17,9 @@ use std::{
 struct CType {
     /// Name of type, as used in C.
     name: String,
+    /// Type measured in C, differs from `name` for the nested unions and
+    /// records which only exist on the Rust side.
+    c_expr: String,
     /// Expression describing when type is available (when defined only conditionally).
     cfg_condition: Option<String>,
 }
@@ -39,44 +44,44 @@ pub fn generate(env: &Env, crate_name: &str) {
 
     let manual_h = tests.join("manual.h");
     if !manual_h.exists() {
-        save_to_file(&manual_h, env.config.make_backup, |w| {
-            generate_manual_h(env, &manual_h, w)
-        });
+        save_to_file(&manual_h, env, |w| generate_manual_h(env, &manual_h, w));
     }
 
     let layout_c = tests.join("layout.c");
-    save_to_file(&layout_c, env.config.make_backup, |w| {
+    save_to_file(&layout_c, env, |w| {
         generate_layout_c(env, &layout_c, w, &ctypes)
     });
 
     let constant_c = tests.join("constant.c");
-    save_to_file(&constant_c, env.config.make_backup, |w| {
+    save_to_file(&constant_c, env, |w| {
         generate_constant_c(env, &constant_c, w, &cconsts)
     });
 
     let abi_rs = tests.join("abi.rs");
-    save_to_file(&abi_rs, env.config.make_backup, |w| {
+    save_to_file(&abi_rs, env, |w| {
         generate_abi_rs(env, &abi_rs, w, crate_name, &ctypes, &cconsts)
     });
 }
 
 fn prepare_ctypes(env: &Env) -> Vec<CType> {
     let ns = env.library.namespace(MAIN_NAMESPACE);
+    let nested = nested_fields(ns);
     let mut types: Vec<CType> = ns
         .types
         .iter()
-        .filter_map(Option::as_ref)
-        .filter(|t| !t.is_incomplete(&env.library))
-        .filter_map(|t| match t {
+        .enumerate()
+        .filter_map(|(id, t)| Some((nested.get(&(id as u32)), t.as_ref()?)))
+        .filter(|(_, t)| !t.is_incomplete(&env.library))
+        .filter_map(|(nested, t)| match t {
             Type::Record(library::Record {
                 disguised: false, ..
-            }) => prepare_ctype(env, ns, t),
+            }) => prepare_ctype(env, ns, t, nested),
             Type::Alias(_)
             | Type::Class(_)
             | Type::Union(_)
             | Type::Enumeration(_)
             | Type::Bitfield(_)
-            | Type::Interface(_) => prepare_ctype(env, ns, t),
+            | Type::Interface(_) => prepare_ctype(env, ns, t, nested),
             _ => None,
         })
         .collect();
@@ -85,16 +90,80 @@ fn prepare_ctypes(env: &Env) -> Vec<CType> {
     types
 }
 
-fn prepare_ctype(env: &Env, ns: &Namespace, t: &Type) -> Option<CType> {
+/// Returns the C expressions of the fields holding the unions and records
+/// nested in other types, by type id.
+///
+/// These types have no name in C, they are measured through a field. The
+/// members of anonymous unions and structs are reached as members of their
+/// parent, but the anonymous types themselves can't be measured at all.
+fn nested_fields(ns: &Namespace) -> HashMap<u32, Option<String>> {
+    let nested: HashSet<u32> = ns
+        .index
+        .iter()
+        .filter(|(name, _)| name.starts_with('#'))
+        .map(|(_, &id)| id)
+        .collect();
+
+    let mut parents = HashMap::new();
+    for (id, t) in ns.types.iter().enumerate() {
+        let fields = match t {
+            Some(Type::Class(library::Class { fields, .. }))
+            | Some(Type::Record(library::Record { fields, .. }))
+            | Some(Type::Union(library::Union { fields, .. })) => fields,
+            _ => continue,
+        };
+        for field in fields
+            .iter()
+            .filter(|f| f.typ.ns_id == MAIN_NAMESPACE && nested.contains(&f.typ.id))
+        {
+            parents.insert(field.typ.id, (id as u32, field));
+        }
+    }
+
+    nested
+        .iter()
+        .map(|&id| {
+            let mut path = Vec::new();
+            let mut current = id;
+            while nested.contains(&current) {
+                match parents.get(&current) {
+                    Some(&(_, field)) if field.anonymous && current == id => return (id, None),
+                    Some(&(parent, field)) => {
+                        if !field.anonymous {
+                            path.push(field.name.as_str());
+                        }
+                        current = parent;
+                    }
+                    None => return (id, None),
+                }
+            }
+            path.reverse();
+            let expr = ns.types[current as usize]
+                .as_ref()
+                .and_then(Type::get_glib_name)
+                .map(|root| format!("(({}*)0)->{}", root, path.join(".")));
+            (id, expr)
+        })
+        .collect()
+}
+
+fn prepare_ctype(
+    env: &Env,
+    ns: &Namespace,
+    t: &Type,
+    nested: Option<&Option<String>>,
+) -> Option<CType> {
     let full_name = format!("{}.{}", ns.name, t.get_name());
     if env.type_status_sys(&full_name).ignored() {
         return None;
     }
     let name = t.get_glib_name()?;
 
-    if is_name_made_up(name) {
-        return None;
-    }
+    let c_expr = match nested {
+        Some(field) => format!("__typeof__({})", field.as_ref()?),
+        None if is_name_made_up(name) => return None,
+        None => name.to_owned(),
+    };
     let cfg_condition = env
         .config
         .objects
@@ -102,6 +171,7 @@ fn prepare_ctype(env: &Env, ns: &Namespace, t: &Type) -> Option<CType> {
         .and_then(|obj| obj.cfg_condition.clone());
     Some(CType {
         name: name.to_owned(),
+        c_expr,
         cfg_condition,
     })
 }
@@ -149,12 +219,14 @@ fn prepare_cconsts(env: &Env) -> Vec<CConstant> {
                     });
                 }
             }
-            Type::Enumeration(Enumeration { members, .. }) => {
-                for member in members {
-                    // GLib assumes that enums are signed integers,
-                    // see the GValue machinery around them for example
+            Type::Enumeration(enum_) => {
+                // GLib assumes that enums are signed integers, see the GValue
+                // machinery around them for example, but compilers may pick
+                // a wider type for out of range values
+                let repr = EnumRepr::of(enum_);
+                for member in &enum_.members {
                     constants.push(CConstant {
-                        name: format!("(gint) {}", member.c_identifier),
+                        name: format!("({}) {}", repr.c_type(), member.c_identifier),
                         value: member.value.clone(),
                     });
                 }
@@ -169,6 +241,8 @@ fn prepare_cconsts(env: &Env) -> Vec<CConstant> {
                 &x.name[7..]
             } else if x.name.starts_with("(guint) ") {
                 &x.name[8..]
+            } else if x.name.starts_with("(gint64) ") {
+                &x.name[9..]
             } else {
                 x.name.as_str()
             }
@@ -220,8 +294,9 @@ fn generate_layout_c(
     for ctype in ctypes {
         writeln!(
             w,
-            "    printf(\"%s;%zu;%zu\\n\", \"{ctype}\", sizeof({ctype}), alignof({ctype}));",
-            ctype = ctype.name
+            "    printf(\"%s;%zu;%zu\\n\", \"{name}\", sizeof({ctype}), alignof({ctype}));",
+            name = ctype.name,
+            ctype = ctype.c_expr
         )?;
     }
 
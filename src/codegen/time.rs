@@ -0,0 +1,206 @@
+use crate::{
+    codegen::{general, panics},
+    config::TimeMapping,
+    env::Env,
+    file_saver,
+    nameutil::use_glib_if_needed,
+};
+use log::info;
+use std::path::Path;
+
+/// Generates `time.rs` with the helpers converting `std::time::SystemTime`
+/// from and into `GDateTime` and `GTimeVal`, when `options.time_mapping`
+/// is `"std"`.
+pub fn generate(env: &Env, root_path: &Path, mod_rs: &mut Vec<String>) {
+    if env.config.time_mapping != TimeMapping::Std {
+        return;
+    }
+
+    info!("Generate time helpers");
+
+    let path = root_path.join("time.rs");
+    let ffi = use_glib_if_needed(env, "ffi");
+
+    file_saver::save_to_file(path, env, |w| {
+        general::start_helpers_module(w, env, "time", mod_rs)?;
+        writeln!(w)?;
+        writeln!(w, "use std::time::{{Duration, SystemTime, UNIX_EPOCH}};")?;
+
+        writeln!(w)?;
+        writeln!(w, "fn unix_usec(time: SystemTime) -> i64 {{")?;
+        writeln!(w, "\tmatch time.duration_since(UNIX_EPOCH) {{")?;
+        writeln!(w, "\t\tOk(since) => since.as_micros() as i64,")?;
+        writeln!(w, "\t\tErr(err) => -(err.duration().as_micros() as i64),")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(w, "fn from_unix_usec(usec: i64) -> SystemTime {{")?;
+        writeln!(
+            w,
+            "\tlet duration = Duration::from_micros(usec.unsigned_abs());"
+        )?;
+        writeln!(w, "\tif usec < 0 {{")?;
+        writeln!(w, "\t\tUNIX_EPOCH - duration")?;
+        writeln!(w, "\t}} else {{")?;
+        writeln!(w, "\t\tUNIX_EPOCH + duration")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Reference on a date time, released once the call is done"
+        )?;
+        writeln!(
+            w,
+            "pub(crate) struct DateTimeStash(pub *mut {}::GDateTime);",
+            ffi
+        )?;
+        writeln!(w)?;
+        writeln!(w, "impl Drop for DateTimeStash {{")?;
+        writeln!(w, "\tfn drop(&mut self) {{")?;
+        writeln!(w, "\t\tif !self.0.is_null() {{")?;
+        writeln!(w, "\t\t\tunsafe {{ {}::g_date_time_unref(self.0) }}", ffi)?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) fn date_time_to_glib_none(time: Option<SystemTime>) -> DateTimeStash {{"
+        )?;
+        writeln!(w, "\tDateTimeStash(date_time_to_glib_full(time))")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Panics if the time is out of the range of `GDateTime`, the years 1 to 9999"
+        )?;
+        writeln!(
+            w,
+            "pub(crate) fn date_time_to_glib_full(time: Option<SystemTime>) -> *mut {}::GDateTime {{",
+            ffi
+        )?;
+        writeln!(w, "\tlet usec = match time {{")?;
+        writeln!(w, "\t\tSome(time) => unix_usec(time),")?;
+        writeln!(w, "\t\tNone => return std::ptr::null_mut(),")?;
+        writeln!(w, "\t}};")?;
+        writeln!(w, "\tunsafe {{")?;
+        writeln!(
+            w,
+            "\t\tlet seconds = std::ptr::NonNull::new({}::g_date_time_new_from_unix_utc(usec.div_euclid(1_000_000))){}.as_ptr();",
+            ffi,
+            panics::returned_null(env, "g_date_time_new_from_unix_utc")
+        )?;
+        writeln!(
+            w,
+            "\t\tlet date_time = std::ptr::NonNull::new({}::g_date_time_add(seconds, usec.rem_euclid(1_000_000)));",
+            ffi
+        )?;
+        writeln!(w, "\t\t{}::g_date_time_unref(seconds);", ffi)?;
+        writeln!(
+            w,
+            "\t\tdate_time{}.as_ptr()",
+            panics::returned_null(env, "g_date_time_add")
+        )?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(w, "pub(crate) trait DateTimeFromGlib {{")?;
+        writeln!(w, "\tfn from_unix_usec(usec: Option<i64>) -> Self;")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(w, "impl DateTimeFromGlib for SystemTime {{")?;
+        writeln!(w, "\tfn from_unix_usec(usec: Option<i64>) -> Self {{")?;
+        writeln!(
+            w,
+            "\t\tfrom_unix_usec(usec{})",
+            panics::mandatory_none_option(env)
+        )?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+        writeln!(w, "impl DateTimeFromGlib for Option<SystemTime> {{")?;
+        writeln!(w, "\tfn from_unix_usec(usec: Option<i64>) -> Self {{")?;
+        writeln!(w, "\t\tusec.map(from_unix_usec)")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) unsafe fn date_time_from_glib_none<T: DateTimeFromGlib>(\
+             ptr: *mut {}::GDateTime) -> T {{",
+            ffi
+        )?;
+        writeln!(w, "\tif ptr.is_null() {{")?;
+        writeln!(w, "\t\treturn T::from_unix_usec(None);")?;
+        writeln!(w, "\t}}")?;
+        writeln!(
+            w,
+            "\tlet usec = {0}::g_date_time_to_unix(ptr) * 1_000_000 \
+             + {0}::g_date_time_get_microsecond(ptr) as i64;",
+            ffi
+        )?;
+        writeln!(w, "\tT::from_unix_usec(Some(usec))")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) unsafe fn date_time_from_glib_full<T: DateTimeFromGlib>(\
+             ptr: *mut {}::GDateTime) -> T {{",
+            ffi
+        )?;
+        writeln!(w, "\tlet time = date_time_from_glib_none(ptr);")?;
+        writeln!(w, "\tif !ptr.is_null() {{")?;
+        writeln!(w, "\t\t{}::g_date_time_unref(ptr);", ffi)?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "\ttime")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "/// Time value filled in for the call, it only lives as long as the stash"
+        )?;
+        writeln!(
+            w,
+            "pub(crate) struct TimeValStash(Option<{}::GTimeVal>);",
+            ffi
+        )?;
+        writeln!(w)?;
+        writeln!(w, "impl TimeValStash {{")?;
+        writeln!(
+            w,
+            "\tpub(crate) fn as_mut_ptr(&mut self) -> *mut {}::GTimeVal {{",
+            ffi
+        )?;
+        writeln!(w, "\t\tmatch self.0 {{")?;
+        writeln!(w, "\t\t\tSome(ref mut time_val) => time_val,")?;
+        writeln!(w, "\t\t\tNone => std::ptr::null_mut(),")?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t}}")?;
+        writeln!(w, "}}")?;
+
+        writeln!(w)?;
+        writeln!(
+            w,
+            "pub(crate) fn time_val_to_glib(time: Option<SystemTime>) -> TimeValStash {{"
+        )?;
+        writeln!(w, "\tTimeValStash(time.map(|time| {{")?;
+        writeln!(w, "\t\tlet usec = unix_usec(time);")?;
+        writeln!(w, "\t\t{}::GTimeVal {{", ffi)?;
+        writeln!(w, "\t\t\ttv_sec: usec.div_euclid(1_000_000) as _,")?;
+        writeln!(w, "\t\t\ttv_usec: usec.rem_euclid(1_000_000) as _,")?;
+        writeln!(w, "\t\t}}")?;
+        writeln!(w, "\t}}))")?;
+        writeln!(w, "}}")?;
+
+        Ok(())
+    });
+}
@@ -1,16 +1,18 @@
 use super::{
-    return_value::ToReturnValue, trampoline_from_glib::TrampolineFromGlib,
+    return_value::ToReturnValue,
+    trampoline_from_glib::{from_glib_xxx, is_string_array, TrampolineFromGlib},
     trampoline_to_glib::TrampolineToGlib,
 };
 use crate::{
     analysis::{
-        bounds::Bounds, ffi_type::ffi_type, ref_mode::RefMode, rust_type::RustType,
-        trampoline_parameters::*, trampolines::Trampoline, try_from_glib::TryFromGlib,
+        bounds::Bounds, conversion_type::ConversionType, ffi_type::ffi_type, ref_mode::RefMode,
+        rust_type::RustType, trampoline_parameters::*, trampolines::Trampoline,
+        try_from_glib::TryFromGlib,
     },
     consts::TYPE_PARAMETERS_START,
     env::Env,
     library,
-    nameutil::{use_glib_if_needed, use_gtk_type},
+    nameutil::{use_glib_if_needed, use_glib_type, use_gtk_type},
     traits::IntoString,
     writer::primitives::tabs,
 };
@@ -51,8 +53,12 @@ pub fn generate(
     )?;
     writeln!(w, "{}\tlet f: &F = &*(f as *const F);", prepend)?;
     transformation_vars(w, env, analysis, &prepend)?;
-    let call = trampoline_call_func(env, analysis, in_trait);
-    writeln!(w, "{}\t{}", prepend, call)?;
+    if analysis.outs.is_empty() {
+        let call = trampoline_call_func(env, analysis, in_trait);
+        writeln!(w, "{}\t{}", prepend, call)?;
+    } else {
+        trampoline_call_func_outs(w, env, analysis, in_trait, &prepend)?;
+    }
     writeln!(w, "{}}}", prepend)?;
 
     Ok(())
@@ -99,7 +105,7 @@ fn func_parameters(
 ) -> String {
     let mut param_str = String::with_capacity(100);
 
-    for (pos, par) in analysis.parameters.rust_parameters.iter().enumerate() {
+    for (pos, par) in closure_parameters(analysis).enumerate() {
         if pos == 0 {
             if let Some(replace_self_bound) = &replace_self_bound {
                 param_str.push_str(par.ref_mode.for_rust_type());
@@ -130,7 +136,7 @@ fn func_parameter(env: &Env, par: &RustParameter, bounds: &Bounds) -> String {
 
     match bounds.get_parameter_bound(&par.name) {
         // TODO: ASYNC??
-        Some(bound) => bound.full_type_parameter_reference(ref_mode, par.nullable, false),
+        Some(bound) => bound.full_type_parameter_reference(ref_mode, None, par.nullable, false),
         // TODO
         // Some((None, _)) => panic!("Trampoline expects type name"),
         None => RustType::builder(env, par.typ)
@@ -142,19 +148,42 @@ fn func_parameter(env: &Env, par: &RustParameter, bounds: &Bounds) -> String {
     }
 }
 
+/// Parameters of the closure, out parameters being returned by it instead
+fn closure_parameters(analysis: &Trampoline) -> impl Iterator<Item = &RustParameter> {
+    analysis
+        .parameters
+        .rust_parameters
+        .iter()
+        .filter(|par| par.direction != library::ParameterDirection::Out)
+}
+
 fn func_returns(env: &Env, analysis: &Trampoline) -> String {
-    if analysis.ret.typ == Default::default() {
-        String::new()
-    } else if analysis.inhibit {
-        " -> glib::signal::Inhibit".into()
-    } else if let Some(return_type) =
-        analysis
-            .ret
-            .to_return_value(env, &TryFromGlib::default(), true)
-    {
-        format!(" -> {}", return_type)
-    } else {
-        String::new()
+    let mut returns = Vec::new();
+    if analysis.ret.typ != Default::default() {
+        if analysis.inhibit {
+            returns.push("glib::signal::Inhibit".to_owned());
+        } else if let Some(return_type) =
+            analysis
+                .ret
+                .to_return_value(env, &TryFromGlib::default(), true)
+        {
+            returns.push(return_type);
+        }
+    }
+    for out in &analysis.outs {
+        let out = library::Parameter {
+            direction: library::ParameterDirection::Return,
+            ..out.clone()
+        };
+        if let Some(return_type) = out.to_return_value(env, &TryFromGlib::default(), true) {
+            returns.push(return_type);
+        }
+    }
+
+    match returns.len() {
+        0 => String::new(),
+        1 => format!(" -> {}", returns[0]),
+        _ => format!(" -> ({})", returns.join(", ")),
     }
 }
 
@@ -199,6 +228,25 @@ fn transformation_vars(
     use crate::analysis::trampoline_parameters::TransformationType::*;
     for transform in &analysis.parameters.transformations {
         match transform.transformation {
+            None if transform.conversion_type == ConversionType::Pointer
+                && is_string_array(env, transform.typ) =>
+            {
+                let (left, right) = from_glib_xxx(transform.transfer, false);
+                writeln!(
+                    w,
+                    "{0}\tlet {1}_vec: Vec<{2}> = FromGlibPtrContainer::{3}{1}{4};",
+                    prepend,
+                    transform.name,
+                    use_glib_type(env, "GString"),
+                    left,
+                    right
+                )?;
+                writeln!(
+                    w,
+                    "{0}\tlet {1}_vec: Vec<&str> = {1}_vec.iter().map(|s| s.as_str()).collect();",
+                    prepend, transform.name
+                )?;
+            }
             None => (),
             Borrow => (),
             TreePath => {
@@ -227,10 +275,60 @@ fn trampoline_call_func(env: &Env, analysis: &Trampoline, in_trait: bool) -> Str
     format!("f({}){}", params, ret)
 }
 
+/// Calls the closure, writes its returned out parameters through the pointers
+/// and returns its return value
+fn trampoline_call_func_outs(
+    w: &mut dyn Write,
+    env: &Env,
+    analysis: &Trampoline,
+    in_trait: bool,
+    prepend: &str,
+) -> Result<()> {
+    let has_ret = analysis.ret.typ != Default::default();
+    let value_name = |out: &library::Parameter| format!("{}_value", out.name.trim_end_matches('_'));
+    let mut values: Vec<String> = analysis.outs.iter().map(value_name).collect();
+    if has_ret {
+        values.insert(0, "ret".to_owned());
+    }
+    let pattern = if values.len() == 1 {
+        values[0].clone()
+    } else {
+        format!("({})", values.join(", "))
+    };
+
+    let params = trampoline_call_parameters(env, analysis, in_trait);
+    writeln!(w, "{}\tlet {} = f({});", prepend, pattern, params)?;
+    // Emitters may pass NULL for outs they aren't interested in
+    for out in &analysis.outs {
+        writeln!(w, "{}\tif !{}.is_null() {{", prepend, out.name)?;
+        writeln!(
+            w,
+            "{}\t\t*{} = {}{};",
+            prepend,
+            out.name,
+            value_name(out),
+            out.trampoline_to_glib(env)
+        )?;
+        writeln!(w, "{}\t}}", prepend)?;
+    }
+    if has_ret {
+        writeln!(
+            w,
+            "{}\tret{}",
+            prepend,
+            analysis.ret.trampoline_to_glib(env)
+        )?;
+    }
+    Ok(())
+}
+
 fn trampoline_call_parameters(env: &Env, analysis: &Trampoline, in_trait: bool) -> String {
     let mut need_downcast = in_trait;
     let mut parameter_strs: Vec<String> = Vec::new();
     for (ind, par) in analysis.parameters.rust_parameters.iter().enumerate() {
+        if par.direction == library::ParameterDirection::Out {
+            continue;
+        }
         let transformation = match analysis.parameters.get(ind) {
             Some(transformation) => transformation,
             None => {
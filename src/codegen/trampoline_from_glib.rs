@@ -1,8 +1,9 @@
 use crate::{
-    analysis::{rust_type::RustType, trampoline_parameters::Transformation},
+    analysis::{ref_mode::RefMode, rust_type::RustType, trampoline_parameters::Transformation},
+    codegen::panics,
     env::Env,
     library,
-    nameutil::is_gstring,
+    nameutil::{is_gstring, use_glib_type},
     traits::*,
 };
 
@@ -17,35 +18,86 @@ impl TrampolineFromGlib for Transformation {
         match self.conversion_type {
             Direct => self.name.clone(),
             Scalar | Option | Result { .. } => format!("from_glib({})", self.name),
+            Pointer if is_string_array(env, self.typ) => {
+                // The strings are collected by `transformation_vars`
+                if nullable {
+                    format!("(!{0}.is_null()).then(|| &{0}_vec[..])", self.name)
+                } else {
+                    format!("&{}_vec", self.name)
+                }
+            }
+            Pointer if env.library.variant_type(self.typ).is_some() => {
+                let variant_type = env.library.variant_type(self.typ).unwrap();
+                let is_borrow = self.transfer == library::Transfer::None;
+                let (left, right) = from_glib_xxx(self.transfer, is_borrow);
+                let variant = use_glib_type(env, "Variant");
+                if !nullable {
+                    format!(
+                        "{}::{}{}{}.get::<{}>(){}",
+                        variant,
+                        left,
+                        self.name,
+                        right,
+                        variant_type,
+                        panics::wrong_variant_type(env, &self.name)
+                    )
+                } else if is_borrow {
+                    format!(
+                        "Option::<{}>::{}{}{}.as_ref().as_ref().map(|v| v.get::<{}>(){})",
+                        variant,
+                        left,
+                        self.name,
+                        right,
+                        variant_type,
+                        panics::wrong_variant_type(env, &self.name)
+                    )
+                } else {
+                    format!(
+                        "Option::<{}>::{}{}{}.map(|v| v.get::<{}>(){})",
+                        variant,
+                        left,
+                        self.name,
+                        right,
+                        variant_type,
+                        panics::wrong_variant_type(env, &self.name)
+                    )
+                }
+            }
             Borrow | Pointer => {
                 let is_borrow = self.conversion_type == Borrow;
+                let is_owned = !is_borrow
+                    && self.ref_mode == RefMode::None
+                    && self.transfer != library::Transfer::None;
                 let need_type_name = need_type_name || (is_borrow && nullable);
                 let (mut left, mut right) = from_glib_xxx(self.transfer, is_borrow);
                 let type_name = RustType::try_new(env, self.typ).into_string();
                 if need_type_name {
-                    if is_borrow && nullable {
+                    if (is_borrow || is_owned) && nullable {
                         left = format!("Option::<{}>::{}", type_name, left);
                     } else {
                         left = format!("{}::{}", type_name, left);
                     }
                 }
 
-                if !nullable {
-                    left = format!(
-                        "{}{}",
-                        if need_downcast && is_borrow { "" } else { "&" },
-                        left
-                    );
-                } else if nullable && is_borrow {
-                    if is_gstring(&type_name) {
-                        right = format!("{}.as_ref().as_deref()", right);
+                // Owned values are handed over to the handler as is
+                if !is_owned {
+                    if !nullable {
+                        left = format!(
+                            "{}{}",
+                            if need_downcast && is_borrow { "" } else { "&" },
+                            left
+                        );
+                    } else if is_borrow {
+                        if is_gstring(&type_name) {
+                            right = format!("{}.as_ref().as_deref()", right);
+                        } else {
+                            right = format!("{}.as_ref().as_ref()", right);
+                        }
+                    } else if is_gstring(&type_name) {
+                        right = format!("{}.as_deref()", right);
                     } else {
-                        right = format!("{}.as_ref().as_ref()", right);
+                        right = format!("{}.as_ref()", right);
                     }
-                } else if is_gstring(&type_name) {
-                    right = format!("{}.as_deref()", right);
-                } else {
-                    right = format!("{}.as_ref()", right);
                 }
 
                 if need_downcast && is_borrow {
@@ -79,3 +131,12 @@ fn is_need_type_name(env: &Env, type_id: library::TypeId) -> bool {
         false
     }
 }
+
+/// Whether the type is an array of strings, handed to the handler as `&[&str]`
+pub fn is_string_array(env: &Env, type_id: library::TypeId) -> bool {
+    use crate::library::{Fundamental::*, Type::*};
+    match env.type_(type_id) {
+        CArray(inner_tid) => matches!(env.type_(*inner_tid), Fundamental(Utf8)),
+        _ => false,
+    }
+}
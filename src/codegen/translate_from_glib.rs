@@ -1,8 +1,11 @@
 use crate::{
     analysis::{
-        self, conversion_type::ConversionType, rust_type::RustType, try_from_glib::TryFromGlib,
+        self, conversion_type::ConversionType, return_value::NegativeIsError, rust_type::RustType,
+        try_from_glib::TryFromGlib,
     },
     chunk::conversion_from_glib::Mode,
+    codegen::panics,
+    custom_type_std_time::StdTime,
     env::Env,
     library,
     nameutil::use_glib_type,
@@ -29,34 +32,37 @@ impl TranslateFromGlib for Mode {
             Scalar => match env.library.type_(self.typ) {
                 library::Type::Fundamental(library::Fundamental::UniChar) => (
                     "std::convert::TryFrom::try_from(".into(),
-                    ").expect(\"conversion from an invalid Unicode value attempted\")".into(),
+                    format!("){}", panics::invalid_unichar(env)),
                 ),
                 _ => ("from_glib(".into(), ")".into()),
             },
             Option => {
                 let (pre, post) = match &self.try_from_glib {
-                    TryFromGlib::Option => ("from_glib(", ")"),
-                    TryFromGlib::OptionMandatory => (
-                        "try_from_glib(",
-                        ").expect(\"mandatory glib value is None\")",
-                    ),
+                    TryFromGlib::Option => ("from_glib(", ""),
+                    TryFromGlib::OptionMandatory => ("try_from_glib(", panics::mandatory_none(env)),
                     other => panic!("Unexpected {:?} for ConversionType::Option", other),
                 };
-                (pre.to_string(), post.to_string())
+                (pre.to_string(), format!("){}", post))
             }
             Result { .. } => {
                 let (pre, post) = match &self.try_from_glib {
-                    TryFromGlib::Result { .. } => ("try_from_glib(", ")"),
-                    TryFromGlib::ResultInfallible { .. } => (
-                        "try_from_glib(",
-                        ").unwrap_or_else(|err| panic!(\"infallible {}\", err))",
-                    ),
+                    TryFromGlib::Result { .. } => ("try_from_glib(", ""),
+                    TryFromGlib::ResultInfallible { .. } => {
+                        ("try_from_glib(", panics::infallible(env))
+                    }
                     other => panic!("Unexpected {:?} for ConversionType::Result", other),
                 };
-                (pre.to_string(), post.to_string())
+                (pre.to_string(), format!("){}", post))
             }
             Pointer => {
                 let trans = from_glib_xxx(self.transfer, array_length);
+                if env.library.std_time(self.typ) == Some(StdTime::DateTime) {
+                    let pre = match self.transfer {
+                        library::Transfer::None => "crate::date_time_from_glib_none(",
+                        _ => "crate::date_time_from_glib_full(",
+                    };
+                    return (pre.into(), ")".into());
+                }
                 match env.type_(self.typ) {
                     library::Type::List(..)
                     | library::Type::SList(..)
@@ -119,11 +125,25 @@ impl TranslateFromGlib for analysis::return_value::Info {
                         (prefix, format!("{}.{}", from_glib_xxx.1, suffix_function))
                     }
                 }
+                None if self.negative_is_error.is_some() => {
+                    match self.negative_is_error.as_ref().unwrap() {
+                        NegativeIsError::BoolError(msg) => (
+                            "usize::try_from(".into(),
+                            format!(
+                                ").map_err(|_| {}(\"{}\"))",
+                                use_glib_type(env, "bool_error!"),
+                                msg
+                            ),
+                        ),
+                        NegativeIsError::Throws => (String::new(), " as usize".into()),
+                    }
+                }
                 None if self.bool_return_is_error.is_some() => (
                     use_glib_type(env, "result_from_gboolean!("),
                     format!(", \"{}\")", self.bool_return_is_error.as_ref().unwrap()),
                 ),
-                None if self.nullable_return_is_error.is_some() => {
+                // Functions returning a success boolean only map their out parameters.
+                None if self.nullable_return_is_error.is_some() && *par.lib_par.nullable => {
                     let res = Mode::from(par).translate_from_glib_as_function(env, array_length);
                     if let Some(ref msg) = self.nullable_return_is_error {
                         assert!(*par.lib_par.nullable);
@@ -140,6 +160,14 @@ impl TranslateFromGlib for analysis::return_value::Info {
                         res
                     }
                 }
+                None if env.is_ref_only_record(par.lib_par.typ) => {
+                    let rust_type = RustType::try_new(env, par.lib_par.typ).into_string();
+                    if *par.lib_par.nullable {
+                        ("(".into(), format!(" as *const {}).as_ref()", rust_type))
+                    } else {
+                        ("&*(".into(), format!(" as *const {})", rust_type))
+                    }
+                }
                 None => Mode::from(par).translate_from_glib_as_function(env, array_length),
             },
             None => (String::new(), ";".into()),
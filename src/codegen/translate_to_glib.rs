@@ -1,5 +1,7 @@
 use crate::{
     analysis::{function_parameters::TransformationType, ref_mode::RefMode},
+    config::bytes_type::BytesType,
+    custom_type_std_time::StdTime,
     library::Transfer,
 };
 
@@ -29,9 +31,14 @@ impl TranslateToGlib for TransformationType {
                 ref pointer_cast,
                 ref explicit_target_type,
                 in_trait,
+                owned,
                 ..
             } => {
-                let (left, right) = to_glib_xxx(transfer, ref_mode, explicit_target_type);
+                let (left, right) = if owned {
+                    ("".into(), ".into_glib_ptr()")
+                } else {
+                    to_glib_xxx(transfer, ref_mode, explicit_target_type)
+                };
 
                 if instance_parameter {
                     format!(
@@ -45,10 +52,118 @@ impl TranslateToGlib for TransformationType {
                     format!("{}{}{}{}{}", left, name, to_glib_extra, right, pointer_cast)
                 }
             }
+            ToGlibRecordValue {
+                ref name,
+                instance_parameter,
+            } => format!(
+                "*{}.to_glib_none().0",
+                if instance_parameter { "self" } else { name }
+            ),
             ToGlibBorrow => "/*Not applicable conversion Borrow*/".to_owned(),
             ToGlibUnknown { ref name } => format!("/*Unknown conversion*/{}", name),
             ToSome(ref name) => format!("Some({})", name),
             IntoRaw(ref name) => format!("Box_::into_raw({}) as *mut _", name),
+            ToGlibFixedArray { ref name, mut_ptr } => format!(
+                "{}.as_ptr() as {} _",
+                name,
+                if mut_ptr { "*mut" } else { "*const" }
+            ),
+            ToGlibOutBuffer { ref name } => format!("{}.as_mut_ptr()", name),
+            InOutScalar { ref name } => format!("&mut {}_glib", name),
+            ToGlibRecordArray {
+                ref name,
+                mut_ptr,
+                nullable,
+                ..
+            } => {
+                let ptr = if nullable {
+                    format!("{}.as_ref().map_or(std::ptr::null(), |s| s.as_ptr())", name)
+                } else {
+                    format!("{}.as_ptr()", name)
+                };
+                format!("{} as {} _", ptr, if mut_ptr { "*mut" } else { "*const" })
+            }
+            ToGlibHashTable {
+                ref name,
+                transfer,
+                nullable,
+            } => {
+                let map = if nullable {
+                    name.clone()
+                } else {
+                    format!("Some({})", name)
+                };
+                // The table owns copies of the items, the callee taking the
+                // container takes all of it
+                match transfer {
+                    Transfer::None => format!("crate::hash_table_to_glib_none({}).0", map),
+                    Transfer::Full | Transfer::Container => {
+                        format!("crate::hash_table_to_glib_full({})", map)
+                    }
+                }
+            }
+            ToGlibSystemTime {
+                ref name,
+                std_time,
+                transfer,
+                nullable,
+            } => {
+                let time = if nullable {
+                    name.clone()
+                } else {
+                    format!("Some({})", name)
+                };
+                match (std_time, transfer) {
+                    (StdTime::DateTime, Transfer::None) => {
+                        format!("crate::date_time_to_glib_none({}).0", time)
+                    }
+                    (StdTime::DateTime, Transfer::Full | Transfer::Container) => {
+                        format!("crate::date_time_to_glib_full({})", time)
+                    }
+                    (StdTime::TimeVal, _) => {
+                        format!("crate::time_val_to_glib({}).as_mut_ptr()", time)
+                    }
+                }
+            }
+            ToGlibVariant {
+                ref name,
+                transfer,
+                nullable,
+            } => {
+                let to_glib = if transfer == Transfer::None {
+                    ".to_glib_none().0"
+                } else {
+                    ".to_glib_full()"
+                };
+                if nullable {
+                    format!("{}.map(|v| v.to_variant()){}", name, to_glib)
+                } else {
+                    format!("{}.to_variant(){}", name, to_glib)
+                }
+            }
+            ToGlibBytes {
+                ref name,
+                bytes_type: BytesType::Slice,
+                transfer,
+                nullable,
+                ..
+            } => {
+                let bytes = if nullable {
+                    format!("{}.map(glib::Bytes::from)", name)
+                } else {
+                    format!("glib::Bytes::from({})", name)
+                };
+                if transfer == Transfer::None {
+                    format!("{}.to_glib_none().0", bytes)
+                } else {
+                    format!("{}.to_glib_full()", bytes)
+                }
+            }
+            ToGlibBytes {
+                ref name,
+                bytes_type: BytesType::Bytes,
+                ..
+            } => format!("{}.as_ptr() as *const _", name),
             _ => unreachable!("Unexpected transformation type {:?}", self),
         }
     }
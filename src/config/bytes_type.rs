@@ -0,0 +1,18 @@
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BytesType {
+    Slice, // &[u8], copied into a new GBytes when needed
+    Bytes, // &glib::Bytes, its data being passed as is
+}
+
+impl FromStr for BytesType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "&[u8]" => Ok(BytesType::Slice),
+            "glib::Bytes" => Ok(BytesType::Bytes),
+            _ => Err(format!("Wrong bytes type '{}'", s)),
+        }
+    }
+}
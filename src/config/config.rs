@@ -1,6 +1,10 @@
 use super::{
+    derives::DeriveProfile,
     external_libraries::{read_external_libraries, ExternalLibrary},
-    gobjects, WorkMode,
+    functions::Instrument,
+    gobjects,
+    properties::PropertyConflict,
+    TimeMapping, WorkMode,
 };
 use crate::{
     analysis::namespaces::{self, Namespace, NsId},
@@ -12,6 +16,7 @@ use crate::{
     version::Version,
 };
 use log::warn;
+use regex::Regex;
 use std::{
     collections::HashMap,
     fs,
@@ -23,7 +28,7 @@ use std::{
 /// Performs canonicalization by removing `foo/../` and `./` components
 /// from `path`, without hitting the file system. It does not turn relative
 /// paths into absolute paths.
-fn normalize_path(path: impl AsRef<Path>) -> PathBuf {
+pub fn normalize_path(path: impl AsRef<Path>) -> PathBuf {
     let mut parts: Vec<Component<'_>> = vec![];
 
     for component in path.as_ref().components() {
@@ -90,6 +95,7 @@ impl GirVersion {
 
 #[derive(Debug)]
 pub struct Config {
+    pub config_file: PathBuf,
     pub work_mode: WorkMode,
     pub girs_dirs: Vec<PathBuf>,
     // Version in girs_dirs, detected by git
@@ -100,6 +106,8 @@ pub struct Config {
     /// Path where files generated in normal and sys mode
     pub auto_path: PathBuf,
     pub doc_target_path: PathBuf,
+    /// Text written at the start of every generated file
+    pub file_header: Option<String>,
     pub external_libraries: Vec<ExternalLibrary>,
     pub objects: gobjects::GObjects,
     pub min_cfg_version: Version,
@@ -111,45 +119,111 @@ pub struct Config {
     pub concurrency: library::Concurrency,
     pub single_version_file: Option<PathBuf>,
     pub generate_display_trait: bool,
+    pub derive_profile: DeriveProfile,
+    pub property_conflict: PropertyConflict,
+    pub instrument: Instrument,
+    /// Panic through the `#[cold]` helpers of the generated `panics.rs`
+    pub cold_panics: bool,
+    /// Implement `glib::HasParamSpec` for enums and flags (glib 0.17+)
+    pub param_spec_impls: bool,
+    /// Move objects and records transferred in full into the C functions
+    /// with `IntoGlibPtr` (glib 0.16+)
+    pub move_owned_parameters: bool,
+    /// Strip `new_` from `new_from_*`, `new_with_*` and `new_for_*` constructors
+    pub rename_constructors: bool,
+    pub time_mapping: TimeMapping,
     pub trust_return_value_nullability: bool,
     pub docs_rs_features: Vec<String>,
     pub disable_format: bool,
+    /// Abort on the first malformed .gir element
+    pub strict_parsing: bool,
     pub split_build_rs: bool,
+    /// Generate `build_stale_check.rs`, checking in the build script of a
+    /// sys crate that the .gir files and the configuration didn't change
+    /// since the generation
+    pub generate_stale_check: bool,
     pub extra_versions: Vec<Version>,
     pub lib_version_overrides: HashMap<Version, Version>,
+    /// Versions of included namespaces to load instead of the ones in the `include`s
+    pub girs_version_overrides: HashMap<String, String>,
     pub feature_dependencies: HashMap<Version, Vec<String>>,
     pub dox_feature_dependencies: Vec<String>,
+    pub generate_prelude: bool,
+    pub prelude_exclude: Vec<String>,
+    /// Objects and functions removed from the generated code, which nothing
+    /// else generated may reference
+    pub strip: Vec<String>,
+    pub generate_settings_binding: bool,
+    pub generate_try_property_getters: bool,
+    pub generate_chainable_setters: bool,
+    pub generate_class_accessors: bool,
+    /// Bind `gconstpointer` instances as `*const` instead of `*mut` pointers
+    pub const_pointers: bool,
+    /// Generate `#![no_std]` sys crates
+    pub sys_no_std: bool,
+    /// Overrides of the `c:identifier-prefixes` of the main namespace
+    pub identifier_prefixes: Option<Vec<String>>,
+    /// Overrides of the `c:symbol-prefixes` of the main namespace
+    pub symbol_prefixes: Option<Vec<String>>,
+    /// Names of the parameters considered as lengths of a neighbouring array
+    pub length_name_patterns: Vec<Regex>,
+    pub disable_length_detect: bool,
+    /// C functions returning the major, minor and micro runtime versions
+    /// of the library, used by the generated `features` module
+    pub runtime_version_functions: Option<Vec<String>>,
+    /// Suffixes tried in order to find the finish function of async functions
+    pub finish_function_suffixes: Vec<String>,
+    /// Where to write the map of removed and renamed C symbols
+    pub migration_file: Option<PathBuf>,
+    /// Root of the -sys crate checked in `consistency_check` mode
+    pub sys_crate_path: PathBuf,
+}
+
+/// Command line settings of [`Config::new`], taking precedence over the
+/// configuration file
+#[derive(Debug, Default)]
+pub struct ConfigOptions<'a> {
+    pub config_file: Option<&'a str>,
+    pub work_mode: Option<WorkMode>,
+    pub girs_dirs: &'a [String],
+    pub library_name: Option<&'a str>,
+    pub library_version: Option<&'a str>,
+    pub target_path: Option<&'a str>,
+    pub doc_target_path: Option<&'a str>,
+    pub make_backup: bool,
+    pub show_statistics: bool,
+    pub disable_format: bool,
+    pub strict_parsing: bool,
+    /// Don't touch the target directory, the generated files are kept in memory
+    pub dry_run: bool,
+    pub config_overrides: &'a [String],
 }
 
 impl Config {
-    pub fn new<'a, S, W>(
-        config_file: S,
-        work_mode: W,
-        girs_dirs: &[String],
-        library_name: S,
-        library_version: S,
-        target_path: S,
-        doc_target_path: S,
-        make_backup: bool,
-        show_statistics: bool,
-        disable_format: bool,
-    ) -> Result<Config, String>
-    where
-        S: Into<Option<&'a str>>,
-        W: Into<Option<WorkMode>>,
-    {
-        let config_file: PathBuf = match config_file.into() {
-            Some("") | None => "Gir.toml",
-            Some(a) => a,
-        }
-        .into();
+    pub fn new(options: &ConfigOptions<'_>) -> Result<Config, String> {
+        let &ConfigOptions {
+            config_file,
+            work_mode,
+            girs_dirs,
+            library_name,
+            library_version,
+            target_path,
+            doc_target_path,
+            make_backup,
+            show_statistics,
+            disable_format,
+            strict_parsing,
+            dry_run,
+            config_overrides,
+        } = options;
+        let config_file = config_file_path(config_file);
 
         let config_dir = match config_file.parent() {
             Some(path) => path.into(),
             None => PathBuf::new(),
         };
 
-        let toml = match read_toml(&config_file) {
+        let toml = match read_config(&config_file, config_overrides) {
             Ok(toml) => toml,
             Err(e) => {
                 return Err(format!(
@@ -165,7 +239,7 @@ impl Config {
             set_crate_name_overrides(overrides);
         }
 
-        let work_mode = match work_mode.into() {
+        let work_mode = match work_mode {
             Some(w) => w,
             None => {
                 let s = match toml.lookup_str("options.work_mode", "No options.work_mode") {
@@ -200,7 +274,7 @@ impl Config {
         let mut girs_version = girs_dirs.iter().map(GirVersion::new).collect::<Vec<_>>();
         girs_version.sort_by(|a, b| a.gir_dir.partial_cmp(&b.gir_dir).unwrap());
 
-        let (library_name, library_version) = match (library_name.into(), library_version.into()) {
+        let (library_name, library_version) = match (library_name, library_version) {
             (Some(""), Some("")) | (None, None) => (
                 toml.lookup_str("options.library", "No options.library")?
                     .to_owned(),
@@ -213,7 +287,7 @@ impl Config {
             (Some(a), Some(b)) => (a.to_owned(), b.to_owned()),
         };
 
-        let target_path: PathBuf = match target_path.into() {
+        let target_path: PathBuf = match target_path {
             Some("") | None => {
                 let path = toml.lookup_str("options.target_path", "No target path specified")?;
                 config_dir.join(path)
@@ -231,12 +305,12 @@ impl Config {
             None if work_mode == WorkMode::Normal => target_path.join("src").join("auto"),
             None => target_path.join("src"),
         };
-        if work_mode == WorkMode::Normal && auto_path.exists() {
+        if work_mode == WorkMode::Normal && !dry_run && auto_path.exists() {
             std::fs::remove_dir_all(&auto_path)
                 .map_err(|e| format!("remove_dir_all failed: {:?}", e))?;
         }
 
-        let doc_target_path: PathBuf = match doc_target_path.into() {
+        let doc_target_path: PathBuf = match doc_target_path {
             Some("") | None => match toml.lookup("options.doc_target_path") {
                 Some(p) => config_dir.join(p.as_result_str("options.doc_target_path")?),
                 None => target_path.join("vendor.md"),
@@ -244,6 +318,17 @@ impl Config {
             Some(p) => config_dir.join(p),
         };
 
+        let file_header = match toml.lookup("options.file_header") {
+            Some(p) => {
+                let path = config_dir.join(p.as_result_str("options.file_header")?);
+                match fs::read_to_string(&path) {
+                    Ok(header) => Some(header),
+                    Err(e) => return Err(format!("Can't read file header {:?}: {}", path, e)),
+                }
+            }
+            None => None,
+        };
+
         let concurrency = match toml.lookup("options.concurrency") {
             Some(v) => v.as_result_str("options.concurrency")?.parse()?,
             None => Default::default(),
@@ -254,6 +339,46 @@ impl Config {
             None => true,
         };
 
+        let derive_profile = match toml.lookup("options.derive_profile") {
+            Some(v) => v.as_result_str("options.derive_profile")?.parse()?,
+            None => Default::default(),
+        };
+
+        let property_conflict = match toml.lookup("options.property_conflict") {
+            Some(v) => v.as_result_str("options.property_conflict")?.parse()?,
+            None => Default::default(),
+        };
+
+        let instrument = match toml.lookup("options.instrument") {
+            Some(v) => v.as_result_str("options.instrument")?.parse()?,
+            None => Default::default(),
+        };
+
+        let cold_panics = match toml.lookup("options.cold_panics") {
+            Some(v) => v.as_result_bool("options.cold_panics")?,
+            None => false,
+        };
+
+        let param_spec_impls = match toml.lookup("options.param_spec_impls") {
+            Some(v) => v.as_result_bool("options.param_spec_impls")?,
+            None => false,
+        };
+
+        let move_owned_parameters = match toml.lookup("options.move_owned_parameters") {
+            Some(v) => v.as_result_bool("options.move_owned_parameters")?,
+            None => false,
+        };
+
+        let time_mapping = match toml.lookup("options.time_mapping") {
+            Some(v) => v.as_result_str("options.time_mapping")?.parse()?,
+            None => Default::default(),
+        };
+
+        let rename_constructors = match toml.lookup("options.rename_constructors") {
+            Some(v) => v.as_result_bool("options.rename_constructors")?,
+            None => true,
+        };
+
         let trust_return_value_nullability =
             match toml.lookup("options.trust_return_value_nullability") {
                 Some(v) => v.as_result_bool("options.trust_return_value_nullability")?,
@@ -294,6 +419,9 @@ impl Config {
             trust_return_value_nullability,
         );
 
+        let strip = read_string_list(&toml, "options.strip")?.unwrap_or_default();
+        gobjects::apply_strip(&mut objects, &strip)?;
+
         let external_libraries = read_external_libraries(&toml)?;
 
         let min_cfg_version = match toml.lookup("options.min_cfg_version") {
@@ -328,6 +456,18 @@ impl Config {
             None => None,
         };
 
+        let migration_file = match toml.lookup("options.migration_file") {
+            Some(v) => match v.as_result_bool("options.migration_file") {
+                Ok(false) => None,
+                Ok(true) => Some(target_path.join("migration.json")),
+                Err(_) => match v.as_str() {
+                    Some(p) => Some(target_path.join(p)),
+                    None => return Err("migration_file must be bool or string path".into()),
+                },
+            },
+            None => None,
+        };
+
         let disable_format: bool = if disable_format {
             true
         } else {
@@ -342,12 +482,110 @@ impl Config {
             None => false,
         };
 
+        let mut generate_stale_check = match toml.lookup("options.generate_stale_check") {
+            Some(v) => v.as_result_bool("options.generate_stale_check")?,
+            None => false,
+        };
+        // The check is called from the build.rs generated next to it
+        if generate_stale_check && work_mode != WorkMode::Sys {
+            warn!("options.generate_stale_check is only supported in sys mode, ignored");
+            generate_stale_check = false;
+        }
+
         let extra_versions = read_extra_versions(&toml)?;
         let lib_version_overrides = read_lib_version_overrides(&toml)?;
+        let girs_version_overrides = read_girs_version_overrides(&toml)?;
         let feature_dependencies = read_feature_dependencies(&toml)?;
         let dox_feature_dependencies = read_dox_feature_dependencies(&toml)?;
 
+        let generate_prelude = match toml.lookup("options.generate_prelude") {
+            Some(v) => v.as_result_bool("options.generate_prelude")?,
+            None => false,
+        };
+        let prelude_exclude = read_prelude_exclude(&toml)?;
+
+        let mut generate_settings_binding = match toml.lookup("options.generate_settings_binding") {
+            Some(v) => v.as_result_bool("options.generate_settings_binding")?,
+            None => false,
+        };
+        // The bindings are built on `gio::Settings`
+        if generate_settings_binding
+            && library_name != "Gio"
+            && !external_libraries.iter().any(|lib| lib.namespace == "Gio")
+        {
+            warn!("options.generate_settings_binding needs Gio in options.external_libraries, ignored");
+            generate_settings_binding = false;
+        }
+
+        let generate_try_property_getters =
+            match toml.lookup("options.generate_try_property_getters") {
+                Some(v) => v.as_result_bool("options.generate_try_property_getters")?,
+                None => false,
+            };
+
+        let generate_chainable_setters = match toml.lookup("options.generate_chainable_setters") {
+            Some(v) => v.as_result_bool("options.generate_chainable_setters")?,
+            None => false,
+        };
+
+        let generate_class_accessors = match toml.lookup("options.generate_class_accessors") {
+            Some(v) => v.as_result_bool("options.generate_class_accessors")?,
+            None => false,
+        };
+
+        let const_pointers = match toml.lookup("options.const_pointers") {
+            Some(v) => v.as_result_bool("options.const_pointers")?,
+            None => false,
+        };
+
+        let sys_no_std = match toml.lookup("options.sys_no_std") {
+            Some(v) => v.as_result_bool("options.sys_no_std")?,
+            None => false,
+        };
+
+        let identifier_prefixes = read_string_list(&toml, "options.identifier_prefixes")?;
+        let symbol_prefixes = read_string_list(&toml, "options.symbol_prefixes")?;
+
+        let length_name_patterns = read_string_list(&toml, "options.length_name_patterns")?
+            .unwrap_or_else(|| vec![".*len".to_owned(), ".*length.*".to_owned()])
+            .iter()
+            .map(|pattern| {
+                Regex::new(&format!("^{}$", pattern)).map_err(|e| {
+                    format!(
+                        "Bad pattern `{}` in options.length_name_patterns: {}",
+                        pattern, e
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let disable_length_detect = match toml.lookup("options.disable_length_detect") {
+            Some(v) => v.as_result_bool("options.disable_length_detect")?,
+            None => false,
+        };
+
+        let runtime_version_functions =
+            read_string_list(&toml, "options.runtime_version_functions")?;
+        if let Some(ref functions) = runtime_version_functions {
+            if functions.len() != 3 {
+                return Err(format!(
+                    "options.runtime_version_functions expected to list the major, minor and \
+                     micro version functions, found {} functions",
+                    functions.len()
+                ));
+            }
+        }
+
+        let finish_function_suffixes = read_string_list(&toml, "options.finish_function_suffixes")?
+            .unwrap_or_else(|| vec!["_finish".to_owned()]);
+
+        let sys_crate_path = match toml.lookup("options.sys_crate_path") {
+            Some(p) => config_dir.join(p.as_result_str("options.sys_crate_path")?),
+            None => target_path.join("sys"),
+        };
+
         Ok(Config {
+            config_file,
             work_mode,
             girs_dirs,
             girs_version,
@@ -356,6 +594,7 @@ impl Config {
             target_path,
             auto_path,
             doc_target_path,
+            file_header,
             external_libraries,
             objects,
             min_cfg_version,
@@ -367,14 +606,42 @@ impl Config {
             concurrency,
             single_version_file,
             generate_display_trait,
+            derive_profile,
+            property_conflict,
+            instrument,
+            cold_panics,
+            param_spec_impls,
+            move_owned_parameters,
+            rename_constructors,
+            time_mapping,
             trust_return_value_nullability,
             docs_rs_features,
             disable_format,
+            strict_parsing,
             split_build_rs,
+            generate_stale_check,
             extra_versions,
             lib_version_overrides,
+            girs_version_overrides,
             feature_dependencies,
             dox_feature_dependencies,
+            generate_prelude,
+            prelude_exclude,
+            strip,
+            generate_settings_binding,
+            generate_try_property_getters,
+            generate_chainable_setters,
+            generate_class_accessors,
+            const_pointers,
+            sys_no_std,
+            identifier_prefixes,
+            symbol_prefixes,
+            length_name_patterns,
+            disable_length_detect,
+            runtime_version_functions,
+            finish_function_suffixes,
+            migration_file,
+            sys_crate_path,
         })
     }
 
@@ -437,6 +704,180 @@ fn read_toml<P: AsRef<Path>>(filename: P) -> Result<toml::Value, String> {
     })
 }
 
+impl Config {
+    /// Returns the configuration read from `config_file`, merged with all
+    /// its includes and overrides, as TOML
+    pub fn dump_toml(
+        config_file: Option<&str>,
+        config_overrides: &[String],
+    ) -> Result<String, String> {
+        let config_file = config_file_path(config_file);
+        let toml = read_config(&config_file, config_overrides)
+            .map_err(|e| format!("Error while reading \"{}\": {}", config_file.display(), e))?;
+        toml::to_string_pretty(&toml).map_err(|e| e.to_string())
+    }
+}
+
+fn config_file_path(config_file: Option<&str>) -> PathBuf {
+    match config_file {
+        Some("") | None => "Gir.toml",
+        Some(a) => a,
+    }
+    .into()
+}
+
+/// Prefix of the environment variables overriding `[options]` settings
+const OPTION_ENV_PREFIX: &str = "GIR_OPT_";
+
+/// Reads `filename` with its includes, then applies the `GIR_OPT_*`
+/// environment variables and finally `config_overrides`.
+fn read_config(filename: &Path, config_overrides: &[String]) -> Result<toml::Value, String> {
+    let mut toml = read_toml_with_includes(filename, &mut Vec::new())?;
+
+    let mut env_overrides = std::env::vars()
+        .filter_map(|(name, value)| {
+            let option = name.strip_prefix(OPTION_ENV_PREFIX)?;
+            Some((format!("options.{}", option.to_lowercase()), value))
+        })
+        .collect::<Vec<_>>();
+    env_overrides.sort();
+    for (key, value) in env_overrides {
+        apply_override(&mut toml, &key, &value)?;
+    }
+
+    for config_override in config_overrides {
+        let (key, value) = config_override.split_once('=').ok_or_else(|| {
+            format!(
+                "Invalid override \"{}\", expected `key=value`",
+                config_override
+            )
+        })?;
+        apply_override(&mut toml, key.trim(), value.trim())?;
+    }
+    Ok(toml)
+}
+
+/// Sets the dotted `key` of `toml` to `value`.
+///
+/// `value` is parsed as a TOML value, unless the key already holds a string,
+/// it is not valid TOML or it is a float, in which case it is taken as a
+/// plain string.
+fn apply_override(toml: &mut toml::Value, key: &str, value: &str) -> Result<(), String> {
+    use toml::Value;
+    let not_table = || format!("Cannot override \"{}\": not a table", key);
+    let mut parts = key.split('.').collect::<Vec<_>>();
+    let name = parts.pop().unwrap_or_default();
+    if name.is_empty() || parts.iter().any(|part| part.is_empty()) {
+        return Err(format!("Invalid override key \"{}\"", key));
+    }
+
+    let mut table = toml;
+    for part in parts {
+        table = table
+            .as_table_mut()
+            .ok_or_else(not_table)?
+            .entry(part)
+            .or_insert_with(|| Value::Table(Default::default()));
+    }
+    let entries = table.as_table_mut().ok_or_else(not_table)?;
+    let value = match entries.get(name) {
+        Some(Value::String(_)) => Value::String(value.to_owned()),
+        _ => match format!("value = {}", value)
+            .parse::<Value>()
+            .ok()
+            .and_then(|mut parsed| parsed.as_table_mut()?.remove("value"))
+        {
+            // No option is a float, `3.20` is a version
+            Some(Value::Float(_)) | None => Value::String(value.to_owned()),
+            Some(parsed) => parsed,
+        },
+    };
+    entries.insert(name.to_owned(), value);
+    Ok(())
+}
+
+/// Reads `filename` and the files listed in its `include` key, relative to
+/// it. The includes are merged in order, then the file itself on top of them.
+fn read_toml_with_includes(
+    filename: &Path,
+    visited: &mut Vec<PathBuf>,
+) -> Result<toml::Value, String> {
+    let path = fs::canonicalize(filename).unwrap_or_else(|_| normalize_path(filename));
+    if visited.contains(&path) {
+        return Err(format!("Recursive include of \"{}\"", filename.display()));
+    }
+    visited.push(path);
+
+    let mut toml = read_toml(filename)?;
+    let includes = match toml.as_table_mut().and_then(|t| t.remove("include")) {
+        Some(toml::Value::Array(includes)) => includes,
+        Some(_) => return Err("\"include\" must be an array of paths".to_owned()),
+        None => Vec::new(),
+    };
+    let base_dir = filename.parent().unwrap_or_else(|| Path::new(""));
+
+    let mut merged = toml::Value::Table(Default::default());
+    for include in includes {
+        let include = include
+            .as_str()
+            .ok_or_else(|| "\"include\" must be an array of paths".to_owned())?;
+        let included = read_toml_with_includes(&base_dir.join(include), visited)
+            .map_err(|e| format!("In include \"{}\": {}", include, e))?;
+        merge_toml(&mut merged, included, "");
+    }
+    merge_toml(&mut merged, toml, "");
+
+    visited.pop();
+    Ok(merged)
+}
+
+/// Merges `overlay` into `base`, with `key` being the dotted path of both.
+///
+/// Tables are merged key by key, `[[object]]` entries with the same `name`
+/// are merged, other arrays are concatenated without duplicates and any
+/// other value of `overlay` replaces the one of `base`.
+fn merge_toml(base: &mut toml::Value, overlay: toml::Value, key: &str) {
+    use toml::Value;
+    match (base, overlay) {
+        (Value::Table(base), Value::Table(overlay)) => {
+            for (name, value) in overlay {
+                let path = if key.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", key, name)
+                };
+                match base.get_mut(&name) {
+                    Some(base_value) => merge_toml(base_value, value, &path),
+                    None => {
+                        base.insert(name, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) if key == "object" => {
+            for value in overlay {
+                let name = value.get("name").and_then(Value::as_str);
+                let existing = name.and_then(|name| {
+                    base.iter_mut()
+                        .find(|v| v.get("name").and_then(Value::as_str) == Some(name))
+                });
+                match existing {
+                    Some(existing) => merge_toml(existing, value, ""),
+                    None => base.push(value),
+                }
+            }
+        }
+        (Value::Array(base), Value::Array(overlay)) => {
+            for value in overlay {
+                if !base.contains(&value) {
+                    base.push(value);
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
 fn make_single_version_file(configured: Option<&str>, target_path: &Path) -> PathBuf {
     let file_dir = match configured {
         None | Some("") => target_path.join("src").join("auto"),
@@ -499,6 +940,39 @@ fn read_dox_feature_dependencies(toml: &toml::Value) -> Result<Vec<String>, Stri
     }
 }
 
+fn read_prelude_exclude(toml: &toml::Value) -> Result<Vec<String>, String> {
+    match toml.lookup("options.prelude_exclude") {
+        Some(a) => a
+            .as_result_vec("options.prelude_exclude")?
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .ok_or_else(|| {
+                        "options.prelude_exclude expected to be array of string".to_string()
+                    })
+                    .map(str::to_owned)
+            })
+            .collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn read_string_list(toml: &toml::Value, key: &str) -> Result<Option<Vec<String>>, String> {
+    match toml.lookup(key) {
+        Some(a) => a
+            .as_result_vec(key)?
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .ok_or_else(|| format!("{} expected to be array of string", key))
+                    .map(str::to_owned)
+            })
+            .collect::<Result<_, _>>()
+            .map(Some),
+        None => Ok(None),
+    }
+}
+
 fn read_lib_version_overrides(toml: &toml::Value) -> Result<HashMap<Version, Version>, String> {
     let v = match toml.lookup("lib_version_overrides") {
         Some(a) => a.as_result_vec("lib_version_overrides")?,
@@ -519,6 +993,30 @@ fn read_lib_version_overrides(toml: &toml::Value) -> Result<HashMap<Version, Ver
     Ok(map)
 }
 
+fn read_girs_version_overrides(toml: &toml::Value) -> Result<HashMap<String, String>, String> {
+    let table = match toml.lookup("girs_version_overrides") {
+        Some(table) => table
+            .as_table()
+            .ok_or("girs_version_overrides expected to be a table")?,
+        None => return Ok(Default::default()),
+    };
+
+    table
+        .iter()
+        .map(|(namespace, version)| {
+            version
+                .as_str()
+                .map(|version| (namespace.clone(), version.to_owned()))
+                .ok_or_else(|| {
+                    format!(
+                        "girs_version_overrides.{} expected to be a string",
+                        namespace
+                    )
+                })
+        })
+        .collect()
+}
+
 fn read_feature_dependencies(toml: &toml::Value) -> Result<HashMap<Version, Vec<String>>, String> {
     let v = match toml.lookup("feature_dependencies") {
         Some(a) => a.as_result_vec("feature_dependencies")?,
@@ -580,4 +1078,106 @@ mod tests {
             PathBuf::from("/tmp/glib/_vers.dat")
         );
     }
+
+    #[test]
+    fn test_apply_override() {
+        let mut toml: toml::Value = r#"
+            [options]
+            min_cfg_version = "3.0"
+            single_version_file = false
+        "#
+        .parse()
+        .unwrap();
+        apply_override(&mut toml, "options.single_version_file", "true").unwrap();
+        apply_override(&mut toml, "options.min_cfg_version", "3.20").unwrap();
+        apply_override(&mut toml, "options.generate", r#"["Gtk.Widget"]"#).unwrap();
+        apply_override(&mut toml, "options.library", "Gtk").unwrap();
+        apply_override(&mut toml, "crate_name_overrides.gtk", "gtk4").unwrap();
+
+        let expected: toml::Value = r#"
+            [options]
+            min_cfg_version = "3.20"
+            single_version_file = true
+            generate = ["Gtk.Widget"]
+            library = "Gtk"
+            [crate_name_overrides]
+            gtk = "gtk4"
+        "#
+        .parse()
+        .unwrap();
+        assert_eq!(toml, expected);
+
+        let mut empty = toml::Value::Table(Default::default());
+        apply_override(&mut empty, "options.min_cfg_version", "3.20").unwrap();
+        let version = empty["options"]["min_cfg_version"].as_str().unwrap();
+        assert_eq!(Version::from_str(version), Ok(Version(3, 20, 0)));
+
+        assert!(apply_override(&mut toml, "options.library.name", "Gtk").is_err());
+        assert!(apply_override(&mut toml, "options..x", "1").is_err());
+    }
+
+    #[test]
+    fn test_merge_toml() {
+        let mut base: toml::Value = toml::from_str(
+            r#"
+[options]
+library = "Gtk"
+generate = ["Gtk.Align"]
+
+[[object]]
+name = "Gtk.Widget"
+status = "generate"
+manual_traits = ["WidgetExtManual"]
+
+[[object]]
+name = "Gtk.Window"
+status = "generate"
+"#,
+        )
+        .unwrap();
+        let overlay: toml::Value = toml::from_str(
+            r#"
+[options]
+version = "4.0"
+generate = ["Gtk.Align", "Gtk.Orientation"]
+
+[[object]]
+name = "Gtk.Widget"
+status = "manual"
+
+[[object]]
+name = "Gtk.Box"
+status = "generate"
+"#,
+        )
+        .unwrap();
+        merge_toml(&mut base, overlay, "");
+
+        assert_eq!(base["options"]["library"].as_str(), Some("Gtk"));
+        assert_eq!(base["options"]["version"].as_str(), Some("4.0"));
+        assert_eq!(
+            base["options"]["generate"],
+            toml::Value::Array(vec!["Gtk.Align".into(), "Gtk.Orientation".into()])
+        );
+        let objects = base["object"].as_array().unwrap();
+        assert_eq!(objects.len(), 3);
+        assert_eq!(objects[0]["status"].as_str(), Some("manual"));
+        assert!(objects[0].get("manual_traits").is_some());
+        assert_eq!(objects[2]["name"].as_str(), Some("Gtk.Box"));
+    }
+
+    #[test]
+    fn test_read_girs_version_overrides() {
+        let toml: toml::Value = r#"
+            [girs_version_overrides]
+            Gtk = "3.0"
+        "#
+        .parse()
+        .unwrap();
+        let overrides = read_girs_version_overrides(&toml).unwrap();
+        assert_eq!(overrides.get("Gtk").map(String::as_str), Some("3.0"));
+
+        let toml: toml::Value = "girs_version_overrides = { Gtk = 3 }".parse().unwrap();
+        assert!(read_girs_version_overrides(&toml).is_err());
+    }
 }
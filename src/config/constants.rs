@@ -9,6 +9,8 @@ pub struct Constant {
     pub status: GStatus,
     pub version: Option<Version>,
     pub cfg_condition: Option<String>,
+    /// Newtype over `&'static str` the constant is wrapped in
+    pub newtype: Option<String>,
 }
 
 impl Parse for Constant {
@@ -31,6 +33,7 @@ impl Parse for Constant {
                 "version",
                 "cfg_condition",
                 "pattern",
+                "newtype",
             ],
             &format!("function {}", object_name),
         );
@@ -43,6 +46,10 @@ impl Parse for Constant {
             .lookup("cfg_condition")
             .and_then(Value::as_str)
             .map(ToOwned::to_owned);
+        let newtype = toml
+            .lookup("newtype")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
 
         let status = {
             if toml
@@ -67,6 +74,7 @@ impl Parse for Constant {
             status,
             version,
             cfg_condition,
+            newtype,
         })
     }
 }
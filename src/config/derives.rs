@@ -1,5 +1,6 @@
 use super::{error::TomlHelper, parsable::Parse};
 use log::error;
+use std::str::FromStr;
 use toml::Value;
 
 #[derive(Clone, Debug)]
@@ -40,3 +41,53 @@ impl Parse for Derive {
 }
 
 pub type Derives = Vec<Derive>;
+
+/// Traits derived for enums and records without configured `derive`s
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeriveProfile {
+    /// Comparison and hashing on top of `Debug`, where the type allows it
+    Full,
+    /// `Debug` and, for enums, equality only
+    Minimal,
+}
+
+impl DeriveProfile {
+    pub fn enum_derives(self) -> &'static [&'static str] {
+        match self {
+            DeriveProfile::Full => &["Debug", "Eq", "PartialEq", "Ord", "PartialOrd", "Hash"],
+            DeriveProfile::Minimal => &["Debug", "Eq", "PartialEq"],
+        }
+    }
+
+    /// On top of the traits the `bitflags!` macro already implements
+    pub fn flags_derives(self) -> &'static [&'static str] {
+        match self {
+            DeriveProfile::Full => &["Default"],
+            DeriveProfile::Minimal => &[],
+        }
+    }
+
+    pub fn record_derives(self) -> &'static [&'static str] {
+        match self {
+            DeriveProfile::Full => &["Debug", "PartialEq", "Eq", "PartialOrd", "Ord", "Hash"],
+            DeriveProfile::Minimal => &["Debug"],
+        }
+    }
+}
+
+impl Default for DeriveProfile {
+    fn default() -> DeriveProfile {
+        DeriveProfile::Full
+    }
+}
+
+impl FromStr for DeriveProfile {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "full" => Ok(DeriveProfile::Full),
+            "minimal" => Ok(DeriveProfile::Minimal),
+            _ => Err(format!("Wrong derive profile '{}'", s)),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use super::{
+    bytes_type::BytesType,
     error::TomlHelper,
     gobjects::GStatus,
     ident::Ident,
@@ -7,7 +8,7 @@ use super::{
     string_type::StringType,
 };
 use crate::{
-    analysis::safety_assertion_mode::SafetyAssertionMode,
+    analysis::{ref_mode::RefMode, safety_assertion_mode::SafetyAssertionMode},
     library::{Infallible, Mandatory, Nullable},
     version::Version,
 };
@@ -15,6 +16,32 @@ use log::error;
 use std::{collections::HashSet, str::FromStr};
 use toml::Value;
 
+/// Instrumentation added to the bodies of the generated functions
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Instrument {
+    None,
+    /// `tracing::trace_span!` named after the C function, behind the
+    /// `tracing` feature of the generated crate
+    Tracing,
+}
+
+impl Default for Instrument {
+    fn default() -> Instrument {
+        Instrument::None
+    }
+}
+
+impl FromStr for Instrument {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "none" => Ok(Instrument::None),
+            "tracing" => Ok(Instrument::Tracing),
+            _ => Err(format!("Wrong instrumentation '{}'", s)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CallbackParameter {
     pub ident: Ident,
@@ -55,6 +82,40 @@ impl AsRef<Ident> for CallbackParameter {
     }
 }
 
+/// How the length of an array is computed from the Rust slice.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LengthScale {
+    /// Number of elements in the slice
+    Elements,
+    /// Size of the slice in bytes
+    Bytes,
+    /// Expression where `len` stands for the number of elements, e.g. `len * 2`
+    Expression(String),
+}
+
+impl Default for LengthScale {
+    fn default() -> Self {
+        LengthScale::Elements
+    }
+}
+
+impl FromStr for LengthScale {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "elements" => Ok(LengthScale::Elements),
+            "bytes" => Ok(LengthScale::Bytes),
+            e if e
+                .split(|c: char| !c.is_alphanumeric() && c != '_')
+                .any(|word| word == "len") =>
+            {
+                Ok(LengthScale::Expression(e.to_owned()))
+            }
+            e => Err(format!("Wrong length_scale: \"{}\"", e)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Parameter {
     pub ident: Ident,
@@ -65,8 +126,20 @@ pub struct Parameter {
     pub mandatory: Option<Mandatory>,
     pub infallible: Option<Infallible>,
     pub length_of: Option<String>,
+    pub length_scale: Option<LengthScale>,
     pub string_type: Option<StringType>,
     pub callback_parameters: CallbackParameters,
+    /// Name of the parameter in the generated code, before keyword mangling
+    pub rename: Option<String>,
+    /// Overrides how the parameter is passed: by value, by reference or by
+    /// mutable reference
+    pub ref_mode: Option<RefMode>,
+    /// Rust type converted to and from the `GVariant` of the parameter
+    pub variant_type: Option<String>,
+    /// Whether bytes are taken as a slice or as `glib::Bytes`
+    pub bytes_type: Option<BytesType>,
+    /// Type actually pointed to by a `gpointer`, or `gpointer` to take it as is
+    pub pointer_type: Option<String>,
 }
 
 impl Parse for Parameter {
@@ -88,10 +161,16 @@ impl Parse for Parameter {
                 "mandatory",
                 "infallible",
                 "length_of",
+                "length_scale",
                 "name",
                 "pattern",
                 "string_type",
                 "callback_parameter",
+                "rename",
+                "ref_mode",
+                "variant_type",
+                "bytes_type",
+                "pointer_type",
             ],
             &format!("function parameter {}", object_name),
         );
@@ -117,6 +196,19 @@ impl Parse for Parameter {
             .and_then(Value::as_str)
             .map(|s| if s == "return" { "" } else { s })
             .map(ToOwned::to_owned);
+        let length_scale = match toml.lookup("length_scale").and_then(Value::as_str) {
+            None => None,
+            Some(val) => match LengthScale::from_str(val) {
+                Ok(val) => Some(val),
+                Err(error_str) => {
+                    error!(
+                        "Error: {} for parameter for object {}",
+                        error_str, object_name
+                    );
+                    None
+                }
+            },
+        };
         let string_type = toml.lookup("string_type").and_then(Value::as_str);
         let string_type = match string_type {
             None => None,
@@ -133,6 +225,47 @@ impl Parse for Parameter {
         };
         let callback_parameters =
             CallbackParameters::parse(toml.lookup("callback_parameter"), object_name);
+        let rename = toml
+            .lookup("rename")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
+        if !check_rename(&rename, object_name, &ident) {
+            return None;
+        }
+        let ref_mode = match toml.lookup("ref_mode").and_then(Value::as_str) {
+            None => None,
+            Some("none") => Some(RefMode::None),
+            Some("ref") => Some(RefMode::ByRef),
+            Some("ref-mut") => Some(RefMode::ByRefMut),
+            Some(val) => {
+                error!(
+                    "Error: Unknown ref_mode '{}' for parameter {} of object {}",
+                    val, ident, object_name
+                );
+                None
+            }
+        };
+        let variant_type = toml
+            .lookup("variant_type")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
+        let bytes_type = match toml.lookup("bytes_type").and_then(Value::as_str) {
+            None => None,
+            Some(val) => match BytesType::from_str(val) {
+                Ok(val) => Some(val),
+                Err(error_str) => {
+                    error!(
+                        "Error: {} for parameter for object {}",
+                        error_str, object_name
+                    );
+                    None
+                }
+            },
+        };
+        let pointer_type = toml
+            .lookup("pointer_type")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
 
         Some(Parameter {
             ident,
@@ -141,8 +274,14 @@ impl Parse for Parameter {
             mandatory,
             infallible,
             length_of,
+            length_scale,
             string_type,
             callback_parameters,
+            rename,
+            ref_mode,
+            variant_type,
+            bytes_type,
+            pointer_type,
         })
     }
 }
@@ -161,7 +300,14 @@ pub struct Return {
     pub mandatory: Option<Mandatory>,
     pub infallible: Option<Infallible>,
     pub bool_return_is_error: Option<String>,
+    /// Whether out parameters of functions returning a success boolean are
+    /// wrapped in an `Option` instead of being returned along with it
+    pub bool_return_is_option: Option<bool>,
     pub nullable_return_is_error: Option<String>,
+    /// Whether a negative `gssize` return is an error, the others being
+    /// returned as `usize`
+    pub negative_is_error: bool,
+    pub debug_assert_not_null: bool,
     pub use_return_for_result: Option<bool>,
     pub string_type: Option<StringType>,
     pub type_name: Option<String>,
@@ -175,7 +321,10 @@ impl Return {
                 mandatory: None,
                 infallible: None,
                 bool_return_is_error: None,
+                bool_return_is_option: None,
                 nullable_return_is_error: None,
+                negative_is_error: false,
+                debug_assert_not_null: false,
                 use_return_for_result: None,
                 string_type: None,
                 type_name: None,
@@ -189,7 +338,10 @@ impl Return {
                 "mandatory",
                 "infallible",
                 "bool_return_is_error",
+                "bool_return_is_option",
                 "nullable_return_is_error",
+                "negative_is_error",
+                "debug_assert_not_null",
                 "use_return_for_result",
                 "string_type",
                 "type",
@@ -210,10 +362,19 @@ impl Return {
             .lookup("bool_return_is_error")
             .and_then(Value::as_str)
             .map(ToOwned::to_owned);
+        let bool_return_is_option = v.lookup("bool_return_is_option").and_then(Value::as_bool);
         let nullable_return_is_error = v
             .lookup("nullable_return_is_error")
             .and_then(Value::as_str)
             .map(ToOwned::to_owned);
+        let negative_is_error = v
+            .lookup("negative_is_error")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let debug_assert_not_null = v
+            .lookup("debug_assert_not_null")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
         let use_return_for_result = v.lookup("use_return_for_result").and_then(Value::as_bool);
         let string_type = v.lookup("string_type").and_then(Value::as_str);
         let string_type = match string_type {
@@ -243,7 +404,10 @@ impl Return {
             mandatory,
             infallible,
             bool_return_is_error,
+            bool_return_is_option,
             nullable_return_is_error,
+            negative_is_error,
+            debug_assert_not_null,
             use_return_for_result,
             string_type,
             type_name,
@@ -266,6 +430,52 @@ fn check_rename(rename: &Option<String>, object_name: &str, function_name: &Iden
     true
 }
 
+/// How asynchronous completion is reported by a function
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AsyncStyle {
+    /// `GAsyncReadyCallback` + `*_finish` function
+    Gio,
+    /// A bare callback receiving the results
+    Callback,
+}
+
+impl FromStr for AsyncStyle {
+    type Err = String;
+    fn from_str(name: &str) -> Result<AsyncStyle, String> {
+        match name {
+            "gio" => Ok(AsyncStyle::Gio),
+            "callback" => Ok(AsyncStyle::Callback),
+            _ => Err(format!("Unknown async style '{}'", name)),
+        }
+    }
+}
+
+impl Default for AsyncStyle {
+    fn default() -> AsyncStyle {
+        AsyncStyle::Gio
+    }
+}
+
+/// Receiver of generated methods
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MethodSelf {
+    /// `&self`
+    Ref,
+    /// `&mut self`
+    Mut,
+}
+
+impl FromStr for MethodSelf {
+    type Err = String;
+    fn from_str(name: &str) -> Result<MethodSelf, String> {
+        match name {
+            "ref" => Ok(MethodSelf::Ref),
+            "mut" => Ok(MethodSelf::Mut),
+            _ => Err(format!("Unknown method self '{}'", name)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Function {
     pub ident: Ident,
@@ -275,16 +485,37 @@ pub struct Function {
     pub parameters: Parameters,
     pub ret: Return,
     pub doc_hidden: bool,
+    /// Parameter the borrowed return value lives as long as, only for
+    /// ref-only records
+    pub lifetime_bound_to: Option<String>,
     pub doc_ignore_parameters: HashSet<String>,
     pub is_windows_utf8: bool,
-    pub disable_length_detect: bool,
+    /// Overrides `options.disable_length_detect`
+    pub disable_length_detect: Option<bool>,
     pub doc_trait_name: Option<String>,
     pub no_future: bool,
+    /// Generate an `IntoFuture` request type for the `{name}_future` method
+    pub into_future: bool,
+    pub async_style: AsyncStyle,
+    /// Overrides the receiver of the method
+    pub method_self: Option<MethodSelf>,
     pub unsafe_: bool,
+    pub safety_doc: Option<String>,
     pub rename: Option<String>,
     pub bypass_auto_rename: bool,
     pub is_constructor: Option<bool>,
     pub assertion: Option<SafetyAssertionMode>,
+    /// Template file for the body, relative to the config file
+    pub body_template: Option<String>,
+    /// C identifier of the function completing this async function
+    pub finish_function: Option<String>,
+    /// Generate a `{name}_weak` variant holding a weak reference for a
+    /// notified callback receiving the object itself, `false` only silences
+    /// the warning about the reference cycle
+    pub weak_variant: Option<bool>,
+    /// Generate a `{name}_optional` variant only requesting the optional out
+    /// parameters asked for
+    pub optional_variant: bool,
 }
 
 impl Parse for Function {
@@ -309,17 +540,26 @@ impl Parse for Function {
                 "return",
                 "name",
                 "doc_hidden",
+                "lifetime_bound_to",
                 "doc_ignore_parameters",
                 "is_windows_utf8",
                 "disable_length_detect",
                 "pattern",
                 "doc_trait_name",
                 "no_future",
+                "into_future",
+                "async_style",
+                "method_self",
                 "unsafe",
+                "safety_doc",
                 "rename",
                 "bypass_auto_rename",
                 "constructor",
                 "assertion",
+                "body_template",
+                "finish_function",
+                "weak_variant",
+                "optional_variant",
             ],
             &format!("function {}", object_name),
         );
@@ -355,6 +595,10 @@ impl Parse for Function {
             .lookup("doc_hidden")
             .and_then(Value::as_bool)
             .unwrap_or(false);
+        let lifetime_bound_to = toml
+            .lookup("lifetime_bound_to")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
         let doc_ignore_parameters = toml
             .lookup_vec("doc_ignore_parameters", "Invalid doc_ignore_parameters")
             .map(|v| {
@@ -369,8 +613,7 @@ impl Parse for Function {
             .unwrap_or(false);
         let disable_length_detect = toml
             .lookup("disable_length_detect")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
+            .and_then(Value::as_bool);
         let doc_trait_name = toml
             .lookup("doc_trait_name")
             .and_then(Value::as_str)
@@ -379,10 +622,48 @@ impl Parse for Function {
             .lookup("no_future")
             .and_then(Value::as_bool)
             .unwrap_or(false);
+        let into_future = toml
+            .lookup("into_future")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        let async_style = match toml
+            .lookup("async_style")
+            .and_then(Value::as_str)
+            .map(AsyncStyle::from_str)
+        {
+            Some(Ok(async_style)) => async_style,
+            Some(Err(err)) => {
+                error!("{} for {}::{}", err, object_name, ident);
+                AsyncStyle::default()
+            }
+            None => AsyncStyle::default(),
+        };
+        let method_self = match toml
+            .lookup("method_self")
+            .and_then(Value::as_str)
+            .map(MethodSelf::from_str)
+        {
+            Some(Ok(method_self)) => Some(method_self),
+            Some(Err(err)) => {
+                error!("{} for {}::{}", err, object_name, ident);
+                None
+            }
+            None => None,
+        };
         let unsafe_ = toml
             .lookup("unsafe")
             .and_then(Value::as_bool)
             .unwrap_or(false);
+        let safety_doc = toml
+            .lookup("safety_doc")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
+        if safety_doc.is_some() && !unsafe_ {
+            error!(
+                "\"safety_doc\" is only used for \"unsafe\" functions, ignoring it for {}::{}",
+                object_name, ident
+            );
+        }
         let rename = toml
             .lookup("rename")
             .and_then(Value::as_str)
@@ -404,6 +685,19 @@ impl Parse for Function {
             error!("{}", err);
         }
         let assertion = assertion.ok().flatten();
+        let body_template = toml
+            .lookup("body_template")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
+        let finish_function = toml
+            .lookup("finish_function")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
+        let weak_variant = toml.lookup("weak_variant").and_then(Value::as_bool);
+        let optional_variant = toml
+            .lookup("optional_variant")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
 
         Some(Function {
             ident,
@@ -413,16 +707,25 @@ impl Parse for Function {
             parameters,
             ret,
             doc_hidden,
+            lifetime_bound_to,
             doc_ignore_parameters,
             is_windows_utf8,
             disable_length_detect,
             doc_trait_name,
             no_future,
+            into_future,
+            async_style,
+            method_self,
             unsafe_,
+            safety_doc,
             rename,
             bypass_auto_rename,
             is_constructor,
             assertion,
+            body_template,
+            finish_function,
+            weak_variant,
+            optional_variant,
         })
     }
 }
@@ -563,6 +866,61 @@ name = "func1"
         assert_eq!(f.ret.nullable, None);
     }
 
+    #[test]
+    fn function_parse_return_debug_assert_not_null() {
+        let toml = toml(
+            r#"
+name = "func1"
+[return]
+nullable = false
+debug_assert_not_null = true
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert_eq!(f.ret.nullable, Some(Nullable(false)));
+        assert!(f.ret.debug_assert_not_null);
+    }
+
+    #[test]
+    fn function_parse_return_bool_return_is_option() {
+        let toml = toml(
+            r#"
+name = "func1"
+[return]
+bool_return_is_option = false
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert_eq!(f.ret.bool_return_is_option, Some(false));
+        assert_eq!(f.ret.nullable, None);
+    }
+
+    #[test]
+    fn function_parse_return_negative_is_error() {
+        let toml = toml(
+            r#"
+name = "func1"
+[return]
+negative_is_error = true
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert!(f.ret.negative_is_error);
+    }
+
+    #[test]
+    fn function_parse_into_future() {
+        let toml = toml(
+            r#"
+name = "read_async"
+into_future = true
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert!(f.into_future);
+        assert!(!f.no_future);
+    }
+
     #[test]
     fn function_parse_parameters() {
         let toml = toml(
@@ -738,6 +1096,202 @@ rename = "anoth er"
         assert!(f.is_none());
     }
 
+    #[test]
+    fn function_parse_weak_variant() {
+        let toml = toml(
+            r#"
+name = "func1"
+weak_variant = true
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert_eq!(f.weak_variant, Some(true));
+    }
+
+    #[test]
+    fn function_parse_optional_variant() {
+        let toml = toml(
+            r#"
+name = "func1"
+optional_variant = true
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert!(f.optional_variant);
+    }
+
+    #[test]
+    fn function_parse_parameter_rename() {
+        let toml = toml(
+            r#"
+name = "func1"
+[[parameter]]
+name = "type_"
+rename = "kind"
+[[parameter]]
+name = "par2"
+rename = "par 2"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        let pars = f.parameters;
+        assert_eq!(pars.len(), 1);
+        assert_eq!(pars[0].ident, Ident::Name("type_".into()));
+        assert_eq!(pars[0].rename, Some("kind".to_owned()));
+    }
+
+    #[test]
+    fn function_parse_parameter_ref_mode() {
+        let toml = toml(
+            r#"
+name = "func1"
+[[parameter]]
+name = "par1"
+ref_mode = "ref"
+[[parameter]]
+name = "par2"
+ref_mode = "none"
+[[parameter]]
+name = "par3"
+ref_mode = "ref-mut"
+[[parameter]]
+name = "par4"
+ref_mode = "ref-fake"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        let pars = f.parameters;
+        assert_eq!(pars.len(), 4);
+        assert_eq!(pars[0].ref_mode, Some(RefMode::ByRef));
+        assert_eq!(pars[1].ref_mode, Some(RefMode::None));
+        assert_eq!(pars[2].ref_mode, Some(RefMode::ByRefMut));
+        assert_eq!(pars[3].ref_mode, None);
+    }
+
+    #[test]
+    fn function_parse_parameter_variant_type() {
+        let toml = toml(
+            r#"
+name = "activate"
+[[parameter]]
+name = "parameter"
+variant_type = "(u32, String)"
+[[parameter]]
+name = "par2"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        let pars = f.parameters;
+        assert_eq!(pars.len(), 2);
+        assert_eq!(pars[0].variant_type, Some("(u32, String)".to_owned()));
+        assert_eq!(pars[1].variant_type, None);
+    }
+
+    #[test]
+    fn function_parse_parameter_bytes_type() {
+        let toml = toml(
+            r#"
+name = "write"
+[[parameter]]
+name = "par1"
+bytes_type = "glib::Bytes"
+[[parameter]]
+name = "par2"
+bytes_type = "&[u8]"
+[[parameter]]
+name = "par3"
+bytes_type = "Vec<u8>"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        let pars = f.parameters;
+        assert_eq!(pars.len(), 3);
+        assert_eq!(pars[0].bytes_type, Some(BytesType::Bytes));
+        assert_eq!(pars[1].bytes_type, Some(BytesType::Slice));
+        assert_eq!(pars[2].bytes_type, None);
+    }
+
+    #[test]
+    fn function_parse_parameter_pointer_type() {
+        let toml = toml(
+            r#"
+name = "add"
+[[parameter]]
+name = "child"
+pointer_type = "Gtk.Widget"
+[[parameter]]
+name = "data"
+pointer_type = "gpointer"
+[[parameter]]
+name = "par3"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        let pars = f.parameters;
+        assert_eq!(pars.len(), 3);
+        assert_eq!(pars[0].pointer_type, Some("Gtk.Widget".to_owned()));
+        assert_eq!(pars[1].pointer_type, Some("gpointer".to_owned()));
+        assert_eq!(pars[2].pointer_type, None);
+    }
+
+    #[test]
+    fn function_parse_unsafe_safety_doc() {
+        let toml = toml(
+            r#"
+name = "func1"
+unsafe = true
+safety_doc = "The object must not be floating."
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert!(f.unsafe_);
+        assert_eq!(
+            f.safety_doc,
+            Some("The object must not be floating.".to_owned())
+        );
+    }
+
+    #[test]
+    fn function_parse_async_style() {
+        let callback = toml(
+            r#"
+name = "func1"
+async_style = "callback"
+"#,
+        );
+        let f = Function::parse(&callback, "a").unwrap();
+        assert_eq!(f.async_style, AsyncStyle::Callback);
+
+        let default = toml(
+            r#"
+name = "func1"
+"#,
+        );
+        let f = Function::parse(&default, "a").unwrap();
+        assert_eq!(f.async_style, AsyncStyle::Gio);
+    }
+
+    #[test]
+    fn function_parse_method_self() {
+        let mutable = toml(
+            r#"
+name = "func1"
+method_self = "mut"
+"#,
+        );
+        let f = Function::parse(&mutable, "a").unwrap();
+        assert_eq!(f.method_self, Some(MethodSelf::Mut));
+
+        let invalid = toml(
+            r#"
+name = "func1"
+method_self = "owned"
+"#,
+        );
+        let f = Function::parse(&invalid, "a").unwrap();
+        assert_eq!(f.method_self, None);
+    }
+
     #[test]
     fn function_bypass_auto_rename() {
         let toml = toml(
@@ -791,6 +1345,44 @@ name = "func1"
         assert_eq!(f.ret.mandatory, Some(Mandatory(false)));
     }
 
+    #[test]
+    fn function_parse_lifetime_bound_to() {
+        let toml = toml(
+            r#"
+name = "func1"
+lifetime_bound_to = "container"
+"#,
+        );
+        let f = Function::parse(&toml, "a").unwrap();
+        assert_eq!(f.lifetime_bound_to.as_deref(), Some("container"));
+    }
+
+    #[test]
+    fn parse_parameter_length_scale() {
+        let toml = toml(
+            r#"
+name = "func1"
+    [[parameter]]
+    name = "len1"
+    length_scale = "bytes"
+    [[parameter]]
+    name = "len2"
+    length_scale = "len * 2"
+    [[parameter]]
+    name = "len3"
+    length_scale = "length"
+"#,
+        );
+        let f = Function::parse(&toml, "a");
+        let f = f.unwrap();
+        assert_eq!(f.parameters[0].length_scale, Some(LengthScale::Bytes));
+        assert_eq!(
+            f.parameters[1].length_scale,
+            Some(LengthScale::Expression("len * 2".into()))
+        );
+        assert_eq!(f.parameters[2].length_scale, None);
+    }
+
     #[test]
     fn parse_parameter_mandatory_default() {
         let toml = toml(
@@ -1,11 +1,11 @@
 use super::{
     child_properties::ChildProperties,
     constants::Constants,
-    derives::Derives,
+    derives::{DeriveProfile, Derives},
     functions::Functions,
     ident::Ident,
     members::Members,
-    properties::Properties,
+    properties::{Properties, PropertyConflict},
     signals::{Signal, Signals},
 };
 use crate::{
@@ -72,6 +72,10 @@ pub struct GObject {
     pub members: Members,
     pub properties: Properties,
     pub derives: Option<Derives>,
+    /// Overrides `options.derive_profile` for this type
+    pub derive_profile: Option<DeriveProfile>,
+    /// Overrides `options.property_conflict` for this type
+    pub property_conflict: Option<PropertyConflict>,
     pub status: GStatus,
     pub module_name: Option<String>,
     pub version: Option<Version>,
@@ -83,17 +87,34 @@ pub struct GObject {
     pub concurrency: library::Concurrency,
     pub ref_mode: Option<ref_mode::RefMode>,
     pub must_use: bool,
+    pub doc_hidden: bool,
+    /// Generate `ALL` and `iter()` helpers listing the enumeration members
+    pub generate_enum_iter: bool,
+    /// Enumeration whose values are the bit positions of this flags type
+    pub flags_of: Option<String>,
     pub conversion_type: Option<ConversionType>,
     pub generate_display_trait: bool,
+    /// Property or method providing the `Display` output of this object
+    pub display_from: Option<String>,
+    /// Expose the class structure with read-only access to the virtual functions
+    pub generate_class_record: bool,
+    /// Generate the `Impl` traits overriding the virtual functions in subclasses
+    pub generate_subclass: bool,
     pub trust_return_value_nullability: bool,
     pub manual_traits: Vec<String>,
     pub align: Option<u32>,
+    /// Names of the unions and records nested in this type, by field name
+    pub anonymous_names: BTreeMap<String, String>,
     pub generate_builder: bool,
     pub builder_postprocess: Option<String>,
     pub boxed_inline: bool,
     pub init_function_expression: Option<String>,
     pub copy_into_function_expression: Option<String>,
     pub clear_function_expression: Option<String>,
+    /// Only generate a borrowed `{Name}Ref` wrapper for this record
+    pub ref_only: bool,
+    /// Generate getters borrowing the records embedded in this record
+    pub field_ref_accessors: bool,
 }
 
 impl Default for GObject {
@@ -106,6 +127,8 @@ impl Default for GObject {
             members: Members::new(),
             properties: Properties::new(),
             derives: None,
+            derive_profile: None,
+            property_conflict: None,
             status: Default::default(),
             module_name: None,
             version: None,
@@ -117,17 +140,26 @@ impl Default for GObject {
             concurrency: Default::default(),
             ref_mode: None,
             must_use: false,
+            doc_hidden: false,
+            generate_enum_iter: false,
+            flags_of: None,
             conversion_type: None,
             generate_display_trait: true,
+            display_from: None,
+            generate_class_record: false,
+            generate_subclass: false,
             trust_return_value_nullability: false,
             manual_traits: Vec::default(),
             align: None,
+            anonymous_names: BTreeMap::new(),
             generate_builder: false,
             builder_postprocess: None,
             boxed_inline: false,
             init_function_expression: None,
             copy_into_function_expression: None,
             clear_function_expression: None,
+            ref_only: false,
+            field_ref_accessors: false,
         }
     }
 }
@@ -255,6 +287,8 @@ fn parse_object(
             "member",
             "property",
             "derive",
+            "derive_profile",
+            "property_conflict",
             "module_name",
             "version",
             "concurrency",
@@ -268,16 +302,25 @@ fn parse_object(
             "trait_name",
             "cfg_condition",
             "must_use",
+            "doc_hidden",
+            "generate_enum_iter",
+            "flags_of",
             "generate_display_trait",
+            "display_from",
+            "generate_class_record",
+            "generate_subclass",
             "trust_return_value_nullability",
             "manual_traits",
             "align",
+            "anonymous_names",
             "generate_builder",
             "builder_postprocess",
             "boxed_inline",
             "init_function_expression",
             "copy_into_function_expression",
             "clear_function_expression",
+            "record_boxed",
+            "field_ref_accessors",
         ],
         &format!("object {}", name),
     );
@@ -315,6 +358,30 @@ fn parse_object(
     let derives = toml_object
         .lookup("derive")
         .map(|derives| Derives::parse(Some(derives), &name));
+    let derive_profile = match toml_object
+        .lookup("derive_profile")
+        .and_then(Value::as_str)
+        .map(DeriveProfile::from_str)
+    {
+        Some(Ok(derive_profile)) => Some(derive_profile),
+        Some(Err(err)) => {
+            error!("{} for object {}", err, name);
+            None
+        }
+        None => None,
+    };
+    let property_conflict = match toml_object
+        .lookup("property_conflict")
+        .and_then(Value::as_str)
+        .map(PropertyConflict::from_str)
+    {
+        Some(Ok(property_conflict)) => Some(property_conflict),
+        Some(Err(err)) => {
+            error!("{} for object {}", err, name);
+            None
+        }
+        None => None,
+    };
     let module_name = toml_object
         .lookup("module_name")
         .and_then(Value::as_str)
@@ -351,10 +418,34 @@ fn parse_object(
         .lookup("must_use")
         .and_then(Value::as_bool)
         .unwrap_or(false);
+    let doc_hidden = toml_object
+        .lookup("doc_hidden")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let flags_of = toml_object
+        .lookup("flags_of")
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned);
+    let generate_enum_iter = toml_object
+        .lookup("generate_enum_iter")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
     let generate_display_trait = toml_object
         .lookup("generate_display_trait")
         .and_then(Value::as_bool)
         .unwrap_or(default_generate_display_trait);
+    let display_from = toml_object
+        .lookup("display_from")
+        .and_then(Value::as_str)
+        .map(ToOwned::to_owned);
+    let generate_class_record = toml_object
+        .lookup("generate_class_record")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let generate_subclass = toml_object
+        .lookup("generate_subclass")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
     let trust_return_value_nullability = toml_object
         .lookup("trust_return_value_nullability")
         .and_then(Value::as_bool)
@@ -381,6 +472,16 @@ fn parse_object(
                 Some(v as u32)
             }
         });
+    let anonymous_names = toml_object
+        .lookup("anonymous_names")
+        .and_then(Value::as_table)
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(|(field, name)| Some((field.clone(), name.as_str()?.to_owned())))
+                .collect()
+        })
+        .unwrap_or_default();
     let generate_builder = toml_object
         .lookup("generate_builder")
         .and_then(Value::as_bool)
@@ -408,6 +509,22 @@ fn parse_object(
         .and_then(Value::as_str)
         .map(ToOwned::to_owned);
 
+    let ref_only = match toml_object.lookup("record_boxed").and_then(Value::as_str) {
+        Some("ref-only") => true,
+        Some(other) => {
+            error!("Unknown record_boxed value `{}` for object {}", other, name);
+            false
+        }
+        None => false,
+    };
+    if ref_only && boxed_inline {
+        panic!("`record_boxed = \"ref-only\"` can't be combined with `boxed_inline`");
+    }
+    let field_ref_accessors = toml_object
+        .lookup("field_ref_accessors")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
     if boxed_inline
         && !((init_function_expression.is_none()
             && copy_into_function_expression.is_none()
@@ -461,6 +578,8 @@ fn parse_object(
         members,
         properties,
         derives,
+        derive_profile,
+        property_conflict,
         status,
         module_name,
         version,
@@ -472,17 +591,26 @@ fn parse_object(
         concurrency,
         ref_mode,
         must_use,
+        doc_hidden,
+        generate_enum_iter,
+        flags_of,
         conversion_type,
         generate_display_trait,
+        display_from,
+        generate_class_record,
+        generate_subclass,
         trust_return_value_nullability,
         manual_traits,
         align,
+        anonymous_names,
         generate_builder,
         builder_postprocess,
         boxed_inline,
         init_function_expression,
         copy_into_function_expression,
         clear_function_expression,
+        ref_only,
+        field_ref_accessors,
     }
 }
 
@@ -541,6 +669,41 @@ fn parse_status_shorthand(
     }
 }
 
+/// Ignores the objects and functions of `options.strip`, given as `Ns.Type`
+/// or `Ns.Type.function` (`Ns.*.function` for global functions).
+pub fn apply_strip(objects: &mut GObjects, strip: &[String]) -> Result<(), String> {
+    for name in strip {
+        if let Some(object) = objects.get_mut(name) {
+            object.status = GStatus::Ignore;
+            continue;
+        }
+        let object = name
+            .rsplit_once('.')
+            .and_then(|(object_name, function_name)| {
+                objects
+                    .get_mut(object_name)
+                    .map(|object| (object, function_name))
+            });
+        match object {
+            Some((object, function_name)) => {
+                let mut toml = toml::value::Table::new();
+                toml.insert("name".into(), Value::String(function_name.into()));
+                toml.insert("ignore".into(), Value::Boolean(true));
+                let function = super::functions::Function::parse(&Value::Table(toml), name)
+                    .ok_or_else(|| format!("Invalid function `{}` in options.strip", name))?;
+                object.functions.push(function);
+            }
+            None => {
+                return Err(format!(
+                    "`{}` in options.strip is neither a configured object nor a function of one",
+                    name
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
 pub fn resolve_type_ids(objects: &mut GObjects, library: &Library) {
     let ns = library.namespace(MAIN_NAMESPACE);
     let global_functions_name = format!("{}.*", ns.name);
@@ -570,6 +733,7 @@ pub fn resolve_type_ids(objects: &mut GObjects, library: &Library) {
 mod tests {
     use super::*;
     use crate::analysis::conversion_type::ConversionType;
+    use crate::config::matchable::Matchable;
     use crate::library::Concurrency;
 
     fn toml(input: &str) -> ::toml::Value {
@@ -578,6 +742,21 @@ mod tests {
         value.unwrap()
     }
 
+    #[test]
+    fn doc_hidden() {
+        let toml = &toml(
+            r#"
+name = "Test"
+status = "manual"
+doc_hidden = true
+"#,
+        );
+
+        let object = parse_object(toml, Concurrency::default(), false, false, false);
+        assert_eq!(object.status, GStatus::Manual);
+        assert!(object.doc_hidden);
+    }
+
     #[test]
     fn conversion_type_default() {
         let toml = &toml(
@@ -685,4 +864,57 @@ status = "generate"
             }),
         );
     }
+
+    #[test]
+    fn record_boxed_ref_only() {
+        let toml = &toml(
+            r#"
+name = "Test"
+status = "generate"
+record_boxed = "ref-only"
+"#,
+        );
+
+        let object = parse_object(toml, Concurrency::default(), false, false, false);
+        assert!(object.ref_only);
+    }
+
+    #[test]
+    fn record_boxed_unknown() {
+        let toml = &toml(
+            r#"
+name = "Test"
+status = "generate"
+record_boxed = "shared"
+"#,
+        );
+
+        let object = parse_object(toml, Concurrency::default(), false, false, false);
+        assert!(!object.ref_only);
+    }
+
+    #[test]
+    fn strip() {
+        let mut objects = GObjects::new();
+        for name in &["Gtk.Widget", "Gtk.Window"] {
+            let toml = &toml(&format!("name = \"{}\"\nstatus = \"generate\"", name));
+            objects.insert(
+                name.to_string(),
+                parse_object(toml, Concurrency::default(), false, false, false),
+            );
+        }
+
+        apply_strip(
+            &mut objects,
+            &["Gtk.Window".to_owned(), "Gtk.Widget.destroy".to_owned()],
+        )
+        .unwrap();
+        assert!(objects["Gtk.Window"].status.ignored());
+        assert!(objects["Gtk.Widget"].status.need_generate());
+        let functions = objects["Gtk.Widget"].functions.matched("destroy");
+        assert_eq!(functions.len(), 1);
+        assert!(functions[0].status.ignored());
+
+        assert!(apply_strip(&mut objects, &["Gtk.Button.destroy".to_owned()]).is_err());
+    }
 }
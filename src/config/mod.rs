@@ -1,3 +1,4 @@
+pub mod bytes_type;
 mod child_properties;
 #[allow(clippy::module_inception)]
 pub mod config;
@@ -16,14 +17,16 @@ pub mod properties;
 pub mod property_generate_flags;
 pub mod signals;
 pub mod string_type;
+pub mod time_mapping;
 pub mod work_mode;
 
 pub use self::{
     child_properties::{ChildProperties, ChildProperty},
-    config::Config,
+    config::{Config, ConfigOptions},
     external_libraries::ExternalLibrary,
     gobjects::GObject,
     property_generate_flags::PropertyGenerateFlags,
     string_type::StringType,
+    time_mapping::TimeMapping,
     work_mode::WorkMode,
 };
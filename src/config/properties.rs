@@ -4,8 +4,38 @@ use super::{
 };
 use crate::version::Version;
 use log::error;
+use std::str::FromStr;
 use toml::Value;
 
+/// What to do when a property accessor has the name of a method
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PropertyConflict {
+    /// Only generate the method
+    Method,
+    /// Only generate the property accessor
+    Property,
+    /// Generate both, adding a `_property` suffix to the accessor
+    Rename,
+}
+
+impl Default for PropertyConflict {
+    fn default() -> PropertyConflict {
+        PropertyConflict::Rename
+    }
+}
+
+impl FromStr for PropertyConflict {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "method" => Ok(PropertyConflict::Method),
+            "property" => Ok(PropertyConflict::Property),
+            "rename" => Ok(PropertyConflict::Rename),
+            _ => Err(format!("Wrong property conflict policy '{}'", s)),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Property {
     pub ident: Ident,
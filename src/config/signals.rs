@@ -40,6 +40,8 @@ pub struct Parameter {
     pub nullable: Option<Nullable>,
     pub transformation: Option<TransformationType>,
     pub new_name: Option<String>,
+    /// Rust type converted from the `GVariant` of the parameter
+    pub variant_type: Option<String>,
 }
 
 impl Parse for Parameter {
@@ -55,7 +57,14 @@ impl Parse for Parameter {
             }
         };
         toml.check_unwanted(
-            &["nullable", "transformation", "new_name", "name", "pattern"],
+            &[
+                "nullable",
+                "transformation",
+                "new_name",
+                "variant_type",
+                "name",
+                "pattern",
+            ],
             &format!("parameter {}", object_name),
         );
 
@@ -78,12 +87,17 @@ impl Parse for Parameter {
             .lookup("new_name")
             .and_then(Value::as_str)
             .map(ToOwned::to_owned);
+        let variant_type = toml
+            .lookup("variant_type")
+            .and_then(Value::as_str)
+            .map(ToOwned::to_owned);
 
         Some(Parameter {
             ident,
             nullable,
             transformation,
             new_name,
+            variant_type,
         })
     }
 }
@@ -100,7 +114,7 @@ pub type Parameters = Vec<Parameter>;
 pub struct Signal {
     pub ident: Ident,
     pub status: GStatus,
-    pub inhibit: bool,
+    pub inhibit: Option<bool>,
     pub version: Option<Version>,
     pub parameters: Parameters,
     pub ret: Return,
@@ -130,6 +144,7 @@ impl Signal {
                 "ignore",
                 "manual",
                 "inhibit",
+                "return_inhibit",
                 "version",
                 "parameter",
                 "return",
@@ -161,9 +176,9 @@ impl Signal {
         };
 
         let inhibit = toml
-            .lookup("inhibit")
-            .and_then(Value::as_bool)
-            .unwrap_or(false);
+            .lookup("return_inhibit")
+            .or_else(|| toml.lookup("inhibit"))
+            .and_then(Value::as_bool);
         let version = toml
             .lookup("version")
             .and_then(Value::as_str)
@@ -261,4 +276,42 @@ manual = true
         let f = Signal::parse(&toml, "a", Default::default()).unwrap();
         assert!(f.status.manual());
     }
+
+    #[test]
+    fn signal_parse_return_inhibit() {
+        let toml = toml(
+            r#"
+name = "signal1"
+return_inhibit = true
+"#,
+        );
+        let f = Signal::parse(&toml, "a", Default::default()).unwrap();
+        assert_eq!(f.inhibit, Some(true));
+    }
+
+    #[test]
+    fn signal_parse_inhibit_default() {
+        let toml = toml(
+            r#"
+name = "signal1"
+"#,
+        );
+        let f = Signal::parse(&toml, "a", Default::default()).unwrap();
+        assert_eq!(f.inhibit, None);
+    }
+
+    #[test]
+    fn signal_parse_parameter_variant_type() {
+        let toml = toml(
+            r#"
+name = "activate"
+[[parameter]]
+name = "parameter"
+variant_type = "i32"
+"#,
+        );
+        let f = Signal::parse(&toml, "a", Default::default()).unwrap();
+        assert_eq!(f.parameters.len(), 1);
+        assert_eq!(f.parameters[0].variant_type, Some("i32".to_owned()));
+    }
 }
@@ -0,0 +1,28 @@
+use std::str::FromStr;
+
+/// Types exposing the GLib time types in the generated functions
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TimeMapping {
+    /// `glib::DateTime`, as any other type
+    Glib,
+    /// `std::time::SystemTime` for `GDateTime` and `GTimeVal`, converted by
+    /// the generated `time.rs`
+    Std,
+}
+
+impl Default for TimeMapping {
+    fn default() -> TimeMapping {
+        TimeMapping::Glib
+    }
+}
+
+impl FromStr for TimeMapping {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "glib" => Ok(TimeMapping::Glib),
+            "std" => Ok(TimeMapping::Std),
+            _ => Err(format!("Wrong time mapping '{}'", s)),
+        }
+    }
+}
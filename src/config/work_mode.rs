@@ -2,15 +2,16 @@ use std::str::FromStr;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum WorkMode {
-    Normal,          // generate widgets etc.
-    Sys,             // generate -sys with FFI
-    Doc,             // generate documentation file
-    DisplayNotBound, // Show not bound types
+    Normal,           // generate widgets etc.
+    Sys,              // generate -sys with FFI
+    Doc,              // generate documentation file
+    DisplayNotBound,  // Show not bound types
+    ConsistencyCheck, // check normal generation against the existing -sys crate
 }
 
 impl WorkMode {
     pub fn is_normal(self) -> bool {
-        matches!(self, WorkMode::Normal)
+        matches!(self, WorkMode::Normal | WorkMode::ConsistencyCheck)
     }
 
     pub fn is_generate_rust_files(self) -> bool {
@@ -32,6 +33,7 @@ impl FromStr for WorkMode {
             "sys" => Ok(WorkMode::Sys),
             "doc" => Ok(WorkMode::Doc),
             "not_bound" => Ok(WorkMode::DisplayNotBound),
+            "consistency_check" => Ok(WorkMode::ConsistencyCheck),
             _ => Err(format!("Wrong work mode '{}'", s)),
         }
     }
@@ -0,0 +1,335 @@
+//! Checks that the normal mode analysis only relies on symbols and types
+//! which are exported by the already generated -sys crate, with the
+//! signatures the current gir files would generate for them.
+
+use crate::{
+    analysis::functions,
+    codegen::sys::{functions::function_signature, EnumRepr},
+    env::Env,
+    library::{self, Type},
+};
+use log::{error, info};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+static SYS_ITEM: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\bpub\s+(?:fn|struct|union|type|const|static)\s+(?:mut\s+)?(\w+)").unwrap()
+});
+
+static SYS_FN: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bpub\s+fn\s+(\w+)\s*(\([^;{]*\)(?:\s*->[^;{]+)?);").unwrap());
+
+static SYS_TYPE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\bpub\s+type\s+(\w+)\s*=\s*([^;]+);").unwrap());
+
+static WHITESPACE: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s+").unwrap());
+
+static PUNCTUATION: Lazy<Regex> = Lazy::new(|| Regex::new(r"\s*([(),<>:])\s*").unwrap());
+
+static PARAMETER_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b\w+:([^:])").unwrap());
+
+/// Returns the number of mismatches found between the analysis and the -sys crate.
+pub fn check(env: &Env) -> Result<usize, String> {
+    let sys_src = env.config.sys_crate_path.join("src");
+    let sys_items = read_sys_items(&sys_src)?;
+    if sys_items.names.is_empty() {
+        return Err(format!(
+            "No items found in -sys crate at {}",
+            sys_src.display()
+        ));
+    }
+    info!(
+        "Checking consistency against {} items in {}",
+        sys_items.names.len(),
+        sys_src.display()
+    );
+
+    let mut checker = Checker {
+        env,
+        sys_items,
+        library_functions: library_functions(env),
+        mismatches: 0,
+    };
+
+    for obj in env.analysis.objects.values() {
+        if obj.type_id.ns_id != library::MAIN_NAMESPACE {
+            continue;
+        }
+        checker.type_(&obj.full_name, &obj.c_type);
+        checker.type_getter(&obj.full_name, &obj.get_type);
+        checker.functions(&obj.functions);
+    }
+
+    for record in env.analysis.records.values() {
+        if record.type_id.ns_id != library::MAIN_NAMESPACE {
+            continue;
+        }
+        if let Some(c_type) = env.library.type_(record.type_id).get_glib_name() {
+            checker.type_(&record.full_name, c_type);
+        }
+        if let Some((get_type, _)) = &record.glib_get_type {
+            checker.type_getter(&record.full_name, get_type);
+        }
+        checker.functions(&record.functions);
+    }
+
+    for info in env.analysis.enumerations.iter() {
+        if let Type::Enumeration(enum_) = env.library.type_(info.type_id) {
+            checker.type_alias(
+                &info.full_name,
+                &enum_.c_type,
+                EnumRepr::of(enum_).ffi_type(),
+            );
+        }
+        checker.functions(&info.functions);
+    }
+
+    for info in env.analysis.flags.iter() {
+        if let Type::Bitfield(flags) = env.library.type_(info.type_id) {
+            checker.type_alias(&info.full_name, &flags.c_type, "c_uint");
+        }
+        checker.functions(&info.functions);
+    }
+
+    if let Some(global_functions) = &env.analysis.global_functions {
+        checker.functions(&global_functions.functions);
+    }
+
+    for constant in &env.analysis.constants {
+        checker.symbol(&constant.name, &constant.glib_name);
+    }
+
+    Ok(checker.mismatches)
+}
+
+/// Items declared by the -sys crate
+#[derive(Default)]
+struct SysItems {
+    names: HashSet<String>,
+    /// Normalized signatures of the functions, without the parameter names
+    signatures: HashMap<String, String>,
+    /// Normalized targets of the type aliases, several with `#[cfg]` variants
+    aliases: HashMap<String, Vec<String>>,
+}
+
+struct Checker<'a> {
+    env: &'a Env,
+    sys_items: SysItems,
+    library_functions: HashMap<&'a str, &'a library::Function>,
+    mismatches: usize,
+}
+
+impl Checker<'_> {
+    fn symbol(&mut self, owner: &str, symbol: &str) -> bool {
+        if !self.sys_items.names.contains(symbol) {
+            error!(
+                "`{}`: symbol `{}` is missing from -sys crate",
+                owner, symbol
+            );
+            self.mismatches += 1;
+            return false;
+        }
+        true
+    }
+
+    fn type_(&mut self, owner: &str, c_type: &str) -> bool {
+        if !self.sys_items.names.contains(c_type) {
+            error!("`{}`: type `{}` is missing from -sys crate", owner, c_type);
+            self.mismatches += 1;
+            return false;
+        }
+        true
+    }
+
+    /// Checks that `c_type` is declared as an alias of the integer `target`.
+    fn type_alias(&mut self, owner: &str, c_type: &str, target: &str) {
+        if !self.type_(owner, c_type) {
+            return;
+        }
+        let found = self.sys_items.aliases.get(c_type);
+        if !found.map_or(false, |aliases| aliases.iter().any(|a| a == target)) {
+            error!(
+                "`{}`: type `{}` is declared as `{}` instead of `{}` in -sys crate",
+                owner,
+                c_type,
+                found.map_or_else(|| "a non-alias".to_owned(), |aliases| aliases.join(" | ")),
+                target
+            );
+            self.mismatches += 1;
+        }
+    }
+
+    fn type_getter(&mut self, owner: &str, get_type: &str) {
+        if !get_type.is_empty() && get_type != "intern" {
+            self.symbol(owner, get_type);
+        }
+    }
+
+    fn functions(&mut self, functions: &[functions::Info]) {
+        for func in functions {
+            if func.status.need_generate()
+                && func.visibility.code_visible()
+                && self.symbol(&func.name, &func.glib_name)
+            {
+                self.signature(&func.name, &func.glib_name);
+            }
+        }
+    }
+
+    /// Checks the signature of `symbol` against the one the gir files would
+    /// generate, skipping the functions the -sys generation comments out.
+    fn signature(&mut self, owner: &str, symbol: &str) {
+        let func = match self.library_functions.get(symbol) {
+            Some(func) => func,
+            None => return,
+        };
+        let (commented, expected) = function_signature(self.env, func, true);
+        if commented {
+            return;
+        }
+        let expected = normalize(&expected);
+        match self.sys_items.signatures.get(symbol) {
+            Some(found) if *found != expected => {
+                error!(
+                    "`{}`: function `{}` is declared as `{}` instead of `{}` in -sys crate",
+                    owner, symbol, found, expected
+                );
+                self.mismatches += 1;
+            }
+            _ => (),
+        }
+    }
+}
+
+/// Functions of the main namespace by C identifier
+fn library_functions(env: &Env) -> HashMap<&str, &library::Function> {
+    let ns = env.library.namespace(library::MAIN_NAMESPACE);
+    ns.types
+        .iter()
+        .flatten()
+        .flat_map(Type::functions)
+        .chain(&ns.functions)
+        .filter_map(|func| Some((func.c_identifier.as_deref()?, func)))
+        .collect()
+}
+
+/// Strips the formatting, trailing commas and parameter names of a
+/// declaration.
+fn normalize(decl: &str) -> String {
+    let decl = WHITESPACE.replace_all(decl.trim(), " ");
+    let decl = PUNCTUATION.replace_all(&decl, "$1").replace(",)", ")");
+    PARAMETER_NAME
+        .replace_all(&decl, "$1")
+        .replace(',', ", ")
+        .replace("->", " -> ")
+}
+
+fn read_sys_items(sys_src: &Path) -> Result<SysItems, String> {
+    let mut items = SysItems::default();
+    for path in collect_rust_files(sys_src)? {
+        let content = fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        parse_sys_items(&content, &mut items);
+    }
+    Ok(items)
+}
+
+fn parse_sys_items(content: &str, items: &mut SysItems) {
+    items.names.extend(
+        SYS_ITEM
+            .captures_iter(content)
+            .map(|caps| caps[1].to_owned()),
+    );
+    items.signatures.extend(
+        SYS_FN
+            .captures_iter(content)
+            .map(|caps| (caps[1].to_owned(), normalize(&caps[2]))),
+    );
+    for caps in SYS_TYPE.captures_iter(content) {
+        items
+            .aliases
+            .entry(caps[1].to_owned())
+            .or_default()
+            .push(normalize(&caps[2]));
+    }
+}
+
+fn collect_rust_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = Vec::new();
+    let entries =
+        fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            files.extend(collect_rust_files(&path)?);
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sys_item_regex() {
+        let content = r#"
+pub type GtkAlign = c_int;
+pub const GTK_ALIGN_FILL: GtkAlign = 0;
+#[repr(C)]
+pub struct GtkWidget {
+    pub parent_instance: gobject::GInitiallyUnowned,
+}
+extern "C" {
+    pub fn gtk_widget_get_type() -> GType;
+    pub static mut gtk_major_version: c_uint;
+}
+"#;
+        let mut items = SysItems::default();
+        parse_sys_items(content, &mut items);
+        for name in &[
+            "GtkAlign",
+            "GTK_ALIGN_FILL",
+            "GtkWidget",
+            "gtk_widget_get_type",
+            "gtk_major_version",
+        ] {
+            assert!(items.names.contains(*name), "{}", name);
+        }
+        assert!(!items.names.contains("parent_instance"));
+        assert_eq!(items.aliases["GtkAlign"], ["c_int"]);
+        assert_eq!(items.signatures["gtk_widget_get_type"], "() -> GType");
+    }
+
+    #[test]
+    fn sys_signatures() {
+        let content = r#"
+extern "C" {
+    pub fn gtk_widget_set_size(
+        widget: *mut GtkWidget,
+        width: c_int,
+        height: c_int,
+    ) -> gboolean;
+    pub fn gtk_widget_set_parent(widget: *mut GtkWidget, parent: *mut gobject::GObject);
+}
+"#;
+        let mut items = SysItems::default();
+        parse_sys_items(content, &mut items);
+        assert_eq!(
+            items.signatures["gtk_widget_set_size"],
+            "(*mut GtkWidget, c_int, c_int) -> gboolean"
+        );
+        assert_eq!(
+            items.signatures["gtk_widget_set_parent"],
+            "(*mut GtkWidget, *mut gobject::GObject)"
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Adds `&[u8]` and `&glib::Bytes` as custom types standing for `GBytes` and
+//! byte arrays, and replaces them with them in function parameters: `GBytes`
+//! are taken as slices unless `bytes_type = "glib::Bytes"`, which turns
+//! borrowed const byte arrays with a length into `glib::Bytes`
+
+use crate::{
+    analysis::{c_type::is_const_ptr, conversion_type::ConversionType},
+    config::{
+        bytes_type::BytesType, matchable::Matchable, parameter_matchable::ParameterMatchable,
+        Config, WorkMode,
+    },
+    library::*,
+    nameutil,
+};
+use log::error;
+
+const SLICE_NAME: &str = "&[u8]";
+const BYTES_NAME: &str = "&glib::Bytes";
+
+impl Library {
+    pub fn add_bytes_types(&mut self, config: &Config) {
+        // `glib::Bytes` are the bytes of the GLib crate itself
+        if config.work_mode == WorkMode::Sys || self.is_glib_crate() {
+            return;
+        }
+        let tid_bytes = match self.find_type(0, "GLib.Bytes") {
+            Some(tid) => tid,
+            None => return,
+        };
+        let tid_u8 = self
+            .find_type(0, "*.guint8")
+            .expect("No fundamental type *.guint8");
+        let tid_u8_array = Type::c_array(self, tid_u8, None, None);
+        let tid_slice = self.add_type(
+            INTERNAL_NAMESPACE,
+            &format!("Bytes({})", SLICE_NAME),
+            Type::Custom(Custom {
+                name: SLICE_NAME.to_owned(),
+                conversion_type: ConversionType::Pointer,
+                ffi_tid: Some(tid_bytes),
+            }),
+        );
+        let tid_glib_bytes = self.add_type(
+            INTERNAL_NAMESPACE,
+            &format!("Bytes({})", BYTES_NAME),
+            Type::Custom(Custom {
+                name: BYTES_NAME.to_owned(),
+                conversion_type: ConversionType::Pointer,
+                ffi_tid: Some(tid_u8_array),
+            }),
+        );
+
+        for obj in config.objects.values() {
+            let functions = match self.configured_functions_mut(obj) {
+                Some((functions, _)) => functions,
+                None => continue,
+            };
+
+            for func in functions {
+                let configured_functions = obj.functions.matched(&func.name);
+                for par in &mut func.parameters {
+                    if par.instance_parameter || par.direction != ParameterDirection::In {
+                        continue;
+                    }
+                    let bytes_type = configured_functions
+                        .matched_parameters(&nameutil::mangle_keywords(&*par.name))
+                        .iter()
+                        .find_map(|p| p.bytes_type);
+                    if par.typ == tid_bytes {
+                        if bytes_type != Some(BytesType::Bytes) {
+                            par.typ = tid_slice;
+                        }
+                    } else if par.typ == tid_u8_array && par.array_length.is_some() {
+                        if bytes_type != Some(BytesType::Bytes) {
+                            continue;
+                        }
+                        // `glib::Bytes` are immutable and stay with the caller
+                        if par.transfer == Transfer::None && is_const_ptr(&par.c_type) {
+                            par.typ = tid_glib_bytes;
+                        } else {
+                            error!(
+                                "bytes_type = \"glib::Bytes\" given for parameter `{}` of `{}` \
+                                 which isn't a transfer none const byte array",
+                                par.name, func.name
+                            );
+                        }
+                    } else if bytes_type.is_some() {
+                        error!(
+                            "bytes_type given for parameter `{}` of `{}` which isn't bytes",
+                            par.name, func.name
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// How bytes added by `add_bytes_types` are taken
+    pub fn bytes_type(&self, tid: TypeId) -> Option<BytesType> {
+        match self.type_(tid) {
+            Type::Custom(Custom { name, .. }) if tid.ns_id == INTERNAL_NAMESPACE => {
+                match name.as_str() {
+                    SLICE_NAME => Some(BytesType::Slice),
+                    BYTES_NAME => Some(BytesType::Bytes),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
 
 impl Library {
     pub fn add_glib_priority(&mut self, work_mode: WorkMode) {
-        if work_mode != WorkMode::Normal {
+        if !work_mode.is_normal() {
             return;
         }
 
@@ -24,6 +24,7 @@ impl Library {
             Type::Custom(Custom {
                 name: "Priority".to_string(),
                 conversion_type: ConversionType::Scalar,
+                ffi_tid: None,
             }),
         );
 
@@ -0,0 +1,99 @@
+//! Replaces `gpointer` parameters with the types configured with
+//! `pointer_type`, or with a custom type standing for the raw pointer when
+//! it's `gpointer`
+
+use crate::{
+    analysis::conversion_type::ConversionType,
+    config::{matchable::Matchable, parameter_matchable::ParameterMatchable, Config, WorkMode},
+    library::*,
+    nameutil,
+};
+use log::error;
+use std::collections::HashMap;
+
+const RAW_POINTER_NAME: &str = "gpointer";
+
+impl Library {
+    pub fn add_pointer_types(&mut self, config: &Config) {
+        // The documentation shows the same types as the generated code
+        if config.work_mode == WorkMode::Sys {
+            return;
+        }
+        let tid_pointer = match self.find_type(0, "*.gpointer") {
+            Some(tid) => tid,
+            None => return,
+        };
+
+        let mut tids = HashMap::new();
+        for obj in config.objects.values() {
+            let names = obj
+                .functions
+                .iter()
+                .flat_map(|f| &f.parameters)
+                .filter_map(|p| p.pointer_type.as_ref());
+            for name in names {
+                if tids.contains_key(name) {
+                    continue;
+                }
+                let tid = if name == RAW_POINTER_NAME {
+                    let raw_name = if self.is_glib_crate() {
+                        "ffi::gpointer"
+                    } else {
+                        "glib::ffi::gpointer"
+                    };
+                    self.add_type(
+                        INTERNAL_NAMESPACE,
+                        &format!("Pointer({})", raw_name),
+                        Type::Custom(Custom {
+                            name: raw_name.to_owned(),
+                            conversion_type: ConversionType::Direct,
+                            ffi_tid: None,
+                        }),
+                    )
+                } else {
+                    match self.find_type(0, name) {
+                        Some(tid) => tid,
+                        None => {
+                            error!("Unknown pointer_type `{}` for {}", name, obj.name);
+                            continue;
+                        }
+                    }
+                };
+                tids.insert(name.clone(), tid);
+            }
+        }
+        if tids.is_empty() {
+            return;
+        }
+
+        for obj in config.objects.values() {
+            let functions = match self.configured_functions_mut(obj) {
+                Some((functions, _)) => functions,
+                None => continue,
+            };
+
+            for func in functions {
+                let configured_functions = obj.functions.matched(&func.name);
+                for par in &mut func.parameters {
+                    let tid = match configured_functions
+                        .matched_parameters(&nameutil::mangle_keywords(&*par.name))
+                        .iter()
+                        .find_map(|p| p.pointer_type.as_ref())
+                        .and_then(|name| tids.get(name))
+                    {
+                        Some(tid) => *tid,
+                        None => continue,
+                    };
+                    if par.typ != tid_pointer || par.direction != ParameterDirection::In {
+                        error!(
+                            "pointer_type given for parameter `{}` of `{}` which isn't an input gpointer",
+                            par.name, func.name
+                        );
+                        continue;
+                    }
+                    par.typ = tid;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Adds `std::time::SystemTime` as custom types standing for `GDateTime`
+//! and `GTimeVal`, and replaces them with it in functions
+//! when `time_mapping = "std"`
+
+use crate::{
+    analysis::conversion_type::ConversionType,
+    config::{TimeMapping, WorkMode},
+    library::*,
+    visitors::FunctionsMutVisitor,
+};
+
+/// GLib time type behind a `std::time::SystemTime`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StdTime {
+    DateTime,
+    TimeVal,
+}
+
+impl Library {
+    pub fn add_std_time(&mut self, work_mode: WorkMode, time_mapping: TimeMapping) {
+        if !work_mode.is_normal() || time_mapping != TimeMapping::Std {
+            return;
+        }
+
+        // Functions of the GLib crate itself keep using its own types
+        let mut replace = |name: &str| {
+            let tid = self
+                .find_type(0, name)
+                .filter(|tid| tid.ns_id != MAIN_NAMESPACE)?;
+            let tid_system_time = self.add_type(
+                INTERNAL_NAMESPACE,
+                &format!("SystemTime({})", name),
+                Type::Custom(Custom {
+                    name: "std::time::SystemTime".to_string(),
+                    conversion_type: ConversionType::Pointer,
+                    ffi_tid: Some(tid),
+                }),
+            );
+            Some((tid, tid_system_time))
+        };
+        let mut replacer = ReplaceToSystemTime {
+            date_time: replace("GLib.DateTime"),
+            time_val: replace("GLib.TimeVal"),
+        };
+        if replacer.date_time.is_none() && replacer.time_val.is_none() {
+            return;
+        }
+
+        let ns = self.namespace_mut(MAIN_NAMESPACE);
+        ns.visit_functions_mut(&mut replacer);
+        for func in &mut ns.functions {
+            replacer.visit_function_mut(func);
+        }
+    }
+
+    /// The GLib time type of a `std::time::SystemTime` added by `add_std_time`
+    pub fn std_time(&self, tid: TypeId) -> Option<StdTime> {
+        let ffi_tid = match self.type_(tid) {
+            Type::Custom(Custom {
+                ffi_tid: Some(ffi_tid),
+                ..
+            }) => *ffi_tid,
+            _ => return None,
+        };
+        match self.type_(ffi_tid).get_glib_name() {
+            Some("GDateTime") => Some(StdTime::DateTime),
+            Some("GTimeVal") => Some(StdTime::TimeVal),
+            _ => None,
+        }
+    }
+}
+
+struct ReplaceToSystemTime {
+    date_time: Option<(TypeId, TypeId)>,
+    time_val: Option<(TypeId, TypeId)>,
+}
+
+impl FunctionsMutVisitor for ReplaceToSystemTime {
+    fn visit_function_mut(&mut self, func: &mut Function) -> bool {
+        if let Some((tid_date_time, tid_system_time)) = self.date_time {
+            for par in &mut func.parameters {
+                if par.typ == tid_date_time
+                    && matches!(
+                        par.direction,
+                        ParameterDirection::In | ParameterDirection::Out
+                    )
+                {
+                    par.typ = tid_system_time;
+                }
+            }
+            if func.ret.typ == tid_date_time {
+                func.ret.typ = tid_system_time;
+            }
+        }
+        // `GTimeVal` is only ever filled in place as out parameter
+        if let Some((tid_time_val, tid_system_time)) = self.time_val {
+            for par in &mut func.parameters {
+                if par.typ == tid_time_val && par.direction == ParameterDirection::In {
+                    par.typ = tid_system_time;
+                }
+            }
+        }
+        true
+    }
+}
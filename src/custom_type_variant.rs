@@ -0,0 +1,116 @@
+//! Adds the Rust types configured with `variant_type` as custom types standing
+//! for `GVariant`, and replaces it with them in the configured parameters
+
+use crate::{
+    analysis::conversion_type::ConversionType,
+    config::{matchable::Matchable, parameter_matchable::ParameterMatchable, Config, WorkMode},
+    library::*,
+    nameutil,
+};
+use log::error;
+use std::collections::HashMap;
+
+impl Library {
+    pub fn add_variant_types(&mut self, config: &Config) {
+        // The documentation shows the same types as the generated code
+        if config.work_mode == WorkMode::Sys {
+            return;
+        }
+        let tid_variant = match self.find_type(0, "GLib.Variant") {
+            Some(tid) => tid,
+            None => return,
+        };
+
+        let mut tids = HashMap::new();
+        for obj in config.objects.values() {
+            let function_types = obj
+                .functions
+                .iter()
+                .flat_map(|f| &f.parameters)
+                .filter_map(|p| p.variant_type.as_ref());
+            let signal_types = obj
+                .signals
+                .iter()
+                .flat_map(|s| &s.parameters)
+                .filter_map(|p| p.variant_type.as_ref());
+            for name in function_types.chain(signal_types) {
+                if tids.contains_key(name) {
+                    continue;
+                }
+                let tid = self.add_type(
+                    INTERNAL_NAMESPACE,
+                    &format!("Variant({})", name),
+                    Type::Custom(Custom {
+                        name: name.clone(),
+                        conversion_type: ConversionType::Pointer,
+                        ffi_tid: Some(tid_variant),
+                    }),
+                );
+                tids.insert(name.clone(), tid);
+            }
+        }
+        if tids.is_empty() {
+            return;
+        }
+
+        for obj in config.objects.values() {
+            let (functions, signals) = match self.configured_functions_mut(obj) {
+                Some(items) => items,
+                None => continue,
+            };
+
+            for func in functions {
+                let configured_functions = obj.functions.matched(&func.name);
+                replace_parameters(&mut func.parameters, tid_variant, &tids, |name| {
+                    configured_functions
+                        .matched_parameters(name)
+                        .iter()
+                        .find_map(|p| p.variant_type.as_ref())
+                });
+            }
+            for signal in signals {
+                let configured_signals = obj.signals.matched(&signal.name);
+                replace_parameters(&mut signal.parameters, tid_variant, &tids, |name| {
+                    configured_signals
+                        .matched_parameters(name)
+                        .iter()
+                        .find_map(|p| p.variant_type.as_ref())
+                });
+            }
+        }
+    }
+
+    /// The Rust type of a `GVariant` added by `add_variant_types`
+    pub fn variant_type(&self, tid: TypeId) -> Option<&str> {
+        match self.type_(tid) {
+            Type::Custom(Custom {
+                name,
+                ffi_tid: Some(ffi_tid),
+                ..
+            }) if self.type_(*ffi_tid).get_glib_name() == Some("GVariant") => Some(name),
+            _ => None,
+        }
+    }
+}
+
+fn replace_parameters<'a>(
+    parameters: &mut [Parameter],
+    tid_variant: TypeId,
+    tids: &HashMap<String, TypeId>,
+    variant_type: impl Fn(&str) -> Option<&'a String>,
+) {
+    for par in parameters {
+        let name = match variant_type(&nameutil::mangle_keywords(&*par.name)) {
+            Some(name) => name,
+            None => continue,
+        };
+        if par.typ != tid_variant || par.direction != ParameterDirection::In {
+            error!(
+                "variant_type given for parameter `{}` which isn't an input GVariant",
+                par.name
+            );
+            continue;
+        }
+        par.typ = tids[name];
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     analysis::{self, namespaces::NsId},
     config::{gobjects::GStatus, Config},
+    file_saver::Output,
     library::*,
     version::Version,
 };
@@ -14,6 +15,7 @@ pub struct Env {
     pub symbols: RefCell<analysis::symbols::Info>,
     pub class_hierarchy: analysis::class_hierarchy::Info,
     pub analysis: analysis::Analysis,
+    pub output: Output,
 }
 
 impl Env {
@@ -36,6 +38,16 @@ impl Env {
             .unwrap_or(GStatus::Generate)
     }
 
+    /// Whether the record is configured with `record_boxed = "ref-only"`
+    pub fn is_ref_only_record(&self, tid: TypeId) -> bool {
+        matches!(self.library.type_(tid), Type::Record(..))
+            && self
+                .config
+                .objects
+                .get(&tid.full_name(&self.library))
+                .map_or(false, |o| o.ref_only)
+    }
+
     pub fn is_totally_deprecated(
         &self,
         ns_id: Option<NsId>,
@@ -1,21 +1,61 @@
-use crate::writer::untabber::Untabber;
+use crate::{env::Env, writer::untabber::Untabber};
 use std::{
+    cell::RefCell,
+    collections::BTreeMap,
     fs::{self, File},
     io::{BufWriter, Result, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
-pub fn save_to_file<P, F>(path: P, make_backup: bool, mut closure: F)
+/// Where the generated files are saved
+#[derive(Debug)]
+pub enum Output {
+    Disk,
+    /// Files are kept in memory by path, without touching the disk
+    Memory(RefCell<BTreeMap<PathBuf, String>>),
+}
+
+impl Default for Output {
+    fn default() -> Output {
+        Output::Disk
+    }
+}
+
+impl Output {
+    pub fn memory() -> Output {
+        Output::Memory(Default::default())
+    }
+
+    /// The files saved in memory, sorted by path
+    pub fn files(&self) -> BTreeMap<PathBuf, String> {
+        match self {
+            Output::Disk => BTreeMap::new(),
+            Output::Memory(files) => files.borrow().clone(),
+        }
+    }
+}
+
+pub fn save_to_file<P, F>(path: P, env: &Env, mut closure: F)
 where
     P: AsRef<Path>,
     F: FnMut(&mut dyn Write) -> Result<()>,
 {
     let path = path.as_ref();
+    if let Output::Memory(files) = &env.output {
+        let mut buf = Vec::new();
+        closure(&mut Untabber::new(Box::new(&mut buf)))
+            .unwrap_or_else(|why| panic!("couldn't write to {:?}: {:?}", path, why));
+        let content = String::from_utf8(buf)
+            .unwrap_or_else(|why| panic!("couldn't write to {:?}: {}", path, why));
+        files.borrow_mut().insert(path.to_owned(), content);
+        return;
+    }
+
     if let Some(parent) = path.parent() {
         let _ = fs::create_dir_all(parent);
     }
 
-    if make_backup {
+    if env.config.make_backup {
         let _backuped = create_backup(&path)
             .unwrap_or_else(|why| panic!("couldn't create backup for {:?}: {:?}", path, why));
     }
@@ -22,8 +22,13 @@ mod case;
 mod chunk;
 mod codegen;
 mod config;
+mod consistency;
 mod consts;
+mod custom_type_bytes;
 mod custom_type_glib_priority;
+mod custom_type_pointer;
+mod custom_type_std_time;
+mod custom_type_variant;
 mod env;
 mod file_saver;
 pub mod fmt;
@@ -33,6 +38,7 @@ mod library_postprocessing;
 mod library_preprocessing;
 mod nameutil;
 mod parser;
+mod pipeline;
 mod traits;
 pub mod update_version;
 mod version;
@@ -46,7 +52,9 @@ pub use crate::{
         run as analysis_run, symbols::run as symbols_run,
     },
     codegen::generate as codegen_generate,
-    config::{Config, WorkMode},
+    config::{Config, ConfigOptions, WorkMode},
     env::Env,
+    file_saver::Output,
     library::Library,
+    pipeline::run,
 };
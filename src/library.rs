@@ -8,6 +8,7 @@ use std::{
     fmt,
     iter::Iterator,
     ops::{Deref, DerefMut},
+    path::PathBuf,
     str::FromStr,
 };
 
@@ -454,6 +455,8 @@ pub struct Field {
     pub bits: Option<u8>,
     pub array_length: Option<u32>,
     pub doc: Option<String>,
+    /// Anonymous union or struct, `name` is made up
+    pub anonymous: bool,
 }
 
 #[derive(Default, Debug)]
@@ -492,6 +495,8 @@ pub struct Parameter {
     pub direction: ParameterDirection,
     pub transfer: Transfer,
     pub caller_allocates: bool,
+    /// Out parameter the caller may pass `NULL` for
+    pub optional: bool,
     pub nullable: Nullable,
     pub allow_none: bool,
     pub array_length: Option<u32>,
@@ -516,6 +521,10 @@ pub struct Function {
     pub deprecated_version: Option<Version>,
     pub doc: Option<String>,
     pub doc_deprecated: Option<String>,
+    /// Name of the function taking the place of this one (`shadowed-by`)
+    pub shadowed_by: Option<String>,
+    /// Name of the function replaced by this one (`shadows`)
+    pub shadows: Option<String>,
 }
 
 #[derive(Debug)]
@@ -543,6 +552,8 @@ pub struct Interface {
     pub signals: Vec<Signal>,
     pub properties: Vec<Property>,
     pub prerequisites: Vec<TypeId>,
+    /// Names of the virtual methods of the interface struct
+    pub virtual_methods: Vec<String>,
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
     pub doc: Option<String>,
@@ -563,6 +574,8 @@ pub struct Class {
     pub properties: Vec<Property>,
     pub parent: Option<TypeId>,
     pub implements: Vec<TypeId>,
+    /// Names of the virtual methods of the class struct
+    pub virtual_methods: Vec<String>,
     pub final_type: bool,
     pub version: Option<Version>,
     pub deprecated_version: Option<Version>,
@@ -575,6 +588,8 @@ pub struct Class {
 pub struct Custom {
     pub name: String,
     pub conversion_type: ConversionType,
+    /// Type the C side still uses, when not a fundamental one
+    pub ffi_tid: Option<TypeId>,
 }
 
 macro_rules! impl_lexical_ord {
@@ -797,16 +812,17 @@ impl Type {
         library.add_type(INTERNAL_NAMESPACE, &format!("fn<#{:?}>", param_tids), typ)
     }
 
+    /// Registers a union nested in another type, under a `#` prefixed name so
+    /// it can't be looked up as a GIR type.
     pub fn union(library: &mut Library, u: Union, ns_id: u16) -> TypeId {
-        let field_tids: Vec<TypeId> = u.fields.iter().map(|f| f.typ).collect();
-        let typ = Type::Union(u);
-        library.add_type(ns_id, &format!("#{:?}", field_tids), typ)
+        let name = format!("#{}", u.name);
+        library.add_type(ns_id, &name, Type::Union(u))
     }
 
+    /// Registers a record nested in another type, see `Type::union`.
     pub fn record(library: &mut Library, r: Record, ns_id: u16) -> TypeId {
-        let field_tids: Vec<TypeId> = r.fields.iter().map(|f| f.typ).collect();
-        let typ = Type::Record(r);
-        library.add_type(ns_id, &format!("#{:?}", field_tids), typ)
+        let name = format!("#{}", r.name);
+        library.add_type(ns_id, &name, Type::Record(r))
     }
 
     pub fn functions(&self) -> &[Function] {
@@ -949,6 +965,8 @@ pub struct Namespace {
     pub symbol_prefixes: Vec<String>,
     /// C headers, relative to include directories provided by pkg-config --cflags.
     pub c_includes: Vec<String>,
+    /// C identifiers of functions with a `moved-to` attribute, with its value
+    pub moved_functions: BTreeMap<String, String>,
 }
 
 impl Namespace {
@@ -1008,6 +1026,17 @@ pub const MAIN_NAMESPACE: u16 = 1;
 pub struct Library {
     pub namespaces: Vec<Namespace>,
     pub index: HashMap<String, u16>,
+    /// Abort on the first malformed element instead of skipping it.
+    pub strict_parsing: bool,
+    /// Number of malformed elements skipped while reading .gir files.
+    pub parse_errors: usize,
+    /// Versions of the included namespaces to load, whatever the
+    /// version asked by the including files.
+    pub version_overrides: HashMap<String, String>,
+    /// Loaded version of each namespace, with the file that asked for it.
+    pub loaded_versions: HashMap<String, (String, String)>,
+    /// Paths of the .gir files read
+    pub gir_files: Vec<PathBuf>,
 }
 
 impl Library {
@@ -1015,6 +1044,11 @@ impl Library {
         let mut library = Library {
             namespaces: Vec::new(),
             index: HashMap::new(),
+            strict_parsing: false,
+            parse_errors: 0,
+            version_overrides: HashMap::new(),
+            loaded_versions: HashMap::new(),
+            gir_files: Vec::new(),
         };
         assert_eq!(
             INTERNAL_NAMESPACE,
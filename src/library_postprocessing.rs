@@ -9,6 +9,7 @@ use crate::{
     nameutil,
     parser::is_empty_c_type,
     traits::MaybeRefAs,
+    visitors::FunctionsMutVisitor,
 };
 use log::{error, info};
 use std::collections::HashMap;
@@ -33,15 +34,21 @@ type DetectedCTypes = HashMap<TypeId, String>;
 impl Library {
     pub fn postprocessing(&mut self, config: &Config) {
         self.fix_gtype();
+        self.fix_prefixes(config);
         self.check_resolved();
+        self.fix_untagged_error_params();
         self.fill_empty_signals_c_types();
         self.resolve_class_structs();
         self.correlate_class_structs();
         self.fix_fields();
+        self.rename_nested_types(config);
         self.make_unrepresentable_types_opaque();
         self.mark_final_types(config);
         self.update_error_domain_functions(config);
         self.mark_ignored_enum_members(config);
+        self.add_variant_types(config);
+        self.add_bytes_types(config);
+        self.add_pointer_types(config);
     }
 
     fn fix_gtype(&mut self) {
@@ -51,6 +58,83 @@ impl Library {
         }
     }
 
+    /// Libraries can have several C prefixes, like `c:identifier-prefixes="Gst,GST"`,
+    /// in which case the names of some constants and functions may be left
+    /// untrimmed. Apply the configured prefixes and strip them from those.
+    fn fix_prefixes(&mut self, config: &Config) {
+        let ns = self.namespace_mut(MAIN_NAMESPACE);
+        if let Some(ref prefixes) = config.identifier_prefixes {
+            ns.identifier_prefixes = prefixes.clone();
+        }
+        if let Some(ref prefixes) = config.symbol_prefixes {
+            ns.symbol_prefixes = prefixes.clone();
+        }
+
+        for ns in &mut self.namespaces {
+            let prefixes: Vec<&String> = ns
+                .identifier_prefixes
+                .iter()
+                .chain(ns.symbol_prefixes.iter())
+                .collect();
+            for constant in &mut ns.constants {
+                if constant.name != constant.c_identifier {
+                    continue;
+                }
+                if let Some(name) = nameutil::strip_prefix(&prefixes, &constant.name) {
+                    info!("Trimming constant name `{}` to `{}`", constant.name, name);
+                    constant.name = name.to_owned();
+                }
+            }
+            for func in &mut ns.functions {
+                if func.c_identifier.as_ref() != Some(&func.name) {
+                    continue;
+                }
+                if let Some(name) = nameutil::strip_prefix(&ns.symbol_prefixes, &func.name) {
+                    info!("Trimming function name `{}` to `{}`", func.name, name);
+                    func.name = name.to_owned();
+                }
+            }
+        }
+    }
+
+    /// Some GIR files declare a trailing `GError**` parameter without marking
+    /// the function as `throws`, so the error would be bound as a regular out
+    /// parameter. Turn those into throwing functions returning `Result`.
+    fn fix_untagged_error_params(&mut self) {
+        let mut fixer = MarkThrows;
+        for ns in &mut self.namespaces {
+            ns.visit_functions_mut(&mut fixer);
+            for func in &mut ns.functions {
+                fixer.visit_function_mut(func);
+            }
+        }
+    }
+
+    /// The functions and signals of the type `obj` configures, or the global
+    /// functions for the `Ns.*` object
+    pub fn configured_functions_mut(
+        &mut self,
+        obj: &GObject,
+    ) -> Option<(&mut [Function], &mut [Signal])> {
+        if obj.name == format!("{}.*", self.namespace(MAIN_NAMESPACE).name) {
+            return Some((&mut self.namespace_mut(MAIN_NAMESPACE).functions, &mut []));
+        }
+        let tid = self.find_type(0, &obj.name)?;
+        match self.type_mut(tid) {
+            Type::Class(Class {
+                functions, signals, ..
+            })
+            | Type::Interface(Interface {
+                functions, signals, ..
+            }) => Some((functions, signals)),
+            Type::Record(Record { functions, .. })
+            | Type::Union(Union { functions, .. })
+            | Type::Enumeration(Enumeration { functions, .. })
+            | Type::Bitfield(Bitfield { functions, .. }) => Some((functions, &mut [])),
+            _ => None,
+        }
+    }
+
     fn check_resolved(&self) {
         let list: Vec<_> = self
             .index
@@ -373,6 +457,67 @@ impl Library {
         }
     }
 
+    fn rename_nested_types(&mut self, config: &Config) {
+        let mut renames: Vec<(TypeId, usize, TypeId, String)> = Vec::new();
+        for (full_name, obj) in &config.objects {
+            if obj.anonymous_names.is_empty() {
+                continue;
+            }
+            let typ = self
+                .find_type(0, full_name)
+                .map(|tid| (tid, self.type_(tid)));
+            let (tid, fields) = match typ {
+                Some((tid, Type::Class(Class { fields, .. })))
+                | Some((tid, Type::Record(Record { fields, .. })))
+                | Some((tid, Type::Union(Union { fields, .. }))) => (tid, fields),
+                _ => {
+                    error!("Type `{}` with anonymous_names has no fields", full_name);
+                    continue;
+                }
+            };
+            for (field_name, c_type) in &obj.anonymous_names {
+                let fid = match fields.iter().position(|f| &f.name == field_name) {
+                    Some(fid) => fid,
+                    None => {
+                        error!("Field `{}::{}` not found", full_name, field_name);
+                        continue;
+                    }
+                };
+                let field_tid = fields[fid].typ;
+                let nested = self
+                    .namespace(field_tid.ns_id)
+                    .index
+                    .iter()
+                    .any(|(name, &id)| id == field_tid.id && name.starts_with('#'));
+                if nested {
+                    renames.push((tid, fid, field_tid, c_type.clone()));
+                } else {
+                    error!(
+                        "Field `{}::{}` is not a nested union or record",
+                        full_name, field_name
+                    );
+                }
+            }
+        }
+
+        for (tid, fid, field_tid, c_type) in renames {
+            match self.type_mut(tid) {
+                Type::Class(Class { fields, .. })
+                | Type::Record(Record { fields, .. })
+                | Type::Union(Union { fields, .. }) => fields[fid].c_type = Some(c_type.clone()),
+                _ => unreachable!("Expected class, record or union"),
+            }
+            let old_c_type = match self.type_mut(field_tid) {
+                Type::Record(record) => std::mem::replace(&mut record.c_type, c_type.clone()),
+                Type::Union(union) => union.c_type.replace(c_type.clone()).unwrap_or_default(),
+                _ => unreachable!("Expected record or union"),
+            };
+            let ns = self.namespace_mut(field_tid.ns_id);
+            ns.glib_name_index.remove(&old_c_type);
+            ns.glib_name_index.insert(c_type, field_tid.id);
+        }
+    }
+
     fn make_unrepresentable_types_opaque(&mut self) {
         // Unions with non-`Copy` fields are unstable (see issue #32836).
         // It would seem that this shouldn't be cause for concern as one can
@@ -652,3 +797,40 @@ impl Library {
         }
     }
 }
+
+struct MarkThrows;
+
+impl FunctionsMutVisitor for MarkThrows {
+    fn visit_function_mut(&mut self, func: &mut Function) -> bool {
+        if func.throws {
+            return true;
+        }
+        // Usually the last parameter, but some libraries have out parameters after it.
+        // Anywhere else the error is an argument, like the destination of
+        // `g_propagate_error()`
+        let pos = match func
+            .parameters
+            .iter()
+            .position(|par| par.c_type == "GError**")
+        {
+            Some(pos) => pos,
+            None => return true,
+        };
+        if func.parameters[pos + 1..]
+            .iter()
+            .any(|par| par.direction != ParameterDirection::Out)
+        {
+            return true;
+        }
+        info!("Function `{}` has an untagged error parameter", func.name);
+        let par = &mut func.parameters[pos];
+        // Named like the parameter added for `throws` functions, as the generated code expects
+        par.name = "error".into();
+        par.direction = ParameterDirection::Out;
+        par.transfer = Transfer::Full;
+        par.nullable = Nullable(true);
+        par.is_error = true;
+        func.throws = true;
+        true
+    }
+}
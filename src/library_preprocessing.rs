@@ -1,7 +1,11 @@
-use crate::{config::WorkMode, library::*};
+use crate::{
+    config::{TimeMapping, WorkMode},
+    library::*,
+};
 
 impl Library {
-    pub fn preprocessing(&mut self, work_mode: WorkMode) {
+    pub fn preprocessing(&mut self, work_mode: WorkMode, time_mapping: TimeMapping) {
         self.add_glib_priority(work_mode);
+        self.add_std_time(work_mode, time_mapping);
     }
 }
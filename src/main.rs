@@ -1,11 +1,11 @@
 use std::env;
 use std::path::PathBuf;
 use std::process;
-use std::{cell::RefCell, str::FromStr};
+use std::str::FromStr;
 
 use getopts::Options;
 use hprof::Profiler;
-use libgir::{self as gir, Config, Library, WorkMode};
+use libgir::{self as gir, Config, ConfigOptions, Library, Output, WorkMode};
 
 fn print_usage(program: &str, opts: Options) {
     let brief = format!(
@@ -28,8 +28,9 @@ impl<S: AsRef<str>> OptionStr for Option<S> {
 
 #[allow(clippy::large_enum_variant)]
 enum RunKind {
-    Config(Config),
-    CheckGirFile(String),
+    Config(Config, bool),
+    CheckGirFile(String, bool),
+    DumpConfig(Option<String>, Vec<String>),
 }
 
 fn build_config() -> Result<RunKind, String> {
@@ -43,6 +44,12 @@ fn build_config() -> Result<RunKind, String> {
         "Config file path (default: Gir.toml)",
         "CONFIG",
     );
+    options.optmulti(
+        "",
+        "config-override",
+        "Override a setting of the config file, like `options.min_cfg_version=3.20`",
+        "KEY=VALUE",
+    );
     options.optflag("h", "help", "Show this message");
     options.optmulti(
         "d",
@@ -53,7 +60,7 @@ fn build_config() -> Result<RunKind, String> {
     options.optopt(
         "m",
         "mode",
-        "Work mode: doc, normal, sys or not_bound",
+        "Work mode: doc, normal, sys, not_bound or consistency_check",
         "MODE",
     );
     options.optopt("o", "target", "Target path", "PATH");
@@ -61,17 +68,42 @@ fn build_config() -> Result<RunKind, String> {
     options.optflag("b", "make-backup", "Make backup before generating");
     options.optflag("s", "stats", "Show statistics");
     options.optflag("", "disable-format", "Disable formatting generated code");
+    options.optflag(
+        "",
+        "strict",
+        "Abort on the first malformed element in `.gir` files",
+    );
     options.optopt(
         "",
         "check-gir-file",
         "Check if the given `.gir` file is valid",
         "PATH",
     );
+    options.optflag(
+        "",
+        "dump-config-defaults",
+        "Print the configuration merged with its includes and exit",
+    );
+    options.optflag(
+        "",
+        "dry-run",
+        "Print the generated files instead of writing them",
+    );
 
     let matches = options.parse(&args[1..]).map_err(|e| e.to_string())?;
 
     if let Some(check_gir_file) = matches.opt_str("check-gir-file") {
-        return Ok(RunKind::CheckGirFile(check_gir_file));
+        return Ok(RunKind::CheckGirFile(
+            check_gir_file,
+            matches.opt_present("strict"),
+        ));
+    }
+
+    if matches.opt_present("dump-config-defaults") {
+        return Ok(RunKind::DumpConfig(
+            matches.opt_str("c"),
+            matches.opt_strs("config-override"),
+        ));
     }
 
     if matches.opt_present("h") {
@@ -79,6 +111,7 @@ fn build_config() -> Result<RunKind, String> {
         process::exit(0);
     }
 
+    let dry_run = matches.opt_present("dry-run");
     let work_mode = match matches.opt_str("m") {
         None => None,
         Some(s) => match WorkMode::from_str(&s) {
@@ -90,22 +123,25 @@ fn build_config() -> Result<RunKind, String> {
         },
     };
 
-    Config::new(
-        matches.opt_str("c").as_str_ref(),
+    Config::new(&ConfigOptions {
+        config_file: matches.opt_str("c").as_str_ref(),
         work_mode,
-        &matches.opt_strs("d"),
-        matches.free.get(0).as_str_ref(),
-        matches.free.get(1).as_str_ref(),
-        matches.opt_str("o").as_str_ref(),
-        matches.opt_str("doc-target-path").as_str_ref(),
-        matches.opt_present("b"),
-        matches.opt_present("s"),
-        matches.opt_present("disable-format"),
-    )
-    .map(RunKind::Config)
+        girs_dirs: &matches.opt_strs("d"),
+        library_name: matches.free.get(0).as_str_ref(),
+        library_version: matches.free.get(1).as_str_ref(),
+        target_path: matches.opt_str("o").as_str_ref(),
+        doc_target_path: matches.opt_str("doc-target-path").as_str_ref(),
+        make_backup: matches.opt_present("b"),
+        show_statistics: matches.opt_present("s"),
+        disable_format: matches.opt_present("disable-format"),
+        strict_parsing: matches.opt_present("strict"),
+        dry_run,
+        config_overrides: &matches.opt_strs("config-override"),
+    })
+    .map(|cfg| RunKind::Config(cfg, dry_run))
 }
 
-fn run_check(check_gir_file: &str) -> Result<(), String> {
+fn run_check(check_gir_file: &str, strict: bool) -> Result<(), String> {
     let path = PathBuf::from(check_gir_file);
     if !path.is_file() {
         return Err(format!("`{}`: file not found", check_gir_file));
@@ -117,12 +153,20 @@ fn run_check(check_gir_file: &str) -> Result<(), String> {
         .to_str()
         .ok_or_else(|| "failed to convert OsStr to str".to_owned())?;
     let mut library = Library::new(lib_name);
+    library.strict_parsing = strict;
     let parent = path.parent().ok_or(format!(
         "Failed to get parent directory from `{}`",
         check_gir_file
     ))?;
 
-    library.read_file(&[parent], &mut vec![lib_name.to_owned()])
+    library.read_file(&[parent], &mut vec![lib_name.to_owned()])?;
+    if library.parse_errors > 0 {
+        return Err(format!(
+            "`{}`: {} malformed element(s) found",
+            check_gir_file, library.parse_errors
+        ));
+    }
+    Ok(())
 }
 
 fn main() -> Result<(), String> {
@@ -131,9 +175,18 @@ fn main() -> Result<(), String> {
     }
     env_logger::init();
 
-    let mut cfg = match build_config() {
-        Ok(RunKind::CheckGirFile(check_gir_file)) => return run_check(&check_gir_file),
-        Ok(RunKind::Config(cfg)) => cfg,
+    let (mut cfg, dry_run) = match build_config() {
+        Ok(RunKind::CheckGirFile(check_gir_file, strict)) => {
+            return run_check(&check_gir_file, strict)
+        }
+        Ok(RunKind::DumpConfig(config_file, config_overrides)) => {
+            print!(
+                "{}",
+                Config::dump_toml(config_file.as_str_ref(), &config_overrides)?
+            );
+            return Ok(());
+        }
+        Ok(RunKind::Config(cfg, dry_run)) => (cfg, dry_run),
         Err(err) => return Err(err),
     };
     cfg.check_disable_format();
@@ -143,70 +196,12 @@ fn main() -> Result<(), String> {
 
     let watcher_total = statistics.enter("Total");
 
-    let mut library = {
-        let _watcher = statistics.enter("Loading");
-
-        let mut library = Library::new(&cfg.library_name);
-        library.read_file(&cfg.girs_dirs, &mut vec![cfg.library_full_name()])?;
-        library
-    };
-
-    {
-        let _watcher = statistics.enter("Preprocessing");
-        library.preprocessing(cfg.work_mode);
-    }
-
-    {
-        let _watcher = statistics.enter("Update library by config");
-        gir::update_version::apply_config(&mut library, &cfg);
-    }
-
-    {
-        let _watcher = statistics.enter("Postprocessing");
-        library.postprocessing(&cfg);
-    }
-
-    {
-        let _watcher = statistics.enter("Resolving type ids");
-        cfg.resolve_type_ids(&library);
-    }
-
-    {
-        let _watcher = statistics.enter("Checking versions");
-        gir::update_version::check_function_real_version(&mut library);
-    }
-
-    let mut env = {
-        let _watcher = statistics.enter("Namespace/symbol/class analysis");
-
-        let namespaces = gir::namespaces_run(&library);
-        let symbols = gir::symbols_run(&library, &namespaces);
-        let class_hierarchy = gir::class_hierarchy_run(&library);
-
-        gir::Env {
-            library,
-            config: cfg,
-            namespaces,
-            symbols: RefCell::new(symbols),
-            class_hierarchy,
-            analysis: Default::default(),
-        }
+    let output = if dry_run {
+        Output::memory()
+    } else {
+        Output::Disk
     };
-
-    if env.config.work_mode != WorkMode::Sys {
-        let _watcher = statistics.enter("Analyzing");
-        gir::analysis_run(&mut env);
-    }
-
-    if env.config.work_mode != WorkMode::DisplayNotBound {
-        let _watcher = statistics.enter("Generating");
-        gir::codegen_generate(&env);
-    }
-
-    if !env.config.disable_format && env.config.work_mode.is_generate_rust_files() {
-        let _watcher = statistics.enter("Formatting");
-        gir::fmt::format(&env.config.target_path);
-    }
+    let env = gir::run(cfg, output, &statistics)?;
 
     drop(watcher_total);
     statistics.end_frame();
@@ -217,6 +212,10 @@ fn main() -> Result<(), String> {
     if env.config.work_mode == WorkMode::DisplayNotBound {
         env.library.show_non_bound_types(&env);
     }
+    for (path, content) in env.output.files() {
+        println!("==> {} <==", path.display());
+        print!("{}", content);
+    }
 
     Ok(())
 }
@@ -32,6 +32,31 @@ pub fn split_namespace_name(name: &str) -> (Option<&str>, &str) {
     (ns, name)
 }
 
+/// Strips the longest matching namespace prefix from a C identifier.
+///
+/// Prefixes are matched as given (`Gst` in `GstBin`) or uppercased (`GST` in
+/// `GST_FLOW_OK`), and must be followed by an uppercase letter or an
+/// underscore, which is dropped too.
+pub fn strip_prefix<'a, S: AsRef<str>>(prefixes: &[S], name: &'a str) -> Option<&'a str> {
+    let mut prefixes: Vec<&str> = prefixes.iter().map(AsRef::as_ref).collect();
+    prefixes.sort_by_key(|p| std::cmp::Reverse(p.len()));
+    prefixes.iter().find_map(|prefix| {
+        let rest = name
+            .strip_prefix(prefix)
+            .or_else(|| name.strip_prefix(&*prefix.to_uppercase()))?;
+        let rest = match rest.strip_prefix('_') {
+            Some(rest) => rest,
+            None if rest.starts_with(char::is_uppercase) => rest,
+            None => return None,
+        };
+        if rest.is_empty() {
+            None
+        } else {
+            Some(rest)
+        }
+    })
+}
+
 /* unused :(
 pub fn strip_suffix<'a>(name: &'a str, suffix: &str) -> Option<&'a str> {
     if name.ends_with(suffix) {
@@ -209,19 +234,35 @@ mod tests {
         assert_eq!(name, "StatusIcon");
     }
 
-    /*
     #[test]
     fn strip_prefix_g() {
-        assert_eq!(strip_prefix("G", "GBusType"), "BusType");
-        assert_eq!(strip_prefix("G", "G_BUS_TYPE_NONE"), "BUS_TYPE_NONE");
+        assert_eq!(strip_prefix(&["G"], "GBusType"), Some("BusType"));
+        assert_eq!(
+            strip_prefix(&["G"], "G_BUS_TYPE_NONE"),
+            Some("BUS_TYPE_NONE")
+        );
+        assert_eq!(strip_prefix(&["g"], "g_bus_get"), Some("bus_get"));
     }
 
     #[test]
     fn strip_prefix_gtk() {
-        assert_eq!(strip_prefix("Gtk", "GtkAlign"), "Align");
-        assert_eq!(strip_prefix("Gtk", "GTK_ALIGN_FILL"), "ALIGN_FILL");
+        assert_eq!(strip_prefix(&["Gtk"], "GtkAlign"), Some("Align"));
+        assert_eq!(strip_prefix(&["Gtk"], "GTK_ALIGN_FILL"), Some("ALIGN_FILL"));
+        assert_eq!(strip_prefix(&["Gtk"], "Gtkalign"), None);
+        assert_eq!(strip_prefix(&["Gtk"], "GdkWindow"), None);
+    }
+
+    #[test]
+    fn strip_prefix_multiple() {
+        let prefixes = ["Gst", "GST", "GstGL"];
+        assert_eq!(strip_prefix(&prefixes, "GstBin"), Some("Bin"));
+        assert_eq!(strip_prefix(&prefixes, "GST_FLOW_OK"), Some("FLOW_OK"));
+        assert_eq!(strip_prefix(&prefixes, "GstGLContext"), Some("Context"));
+        assert_eq!(
+            strip_prefix(&prefixes, "GST_GL_API_NONE"),
+            Some("GL_API_NONE")
+        );
     }
-    */
 
     #[test]
     fn crate_name_works() {
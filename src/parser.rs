@@ -3,7 +3,7 @@ use crate::{
     version::Version,
     xmlparser::{Element, XmlParser},
 };
-use log::{trace, warn};
+use log::{error, trace, warn};
 use std::{
     path::{Path, PathBuf},
     str::FromStr,
@@ -28,6 +28,7 @@ impl Library {
                 Ok(p) => p,
                 _ => continue,
             };
+            self.gir_files.push(file_name.clone());
             return parser.document(|p, _| {
                 p.element_with_name("repository", |sub_parser, _elem| {
                     self.read_repository(dirs, sub_parser, libs)
@@ -45,11 +46,29 @@ impl Library {
     ) -> Result<(), String> {
         let mut package = None;
         let mut includes = Vec::new();
+        let current = libs[libs.len() - 1].clone();
+        if libs.len() == 1 {
+            if let Some((name, ver)) = current.rsplit_once('-') {
+                self.loaded_versions
+                    .insert(name.to_owned(), (ver.to_owned(), current.clone()));
+            }
+        }
         parser.elements(|parser, elem| match elem.name() {
             "include" => {
                 match (elem.attr("name"), elem.attr("version")) {
                     (Some(name), Some(ver)) => {
+                        let pinned = self.version_overrides.get(name).cloned();
+                        if let Some((loaded, loaded_by)) = self.loaded_versions.get(name) {
+                            if pinned.is_none() && loaded != ver {
+                                warn!(
+                                    "`{}` includes {}-{} but {}-{} is already loaded for `{}`, \
+                                     pin one of them in `girs_version_overrides`",
+                                    current, name, ver, name, loaded, loaded_by
+                                );
+                            }
+                        }
                         if self.find_namespace(name).is_none() {
+                            let ver = pinned.as_deref().unwrap_or(ver);
                             let lib = format!("{}-{}", name, ver);
                             if libs.iter().any(|x| *x == lib) {
                                 return Err(format!(
@@ -58,6 +77,8 @@ impl Library {
                                     libs.join("::")
                                 ));
                             }
+                            self.loaded_versions
+                                .insert(name.to_owned(), (ver.to_owned(), current.clone()));
                             libs.push(lib);
                             self.read_file(dirs, libs)?;
                             libs.pop();
@@ -116,7 +137,9 @@ impl Library {
             elem.attr("version").unwrap_or("?")
         );
 
-        parser.elements(|parser, elem| {
+        let strict = self.strict_parsing;
+        let mut errors = Vec::new();
+        let read_element = |parser: &mut XmlParser<'_>, elem: &Element| {
             trace!("<{} name={:?}>", elem.name(), elem.attr("name"));
             match elem.name() {
                 "class" => self.read_class(parser, ns_id, elem),
@@ -135,7 +158,16 @@ impl Library {
                     parser.ignore_element()
                 }
             }
-        })?;
+        };
+        if strict {
+            parser.elements(read_element)?;
+        } else {
+            parser.elements_recovering(&mut errors, read_element)?;
+        }
+        for error in &errors {
+            error!("{}, element skipped", error);
+        }
+        self.parse_errors += errors.len();
         Ok(())
     }
 
@@ -159,6 +191,7 @@ impl Library {
         let mut properties = Vec::new();
         let mut impls = Vec::new();
         let mut fields = Vec::new();
+        let mut virtual_methods = Vec::new();
         let mut doc = None;
         let mut doc_deprecated = None;
         let mut union_count = 1;
@@ -181,37 +214,45 @@ impl Library {
             "field" => self.read_field(parser, ns_id, elem).map(|f| {
                 fields.push(f);
             }),
-            "virtual-method" => parser.ignore_element(),
+            "virtual-method" => {
+                virtual_methods.extend(elem.attr("name").map(ToOwned::to_owned));
+                parser.ignore_element()
+            }
             "doc" => parser.text().map(|t| doc = Some(t)),
             "doc-deprecated" => parser.text().map(|t| doc_deprecated = Some(t)),
             "source-position" => parser.ignore_element(),
-            "union" => self
-                .read_union(parser, ns_id, elem, Some(class_name), Some(c_type))
-                .map(|mut u| {
-                    let field_name = if let Some(field_name) = elem.attr("name") {
-                        field_name.into()
-                    } else {
-                        format!("u{}", union_count)
-                    };
-
-                    u = Union {
-                        name: format!("{}_{}", class_name, field_name),
-                        c_type: Some(format!("{}_{}", c_type, field_name)),
-                        ..u
-                    };
-
-                    let u_doc = u.doc.clone();
-                    let ctype = u.c_type.clone();
-
-                    fields.push(Field {
-                        name: field_name,
-                        typ: Type::union(self, u, ns_id),
-                        doc: u_doc,
-                        c_type: ctype,
-                        ..Field::default()
-                    });
-                    union_count += 1;
-                }),
+            "union" => {
+                let field_name = elem
+                    .attr("name")
+                    .map_or_else(|| format!("u{}", union_count), ToOwned::to_owned);
+                let name = nested_name(None, class_name, &field_name);
+                let nested_c_type = nested_name(None, c_type, &field_name);
+                let (name_prefix, ctype_prefix) = match elem.attr("name") {
+                    Some(_) => (class_name.to_owned(), c_type.to_owned()),
+                    None => (name.clone(), nested_c_type.clone()),
+                };
+                self.read_union(parser, ns_id, elem, Some(&name_prefix), Some(&ctype_prefix))
+                    .map(|u| {
+                        let u = Union {
+                            name,
+                            c_type: Some(nested_c_type),
+                            ..u
+                        };
+
+                        let u_doc = u.doc.clone();
+                        let ctype = u.c_type.clone();
+
+                        fields.push(Field {
+                            name: field_name,
+                            typ: Type::union(self, u, ns_id),
+                            doc: u_doc,
+                            c_type: ctype,
+                            anonymous: elem.attr("name").is_none(),
+                            ..Field::default()
+                        });
+                        union_count += 1;
+                    })
+            }
             "attribute" => parser.ignore_element(),
             _ => Err(parser.unexpected_element(elem)),
         })?;
@@ -231,6 +272,7 @@ impl Library {
             properties,
             parent,
             implements: impls,
+            virtual_methods,
             final_type: false, // this will be set during postprocessing
             doc,
             doc_deprecated,
@@ -264,8 +306,15 @@ impl Library {
         parent_name_prefix: Option<&str>,
         parent_ctype_prefix: Option<&str>,
     ) -> Result<Option<Type>, String> {
-        let record_name = elem.attr_required("name")?;
-        let c_type = elem.attr_required("type")?;
+        // Records nested in unions may be anonymous, their names are made up by the parent
+        let (record_name, c_type) = if parent_name_prefix.is_some() {
+            (
+                elem.attr("name").unwrap_or(""),
+                elem.attr("type").unwrap_or(""),
+            )
+        } else {
+            (elem.attr_required("name")?, elem.attr_required("type")?)
+        };
         let symbol_prefix = elem.attr("symbol-prefix").map(ToOwned::to_owned);
         let get_type = elem.attr("get-type").map(ToOwned::to_owned);
         let gtype_struct_for = elem.attr("is-gtype-struct-for");
@@ -283,55 +332,40 @@ impl Library {
             "constructor" | "function" | "method" => {
                 self.read_function_to_vec(parser, ns_id, elem, &mut fns)
             }
-            "union" => self
-                .read_union(parser, ns_id, elem, Some(record_name), Some(c_type))
-                .map(|mut u| {
-                    let field_name = if let Some(field_name) = elem.attr("name") {
-                        field_name.into()
-                    } else {
-                        format!("u{}", union_count)
-                    };
-
-                    u = Union {
-                        name: format!(
-                            "{}{}_{}",
-                            parent_name_prefix
-                                .map(|s| {
-                                    let mut s = String::from(s);
-                                    s.push('_');
-                                    s
-                                })
-                                .unwrap_or_else(String::new),
-                            record_name,
-                            field_name
-                        ),
-                        c_type: Some(format!(
-                            "{}{}_{}",
-                            parent_ctype_prefix
-                                .map(|s| {
-                                    let mut s = String::from(s);
-                                    s.push('_');
-                                    s
-                                })
-                                .unwrap_or_else(String::new),
-                            c_type,
-                            field_name
-                        )),
-                        ..u
-                    };
-
-                    let u_doc = u.doc.clone();
-                    let ctype = u.c_type.clone();
-
-                    fields.push(Field {
-                        name: field_name,
-                        typ: Type::union(self, u, ns_id),
-                        doc: u_doc,
-                        c_type: ctype,
-                        ..Field::default()
-                    });
-                    union_count += 1;
-                }),
+            "union" => {
+                let field_name = elem
+                    .attr("name")
+                    .map_or_else(|| format!("u{}", union_count), ToOwned::to_owned);
+                let name = nested_name(parent_name_prefix, record_name, &field_name);
+                let nested_c_type = nested_name(parent_ctype_prefix, c_type, &field_name);
+                // Anonymous unions hand their synthesized names down, so that
+                // records nested in sibling unions don't clash.
+                let (name_prefix, ctype_prefix) = match elem.attr("name") {
+                    Some(_) => (record_name.to_owned(), c_type.to_owned()),
+                    None => (name.clone(), nested_c_type.clone()),
+                };
+                self.read_union(parser, ns_id, elem, Some(&name_prefix), Some(&ctype_prefix))
+                    .map(|u| {
+                        let u = Union {
+                            name,
+                            c_type: Some(nested_c_type),
+                            ..u
+                        };
+
+                        let u_doc = u.doc.clone();
+                        let ctype = u.c_type.clone();
+
+                        fields.push(Field {
+                            name: field_name,
+                            typ: Type::union(self, u, ns_id),
+                            doc: u_doc,
+                            c_type: ctype,
+                            anonymous: elem.attr("name").is_none(),
+                            ..Field::default()
+                        });
+                        union_count += 1;
+                    })
+            }
             "field" => {
                 self.read_field(parser, ns_id, elem).map(|mut f| {
                     // Workaround for bitfields
@@ -432,48 +466,32 @@ impl Library {
                 self.read_function_to_vec(parser, ns_id, elem, &mut fns)
             }
             "record" => {
-                let mut r = match self.read_record(
+                let field_name = elem
+                    .attr("name")
+                    .map_or_else(|| format!("s{}", struct_count), ToOwned::to_owned);
+                let name = nested_name(parent_name_prefix, union_name, &field_name);
+                let nested_c_type = nested_name(parent_ctype_prefix, c_type, &field_name);
+                let (name_prefix, ctype_prefix) = match elem.attr("name") {
+                    Some(_) => (
+                        parent_name_prefix.map(ToOwned::to_owned),
+                        parent_ctype_prefix.map(ToOwned::to_owned),
+                    ),
+                    None => (Some(name.clone()), Some(nested_c_type.clone())),
+                };
+                let r = match self.read_record(
                     parser,
                     ns_id,
                     elem,
-                    parent_name_prefix,
-                    parent_ctype_prefix,
+                    name_prefix.as_deref(),
+                    ctype_prefix.as_deref(),
                 )? {
                     Some(Type::Record(r)) => r,
                     _ => return Ok(()),
                 };
 
-                let field_name = if let Some(field_name) = elem.attr("name") {
-                    field_name.into()
-                } else {
-                    format!("s{}", struct_count)
-                };
-
-                r = Record {
-                    name: format!(
-                        "{}{}_{}",
-                        parent_name_prefix
-                            .map(|s| {
-                                let mut s = String::from(s);
-                                s.push('_');
-                                s
-                            })
-                            .unwrap_or_else(String::new),
-                        union_name,
-                        field_name
-                    ),
-                    c_type: format!(
-                        "{}{}_{}",
-                        parent_ctype_prefix
-                            .map(|s| {
-                                let mut s = String::from(s);
-                                s.push('_');
-                                s
-                            })
-                            .unwrap_or_else(String::new),
-                        c_type,
-                        field_name
-                    ),
+                let r = Record {
+                    name,
+                    c_type: nested_c_type,
                     ..r
                 };
 
@@ -485,6 +503,7 @@ impl Library {
                     typ: Type::record(self, r, ns_id),
                     doc: r_doc,
                     c_type: Some(ctype),
+                    anonymous: elem.attr("name").is_none(),
                     ..Field::default()
                 });
 
@@ -553,6 +572,7 @@ impl Library {
                 bits,
                 array_length,
                 doc,
+                anonymous: false,
             })
         } else {
             Err(parser.fail("Missing <type> element"))
@@ -592,6 +612,7 @@ impl Library {
         let mut signals = Vec::new();
         let mut properties = Vec::new();
         let mut prereqs = Vec::new();
+        let mut virtual_methods = Vec::new();
         let mut doc = None;
         let mut doc_deprecated = None;
 
@@ -612,7 +633,10 @@ impl Library {
             }),
             "doc" => parser.text().map(|t| doc = Some(t)),
             "doc-deprecated" => parser.text().map(|t| doc_deprecated = Some(t)),
-            "virtual-method" => parser.ignore_element(),
+            "virtual-method" => {
+                virtual_methods.extend(elem.attr("name").map(ToOwned::to_owned));
+                parser.ignore_element()
+            }
             "source-position" => parser.ignore_element(),
             "attribute" => parser.ignore_element(),
             _ => Err(parser.unexpected_element(elem)),
@@ -628,6 +652,7 @@ impl Library {
             signals,
             properties,
             prerequisites: prereqs,
+            virtual_methods,
             doc,
             doc_deprecated,
             version,
@@ -917,6 +942,8 @@ impl Library {
         let is_method = kind == FunctionKind::Method;
         let version = self.read_version(parser, ns_id, elem)?;
         let deprecated_version = self.read_deprecated_version(parser, ns_id, elem)?;
+        let shadowed_by = elem.attr("shadowed-by").map(ToOwned::to_owned);
+        let shadows = elem.attr("shadows").map(ToOwned::to_owned);
 
         let mut params = Vec::new();
         let mut ret = None;
@@ -955,6 +982,7 @@ impl Library {
                 direction: ParameterDirection::Out,
                 transfer: Transfer::Full,
                 caller_allocates: false,
+                optional: false,
                 nullable: Nullable(true),
                 array_length: None,
                 allow_none: true,
@@ -977,6 +1005,8 @@ impl Library {
                 deprecated_version,
                 doc,
                 doc_deprecated,
+                shadowed_by,
+                shadows,
             })
         } else {
             Err(parser.fail_with_position(
@@ -1006,7 +1036,12 @@ impl Library {
         kind_str: &str,
         elem: &Element,
     ) -> Result<Option<Function>, String> {
-        if elem.attr("moved-to").is_some() {
+        if let Some(moved_to) = elem.attr("moved-to") {
+            if let Some(c_identifier) = elem.attr("identifier") {
+                self.namespace_mut(ns_id)
+                    .moved_functions
+                    .insert(c_identifier.into(), moved_to.into());
+            }
             return parser.ignore_element().map(|_| None);
         }
         self.read_function(parser, ns_id, kind_str, elem)
@@ -1114,6 +1149,7 @@ impl Library {
         let closure = elem.attr_from_str("closure")?;
         let destroy = elem.attr_from_str("destroy")?;
         let caller_allocates = elem.attr_bool("caller-allocates", false);
+        let optional = elem.attr_bool("optional", false);
         let direction = if elem.name() == "return-value" {
             Ok(ParameterDirection::Return)
         } else {
@@ -1167,6 +1203,7 @@ impl Library {
                 direction,
                 transfer,
                 caller_allocates,
+                optional,
                 nullable: Nullable(nullable),
                 allow_none,
                 array_length,
@@ -1185,6 +1222,7 @@ impl Library {
                 direction: Default::default(),
                 transfer: Transfer::None,
                 caller_allocates: false,
+                optional: false,
                 nullable: Nullable(false),
                 allow_none,
                 array_length: None,
@@ -1398,3 +1436,14 @@ fn make_file_name(dir: &Path, name: &str) -> PathBuf {
     path.push(name);
     path
 }
+
+/// Builds the name of a union or record nested in a type, skipping the empty
+/// names of anonymous parents.
+fn nested_name(prefix: Option<&str>, parent: &str, field_name: &str) -> String {
+    prefix
+        .into_iter()
+        .chain([parent, field_name].iter().copied())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
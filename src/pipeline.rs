@@ -0,0 +1,107 @@
+use crate::{
+    analysis, codegen, consistency, env::Env, file_saver::Output, fmt, library::Library,
+    update_version, Config, WorkMode,
+};
+use hprof::Profiler;
+use std::cell::RefCell;
+
+/// Loads the libraries of `cfg`, then analyzes them and generates the code
+/// of its work mode into `output`, recording the time of each step in
+/// `statistics`
+pub fn run(mut cfg: Config, output: Output, statistics: &Profiler) -> Result<Env, String> {
+    let mut library = {
+        let _watcher = statistics.enter("Loading");
+
+        let mut library = Library::new(&cfg.library_name);
+        library.strict_parsing = cfg.strict_parsing;
+        library.version_overrides = cfg.girs_version_overrides.clone();
+        library.read_file(&cfg.girs_dirs, &mut vec![cfg.library_full_name()])?;
+        if library.parse_errors > 0 {
+            log::warn!(
+                "Skipped {} malformed element(s) while loading .gir files",
+                library.parse_errors
+            );
+        }
+        library
+    };
+
+    {
+        let _watcher = statistics.enter("Preprocessing");
+        library.preprocessing(cfg.work_mode, cfg.time_mapping);
+    }
+
+    {
+        let _watcher = statistics.enter("Update library by config");
+        update_version::apply_config(&mut library, &cfg);
+    }
+
+    {
+        let _watcher = statistics.enter("Postprocessing");
+        library.postprocessing(&cfg);
+    }
+
+    {
+        let _watcher = statistics.enter("Resolving type ids");
+        cfg.resolve_type_ids(&library);
+    }
+
+    {
+        let _watcher = statistics.enter("Checking versions");
+        update_version::check_function_real_version(&mut library);
+    }
+
+    let mut env = {
+        let _watcher = statistics.enter("Namespace/symbol/class analysis");
+
+        let namespaces = analysis::namespaces::run(&library);
+        let symbols = analysis::symbols::run(&library, &namespaces);
+        let class_hierarchy = analysis::class_hierarchy::run(&library);
+
+        Env {
+            library,
+            config: cfg,
+            namespaces,
+            symbols: RefCell::new(symbols),
+            class_hierarchy,
+            analysis: Default::default(),
+            output,
+        }
+    };
+
+    if env.config.work_mode != WorkMode::Sys {
+        let _watcher = statistics.enter("Analyzing");
+        analysis::run(&mut env);
+    }
+
+    if env.config.work_mode == WorkMode::Normal {
+        analysis::strip::check(&env)?;
+    }
+
+    if env.config.work_mode == WorkMode::ConsistencyCheck {
+        let _watcher = statistics.enter("Checking consistency");
+        let mismatches = consistency::check(&env)?;
+        if mismatches > 0 {
+            return Err(format!(
+                "{} mismatches found against the -sys crate",
+                mismatches
+            ));
+        }
+    }
+
+    if env.config.work_mode != WorkMode::DisplayNotBound
+        && env.config.work_mode != WorkMode::ConsistencyCheck
+    {
+        let _watcher = statistics.enter("Generating");
+        codegen::generate(&env);
+    }
+
+    if !env.config.disable_format
+        && matches!(env.output, Output::Disk)
+        && env.config.work_mode.is_generate_rust_files()
+    {
+        let _watcher = statistics.enter("Formatting");
+        fmt::format(&env.config.target_path);
+    }
+
+    Ok(env)
+}
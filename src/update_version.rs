@@ -29,31 +29,39 @@ pub fn check_function_real_version(library: &mut Library) {
     );
 }
 
-fn check_versions(param: &Parameter, current_version: &mut Option<Version>, lib: *const Library) {
-    if param.typ.ns_id != MAIN_NAMESPACE {
-        return;
-    }
-    let ty_version = match unsafe { (*lib).type_(param.typ) } {
-        library::Type::Class(c) => c.version,
-        library::Type::Enumeration(c) => c.version,
-        library::Type::Bitfield(c) => c.version,
-        library::Type::Record(c) => c.version,
-        library::Type::Interface(c) => c.version,
-        _ => None,
-    };
-    let new_version = match (*current_version, ty_version) {
-        (Some(current_version), Some(ty_version)) => {
-            if current_version < ty_version {
-                Some(ty_version)
-            } else {
-                None
-            }
-        }
-        (None, Some(ty_version)) => Some(ty_version),
+/// Returns the version required by a type of the main namespace, looking into
+/// the types it is built from, like the elements of containers or the
+/// parameters of callbacks
+fn type_version(lib: &Library, tid: library::TypeId) -> Option<Version> {
+    match lib.type_(tid) {
+        Type::Array(t)
+        | Type::CArray(t)
+        | Type::FixedArray(t, _, _)
+        | Type::PtrArray(t)
+        | Type::List(t)
+        | Type::SList(t) => type_version(lib, *t),
+        Type::HashTable(k, v) => type_version(lib, *k).max(type_version(lib, *v)),
+        _ if tid.ns_id != MAIN_NAMESPACE => None,
+        Type::Alias(a) => type_version(lib, a.typ),
+        Type::Function(f) => f
+            .parameters
+            .iter()
+            .chain(std::iter::once(&f.ret))
+            .map(|p| type_version(lib, p.typ))
+            .fold(f.version, Ord::max),
+        Type::Class(c) => c.version,
+        Type::Enumeration(c) => c.version,
+        Type::Bitfield(c) => c.version,
+        Type::Record(c) => c.version,
+        Type::Interface(c) => c.version,
         _ => None,
-    };
-    if let Some(new_version) = new_version {
-        *current_version = Some(new_version);
+    }
+}
+
+fn check_versions(param: &Parameter, current_version: &mut Option<Version>, lib: *const Library) {
+    let ty_version = type_version(unsafe { &*lib }, param.typ);
+    if ty_version > *current_version {
+        *current_version = ty_version;
     }
 }
 
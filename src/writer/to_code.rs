@@ -4,6 +4,7 @@ use crate::{
     chunk::{Chunk, Param, TupleMode},
     codegen::{translate_from_glib::TranslateFromGlib, translate_to_glib::TranslateToGlib},
     env::Env,
+    nameutil::use_glib_type,
 };
 
 pub trait ToCode {
@@ -38,21 +39,36 @@ impl ToCode for Chunk {
                 vec![s]
             }
             FfiCallOutParameter { ref par } => {
-                let s = if par.caller_allocates {
-                    format!("{}.to_glib_none_mut().0", par.name)
-                } else if par.is_uninitialized && !par.is_error {
+                let s = if par.is_uninitialized && !par.is_error {
                     format!("{}.as_mut_ptr()", par.name)
+                } else if par.caller_allocates {
+                    format!("{}.to_glib_none_mut().0", par.name)
                 } else {
                     format!("&mut {}", par.name)
                 };
-                vec![s]
+                match par.requested_by {
+                    Some(ref flag) => vec![format!(
+                        "if {} {{ {} }} else {{ std::ptr::null_mut() }}",
+                        flag, s
+                    )],
+                    None => vec![s],
+                }
             }
             FfiCallConversion {
                 ref ret,
                 ref array_length_name,
                 ref call,
             } => {
-                let call_strings = call.to_code(env);
+                let mut call_strings = call.to_code(env);
+                if ret.debug_assert_not_null {
+                    call_strings = vec![format_block_one_line(
+                        "{ let ret = ",
+                        "; debug_assert!(!ret.is_null(), \"unexpected NULL return value\"); ret }",
+                        &call_strings,
+                        "",
+                        "",
+                    )];
+                }
                 let (prefix, suffix) =
                     ret.translate_from_glib_as_function(env, array_length_name.as_ref());
                 let s = format_block_one_line(&prefix, &suffix, &call_strings, "", "");
@@ -123,6 +139,54 @@ impl ToCode for Chunk {
                 let s = format_block_one_line(prefix, suffix, &value_strings, "", "");
                 vec![s]
             }
+            BoolErrorReturn {
+                ref condition,
+                ref message,
+                ref value,
+            } => {
+                let value_strings = value.to_code(env);
+                let prefix = format!("if {} {{ Ok(", condition);
+                let suffix = format!(
+                    ") }} else {{ Err({}(\"{}\")) }}",
+                    use_glib_type(env, "bool_error!"),
+                    message
+                );
+                let s = format_block_one_line(&prefix, &suffix, &value_strings, "", "");
+                vec![s]
+            }
+            NoneIsError {
+                ref message,
+                ref value,
+            } => {
+                let value_strings = value.to_code(env);
+                let suffix = format!(
+                    ", || {}(\"{}\"))?",
+                    use_glib_type(env, "bool_error!"),
+                    message
+                );
+                let s =
+                    format_block_one_line("Option::ok_or_else(", &suffix, &value_strings, "", "");
+                vec![s]
+            }
+            Requested {
+                ref flag,
+                nullable,
+                ref value,
+            } => {
+                let value_strings = value.to_code(env);
+                let (prefix, suffix) = if nullable {
+                    (format!("if {} {{ ", flag), " } else { None }")
+                } else {
+                    (format!("if {} {{ Some(", flag), ") } else { None }")
+                };
+                vec![format_block_one_line(
+                    &prefix,
+                    suffix,
+                    &value_strings,
+                    "",
+                    "",
+                )]
+            }
             AssertInit(x) => vec![safety_assertion_mode_to_str(x).to_owned()],
             Connect {
                 ref signal,
@@ -27,6 +27,8 @@ pub struct XmlParser<'a> {
     peek_position: TextPosition,
     /// Used to emits errors. Rc so that it can be cheaply shared with Element type.
     error_emitter: Rc<ErrorEmitter>,
+    /// Number of currently open elements, counting consumed events only.
+    depth: usize,
 }
 
 struct ErrorEmitter {
@@ -151,6 +153,7 @@ impl<'a> XmlParser<'a> {
                 error_emitter: Rc::new(ErrorEmitter {
                     path: Some(path.to_owned()),
                 }),
+                depth: 0,
             }),
         }
     }
@@ -162,6 +165,7 @@ impl<'a> XmlParser<'a> {
             peek_event: None,
             peek_position: TextPosition::new(),
             error_emitter: Rc::new(ErrorEmitter { path: None }),
+            depth: 0,
         })
     }
 
@@ -203,10 +207,16 @@ impl<'a> XmlParser<'a> {
 
     /// Consumes and returns next XML event.
     fn next_event(&mut self) -> Result<XmlEvent, String> {
-        match self.peek_event.take() {
+        let event = match self.peek_event.take() {
             None => self.next_event_impl(),
             Some(e) => e,
+        };
+        match event {
+            Ok(XmlEvent::StartElement { .. }) => self.depth += 1,
+            Ok(XmlEvent::EndElement { .. }) => self.depth -= 1,
+            _ => (),
         }
+        event
     }
 
     /// Returns next XML event directly from parser.
@@ -262,6 +272,46 @@ impl<'a> XmlParser<'a> {
         }
     }
 
+    /// Like `elements`, but an error returned by `f` doesn't abort parsing.
+    ///
+    /// The error is appended to `errors` and the rest of the failing element
+    /// is skipped. Errors from the underlying XML reader are still fatal.
+    pub fn elements_recovering<F>(
+        &mut self,
+        errors: &mut Vec<String>,
+        mut f: F,
+    ) -> Result<(), String>
+    where
+        F: FnMut(&mut XmlParser<'_>, &Element) -> Result<(), String>,
+    {
+        loop {
+            match *self.peek_event() {
+                Ok(XmlEvent::StartElement { .. }) => {
+                    let depth = self.depth;
+                    let element = self.start_element()?;
+                    match f(self, &element) {
+                        Ok(()) => self.end_element()?,
+                        Err(e) => {
+                            errors.push(e);
+                            self.skip_to_depth(depth)?;
+                        }
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+    }
+
+    /// Consumes events until only `depth` elements remain open.
+    fn skip_to_depth(&mut self, depth: usize) -> Result<(), String> {
+        while self.depth > depth {
+            if let XmlEvent::EndDocument = self.next_event()? {
+                return Err(self.fail("Unexpected end of document"));
+            }
+        }
+        Ok(())
+    }
+
     pub fn element_with_name<R, F>(&mut self, expected_name: &str, f: F) -> Result<R, String>
     where
         F: FnOnce(&mut XmlParser<'_>, &Element) -> Result<R, String>,
@@ -413,6 +463,36 @@ mod tests {
         assert_eq!("a.b.c", result);
     }
 
+    #[test]
+    fn test_elements_recovering() {
+        let xml = br#"<?xml version="1.0"?>
+            <root>
+                <child name="a" />
+                <child>
+                    <nested><deeper /></nested>
+                </child>
+                <child name="c" />
+            </root>"#;
+
+        let mut errors = Vec::new();
+        let mut names = Vec::new();
+        with_parser(xml, |mut p| {
+            p.document(|p, _| {
+                p.element_with_name("root", |p, _| {
+                    p.elements_recovering(&mut errors, |_, elem| {
+                        names.push(elem.attr_required("name")?.to_owned());
+                        Ok(())
+                    })
+                })
+            })
+        })
+        .unwrap();
+
+        assert_eq!(names, ["a", "c"]);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("`name`"), "{}", errors[0]);
+    }
+
     #[test]
     fn test_text() {
         let xml = br#"<?xml version="1.0"?>
@@ -0,0 +1,173 @@
+//! Generates the bindings of every library in `tests/generation` and compares
+//! them with the files in its `expected` directory.
+//!
+//! Run `GIR_BLESS=1 cargo test --test generation` to update the expected files
+//! after an intended change of the generated code.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use hprof::Profiler;
+use libgir::{self as gir, Config, ConfigOptions, Output};
+use regex::Regex;
+
+const CASES_DIR: &str = "tests/generation";
+const EXPECTED_DIR: &str = "expected";
+
+/// Runs gir, keeping the generated files in memory
+fn generate(config_file: &Path) -> Result<BTreeMap<PathBuf, String>, String> {
+    let cfg = Config::new(&ConfigOptions {
+        config_file: config_file.to_str(),
+        disable_format: true,
+        strict_parsing: true,
+        dry_run: true,
+        ..Default::default()
+    })?;
+
+    let env = gir::run(cfg, Output::memory(), &Profiler::new("Gir"))?;
+
+    let target_path = &env.config.target_path;
+    Ok(env
+        .output
+        .files()
+        .into_iter()
+        .map(|(path, content)| {
+            let path = path.strip_prefix(target_path).unwrap_or(&path).to_owned();
+            (path, normalize(&content))
+        })
+        .collect())
+}
+
+/// Hides the versions of gir and of the `.gir` files, which change with
+/// every commit
+fn normalize(content: &str) -> String {
+    let gir_version =
+        Regex::new(r"(Generated by gir \(https://github.com/gtk-rs/gir) @ [^)]*\)").unwrap();
+    let girs_version = Regex::new(r"(?m)^(// from \S+) \(.*\)$").unwrap();
+    let content = gir_version.replace_all(content, "$1)");
+    girs_version.replace_all(&content, "$1").into_owned()
+}
+
+fn read_expected(dir: &Path, files: &mut BTreeMap<PathBuf, String>, root: &Path) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_expected(&path, files, root);
+        } else {
+            let content = fs::read_to_string(&path).unwrap();
+            files.insert(path.strip_prefix(root).unwrap().to_owned(), content);
+        }
+    }
+}
+
+fn bless(expected_dir: &Path, files: &BTreeMap<PathBuf, String>) {
+    if expected_dir.exists() {
+        fs::remove_dir_all(expected_dir).unwrap();
+    }
+    for (path, content) in files {
+        let path = expected_dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+}
+
+/// Describes how the generated files differ from the expected ones
+fn compare(
+    case: &str,
+    expected: &BTreeMap<PathBuf, String>,
+    generated: &BTreeMap<PathBuf, String>,
+) -> Vec<String> {
+    let mut mismatches = Vec::new();
+    for path in expected.keys().filter(|p| !generated.contains_key(*p)) {
+        mismatches.push(format!("{}: `{}` not generated", case, path.display()));
+    }
+    for (path, content) in generated {
+        let expected = match expected.get(path) {
+            Some(expected) => expected,
+            None => {
+                mismatches.push(format!("{}: `{}` not expected", case, path.display()));
+                continue;
+            }
+        };
+        let mut expected_lines = expected.lines();
+        let mut lines = content.lines();
+        for line_no in 1.. {
+            match (expected_lines.next(), lines.next()) {
+                (None, None) => break,
+                (expected_line, line) if expected_line == line => {}
+                (expected_line, line) => {
+                    mismatches.push(format!(
+                        "{}: `{}` differs at line {}\n  expected: {}\n  generated: {}",
+                        case,
+                        path.display(),
+                        line_no,
+                        expected_line.unwrap_or("<end of file>"),
+                        line.unwrap_or("<end of file>"),
+                    ));
+                    break;
+                }
+            }
+        }
+    }
+    mismatches
+}
+
+#[test]
+fn dry_run_keeps_auto_path() {
+    let target_path = std::env::temp_dir().join(format!("gir-dry-run-{}", std::process::id()));
+    let kept = target_path.join("src").join("auto").join("mod.rs");
+    fs::create_dir_all(kept.parent().unwrap()).unwrap();
+    fs::write(&kept, "").unwrap();
+
+    let config_file = Path::new(CASES_DIR).join("enums").join("Gir.toml");
+    let cfg = Config::new(&ConfigOptions {
+        config_file: config_file.to_str(),
+        target_path: target_path.to_str(),
+        dry_run: true,
+        ..Default::default()
+    });
+    let survived = kept.exists();
+    fs::remove_dir_all(&target_path).unwrap();
+
+    assert!(cfg.is_ok());
+    assert!(survived, "`src/auto` removed in dry run mode");
+}
+
+#[test]
+fn generated_code_matches_expected() {
+    let blessing = std::env::var_os("GIR_BLESS").is_some();
+    let mut cases = fs::read_dir(CASES_DIR)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.join("Gir.toml").is_file())
+        .collect::<Vec<_>>();
+    cases.sort();
+    assert!(!cases.is_empty(), "No cases found in {}", CASES_DIR);
+
+    let mut mismatches = Vec::new();
+    for case_dir in &cases {
+        let case = case_dir.file_name().unwrap().to_str().unwrap();
+        let generated = generate(&case_dir.join("Gir.toml"))
+            .unwrap_or_else(|e| panic!("{}: generation failed: {}", case, e));
+        let expected_dir = case_dir.join(EXPECTED_DIR);
+        if blessing {
+            bless(&expected_dir, &generated);
+            continue;
+        }
+        let mut expected = BTreeMap::new();
+        if expected_dir.is_dir() {
+            read_expected(&expected_dir, &mut expected, &expected_dir);
+        }
+        mismatches.extend(compare(case, &expected, &generated));
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "Generated code differs from the expected one, run with `GIR_BLESS=1` \
+         if intended:\n{}",
+        mismatches.join("\n")
+    );
+}
@@ -0,0 +1,140 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/arrays
+// from tests/generation/girs
+// DO NOT EDIT
+
+use crate::Item;
+use crate::Region;
+use glib::translate::*;
+use std::mem;
+use std::ptr;
+
+
+#[doc(alias = "test_sum")]
+pub fn sum(values: &[i32]) -> i32 {
+    let n_values = values.len() as usize;
+    unsafe {
+        ffi::test_sum(values.to_glib_none().0, n_values)
+    }
+}
+
+#[doc(alias = "test_write")]
+pub fn write(data: &[u8]) -> bool {
+    let len = data.len() as usize;
+    unsafe {
+        from_glib(ffi::test_write(data.to_glib_none().0, len))
+    }
+}
+
+#[doc(alias = "test_get_values")]
+#[doc(alias = "get_values")]
+pub fn values() -> Vec<i32> {
+    unsafe {
+        let mut n_values = mem::MaybeUninit::uninit();
+        let ret = FromGlibPtrContainer::from_glib_full(ffi::test_get_values(n_values.as_mut_ptr()));
+        let n_values = n_values.assume_init();
+        ret
+    }
+}
+
+#[doc(alias = "test_join")]
+pub fn join(strings: &[&str]) -> Option<glib::GString> {
+    unsafe {
+        from_glib_full(ffi::test_join(strings.to_glib_none().0))
+    }
+}
+
+#[doc(alias = "test_list_names")]
+pub fn list_names() -> Vec<glib::GString> {
+    unsafe {
+        FromGlibPtrContainer::from_glib_full(ffi::test_list_names())
+    }
+}
+
+#[doc(alias = "test_set_matrix")]
+pub fn set_matrix(matrix: &[f64; 4]) {
+    unsafe {
+        ffi::test_set_matrix(matrix.as_ptr() as *mut _);
+    }
+}
+
+#[doc(alias = "test_set_names")]
+pub fn set_names(names: &[&str]) {
+    unsafe {
+        ffi::test_set_names(names.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_set_labels")]
+pub fn set_labels(labels: &[&str]) {
+    let n_labels = labels.len() as usize;
+    unsafe {
+        ffi::test_set_labels(labels.to_glib_none().0, n_labels);
+    }
+}
+
+#[doc(alias = "test_set_items")]
+pub fn set_items(items: &[Item]) {
+    unsafe {
+        ffi::test_set_items(items.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_get_items")]
+#[doc(alias = "get_items")]
+pub fn items() -> Vec<Item> {
+    unsafe {
+        let mut items = ptr::null_mut();
+        ffi::test_get_items(&mut items);
+        FromGlibPtrContainer::from_glib_full(items)
+    }
+}
+
+#[doc(alias = "test_get_shared_items")]
+#[doc(alias = "get_shared_items")]
+pub fn shared_items() -> Vec<Item> {
+    unsafe {
+        let mut items = ptr::null_mut();
+        ffi::test_get_shared_items(&mut items);
+        FromGlibPtrContainer::from_glib_container(items)
+    }
+}
+
+#[doc(alias = "test_set_regions")]
+pub fn set_regions(regions: &[&Region]) {
+    unsafe {
+        ffi::test_set_regions(regions.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_set_attributes")]
+pub fn set_attributes(attributes: &std::collections::HashMap<String, String>) {
+    unsafe {
+        ffi::test_set_attributes(crate::hash_table_to_glib_none(Some(attributes)).0);
+    }
+}
+
+#[doc(alias = "test_take_attributes")]
+pub fn take_attributes(attributes: &std::collections::HashMap<String, String>) {
+    unsafe {
+        ffi::test_take_attributes(crate::hash_table_to_glib_full(Some(attributes)));
+    }
+}
+
+#[doc(alias = "test_get_attributes")]
+#[doc(alias = "get_attributes")]
+pub fn attributes() -> Option<std::collections::HashMap<String, String>> {
+    unsafe {
+        from_glib_full(ffi::test_get_attributes())
+    }
+}
+
+#[doc(alias = "test_get_metadata")]
+#[doc(alias = "get_metadata")]
+pub fn metadata(key: &str) -> std::collections::HashMap<String, String> {
+    unsafe {
+        let mut metadata = ptr::null_mut();
+        ffi::test_get_metadata(key.to_glib_none().0, &mut metadata);
+        from_glib_full(metadata)
+    }
+}
@@ -0,0 +1,165 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/arrays
+// from tests/generation/girs
+// DO NOT EDIT
+
+#![allow(dead_code)]
+
+use glib::translate::*;
+use std::collections::HashMap;
+
+pub(crate) trait HashTableItem {
+    fn hash_func() -> glib::ffi::GHashFunc;
+    fn equal_func() -> glib::ffi::GEqualFunc;
+    fn destroy_func() -> glib::ffi::GDestroyNotify;
+    fn to_glib_item(&self) -> glib::ffi::gpointer;
+}
+
+impl HashTableItem for String {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_str_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_str_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        Some(glib::ffi::g_free)
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        ToGlibPtr::<*mut std::os::raw::c_char>::to_glib_full(self) as _
+    }
+}
+
+impl HashTableItem for bool {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        self.into_glib() as isize as _
+    }
+}
+
+impl HashTableItem for i8 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as isize as _
+    }
+}
+
+impl HashTableItem for u8 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as usize as _
+    }
+}
+
+impl HashTableItem for i16 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as isize as _
+    }
+}
+
+impl HashTableItem for u16 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as usize as _
+    }
+}
+
+impl HashTableItem for i32 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as isize as _
+    }
+}
+
+impl HashTableItem for u32 {
+    fn hash_func() -> glib::ffi::GHashFunc {
+        Some(glib::ffi::g_direct_hash)
+    }
+    fn equal_func() -> glib::ffi::GEqualFunc {
+        Some(glib::ffi::g_direct_equal)
+    }
+    fn destroy_func() -> glib::ffi::GDestroyNotify {
+        None
+    }
+    fn to_glib_item(&self) -> glib::ffi::gpointer {
+        *self as usize as _
+    }
+}
+
+/// Reference on a table, released once the call is done
+pub(crate) struct HashTableStash(pub *mut glib::ffi::GHashTable);
+
+impl Drop for HashTableStash {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { glib::ffi::g_hash_table_unref(self.0) }
+        }
+    }
+}
+
+pub(crate) fn hash_table_to_glib_none<K: HashTableItem, V: HashTableItem>(map: Option<&HashMap<K, V>>) -> HashTableStash {
+    HashTableStash(hash_table_to_glib_full(map))
+}
+
+pub(crate) fn hash_table_to_glib_full<K: HashTableItem, V: HashTableItem>(map: Option<&HashMap<K, V>>) -> *mut glib::ffi::GHashTable {
+    let map = match map {
+        Some(map) => map,
+        None => return std::ptr::null_mut(),
+    };
+    unsafe {
+        let table = glib::ffi::g_hash_table_new_full(K::hash_func(), K::equal_func(), K::destroy_func(), V::destroy_func());
+        for (key, value) in map {
+            glib::ffi::g_hash_table_insert(table, key.to_glib_item(), value.to_glib_item());
+        }
+        table
+    }
+}
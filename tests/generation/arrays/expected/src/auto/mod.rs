@@ -0,0 +1,17 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/arrays
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod item;
+pub use self::item::Item;
+
+mod region;
+pub use self::region::Region;
+
+pub mod functions;
+
+mod hash_tables;
+#[allow(unused_imports)]
+pub(crate) use self::hash_tables::*;
+
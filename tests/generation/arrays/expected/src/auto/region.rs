@@ -0,0 +1,15 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/arrays
+// from tests/generation/girs
+// DO NOT EDIT
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Region(Boxed<ffi::TestRegion>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_region_get_type(), ptr as *mut _) as *mut ffi::TestRegion,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_region_get_type(), ptr as *mut _),
+        type_ => || ffi::test_region_get_type(),
+    }
+}
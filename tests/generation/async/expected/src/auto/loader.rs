@@ -0,0 +1,361 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/async
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::object::Cast;
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::pin::Pin;
+use std::ptr;
+
+glib::wrapper! {
+    #[doc(alias = "TestLoader")]
+    pub struct Loader(Object<ffi::TestLoader, ffi::TestLoaderClass>);
+
+    match fn {
+        type_ => || ffi::test_loader_get_type(),
+    }
+}
+
+impl Loader {
+    #[doc(alias = "test_loader_load_async")]
+    pub fn load_async<P: FnOnce(Result<glib::GString, glib::Error>) + Send + 'static>(&self, uri: &str, cancellable: Option<&gio::Cancellable>, callback: P) {
+        let user_data: Box_<P> = Box_::new(callback);
+        unsafe extern "C" fn load_async_trampoline<P: FnOnce(Result<glib::GString, glib::Error>) + Send + 'static>(_source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+            let mut error = ptr::null_mut();
+            let ret = ffi::test_loader_load_finish(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() { Ok(from_glib_full(ret)) } else { Err(from_glib_full(error)) };
+            let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = load_async_trampoline::<P>;
+        unsafe {
+            ffi::test_loader_load_async(self.to_glib_none().0, uri.to_glib_none().0, cancellable.to_glib_none().0, Some(callback), Box_::into_raw(user_data) as *mut _);
+        }
+    }
+
+    
+    pub fn load_async_future(&self, uri: &str) -> Pin<Box_<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>> {
+
+        let uri = String::from(uri);
+        Box_::pin(gio::GioFuture::new(self, move |obj, cancellable, send| {
+            obj.load_async(
+                &uri,
+                Some(cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+        }))
+    }
+
+    #[doc(alias = "test_loader_close_async")]
+    pub fn close_async<P: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, cancellable: Option<&gio::Cancellable>, callback: P) {
+        let user_data: Box_<P> = Box_::new(callback);
+        unsafe extern "C" fn close_async_trampoline<P: FnOnce(Result<(), glib::Error>) + Send + 'static>(_source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+            let mut error = ptr::null_mut();
+            let _ = ffi::test_loader_close_finish(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() { Ok(()) } else { Err(from_glib_full(error)) };
+            let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = close_async_trampoline::<P>;
+        unsafe {
+            ffi::test_loader_close_async(self.to_glib_none().0, cancellable.to_glib_none().0, Some(callback), Box_::into_raw(user_data) as *mut _);
+        }
+    }
+
+    
+    pub fn close_async_future(&self) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+
+        Box_::pin(gio::GioFuture::new(self, move |obj, cancellable, send| {
+            obj.close_async(
+                Some(cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+        }))
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Returns a request calling `close_async_future()` with default arguments once `.await`ed.
+    pub fn close_request(&self) -> LoaderCloseRequest {
+        LoaderCloseRequest { obj: self.clone() }
+    }
+
+    #[doc(alias = "test_loader_store_async")]
+    pub fn store_async<P: FnOnce(Result<(), glib::Error>) + Send + 'static>(&self, cancellable: Option<&gio::Cancellable>, callback: P) {
+        let user_data: Box_<P> = Box_::new(callback);
+        unsafe extern "C" fn store_async_trampoline<P: FnOnce(Result<(), glib::Error>) + Send + 'static>(_source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+            let mut error = ptr::null_mut();
+            let _ = ffi::test_loader_store_end(_source_object as *mut _, res, &mut error);
+            let result = if error.is_null() { Ok(()) } else { Err(from_glib_full(error)) };
+            let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+            callback(result);
+        }
+        let callback = store_async_trampoline::<P>;
+        unsafe {
+            ffi::test_loader_store_async(self.to_glib_none().0, cancellable.to_glib_none().0, Some(callback), Box_::into_raw(user_data) as *mut _);
+        }
+    }
+
+    
+    pub fn store_async_future(&self) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+
+        Box_::pin(gio::GioFuture::new(self, move |obj, cancellable, send| {
+            obj.store_async(
+                Some(cancellable),
+                move |res| {
+                    send.resolve(res);
+                },
+            );
+        }))
+    }
+
+    #[doc(alias = "test_loader_compute")]
+    pub fn compute<P: FnOnce(i32) + 'static>(&self, callback: P) {
+        let callback_data: Box_<P> = Box_::new(callback);
+        unsafe extern "C" fn callback_func<P: FnOnce(i32) + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) {
+            let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+            (*callback)(value);
+        }
+        let callback = Some(callback_func::<P> as _);
+        let super_callback0: Box_<P> = callback_data;
+        unsafe {
+            ffi::test_loader_compute(self.to_glib_none().0, callback, Box_::into_raw(super_callback0) as *mut _);
+        }
+    }
+
+    
+    pub fn compute_future(&self) -> Pin<Box_<dyn std::future::Future<Output = i32> + 'static>> {
+
+        Box_::pin(gio::GioFuture::new(self, move |obj, _cancellable, send| {
+            obj.compute(
+                move |value| {
+                    send.resolve(value);
+                },
+            );
+        }))
+    }
+
+    #[doc(alias = "test_loader_watch")]
+    pub fn watch<P: Fn(i32) + 'static>(&self, callback: P) {
+        let callback_data: Box_<P> = Box_::new(callback);
+        unsafe extern "C" fn callback_func<P: Fn(i32) + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) {
+            let callback: &P = &*(user_data as *mut _);
+            (*callback)(value);
+        }
+        let callback = Some(callback_func::<P> as _);
+        unsafe extern "C" fn notify_func<P: Fn(i32) + 'static>(data: glib::ffi::gpointer) {
+            let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+        }
+        let destroy_call3 = Some(notify_func::<P> as _);
+        let super_callback0: Box_<P> = callback_data;
+        unsafe {
+            ffi::test_loader_watch(self.to_glib_none().0, callback, Box_::into_raw(super_callback0) as *mut _, destroy_call3);
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Trait to implement in subclasses of [`Loader`], overriding its virtual functions.
+pub trait LoaderImpl: LoaderImplExt + ObjectImpl {
+    // rustdoc-stripper-ignore-next
+    /// Overrides `load_async` and `load_finish`, running the future on the thread-default main context.
+    fn load_future(&self, loader: &Self::Type, uri: &str) -> Pin<Box_<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>> {
+        self.parent_load_future(loader, uri)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Overrides `close_async` and `close_finish`, running the future on the thread-default main context.
+    fn close_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        self.parent_close_future(loader)
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Overrides `flush_async` and `flush_finish`, running the future on the thread-default main context.
+    fn flush_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        self.parent_flush_future(loader)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Calls of the parent implementations of the virtual functions of [`Loader`].
+pub trait LoaderImplExt: ObjectSubclass {
+    fn parent_load_future(&self, loader: &Self::Type, uri: &str) -> Pin<Box_<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>>;
+    fn parent_close_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>;
+    fn parent_flush_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>>;
+}
+
+impl<T: LoaderImpl> LoaderImplExt for T {
+    fn parent_load_future(&self, loader: &Self::Type, uri: &str) -> Pin<Box_<dyn std::future::Future<Output = Result<glib::GString, glib::Error>> + 'static>> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+            let f = (*parent_class).load_async.expect("No parent implementation of `load_async`");
+            let uri = uri.to_owned();
+            Box_::pin(gio::GioFuture::new(loader, move |obj, cancellable, send| {
+                f(obj.unsafe_cast_ref::<Loader>().to_glib_none().0, uri.to_glib_none().0, cancellable.to_glib_none().0, Some(loader_parent_load_ready::<T>), Box_::into_raw(Box_::new(send)) as *mut _);
+            }))
+        }
+    }
+
+    fn parent_close_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+            let f = (*parent_class).close_async.expect("No parent implementation of `close_async`");
+            Box_::pin(gio::GioFuture::new(loader, move |obj, cancellable, send| {
+                f(obj.unsafe_cast_ref::<Loader>().to_glib_none().0, cancellable.to_glib_none().0, Some(loader_parent_close_ready::<T>), Box_::into_raw(Box_::new(send)) as *mut _);
+            }))
+        }
+    }
+
+    fn parent_flush_future(&self, loader: &Self::Type) -> Pin<Box_<dyn std::future::Future<Output = Result<(), glib::Error>> + 'static>> {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+            let f = (*parent_class).flush_async.expect("No parent implementation of `flush_async`");
+            Box_::pin(gio::GioFuture::new(loader, move |obj, _cancellable, send| {
+                f(obj.unsafe_cast_ref::<Loader>().to_glib_none().0, Some(loader_parent_flush_ready::<T>), Box_::into_raw(Box_::new(send)) as *mut _);
+            }))
+        }
+    }
+}
+
+unsafe impl<T: LoaderImpl> IsSubclassable<T> for Loader {
+    fn class_init(class: &mut glib::Class<Self>) {
+        Self::parent_class_init::<T>(class);
+
+        let klass = class.as_mut();
+        klass.load_async = Some(loader_load_async::<T>);
+        klass.load_finish = Some(loader_load_finish::<T>);
+        klass.close_async = Some(loader_close_async::<T>);
+        klass.close_finish = Some(loader_close_finish::<T>);
+        klass.flush_async = Some(loader_flush_async::<T>);
+        klass.flush_finish = Some(loader_flush_finish::<T>);
+    }
+}
+
+unsafe extern "C" fn loader_load_async<T: LoaderImpl>(this: *mut ffi::TestLoader, uri: *const libc::c_char, cancellable: *mut gio::ffi::GCancellable, callback: gio::ffi::GAsyncReadyCallback, user_data: glib::ffi::gpointer) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    let future = imp.load_future(from_glib_borrow::<_, Loader>(this).unsafe_cast_ref(), &glib::GString::from_glib_borrow(uri));
+    let task = gio::ffi::g_task_new(this as *mut _, cancellable, callback, user_data);
+    glib::MainContext::ref_thread_default().spawn_local(async move {
+        match future.await {
+            Ok(value) => {
+                unsafe extern "C" fn drop_value(value: glib::ffi::gpointer) {
+                    let _ = Box_::from_raw(value as *mut glib::GString);
+                }
+                gio::ffi::g_task_return_pointer(task, Box_::into_raw(Box_::new(value)) as *mut _, Some(drop_value));
+            }
+            Err(error) => gio::ffi::g_task_return_error(task, error.into_raw()),
+        }
+        glib::gobject_ffi::g_object_unref(task as *mut _);
+    });
+}
+
+unsafe extern "C" fn loader_load_finish<T: LoaderImpl>(_this: *mut ffi::TestLoader, result: *mut gio::ffi::GAsyncResult, error: *mut *mut glib::ffi::GError) -> *mut libc::c_char {
+    let value = gio::ffi::g_task_propagate_pointer(result as *mut _, error) as *mut glib::GString;
+    if value.is_null() {
+        return ptr::null_mut();
+    }
+    Box_::from_raw(value).to_glib_full()
+}
+
+unsafe extern "C" fn loader_parent_load_ready<T: LoaderImpl>(source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+    let data = T::type_data();
+    let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+    let f = (*parent_class).load_finish.expect("No parent implementation of `load_finish`");
+    let mut error = ptr::null_mut();
+    let ret = f(source_object as *mut _, res, &mut error);
+    let result = if error.is_null() { Ok(from_glib_full(ret)) } else { Err(from_glib_full(error)) };
+    let send: Box_<gio::GioFutureResult<glib::GString, glib::Error>> = Box_::from_raw(user_data as *mut _);
+    send.resolve(result);
+}
+
+unsafe extern "C" fn loader_close_async<T: LoaderImpl>(this: *mut ffi::TestLoader, cancellable: *mut gio::ffi::GCancellable, callback: gio::ffi::GAsyncReadyCallback, user_data: glib::ffi::gpointer) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    let future = imp.close_future(from_glib_borrow::<_, Loader>(this).unsafe_cast_ref());
+    let task = gio::ffi::g_task_new(this as *mut _, cancellable, callback, user_data);
+    glib::MainContext::ref_thread_default().spawn_local(async move {
+        match future.await {
+            Ok(()) => gio::ffi::g_task_return_boolean(task, glib::ffi::GTRUE),
+            Err(error) => gio::ffi::g_task_return_error(task, error.into_raw()),
+        }
+        glib::gobject_ffi::g_object_unref(task as *mut _);
+    });
+}
+
+unsafe extern "C" fn loader_close_finish<T: LoaderImpl>(_this: *mut ffi::TestLoader, result: *mut gio::ffi::GAsyncResult, error: *mut *mut glib::ffi::GError) -> glib::ffi::gboolean {
+    gio::ffi::g_task_propagate_boolean(result as *mut _, error)
+}
+
+unsafe extern "C" fn loader_parent_close_ready<T: LoaderImpl>(source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+    let data = T::type_data();
+    let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+    let f = (*parent_class).close_finish.expect("No parent implementation of `close_finish`");
+    let mut error = ptr::null_mut();
+    f(source_object as *mut _, res, &mut error);
+    let result = if error.is_null() { Ok(()) } else { Err(from_glib_full(error)) };
+    let send: Box_<gio::GioFutureResult<(), glib::Error>> = Box_::from_raw(user_data as *mut _);
+    send.resolve(result);
+}
+
+unsafe extern "C" fn loader_flush_async<T: LoaderImpl>(this: *mut ffi::TestLoader, callback: gio::ffi::GAsyncReadyCallback, user_data: glib::ffi::gpointer) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    let future = imp.flush_future(from_glib_borrow::<_, Loader>(this).unsafe_cast_ref());
+    let task = gio::ffi::g_task_new(this as *mut _, ptr::null_mut(), callback, user_data);
+    glib::MainContext::ref_thread_default().spawn_local(async move {
+        match future.await {
+            Ok(()) => gio::ffi::g_task_return_boolean(task, glib::ffi::GTRUE),
+            Err(error) => gio::ffi::g_task_return_error(task, error.into_raw()),
+        }
+        glib::gobject_ffi::g_object_unref(task as *mut _);
+    });
+}
+
+unsafe extern "C" fn loader_flush_finish<T: LoaderImpl>(_this: *mut ffi::TestLoader, result: *mut gio::ffi::GAsyncResult, error: *mut *mut glib::ffi::GError) {
+    gio::ffi::g_task_propagate_boolean(result as *mut _, error);
+}
+
+unsafe extern "C" fn loader_parent_flush_ready<T: LoaderImpl>(source_object: *mut glib::gobject_ffi::GObject, res: *mut gio::ffi::GAsyncResult, user_data: glib::ffi::gpointer) {
+    let data = T::type_data();
+    let parent_class = data.as_ref().parent_class() as *const ffi::TestLoaderClass;
+    let f = (*parent_class).flush_finish.expect("No parent implementation of `flush_finish`");
+    let mut error = ptr::null_mut();
+    f(source_object as *mut _, res, &mut error);
+    let result = if error.is_null() { Ok(()) } else { Err(from_glib_full(error)) };
+    let send: Box_<gio::GioFutureResult<(), glib::Error>> = Box_::from_raw(user_data as *mut _);
+    send.resolve(result);
+}
+
+// rustdoc-stripper-ignore-next
+/// Request returned by [`Loader::close_request()`], calling [`Loader::close_async_future()`] with default arguments once `.await`ed.
+#[derive(Clone, Debug)]
+#[must_use = "requests do nothing unless `.await`ed"]
+pub struct LoaderCloseRequest {
+    obj: Loader,
+}
+
+impl std::future::IntoFuture for LoaderCloseRequest {
+    type Output = Result<(), glib::Error>;
+    type IntoFuture = Pin<Box_<dyn std::future::Future<Output = Self::Output> + 'static>>;
+
+    fn into_future(self) -> Self::IntoFuture {
+        Loader::close_async_future(&self.obj)
+    }
+}
+
+impl fmt::Display for Loader {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Loader")
+    }
+}
@@ -0,0 +1,13 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/async
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod loader;
+pub use self::loader::Loader;
+pub use self::loader::LoaderCloseRequest;
+
+#[doc(hidden)]
+pub mod subclass {
+    pub use super::loader::{LoaderImpl, LoaderImplExt};
+}
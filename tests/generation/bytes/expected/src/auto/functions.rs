@@ -0,0 +1,44 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/bytes
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::translate::*;
+
+
+#[doc(alias = "test_set_data")]
+pub fn set_data(data: &[u8]) {
+    unsafe {
+        ffi::test_set_data(glib::Bytes::from(data).to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_set_maybe_data")]
+pub fn set_maybe_data(data: Option<&[u8]>) {
+    unsafe {
+        ffi::test_set_maybe_data(data.map(glib::Bytes::from).to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_take_data")]
+pub fn take_data(data: &[u8]) {
+    unsafe {
+        ffi::test_take_data(glib::Bytes::from(data).to_glib_full());
+    }
+}
+
+#[doc(alias = "test_write_bytes")]
+pub fn write_bytes(data: &glib::Bytes) {
+    let len = data.len() as usize;
+    unsafe {
+        ffi::test_write_bytes(data.as_ptr() as *const _, len);
+    }
+}
+
+#[doc(alias = "test_fill_bytes")]
+pub fn fill_bytes(data: &[u8]) {
+    let len = data.len() as usize;
+    unsafe {
+        ffi::test_fill_bytes(data.to_glib_none().0, len);
+    }
+}
@@ -0,0 +1,7 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/bytes
+// from tests/generation/girs
+// DO NOT EDIT
+
+pub mod functions;
+
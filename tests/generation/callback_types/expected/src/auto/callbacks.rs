@@ -0,0 +1,59 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callback_types
+// from tests/generation/girs
+// DO NOT EDIT
+
+use crate::Widget;
+use glib::translate::*;
+
+pub type FillFunc = ffi::TestFillFunc;
+pub type LookupFunc = ffi::TestLookupFunc;
+pub type NotifyFunc = ffi::TestNotifyFunc;
+
+/// [`LookupFunc`] handed out by C, along with its user data
+#[derive(Clone, Copy)]
+pub struct LookupFuncHandle {
+    func: unsafe extern "C" fn(*const libc::c_char, glib::ffi::gpointer) -> *mut libc::c_char,
+    user_data: glib::ffi::gpointer,
+}
+
+impl LookupFuncHandle {
+    /// Wraps `func`, `None` if it's `NULL`.
+    ///
+    /// # Safety
+    ///
+    /// `func` and `user_data` must be handed out together by C, and stay valid
+    /// as long as the handle is used.
+    pub unsafe fn from_raw(func: LookupFunc, user_data: glib::ffi::gpointer) -> Option<Self> {
+        func.map(|func| Self { func, user_data })
+    }
+
+    pub fn call(&self, key: &str) -> Option<glib::GString> {
+        unsafe {
+            from_glib_full((self.func)(key.to_glib_none().0, self.user_data))
+        }
+    }
+}
+
+/// [`NotifyFunc`] handed out by C
+#[derive(Clone, Copy)]
+pub struct NotifyFuncHandle {
+    func: unsafe extern "C" fn(*mut ffi::TestWidget, glib::ffi::gboolean),
+}
+
+impl NotifyFuncHandle {
+    /// Wraps `func`, `None` if it's `NULL`.
+    ///
+    /// # Safety
+    ///
+    /// `func` must stay valid as long as the handle is used.
+    pub unsafe fn from_raw(func: NotifyFunc) -> Option<Self> {
+        func.map(|func| Self { func })
+    }
+
+    pub fn call(&self, widget: &Widget, visible: bool) {
+        unsafe {
+            (self.func)(widget.to_glib_none().0, visible.into_glib())
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callback_types
+// from tests/generation/girs
+// DO NOT EDIT
+
+use crate::FillFunc;
+use crate::LookupFunc;
+use crate::NotifyFunc;
+use glib::translate::*;
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Handlers(Boxed<ffi::TestHandlers>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_handlers_get_type(), ptr as *mut _) as *mut ffi::TestHandlers,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_handlers_get_type(), ptr as *mut _),
+        type_ => || ffi::test_handlers_get_type(),
+    }
+}
+
+impl Handlers {
+    pub fn lookup(&self) -> LookupFunc {
+        unsafe {
+            let ptr: *const ffi::TestHandlers = self.to_glib_none().0;
+            (*ptr).lookup
+        }
+    }
+
+    pub fn notify(&self) -> NotifyFunc {
+        unsafe {
+            let ptr: *const ffi::TestHandlers = self.to_glib_none().0;
+            (*ptr).notify
+        }
+    }
+
+    pub fn fill(&self) -> FillFunc {
+        unsafe {
+            let ptr: *const ffi::TestHandlers = self.to_glib_none().0;
+            (*ptr).fill
+        }
+    }
+
+    pub fn type_(&self) -> NotifyFunc {
+        unsafe {
+            let ptr: *const ffi::TestHandlers = self.to_glib_none().0;
+            (*ptr).type_
+        }
+    }
+}
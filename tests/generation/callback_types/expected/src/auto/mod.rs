@@ -0,0 +1,18 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callback_types
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod widget;
+pub use self::widget::Widget;
+
+mod handlers;
+pub use self::handlers::Handlers;
+
+mod callbacks;
+pub use self::callbacks::FillFunc;
+pub use self::callbacks::LookupFunc;
+pub use self::callbacks::NotifyFunc;
+pub use self::callbacks::LookupFuncHandle;
+pub use self::callbacks::NotifyFuncHandle;
+
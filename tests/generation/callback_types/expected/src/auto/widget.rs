@@ -0,0 +1,33 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callback_types
+// from tests/generation/girs
+// DO NOT EDIT
+
+use crate::NotifyFunc;
+use glib::translate::*;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    #[doc(alias = "test_widget_get_notify_func")]
+    #[doc(alias = "get_notify_func")]
+    pub fn notify_func(&self) -> NotifyFunc {
+        unsafe {
+            ffi::test_widget_get_notify_func(self.to_glib_none().0)
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
@@ -0,0 +1,171 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callbacks
+// from tests/generation/girs
+// DO NOT EDIT
+
+use crate::Widget;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+
+
+#[doc(alias = "test_foreach")]
+pub fn foreach<P: FnMut(i32) -> bool>(func: P) {
+    let mut func_data: P = func;
+    unsafe extern "C" fn func_func<P: FnMut(i32) -> bool>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: *mut P = user_data as *const _ as usize as *mut P;
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    let super_callback0: &mut P = &mut func_data;
+    unsafe {
+        ffi::test_foreach(func, super_callback0 as *mut _ as usize as *mut _);
+    }
+}
+
+#[doc(alias = "test_foreach_filtered")]
+pub fn foreach_filtered<P: FnMut(i32) -> bool, Q: FnMut(i32) -> bool>(filter: P, func: Q) {
+    let mut filter_data: P = filter;
+    unsafe extern "C" fn filter_func<P: FnMut(i32) -> bool, Q: FnMut(i32) -> bool>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: &mut (&mut P, &mut Q) = &mut *(user_data as *mut _);
+        let callback = &mut callback.0;
+        let res = 
+        (*callback)(value);
+        res.into_glib()
+    }
+    let filter = Some(filter_func::<P, Q> as _);
+    let mut func_data: Q = func;
+    unsafe extern "C" fn func_func<P: FnMut(i32) -> bool, Q: FnMut(i32) -> bool>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: &mut (&mut P, &mut Q) = &mut *(user_data as *mut _);
+        let callback = &mut callback.1;
+        let res = 
+        (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P, Q> as _);
+    let super_callback0: &mut (&mut P, &mut Q) = &mut (&mut filter_data, &mut func_data);
+    unsafe {
+        ffi::test_foreach_filtered(filter, func, super_callback0 as *mut _ as usize as *mut _);
+    }
+}
+
+#[doc(alias = "test_foreach_maybe")]
+pub fn foreach_maybe(func: Option<&mut dyn (FnMut(i32) -> bool)>) {
+    let mut func_data: Option<&mut dyn (FnMut(i32) -> bool)> = func;
+    unsafe extern "C" fn func_func(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: *mut Option<&mut dyn (FnMut(i32) -> bool)> = user_data as *const _ as usize as *mut Option<&mut dyn (FnMut(i32) -> bool)>;
+        let res = if let Some(ref mut callback) = *callback {
+            callback(value)
+        } else {
+            panic!("cannot get closure...")
+        };
+        res.into_glib()
+    }
+    let func = if func_data.is_some() { Some(func_func as _) } else { None };
+    let super_callback0: &mut Option<&mut dyn (FnMut(i32) -> bool)> = &mut func_data;
+    unsafe {
+        ffi::test_foreach_maybe(func, if super_callback0.is_some() { super_callback0 as *mut _ as usize as *mut _ } else { std::ptr::null_mut() });
+    }
+}
+
+#[doc(alias = "test_run_later")]
+pub fn run_later<P: FnOnce(i32) -> bool + Send + 'static>(func: P) {
+    let func_data: Box_<P> = Box_::new(func);
+    unsafe extern "C" fn func_func<P: FnOnce(i32) -> bool + Send + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    let super_callback0: Box_<P> = func_data;
+    unsafe {
+        ffi::test_run_later(func, Box_::into_raw(super_callback0) as *mut _);
+    }
+}
+
+#[doc(alias = "test_measure_later")]
+pub fn measure_later<P: FnOnce(&Widget, i32) + Send + 'static>(func: P) {
+    let func_data: Box_<P> = Box_::new(func);
+    unsafe extern "C" fn func_func<P: FnOnce(&Widget, i32) + Send + 'static>(widget: *mut ffi::TestWidget, width: libc::c_int, user_data: glib::ffi::gpointer) {
+        let widget = from_glib_borrow(widget);
+        let callback: Box_<P> = Box_::from_raw(user_data as *mut _);
+        (*callback)(&widget, width);
+    }
+    let func = Some(func_func::<P> as _);
+    let super_callback0: Box_<P> = func_data;
+    unsafe {
+        ffi::test_measure_later(func, Box_::into_raw(super_callback0) as *mut _);
+    }
+}
+
+#[doc(alias = "test_set_handler")]
+pub fn set_handler<P: Fn(i32) -> bool + Send + Sync + 'static>(func: P) {
+    let func_data: Box_<P> = Box_::new(func);
+    unsafe extern "C" fn func_func<P: Fn(i32) -> bool + Send + Sync + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: &P = &*(user_data as *mut _);
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    unsafe extern "C" fn notify_func<P: Fn(i32) -> bool + Send + Sync + 'static>(data: glib::ffi::gpointer) {
+        let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+    }
+    let destroy_call2 = Some(notify_func::<P> as _);
+    let super_callback0: Box_<P> = func_data;
+    unsafe {
+        ffi::test_set_handler(func, Box_::into_raw(super_callback0) as *mut _, destroy_call2);
+    }
+}
+
+#[doc(alias = "test_set_handler_unlinked")]
+pub fn set_handler_unlinked<P: Fn(i32) -> bool + Send + Sync + 'static>(func: P) {
+    let func_data: Box_<P> = Box_::new(func);
+    unsafe extern "C" fn func_func<P: Fn(i32) -> bool + Send + Sync + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: &P = &*(user_data as *mut _);
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    unsafe extern "C" fn notify_func<P: Fn(i32) -> bool + Send + Sync + 'static>(data: glib::ffi::gpointer) {
+        let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+    }
+    let destroy_call2 = Some(notify_func::<P> as _);
+    let super_callback0: Box_<P> = func_data;
+    unsafe {
+        ffi::test_set_handler_unlinked(func, Box_::into_raw(super_callback0) as *mut _, destroy_call2);
+    }
+}
+
+#[doc(alias = "test_set_handler_notify_first")]
+pub fn set_handler_notify_first<P: Fn(i32) -> bool + Send + Sync + 'static>(func: P) {
+    let func_data: Box_<P> = Box_::new(func);
+    unsafe extern "C" fn func_func<P: Fn(i32) -> bool + Send + Sync + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: &P = &*(user_data as *mut _);
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    unsafe extern "C" fn notify_func<P: Fn(i32) -> bool + Send + Sync + 'static>(data: glib::ffi::gpointer) {
+        let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+    }
+    let destroy_call0 = Some(notify_func::<P> as _);
+    let super_callback0: Box_<P> = func_data;
+    unsafe {
+        ffi::test_set_handler_notify_first(destroy_call0, func, Box_::into_raw(super_callback0) as *mut _);
+    }
+}
+
+#[doc(alias = "test_foreach_inverse")]
+pub fn foreach_inverse<P: FnMut(i32) -> bool>(func: P) {
+    let mut func_data: P = func;
+    unsafe extern "C" fn func_func<P: FnMut(i32) -> bool>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+        let callback: *mut P = user_data as *const _ as usize as *mut P;
+        let res = (*callback)(value);
+        res.into_glib()
+    }
+    let func = Some(func_func::<P> as _);
+    let super_callback0: &mut P = &mut func_data;
+    unsafe {
+        ffi::test_foreach_inverse(super_callback0 as *mut _ as usize as *mut _, func);
+    }
+}
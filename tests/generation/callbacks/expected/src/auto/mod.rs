@@ -0,0 +1,10 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callbacks
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod widget;
+pub use self::widget::Widget;
+
+pub mod functions;
+
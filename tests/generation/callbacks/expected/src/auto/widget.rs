@@ -0,0 +1,155 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/callbacks
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::object::ObjectType as ObjectType_;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget, ffi::TestWidgetClass>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    #[doc(alias = "test_widget_set_draw_func")]
+    pub fn set_draw_func<P: Fn(&Widget, i32) + 'static>(&self, func: P) {
+        let func_data: Box_<P> = Box_::new(func);
+        unsafe extern "C" fn func_func<P: Fn(&Widget, i32) + 'static>(widget: *mut ffi::TestWidget, width: libc::c_int, user_data: glib::ffi::gpointer) {
+            let widget = from_glib_borrow(widget);
+            let callback: &P = &*(user_data as *mut _);
+            (*callback)(&widget, width);
+        }
+        let func = Some(func_func::<P> as _);
+        unsafe extern "C" fn notify_func<P: Fn(&Widget, i32) + 'static>(data: glib::ffi::gpointer) {
+            let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+        }
+        let destroy_call3 = Some(notify_func::<P> as _);
+        let super_callback0: Box_<P> = func_data;
+        unsafe {
+            ffi::test_widget_set_draw_func(self.to_glib_none().0, func, Box_::into_raw(super_callback0) as *mut _, destroy_call3);
+        }
+    }
+
+    // rustdoc-stripper-ignore-next
+    /// Like [`set_draw_func()`][Self::set_draw_func()], but holding `object` weakly instead of capturing it in `func`: it is passed first to `func`, which isn't called anymore once `object` is gone.
+    pub fn set_draw_func_weak<P: Fn(&Q, &Widget, i32) + 'static, Q: glib::ObjectType>(&self, object: &Q, func: P) {
+        let object = glib::object::ObjectExt::downgrade(object);
+        self.set_draw_func(
+            move |widget: &Widget, width: i32| {
+                if let Some(object) = object.upgrade() {
+                    func(&object, widget, width);
+                }
+            },
+        )
+    }
+
+    #[doc(alias = "test_widget_set_layout_func")]
+    pub fn set_layout_func<P: Fn(&Widget, i32) + 'static>(&self, func: P) {
+        let func_data: Box_<P> = Box_::new(func);
+        unsafe extern "C" fn func_func<P: Fn(&Widget, i32) + 'static>(widget: *mut ffi::TestWidget, width: libc::c_int, user_data: glib::ffi::gpointer) {
+            let widget = from_glib_borrow(widget);
+            let callback: &P = &*(user_data as *mut _);
+            (*callback)(&widget, width);
+        }
+        let func = Some(func_func::<P> as _);
+        unsafe extern "C" fn notify_func<P: Fn(&Widget, i32) + 'static>(data: glib::ffi::gpointer) {
+            let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+        }
+        let destroy_call3 = Some(notify_func::<P> as _);
+        let super_callback0: Box_<P> = func_data;
+        unsafe {
+            ffi::test_widget_set_layout_func(self.to_glib_none().0, func, Box_::into_raw(super_callback0) as *mut _, destroy_call3);
+        }
+    }
+
+    #[doc(alias = "test_widget_set_filter")]
+    pub fn set_filter(&self, filter: Option<Box_<dyn Fn(i32) -> bool + 'static>>) {
+        let filter_data: Box_<Option<Box_<dyn Fn(i32) -> bool + 'static>>> = Box_::new(filter);
+        unsafe extern "C" fn filter_func(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+            let callback: &Option<Box_<dyn Fn(i32) -> bool + 'static>> = &*(user_data as *mut _);
+            let res = if let Some(ref callback) = *callback {
+                callback(value)
+            } else {
+                panic!("cannot get closure...")
+            };
+            res.into_glib()
+        }
+        let filter = if filter_data.is_some() { Some(filter_func as _) } else { None };
+        unsafe extern "C" fn notify_func(data: glib::ffi::gpointer) {
+            let _callback: Box_<Option<Box_<dyn Fn(i32) -> bool + 'static>>> = Box_::from_raw(data as *mut _);
+        }
+        let destroy_call3 = if filter_data.is_some() { Some(notify_func as _) } else { None };
+        let super_callback0: Box_<Option<Box_<dyn Fn(i32) -> bool + 'static>>> = filter_data;
+        unsafe {
+            ffi::test_widget_set_filter(self.to_glib_none().0, filter, if super_callback0.is_some() { Box_::into_raw(super_callback0) as *mut _ } else { std::ptr::null_mut() }, destroy_call3);
+        }
+    }
+
+    #[doc(alias = "test_widget_set_validator")]
+    pub fn set_validator<P: Fn(i32) -> bool + 'static>(&self, validator: P) {
+        let validator_data: Box_<P> = Box_::new(validator);
+        unsafe extern "C" fn validator_func<P: Fn(i32) -> bool + 'static>(value: libc::c_int, user_data: glib::ffi::gpointer) -> glib::ffi::gboolean {
+            let callback: &P = &*(user_data as *mut _);
+            let res = (*callback)(value);
+            res.into_glib()
+        }
+        let validator = Some(validator_func::<P> as _);
+        unsafe extern "C" fn notify_func<P: Fn(i32) -> bool + 'static>(data: glib::ffi::gpointer) {
+            let _callback: Box_<P> = Box_::from_raw(data as *mut _);
+        }
+        let destroy_call3 = Some(notify_func::<P> as _);
+        let super_callback0: Box_<P> = validator_data;
+        unsafe {
+            ffi::test_widget_set_validator(self.to_glib_none().0, validator, Box_::into_raw(super_callback0) as *mut _, destroy_call3);
+        }
+    }
+
+    #[doc(alias = "changed")]
+    pub fn connect_changed<F: Fn(&Self, i32) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn changed_trampoline<F: Fn(&Widget, i32) + 'static>(this: *mut ffi::TestWidget, value: libc::c_int, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(&from_glib_borrow(this), value)
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"changed\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(changed_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+
+    #[doc(alias = "measure")]
+    pub fn connect_measure<F: Fn(&Self, i32) -> (bool, i32, i32) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn measure_trampoline<F: Fn(&Widget, i32) -> (bool, i32, i32) + 'static>(this: *mut ffi::TestWidget, for_size: libc::c_int, minimum: *mut libc::c_int, natural: *mut libc::c_int, f: glib::ffi::gpointer) -> glib::ffi::gboolean {
+            let f: &F = &*(f as *const F);
+            let (ret, minimum_value, natural_value) = f(&from_glib_borrow(this), for_size);
+            if !minimum.is_null() {
+                *minimum = minimum_value;
+            }
+            if !natural.is_null() {
+                *natural = natural_value;
+            }
+            ret.into_glib()
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"measure\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(measure_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
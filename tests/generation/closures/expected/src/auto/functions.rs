@@ -0,0 +1,57 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/closures
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::translate::*;
+
+
+#[doc(alias = "test_watch_closure")]
+pub fn watch_closure(closure: &glib::Closure) {
+    unsafe {
+        ffi::test_watch_closure(closure.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_set_closure")]
+pub fn set_closure(closure: Option<&glib::Closure>) {
+    unsafe {
+        ffi::test_set_closure(closure.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_take_closure")]
+pub fn take_closure(closure: &glib::Closure) {
+    unsafe {
+        ffi::test_take_closure(closure.to_glib_full());
+    }
+}
+
+#[doc(alias = "test_replace_closure")]
+pub fn replace_closure(closure: Option<&glib::Closure>) {
+    unsafe {
+        ffi::test_replace_closure(closure.to_glib_full());
+    }
+}
+
+#[doc(alias = "test_get_closure")]
+#[doc(alias = "get_closure")]
+pub fn closure() -> glib::Closure {
+    unsafe {
+        from_glib_none(ffi::test_get_closure())
+    }
+}
+
+#[doc(alias = "test_lookup_closure")]
+pub fn lookup_closure() -> Option<glib::Closure> {
+    unsafe {
+        from_glib_none(ffi::test_lookup_closure())
+    }
+}
+
+#[doc(alias = "test_new_closure")]
+pub fn new_closure() -> glib::Closure {
+    unsafe {
+        from_glib_full(ffi::test_new_closure())
+    }
+}
@@ -0,0 +1,303 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::error::ErrorDomain;
+use glib::translate::*;
+use glib::value::FromValue;
+use glib::value::ToValue;
+use glib::Quark;
+use glib::StaticType;
+use glib::Type;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "TestHuge")]
+pub enum Huge {
+    #[doc(alias = "TEST_HUGE_NEGATIVE")]
+    Negative,
+    #[doc(alias = "TEST_HUGE_LAST")]
+    Last,
+#[doc(hidden)]
+    __Unknown(i64),
+}
+
+impl fmt::Display for Huge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Huge::{}", match *self {
+            Self::Negative => "Negative",
+            Self::Last => "Last",
+            _ => "Unknown",
+        })
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Huge {
+    type GlibType = ffi::TestHuge;
+
+    fn into_glib(self) -> ffi::TestHuge {
+        match self {
+            Self::Negative => ffi::TEST_HUGE_NEGATIVE,
+            Self::Last => ffi::TEST_HUGE_LAST,
+            Self::__Unknown(value) => value as _,
+}
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestHuge> for Huge {
+    unsafe fn from_glib(value: ffi::TestHuge) -> Self {
+        match value {
+            ffi::TEST_HUGE_NEGATIVE => Self::Negative,
+            ffi::TEST_HUGE_LAST => Self::Last,
+            value => Self::__Unknown(value as _),
+}
+    }
+}
+
+impl StaticType for Huge {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_huge_get_type()) }
+    }
+}
+
+impl glib::HasParamSpec for Huge {
+    type ParamSpec = glib::ParamSpecEnum;
+    type SetValue = Self;
+    type BuilderFn = fn(&str, Self) -> glib::ParamSpecEnumBuilder<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {
+        |name, default_value| Self::ParamSpec::builder_with_default(name, default_value)
+    }
+}
+
+impl glib::value::ValueType for Huge {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Huge {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_enum(value.to_glib_none().0) as ffi::TestHuge)
+    }
+}
+
+impl ToValue for Huge {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_enum(value.to_glib_none_mut().0, self.into_glib() as _);
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "TestLarge")]
+pub enum Large {
+    #[doc(alias = "TEST_LARGE_FIRST")]
+    First,
+    #[doc(alias = "TEST_LARGE_LAST")]
+    Last,
+#[doc(hidden)]
+    __Unknown(u32),
+}
+
+impl fmt::Display for Large {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Large::{}", match *self {
+            Self::First => "First",
+            Self::Last => "Last",
+            _ => "Unknown",
+        })
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Large {
+    type GlibType = ffi::TestLarge;
+
+    fn into_glib(self) -> ffi::TestLarge {
+        match self {
+            Self::First => ffi::TEST_LARGE_FIRST,
+            Self::Last => ffi::TEST_LARGE_LAST,
+            Self::__Unknown(value) => value as _,
+}
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestLarge> for Large {
+    unsafe fn from_glib(value: ffi::TestLarge) -> Self {
+        match value {
+            ffi::TEST_LARGE_FIRST => Self::First,
+            ffi::TEST_LARGE_LAST => Self::Last,
+            value => Self::__Unknown(value as _),
+}
+    }
+}
+
+impl ErrorDomain for Large {
+    fn domain() -> Quark {
+        
+        static QUARK: once_cell::sync::Lazy<glib::ffi::GQuark> = once_cell::sync::Lazy::new(|| unsafe {
+            glib::ffi::g_quark_from_static_string(b"test-large-quark\0".as_ptr() as *const _)
+        });
+        unsafe { from_glib(*QUARK) }
+    }
+
+    fn code(self) -> i32 {
+        self.into_glib() as _
+    }
+
+    fn from(code: i32) -> Option<Self> {
+        match code as ffi::TestLarge {
+            ffi::TEST_LARGE_FIRST => Some(Self::First),
+            ffi::TEST_LARGE_LAST => Some(Self::Last),
+            value => Some(Self::__Unknown(value as _)),
+}
+    }
+}
+
+impl StaticType for Large {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_large_get_type()) }
+    }
+}
+
+impl glib::HasParamSpec for Large {
+    type ParamSpec = glib::ParamSpecEnum;
+    type SetValue = Self;
+    type BuilderFn = fn(&str, Self) -> glib::ParamSpecEnumBuilder<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {
+        |name, default_value| Self::ParamSpec::builder_with_default(name, default_value)
+    }
+}
+
+impl glib::value::ValueType for Large {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Large {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_enum(value.to_glib_none().0) as ffi::TestLarge)
+    }
+}
+
+impl ToValue for Large {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_enum(value.to_glib_none_mut().0, self.into_glib() as _);
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "TestSmall")]
+pub enum Small {
+    #[doc(alias = "TEST_SMALL_FIRST")]
+    First,
+    #[doc(alias = "TEST_SMALL_LAST")]
+    Last,
+#[doc(hidden)]
+    __Unknown(i32),
+}
+
+impl fmt::Display for Small {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Small::{}", match *self {
+            Self::First => "First",
+            Self::Last => "Last",
+            _ => "Unknown",
+        })
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Small {
+    type GlibType = ffi::TestSmall;
+
+    fn into_glib(self) -> ffi::TestSmall {
+        match self {
+            Self::First => ffi::TEST_SMALL_FIRST,
+            Self::Last => ffi::TEST_SMALL_LAST,
+            Self::__Unknown(value) => value,
+}
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestSmall> for Small {
+    unsafe fn from_glib(value: ffi::TestSmall) -> Self {
+        match value {
+            ffi::TEST_SMALL_FIRST => Self::First,
+            ffi::TEST_SMALL_LAST => Self::Last,
+            value => Self::__Unknown(value),
+}
+    }
+}
+
+impl StaticType for Small {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_small_get_type()) }
+    }
+}
+
+impl glib::HasParamSpec for Small {
+    type ParamSpec = glib::ParamSpecEnum;
+    type SetValue = Self;
+    type BuilderFn = fn(&str, Self) -> glib::ParamSpecEnumBuilder<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {
+        |name, default_value| Self::ParamSpec::builder_with_default(name, default_value)
+    }
+}
+
+impl glib::value::ValueType for Small {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Small {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_enum(value.to_glib_none().0))
+    }
+}
+
+impl ToValue for Small {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_enum(value.to_glib_none_mut().0, self.into_glib());
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
@@ -0,0 +1,162 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums
+// from tests/generation/girs
+// DO NOT EDIT
+
+use bitflags::bitflags;
+use glib::translate::*;
+use glib::value::FromValue;
+use glib::value::ToValue;
+use glib::StaticType;
+use glib::Type;
+use std::fmt;
+
+bitflags! {
+    #[doc(alias = "TestModes")]
+    pub struct Modes: u32 {
+        #[doc(alias = "TEST_MODES_READ")]
+        const READ = ffi::TEST_MODES_READ as u32;
+        #[doc(alias = "TEST_MODES_WRITE")]
+        const WRITE = ffi::TEST_MODES_WRITE as u32;
+    }
+}
+
+impl fmt::Display for Modes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Modes {
+    type GlibType = ffi::TestModes;
+
+    fn into_glib(self) -> ffi::TestModes {
+        self.bits()
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestModes> for Modes {
+    unsafe fn from_glib(value: ffi::TestModes) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+impl StaticType for Modes {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_modes_get_type()) }
+    }
+}
+
+impl glib::HasParamSpec for Modes {
+    type ParamSpec = glib::ParamSpecFlags;
+    type SetValue = Self;
+    type BuilderFn = fn(&str) -> glib::ParamSpecFlagsBuilder<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {
+        |name| Self::ParamSpec::builder(name)
+    }
+}
+
+impl glib::value::ValueType for Modes {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Modes {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_flags(value.to_glib_none().0))
+    }
+}
+
+impl ToValue for Modes {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_flags(value.to_glib_none_mut().0, self.into_glib());
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
+bitflags! {
+    #[derive(Default)]
+    #[doc(alias = "TestOptions")]
+    pub struct Options: u32 {
+        #[doc(alias = "TEST_OPTIONS_NONE")]
+        const NONE = ffi::TEST_OPTIONS_NONE as u32;
+        #[doc(alias = "TEST_OPTIONS_VERBOSE")]
+        const VERBOSE = ffi::TEST_OPTIONS_VERBOSE as u32;
+    }
+}
+
+impl fmt::Display for Options {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        <Self as fmt::Debug>::fmt(self, f)
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Options {
+    type GlibType = ffi::TestOptions;
+
+    fn into_glib(self) -> ffi::TestOptions {
+        self.bits()
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestOptions> for Options {
+    unsafe fn from_glib(value: ffi::TestOptions) -> Self {
+        Self::from_bits_truncate(value)
+    }
+}
+
+impl StaticType for Options {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_options_get_type()) }
+    }
+}
+
+impl glib::HasParamSpec for Options {
+    type ParamSpec = glib::ParamSpecFlags;
+    type SetValue = Self;
+    type BuilderFn = fn(&str) -> glib::ParamSpecFlagsBuilder<Self>;
+
+    fn param_spec_builder() -> Self::BuilderFn {
+        |name| Self::ParamSpec::builder(name)
+    }
+}
+
+impl glib::value::ValueType for Options {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Options {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_flags(value.to_glib_none().0))
+    }
+}
+
+impl ToValue for Options {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_flags(value.to_glib_none_mut().0, self.into_glib());
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
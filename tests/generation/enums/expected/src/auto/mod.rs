@@ -0,0 +1,14 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod enums;
+pub use self::enums::Huge;
+pub use self::enums::Large;
+pub use self::enums::Small;
+
+mod flags;
+pub use self::flags::Modes;
+pub use self::flags::Options;
+
@@ -0,0 +1,18 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums_sys
+// from tests/generation/girs
+// DO NOT EDIT
+
+#[cfg(not(feature = "dox"))]
+use std::process;
+
+#[cfg(feature = "dox")]
+fn main() {} // prevent linking libraries to avoid documentation failure
+
+#[cfg(not(feature = "dox"))]
+fn main() {
+    if let Err(s) = system_deps::Config::new().probe() {
+        println!("cargo:warning={}", s);
+        process::exit(1);
+    }
+}
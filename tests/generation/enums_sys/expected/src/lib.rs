@@ -0,0 +1,75 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums_sys
+// from tests/generation/girs
+// DO NOT EDIT
+
+#![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#![allow(clippy::approx_constant, clippy::type_complexity, clippy::unreadable_literal, clippy::upper_case_acronyms)]
+#![cfg_attr(feature = "dox", feature(doc_cfg))]
+
+
+#[allow(unused_imports)]
+use libc::{c_int, c_char, c_uchar, c_float, c_uint, c_double,
+    c_short, c_ushort, c_long, c_ulong,
+    c_void, size_t, ssize_t, intptr_t, uintptr_t, time_t, FILE};
+
+#[allow(unused_imports)]
+use glib::{gboolean, gconstpointer, gpointer, GType};
+
+// Enums
+#[cfg(target_env = "msvc")]
+pub type TestHuge = c_int;
+#[cfg(not(target_env = "msvc"))]
+pub type TestHuge = i64;
+pub const TEST_HUGE_NEGATIVE: TestHuge = -1_i64 as TestHuge;
+pub const TEST_HUGE_LAST: TestHuge = 4294967296_i64 as TestHuge;
+
+#[cfg(target_env = "msvc")]
+pub type TestLarge = c_int;
+#[cfg(not(target_env = "msvc"))]
+pub type TestLarge = c_uint;
+pub const TEST_LARGE_FIRST: TestLarge = 0_i64 as TestLarge;
+pub const TEST_LARGE_LAST: TestLarge = 2147483648_i64 as TestLarge;
+
+pub type TestSmall = c_int;
+pub const TEST_SMALL_FIRST: TestSmall = 0;
+pub const TEST_SMALL_LAST: TestSmall = 2147483647;
+
+// Flags
+pub type TestModes = c_uint;
+pub const TEST_MODES_READ: TestModes = 1;
+pub const TEST_MODES_WRITE: TestModes = 2;
+
+pub type TestOptions = c_uint;
+pub const TEST_OPTIONS_NONE: TestOptions = 0;
+pub const TEST_OPTIONS_VERBOSE: TestOptions = 1;
+
+#[link(name = "test-1.0")]
+extern "C" {
+
+    //=========================================================================
+    // TestHuge
+    //=========================================================================
+    pub fn test_huge_get_type() -> GType;
+
+    //=========================================================================
+    // TestLarge
+    //=========================================================================
+    pub fn test_large_get_type() -> GType;
+
+    //=========================================================================
+    // TestSmall
+    //=========================================================================
+    pub fn test_small_get_type() -> GType;
+
+    //=========================================================================
+    // TestModes
+    //=========================================================================
+    pub fn test_modes_get_type() -> GType;
+
+    //=========================================================================
+    // TestOptions
+    //=========================================================================
+    pub fn test_options_get_type() -> GType;
+
+}
@@ -0,0 +1,43 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums_sys
+// from tests/generation/girs
+// DO NOT EDIT
+
+#include "manual.h"
+#include <stdio.h>
+
+#define PRINT_CONSTANT(CONSTANT_NAME) \
+    printf("%s;", #CONSTANT_NAME); \
+    printf(_Generic((CONSTANT_NAME), \
+                    char *: "%s", \
+                    const char *: "%s", \
+                    char: "%c", \
+                    signed char: "%hhd", \
+                    unsigned char: "%hhu", \
+                    short int: "%hd", \
+                    unsigned short int: "%hu", \
+                    int: "%d", \
+                    unsigned int: "%u", \
+                    long: "%ld", \
+                    unsigned long: "%lu", \
+                    long long: "%lld", \
+                    unsigned long long: "%llu", \
+                    float: "%f", \
+                    double: "%f", \
+                    long double: "%ld"), \
+           CONSTANT_NAME); \
+    printf("\n");
+
+int main() {
+    PRINT_CONSTANT((gint64) TEST_HUGE_LAST);
+    PRINT_CONSTANT((gint64) TEST_HUGE_NEGATIVE);
+    PRINT_CONSTANT((guint) TEST_LARGE_FIRST);
+    PRINT_CONSTANT((guint) TEST_LARGE_LAST);
+    PRINT_CONSTANT((guint) TEST_MODES_READ);
+    PRINT_CONSTANT((guint) TEST_MODES_WRITE);
+    PRINT_CONSTANT((guint) TEST_OPTIONS_NONE);
+    PRINT_CONSTANT((guint) TEST_OPTIONS_VERBOSE);
+    PRINT_CONSTANT((gint) TEST_SMALL_FIRST);
+    PRINT_CONSTANT((gint) TEST_SMALL_LAST);
+    return 0;
+}
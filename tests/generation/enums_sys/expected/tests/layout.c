@@ -0,0 +1,17 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/enums_sys
+// from tests/generation/girs
+// DO NOT EDIT
+
+#include "manual.h"
+#include <stdalign.h>
+#include <stdio.h>
+
+int main() {
+    printf("%s;%zu;%zu\n", "TestHuge", sizeof(TestHuge), alignof(TestHuge));
+    printf("%s;%zu;%zu\n", "TestLarge", sizeof(TestLarge), alignof(TestLarge));
+    printf("%s;%zu;%zu\n", "TestModes", sizeof(TestModes), alignof(TestModes));
+    printf("%s;%zu;%zu\n", "TestOptions", sizeof(TestOptions), alignof(TestOptions));
+    printf("%s;%zu;%zu\n", "TestSmall", sizeof(TestSmall), alignof(TestSmall));
+    return 0;
+}
@@ -0,0 +1,8 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/errors
+// from tests/generation/girs
+// DO NOT EDIT
+
+mod parser;
+pub use self::parser::Parser;
+
@@ -0,0 +1,44 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/errors
+// from tests/generation/girs
+// DO NOT EDIT
+
+use glib::translate::*;
+use std::fmt;
+use std::mem;
+use std::ptr;
+
+glib::wrapper! {
+    #[doc(alias = "TestParser")]
+    pub struct Parser(Object<ffi::TestParser, ffi::TestParserClass>);
+
+    match fn {
+        type_ => || ffi::test_parser_get_type(),
+    }
+}
+
+impl Parser {
+    #[doc(alias = "test_parser_parse")]
+    pub fn parse(&self, text: &str) -> Result<(glib::GString, i32), glib::Error> {
+        unsafe {
+            let mut error = ptr::null_mut();
+            let mut position = mem::MaybeUninit::uninit();
+            let ret = ffi::test_parser_parse(self.to_glib_none().0, text.to_glib_none().0, &mut error, position.as_mut_ptr());
+            let position = position.assume_init();
+            if error.is_null() { Ok((from_glib_full(ret), position)) } else { Err(from_glib_full(error)) }
+        }
+    }
+
+    #[doc(alias = "test_parser_validate")]
+    pub fn validate(&self, error: &mut glib::Error, strict: bool) -> bool {
+        unsafe {
+            from_glib(ffi::test_parser_validate(self.to_glib_none().0, error.to_glib_none_mut().0, strict.into_glib()))
+        }
+    }
+}
+
+impl fmt::Display for Parser {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Parser")
+    }
+}
@@ -0,0 +1,8 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/io
+// DO NOT EDIT
+
+mod stream;
+pub use self::stream::Stream;
+
@@ -0,0 +1,75 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/io
+// DO NOT EDIT
+
+use glib::translate::*;
+use std::fmt;
+use std::ptr;
+
+glib::wrapper! {
+    #[doc(alias = "TestStream")]
+    pub struct Stream(Object<ffi::TestStream, ffi::TestStreamClass>);
+
+    match fn {
+        type_ => || ffi::test_stream_get_type(),
+    }
+}
+
+impl Stream {
+    #[doc(alias = "test_stream_write")]
+    pub fn write(&self, buffer: &[u8]) -> Result<usize, glib::Error> {
+        let count = buffer.len() as usize;
+        unsafe {
+            let mut error = ptr::null_mut();
+            let ret = ffi::test_stream_write(self.to_glib_none().0, buffer.to_glib_none().0, count, &mut error);
+            if error.is_null() { Ok(ret as usize) } else { Err(from_glib_full(error)) }
+        }
+    }
+
+    #[doc(alias = "test_stream_read")]
+    pub fn read(&self, buffer: &mut Vec<u8>) -> bool {
+        let mut count = buffer.len() as usize;
+        unsafe {
+            let ret = from_glib(ffi::test_stream_read(self.to_glib_none().0, buffer.as_mut_ptr(), &mut count));
+            buffer.truncate(count as usize);
+            ret
+        }
+    }
+
+    #[doc(alias = "test_stream_get_available")]
+    #[doc(alias = "get_available")]
+    pub fn available(&self) -> isize {
+        unsafe {
+            ffi::test_stream_get_available(self.to_glib_none().0)
+        }
+    }
+
+    #[doc(alias = "test_stream_skip")]
+    pub fn skip(&self, count: usize) -> Result<usize, glib::BoolError> {
+        unsafe {
+            usize::try_from(ffi::test_stream_skip(self.to_glib_none().0, count)).map_err(|_| glib::bool_error!("Failed to skip"))
+        }
+    }
+
+    #[doc(alias = "test_stream_splice")]
+    pub fn splice(&self, source: &Stream, label: Option<&str>) -> isize {
+        // splice(stream, source, label)
+        let this: &Self = self;
+        let source: &Stream = source;
+        let label: Option<&str> = label;
+        unsafe {
+            ffi::test_stream_splice(
+                this.to_glib_none().0,
+                source.to_glib_none().0,
+                label.to_glib_none().0,
+            )
+        }
+    }
+}
+
+impl fmt::Display for Stream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Stream")
+    }
+}
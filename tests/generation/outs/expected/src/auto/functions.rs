@@ -0,0 +1,88 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/outs
+// DO NOT EDIT
+
+use crate::Rectangle;
+use glib::translate::*;
+use std::mem;
+use std::ptr;
+
+
+#[doc(alias = "test_get_size")]
+#[doc(alias = "get_size")]
+pub fn size() -> (i32, i32) {
+    unsafe {
+        let mut width = mem::MaybeUninit::uninit();
+        let mut height = mem::MaybeUninit::uninit();
+        ffi::test_get_size(width.as_mut_ptr(), height.as_mut_ptr());
+        let width = width.assume_init();
+        let height = height.assume_init();
+        (width, height)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Like `size()`, but only requesting the optional out parameters whose `want_` argument is `true`, the others being returned as `None`.
+pub fn size_optional(want_width: bool, want_height: bool) -> (Option<i32>, Option<i32>) {
+    unsafe {
+        let mut width = mem::MaybeUninit::uninit();
+        let mut height = mem::MaybeUninit::uninit();
+        ffi::test_get_size(if want_width { width.as_mut_ptr() } else { std::ptr::null_mut() }, if want_height { height.as_mut_ptr() } else { std::ptr::null_mut() });
+        (if want_width { Some(width.assume_init()) } else { None }, if want_height { Some(height.assume_init()) } else { None })
+    }
+}
+
+#[doc(alias = "test_lookup")]
+pub fn lookup(key: &str) -> Option<(glib::GString, Option<glib::GString>)> {
+    unsafe {
+        let mut value = ptr::null_mut();
+        let mut description = ptr::null_mut();
+        let ret = from_glib(ffi::test_lookup(key.to_glib_none().0, &mut value, &mut description));
+        if ret { Some((from_glib_full(value), from_glib_full(description))) } else { None }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Like `lookup()`, but only requesting the optional out parameters whose `want_` argument is `true`, the others being returned as `None`.
+pub fn lookup_optional(key: &str, want_description: bool) -> Option<(glib::GString, Option<glib::GString>)> {
+    unsafe {
+        let mut value = ptr::null_mut();
+        let mut description = ptr::null_mut();
+        let ret = from_glib(ffi::test_lookup(key.to_glib_none().0, &mut value, if want_description { &mut description } else { std::ptr::null_mut() }));
+        if ret { Some((from_glib_full(value), if want_description { from_glib_full(description) } else { None })) } else { None }
+    }
+}
+
+#[doc(alias = "test_parse")]
+pub fn parse(text: &str) -> Result<f64, glib::Error> {
+    unsafe {
+        let mut value = mem::MaybeUninit::uninit();
+        let mut error = ptr::null_mut();
+        let _ = ffi::test_parse(text.to_glib_none().0, value.as_mut_ptr(), &mut error);
+        let value = value.assume_init();
+        if error.is_null() { Ok(value) } else { Err(from_glib_full(error)) }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Like `parse()`, but only requesting the optional out parameters whose `want_` argument is `true`, the others being returned as `None`.
+pub fn parse_optional(text: &str, want_value: bool) -> Result<Option<f64>, glib::Error> {
+    unsafe {
+        let mut value = mem::MaybeUninit::uninit();
+        let mut error = ptr::null_mut();
+        let _ = ffi::test_parse(text.to_glib_none().0, if want_value { value.as_mut_ptr() } else { std::ptr::null_mut() }, &mut error);
+        if error.is_null() { Ok(if want_value { Some(value.assume_init()) } else { None }) } else { Err(from_glib_full(error)) }
+    }
+}
+
+#[doc(alias = "test_get_bounds")]
+#[doc(alias = "get_bounds")]
+pub fn bounds() -> Rectangle {
+    unsafe {
+        let mut rect = mem::MaybeUninit::uninit();
+        ffi::test_get_bounds(rect.as_mut_ptr());
+        let rect = rect.assume_init();
+        from_glib_none(&rect as *const _)
+    }
+}
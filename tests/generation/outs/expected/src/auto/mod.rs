@@ -0,0 +1,10 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/outs
+// DO NOT EDIT
+
+mod rectangle;
+pub use self::rectangle::Rectangle;
+
+pub mod functions;
+
@@ -0,0 +1,15 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/outs
+// DO NOT EDIT
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Rectangle(Boxed<ffi::TestRectangle>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_rectangle_get_type(), ptr as *mut _) as *mut ffi::TestRectangle,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_rectangle_get_type(), ptr as *mut _),
+        type_ => || ffi::test_rectangle_get_type(),
+    }
+}
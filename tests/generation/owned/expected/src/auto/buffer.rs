@@ -0,0 +1,15 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/owned
+// DO NOT EDIT
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Buffer(Boxed<ffi::TestBuffer>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_buffer_get_type(), ptr as *mut _) as *mut ffi::TestBuffer,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_buffer_get_type(), ptr as *mut _),
+        type_ => || ffi::test_buffer_get_type(),
+    }
+}
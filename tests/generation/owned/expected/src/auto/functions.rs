@@ -0,0 +1,44 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/owned
+// DO NOT EDIT
+
+use crate::Buffer;
+use crate::Widget;
+use glib::translate::*;
+
+
+#[doc(alias = "test_take_widget")]
+pub fn take_widget(value: Widget) {
+    unsafe {
+        ffi::test_take_widget(value.into_glib_ptr());
+    }
+}
+
+#[doc(alias = "test_take_maybe_widget")]
+pub fn take_maybe_widget(value: Option<Widget>) {
+    unsafe {
+        ffi::test_take_maybe_widget(value.into_glib_ptr());
+    }
+}
+
+#[doc(alias = "test_show_widget")]
+pub fn show_widget(value: &Widget) {
+    unsafe {
+        ffi::test_show_widget(value.to_glib_none().0);
+    }
+}
+
+#[doc(alias = "test_take_buffer")]
+pub fn take_buffer(value: Buffer) {
+    unsafe {
+        ffi::test_take_buffer(value.into_glib_ptr());
+    }
+}
+
+#[doc(alias = "test_take_closure")]
+pub fn take_closure(value: glib::Closure) {
+    unsafe {
+        ffi::test_take_closure(value.into_glib_ptr());
+    }
+}
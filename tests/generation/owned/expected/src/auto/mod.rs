@@ -0,0 +1,13 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/owned
+// DO NOT EDIT
+
+mod widget;
+pub use self::widget::Widget;
+
+mod buffer;
+pub use self::buffer::Buffer;
+
+pub mod functions;
+
@@ -0,0 +1,21 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/owned
+// DO NOT EDIT
+
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
@@ -0,0 +1,103 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/panics
+// DO NOT EDIT
+
+use glib::translate::*;
+use glib::value::FromValue;
+use glib::value::ToValue;
+use glib::StaticType;
+use glib::Type;
+use std::ffi::CStr;
+use std::fmt;
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Clone, Copy)]
+#[non_exhaustive]
+#[doc(alias = "TestColor")]
+pub enum Color {
+    #[doc(alias = "TEST_COLOR_RED")]
+    Red,
+    #[doc(alias = "TEST_COLOR_GREEN")]
+    Green,
+#[doc(hidden)]
+    __Unknown(i32),
+}
+
+impl Color {
+    pub fn to_str<'a>(self) -> &'a str {
+        unsafe {
+            CStr::from_ptr(
+                ffi::test_color_to_string(self.into_glib())
+                    .as_ref()
+                    .unwrap_or_else(|| crate::panic_returned_null("test_color_to_string")),
+            )
+            .to_str()
+            .unwrap_or_else(|_| crate::panic_invalid_string("test_color_to_string"))
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_str())
+    }
+}
+
+#[doc(hidden)]
+impl IntoGlib for Color {
+    type GlibType = ffi::TestColor;
+
+    fn into_glib(self) -> ffi::TestColor {
+        match self {
+            Self::Red => ffi::TEST_COLOR_RED,
+            Self::Green => ffi::TEST_COLOR_GREEN,
+            Self::__Unknown(value) => value,
+}
+    }
+}
+
+#[doc(hidden)]
+impl FromGlib<ffi::TestColor> for Color {
+    unsafe fn from_glib(value: ffi::TestColor) -> Self {
+        match value {
+            ffi::TEST_COLOR_RED => Self::Red,
+            ffi::TEST_COLOR_GREEN => Self::Green,
+            value => Self::__Unknown(value),
+}
+    }
+}
+
+impl StaticType for Color {
+    fn static_type() -> Type {
+        unsafe { from_glib(ffi::test_color_get_type()) }
+    }
+}
+
+impl glib::value::ValueType for Color {
+    type Type = Self;
+}
+
+unsafe impl<'a> FromValue<'a> for Color {
+    type Checker = glib::value::GenericValueTypeChecker<Self>;
+
+    unsafe fn from_value(value: &'a glib::Value) -> Self {
+        from_glib(glib::gobject_ffi::g_value_get_enum(value.to_glib_none().0))
+    }
+}
+
+impl ToValue for Color {
+    fn to_value(&self) -> glib::Value {
+        let mut value = glib::Value::for_value_type::<Self>();
+        unsafe {
+            glib::gobject_ffi::g_value_set_enum(value.to_glib_none_mut().0, self.into_glib());
+        }
+        value
+    }
+
+    fn value_type(&self) -> glib::Type {
+        Self::static_type()
+    }
+}
+
@@ -0,0 +1,23 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/panics
+// DO NOT EDIT
+
+mod widget;
+pub use self::widget::Widget;
+
+mod enums;
+pub use self::enums::Color;
+
+mod panics;
+#[allow(unused_imports)]
+pub(crate) use self::panics::*;
+
+mod time;
+#[allow(unused_imports)]
+pub(crate) use self::time::*;
+
+#[doc(hidden)]
+pub mod builders {
+    pub use super::widget::WidgetBuilder;
+}
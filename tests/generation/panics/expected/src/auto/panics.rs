@@ -0,0 +1,83 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/panics
+// DO NOT EDIT
+
+#![allow(dead_code)]
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_mandatory_none() -> ! {
+    panic!("mandatory glib value is None")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_invalid_unichar() -> ! {
+    panic!("conversion from an invalid Unicode value attempted")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_infallible(err: &dyn std::fmt::Display) -> ! {
+    panic!("infallible {}", err)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_missing_closure() -> ! {
+    panic!("cannot get closure...")
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_property_value(name: &str, err: &dyn std::fmt::Debug) -> ! {
+    panic!("Return Value for property `{}` getter: {:?}", name, err)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_returned_null(name: &str) -> ! {
+    panic!("{} returned NULL", name)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_invalid_string(name: &str) -> ! {
+    panic!("{} returned an invalid string", name)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_default_construct(name: &str) -> ! {
+    panic!("Can't construct {} object with default parameters", name)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_build_object(name: &str) -> ! {
+    panic!("Failed to create an instance of {}", name)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_wrong_variant_type(name: &str) -> ! {
+    panic!("Wrong variant type for `{}`", name)
+}
+
+#[cold]
+#[inline(never)]
+#[track_caller]
+pub(crate) fn panic_missing_parent_vfunc(name: &str) -> ! {
+    panic!("No parent implementation of `{}`", name)
+}
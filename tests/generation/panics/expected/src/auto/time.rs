@@ -0,0 +1,107 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/panics
+// DO NOT EDIT
+
+#![allow(dead_code)]
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+fn unix_usec(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_micros() as i64,
+        Err(err) => -(err.duration().as_micros() as i64),
+    }
+}
+
+fn from_unix_usec(usec: i64) -> SystemTime {
+    let duration = Duration::from_micros(usec.unsigned_abs());
+    if usec < 0 {
+        UNIX_EPOCH - duration
+    } else {
+        UNIX_EPOCH + duration
+    }
+}
+
+/// Reference on a date time, released once the call is done
+pub(crate) struct DateTimeStash(pub *mut glib::ffi::GDateTime);
+
+impl Drop for DateTimeStash {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { glib::ffi::g_date_time_unref(self.0) }
+        }
+    }
+}
+
+pub(crate) fn date_time_to_glib_none(time: Option<SystemTime>) -> DateTimeStash {
+    DateTimeStash(date_time_to_glib_full(time))
+}
+
+/// Panics if the time is out of the range of `GDateTime`, the years 1 to 9999
+pub(crate) fn date_time_to_glib_full(time: Option<SystemTime>) -> *mut glib::ffi::GDateTime {
+    let usec = match time {
+        Some(time) => unix_usec(time),
+        None => return std::ptr::null_mut(),
+    };
+    unsafe {
+        let seconds = std::ptr::NonNull::new(glib::ffi::g_date_time_new_from_unix_utc(usec.div_euclid(1_000_000))).unwrap_or_else(|| crate::panic_returned_null("g_date_time_new_from_unix_utc")).as_ptr();
+        let date_time = std::ptr::NonNull::new(glib::ffi::g_date_time_add(seconds, usec.rem_euclid(1_000_000)));
+        glib::ffi::g_date_time_unref(seconds);
+        date_time.unwrap_or_else(|| crate::panic_returned_null("g_date_time_add")).as_ptr()
+    }
+}
+
+pub(crate) trait DateTimeFromGlib {
+    fn from_unix_usec(usec: Option<i64>) -> Self;
+}
+
+impl DateTimeFromGlib for SystemTime {
+    fn from_unix_usec(usec: Option<i64>) -> Self {
+        from_unix_usec(usec.unwrap_or_else(|| crate::panic_mandatory_none()))
+    }
+}
+
+impl DateTimeFromGlib for Option<SystemTime> {
+    fn from_unix_usec(usec: Option<i64>) -> Self {
+        usec.map(from_unix_usec)
+    }
+}
+
+pub(crate) unsafe fn date_time_from_glib_none<T: DateTimeFromGlib>(ptr: *mut glib::ffi::GDateTime) -> T {
+    if ptr.is_null() {
+        return T::from_unix_usec(None);
+    }
+    let usec = glib::ffi::g_date_time_to_unix(ptr) * 1_000_000 + glib::ffi::g_date_time_get_microsecond(ptr) as i64;
+    T::from_unix_usec(Some(usec))
+}
+
+pub(crate) unsafe fn date_time_from_glib_full<T: DateTimeFromGlib>(ptr: *mut glib::ffi::GDateTime) -> T {
+    let time = date_time_from_glib_none(ptr);
+    if !ptr.is_null() {
+        glib::ffi::g_date_time_unref(ptr);
+    }
+    time
+}
+
+/// Time value filled in for the call, it only lives as long as the stash
+pub(crate) struct TimeValStash(Option<glib::ffi::GTimeVal>);
+
+impl TimeValStash {
+    pub(crate) fn as_mut_ptr(&mut self) -> *mut glib::ffi::GTimeVal {
+        match self.0 {
+            Some(ref mut time_val) => time_val,
+            None => std::ptr::null_mut(),
+        }
+    }
+}
+
+pub(crate) fn time_val_to_glib(time: Option<SystemTime>) -> TimeValStash {
+    TimeValStash(time.map(|time| {
+        let usec = unix_usec(time);
+        glib::ffi::GTimeVal {
+            tv_sec: usec.div_euclid(1_000_000) as _,
+            tv_usec: usec.rem_euclid(1_000_000) as _,
+        }
+    }))
+}
@@ -0,0 +1,174 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/panics
+// DO NOT EDIT
+
+use glib::object::Cast;
+use glib::object::ObjectType as ObjectType_;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::translate::*;
+use glib::StaticType;
+use glib::ToValue;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    #[doc(alias = "test_widget_new")]
+    pub fn new(label: &str) -> Widget {
+        unsafe {
+            from_glib_full(ffi::test_widget_new(label.to_glib_none().0))
+        }
+    }
+
+            // rustdoc-stripper-ignore-next
+            /// Creates a new builder-pattern struct instance to construct [`Widget`] objects.
+            ///
+            /// This method returns an instance of [`WidgetBuilder`](crate::builders::WidgetBuilder) which can be used to create [`Widget`] objects.
+            pub fn builder() -> WidgetBuilder {
+                WidgetBuilder::default()
+            }
+        
+
+    #[doc(alias = "test_widget_set_width")]
+    pub fn set_width(&self, width: i32) {
+        unsafe {
+            ffi::test_widget_set_width(self.to_glib_none().0, width);
+        }
+    }
+
+    pub fn with_width(self, width: i32) -> Self {
+        self.set_width(width);
+        self
+    }
+
+    pub fn label(&self) -> Option<glib::GString> {
+        glib::ObjectExt::property(self, "label")
+    }
+
+    pub fn try_label(&self) -> Result<Option<glib::GString>, glib::value::ValueTypeMismatchOrNoneError<glib::value::ValueTypeMismatchError>> {
+        glib::ObjectExt::property_value(self, "label").get().map_err(Into::into)
+    }
+
+    pub fn set_label(&self, label: Option<&str>) {
+        glib::ObjectExt::set_property(self,"label", &label)
+    }
+
+    pub fn with_label(self, label: Option<&str>) -> Self {
+        self.set_label(label);
+        self
+    }
+
+    pub fn width(&self) -> i32 {
+        glib::ObjectExt::property(self, "width")
+    }
+
+    pub fn try_width(&self) -> Result<i32, glib::value::ValueTypeMismatchOrNoneError<glib::value::ValueTypeMismatchError>> {
+        glib::ObjectExt::property_value(self, "width").get().map_err(Into::into)
+    }
+
+    #[doc(alias = "activated")]
+    pub fn connect_activated<F: Fn(&Self, (u32, String), Option<String>) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn activated_trampoline<F: Fn(&Widget, (u32, String), Option<String>) + 'static>(this: *mut ffi::TestWidget, state: *mut glib::ffi::GVariant, hint: *mut glib::ffi::GVariant, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(&from_glib_borrow(this), glib::Variant::from_glib_borrow(state).get::<(u32, String)>().unwrap_or_else(|| crate::panic_wrong_variant_type("state")), Option::<glib::Variant>::from_glib_borrow(hint).as_ref().as_ref().map(|v| v.get::<String>().unwrap_or_else(|| crate::panic_wrong_variant_type("hint"))))
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"activated\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(activated_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+
+    #[doc(alias = "label")]
+    pub fn connect_label_notify<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"notify::label\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(notify_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+
+    #[doc(alias = "width")]
+    pub fn connect_width_notify<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"notify::width\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(notify_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+}
+
+impl Default for Widget {
+                     fn default() -> Self {
+                         glib::object::Object::new::<Self>(&[])
+                            .unwrap_or_else(|_| crate::panic_default_construct("Widget"))
+                     }
+                 }
+
+#[derive(Clone, Default)]
+// rustdoc-stripper-ignore-next
+        /// A [builder-pattern] type to construct [`Widget`] objects.
+        ///
+        /// [builder-pattern]: https://doc.rust-lang.org/1.0.0/style/ownership/builders.html
+#[must_use = "The builder must be built to be used"]
+pub struct WidgetBuilder {
+    label: Option<String>,
+    width: Option<i32>,
+}
+
+impl WidgetBuilder {
+    // rustdoc-stripper-ignore-next
+    /// Create a new [`WidgetBuilder`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+
+    // rustdoc-stripper-ignore-next
+    /// Build the [`Widget`].
+    #[must_use = "Building the object from the builder is usually expensive and is not expected to have side effects"]
+    pub fn build(self) -> Widget {
+        let mut properties: Vec<(&str, &dyn ToValue)> = vec![];
+if let Some(ref label) = self.label {
+                properties.push(("label", label));
+            }
+if let Some(ref width) = self.width {
+                properties.push(("width", width));
+            }
+        glib::Object::new::<Widget>(&properties)
+                .unwrap_or_else(|_| crate::panic_build_object("Widget"))
+
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    pub fn width(mut self, width: i32) -> Self {
+        self.width = Some(width);
+        self
+    }
+}
+
+unsafe extern "C" fn notify_trampoline<F: Fn(&Widget) + 'static>(this: *mut ffi::TestWidget, _param_spec: glib::ffi::gpointer, f: glib::ffi::gpointer) {
+    let f: &F = &*(f as *const F);
+    f(&from_glib_borrow(this))
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
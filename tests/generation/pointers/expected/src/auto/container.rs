@@ -0,0 +1,47 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/pointers
+// DO NOT EDIT
+
+use crate::Widget;
+use glib::object::IsA;
+use glib::translate::*;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestContainer")]
+    pub struct Container(Object<ffi::TestContainer, ffi::TestContainerClass>);
+
+    match fn {
+        type_ => || ffi::test_container_get_type(),
+    }
+}
+
+impl Container {
+    #[doc(alias = "test_container_add")]
+    pub fn add(&self, child: &impl IsA<Widget>) {
+        unsafe {
+            ffi::test_container_add(self.to_glib_none().0, child.as_ref().to_glib_none().0 as glib::ffi::gpointer);
+        }
+    }
+
+    #[doc(alias = "test_container_contains")]
+    pub fn contains(&self, child: Option<&impl IsA<Widget>>) -> bool {
+        unsafe {
+            from_glib(ffi::test_container_contains(self.to_glib_none().0, child.map(|p| p.as_ref()).to_glib_none().0 as glib::ffi::gconstpointer))
+        }
+    }
+
+    #[doc(alias = "test_container_set_data")]
+    pub fn set_data(&self, data: glib::ffi::gpointer) {
+        unsafe {
+            ffi::test_container_set_data(self.to_glib_none().0, data);
+        }
+    }
+}
+
+impl fmt::Display for Container {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Container")
+    }
+}
@@ -0,0 +1,11 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/pointers
+// DO NOT EDIT
+
+mod container;
+pub use self::container::Container;
+
+mod widget;
+pub use self::widget::Widget;
+
@@ -0,0 +1,26 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/pointers
+// DO NOT EDIT
+
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget, ffi::TestWidgetClass>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+        pub const NONE: Option<&'static Widget> = None;
+    
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
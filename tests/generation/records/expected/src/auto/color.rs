@@ -0,0 +1,44 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+use glib::translate::*;
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Color(Boxed<ffi::TestColor>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_color_get_type(), ptr as *mut _) as *mut ffi::TestColor,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_color_get_type(), ptr as *mut _),
+        type_ => || ffi::test_color_get_type(),
+    }
+}
+
+impl Color {
+    #[doc(alias = "test_color_blend")]
+    pub fn blend(colors: &[Color]) -> bool {
+        let n_colors = colors.len() as usize;
+        let colors = colors.iter().map(|r| unsafe { *ToGlibPtr::<*const ffi::TestColor>::to_glib_none(r).0 }).collect::<Vec<_>>();
+        unsafe {
+            from_glib(ffi::test_color_blend(colors.as_ptr() as *const _, n_colors))
+        }
+    }
+
+    #[doc(alias = "test_color_blend_or_default")]
+    pub fn blend_or_default(colors: Option<&[Color]>) -> bool {
+        let n_colors = colors.map_or(0, |s| s.len()) as usize;
+        let colors = colors.map(|s| s.iter().map(|r| unsafe { *ToGlibPtr::<*const ffi::TestColor>::to_glib_none(r).0 }).collect::<Vec<_>>());
+        unsafe {
+            from_glib(ffi::test_color_blend_or_default(colors.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()) as *const _, n_colors))
+        }
+    }
+
+    #[doc(alias = "test_color_release")]
+    pub fn release(color: Color) {
+        unsafe {
+            ffi::test_color_release(color.to_glib_full());
+        }
+    }
+}
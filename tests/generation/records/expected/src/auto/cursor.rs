@@ -0,0 +1,25 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+use glib::translate::*;
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Cursor(Boxed<ffi::TestCursor>);
+
+    match fn {
+        copy => |ptr| ffi::test_cursor_dup(mut_override(ptr)),
+        free => |ptr| ffi::test_cursor_free(ptr),
+    }
+}
+
+impl Cursor {
+    #[doc(alias = "test_cursor_dup")]
+    pub fn dup(&mut self) -> Option<Cursor> {
+        unsafe {
+            from_glib_full(ffi::test_cursor_dup(self.to_glib_none_mut().0))
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+mod color;
+pub use self::color::Color;
+
+mod cursor;
+pub use self::cursor::Cursor;
+
+mod node;
+pub use self::node::NodeRef;
+
+mod size;
+pub use self::size::Size;
+
+mod tree;
+pub use self::tree::Tree;
+
@@ -0,0 +1,30 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+use glib::translate::*;
+
+#[repr(transparent)]
+#[doc(alias = "TestNode")]
+pub struct NodeRef(ffi::TestNode);
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *const ffi::TestNode> for NodeRef {
+    type Storage = &'a Self;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *const ffi::TestNode, Self> {
+        Stash(&self.0, self)
+    }
+}
+
+#[doc(hidden)]
+impl<'a> ToGlibPtr<'a, *mut ffi::TestNode> for NodeRef {
+    type Storage = &'a Self;
+
+    #[inline]
+    fn to_glib_none(&'a self) -> Stash<'a, *mut ffi::TestNode, Self> {
+        Stash(&self.0 as *const _ as *mut _, self)
+    }
+}
@@ -0,0 +1,48 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+use glib::translate::*;
+
+glib::wrapper! {
+    pub struct Size(BoxedInline<ffi::TestSize>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_size_get_type(), ptr as *mut _) as *mut ffi::TestSize,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_size_get_type(), ptr as *mut _),
+        type_ => || ffi::test_size_get_type(),
+    }
+}
+
+impl Size {
+    #[doc(alias = "test_size_area")]
+    pub fn area(&self) -> i32 {
+        unsafe {
+            ffi::test_size_area(*self.to_glib_none().0)
+        }
+    }
+
+    #[doc(alias = "test_size_total")]
+    pub fn total(sizes: &[Size]) -> i32 {
+        let n_sizes = sizes.len() as usize;
+        unsafe {
+            ffi::test_size_total(sizes.as_ptr() as *const _, n_sizes)
+        }
+    }
+
+    #[doc(alias = "test_size_largest")]
+    pub fn largest(sizes: Option<&[Size]>) -> i32 {
+        let n_sizes = sizes.map_or(0, |s| s.len()) as usize;
+        unsafe {
+            ffi::test_size_largest(sizes.as_ref().map_or(std::ptr::null(), |s| s.as_ptr()) as *const _, n_sizes)
+        }
+    }
+
+    #[doc(alias = "test_size_merge")]
+    pub fn merge(target: &mut Size, other: &Size) {
+        unsafe {
+            ffi::test_size_merge(target.to_glib_none_mut().0, mut_override(other.to_glib_none().0));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/records
+// DO NOT EDIT
+
+use crate::NodeRef;
+use glib::translate::*;
+
+glib::wrapper! {
+    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Tree(Boxed<ffi::TestTree>);
+
+    match fn {
+        copy => |ptr| glib::gobject_ffi::g_boxed_copy(ffi::test_tree_get_type(), ptr as *mut _) as *mut ffi::TestTree,
+        free => |ptr| glib::gobject_ffi::g_boxed_free(ffi::test_tree_get_type(), ptr as *mut _),
+        type_ => || ffi::test_tree_get_type(),
+    }
+}
+
+impl Tree {
+    #[doc(alias = "test_tree_root")]
+    pub fn root<'a>(container: &'a Tree) -> Option<&'a NodeRef> {
+        unsafe {
+            (ffi::test_tree_root(container.to_glib_none().0) as *const NodeRef).as_ref()
+        }
+    }
+}
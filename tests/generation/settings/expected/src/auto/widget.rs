@@ -0,0 +1,56 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/settings
+// DO NOT EDIT
+
+use glib::object::ObjectType as ObjectType_;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::translate::*;
+use glib::StaticType;
+use glib::ToValue;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    pub fn label(&self) -> Option<glib::GString> {
+        glib::ObjectExt::property(self, "label")
+    }
+
+    pub fn set_label(&self, label: Option<&str>) {
+        glib::ObjectExt::set_property(self,"label", &label)
+    }
+
+    pub fn bind_label_to_settings(&self, settings: &gio::Settings, key: &str, flags: gio::SettingsBindFlags) {
+        gio::prelude::SettingsExtManual::bind(settings, key, self, "label").flags(flags).build();
+    }
+
+    #[doc(alias = "label")]
+    pub fn connect_label_notify<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn notify_label_trampoline<F: Fn(&Widget) + 'static>(this: *mut ffi::TestWidget, _param_spec: glib::ffi::gpointer, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(&from_glib_borrow(this))
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"notify::label\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(notify_label_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
@@ -0,0 +1,8 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/shadows
+// DO NOT EDIT
+
+mod widget;
+pub use self::widget::Widget;
+
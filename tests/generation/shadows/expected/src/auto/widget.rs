@@ -0,0 +1,50 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/shadows
+// DO NOT EDIT
+
+use glib::translate::*;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    #[cfg(any(feature = "labels", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "labels")))]
+    #[doc(alias = "test_widget_set_label_full")]
+    #[doc(alias = "set_label_full")]
+    pub fn set_label(&self, label: &str, markup: bool) {
+        unsafe {
+            ffi::test_widget_set_label_full(self.to_glib_none().0, label.to_glib_none().0, markup.into_glib());
+        }
+    }
+
+    #[doc(alias = "test_widget_lookup")]
+    #[doc(alias = "lookup")]
+    pub fn lookup_simple(&self, key: &str) -> i32 {
+        unsafe {
+            ffi::test_widget_lookup(self.to_glib_none().0, key.to_glib_none().0)
+        }
+    }
+
+    #[doc(alias = "test_widget_lookup_full")]
+    #[doc(alias = "lookup_full")]
+    pub fn lookup(&self, key: &str, fallback: i32) -> i32 {
+        unsafe {
+            ffi::test_widget_lookup_full(self.to_glib_none().0, key.to_glib_none().0, fallback)
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
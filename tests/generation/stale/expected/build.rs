@@ -0,0 +1,23 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/stale
+// DO NOT EDIT
+
+#[cfg(not(feature = "dox"))]
+use std::process;
+
+mod build_stale_check;
+
+#[cfg(feature = "dox")]
+fn main() {
+    build_stale_check::check();
+} // prevent linking libraries to avoid documentation failure
+
+#[cfg(not(feature = "dox"))]
+fn main() {
+    build_stale_check::check();
+    if let Err(s) = system_deps::Config::new().probe() {
+        println!("cargo:warning={}", s);
+        process::exit(1);
+    }
+}
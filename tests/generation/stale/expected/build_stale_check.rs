@@ -0,0 +1,41 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/stale
+// DO NOT EDIT
+
+const INPUTS: &[(&str, u64)] = &[
+    ("../Test-1.0.gir", 0x997ed0260b430c88),
+    ("../../girs/GObject-2.0.gir", 0xa3495810643fb598),
+    ("../../girs/GLib-2.0.gir", 0x3092ec0fcc6a7a74),
+    ("../Gir.toml", 0xad415e55fdf36913),
+];
+
+/// Warns when the files the code was generated from changed since then.
+/// Files which can't be read, like in a published crate, are skipped.
+///
+/// The build script is rerun when these files change. Like any
+/// `cargo:rerun-if-changed` line, this stops cargo from rerunning it when any
+/// other file of the package changes.
+pub fn check() {
+    let mut stale = Vec::new();
+    for (path, hash) in INPUTS {
+        if let Ok(content) = std::fs::read(path) {
+            println!("cargo:rerun-if-changed={}", path);
+            if fnv1a(&content) != *hash {
+                stale.push(*path);
+            }
+        }
+    }
+    if !stale.is_empty() {
+        println!(
+            "cargo:warning=The generated code is out of date, regenerate it with gir: {} changed",
+            stale.join(", ")
+        );
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
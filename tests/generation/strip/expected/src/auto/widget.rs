@@ -0,0 +1,37 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/strip
+// DO NOT EDIT
+
+use glib::translate::*;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    //#[doc(alias = "test_widget_get_secret")]
+    //#[doc(alias = "get_secret")]
+    //pub fn secret(&self) -> /*Ignored*/Option<Secret> {
+    //    unsafe { TODO: call ffi:test_widget_get_secret() }
+    //}
+
+    #[doc(alias = "test_widget_show")]
+    pub fn show(&self) {
+        unsafe {
+            ffi::test_widget_show(self.to_glib_none().0);
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
@@ -0,0 +1,93 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/subclass
+// DO NOT EDIT
+
+use crate::Widget;
+use glib::object::Cast;
+use glib::object::ObjectExt;
+use glib::object::ObjectType as ObjectType_;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestButton")]
+    pub struct Button(Object<ffi::TestButton, ffi::TestButtonClass>) @extends Widget;
+
+    match fn {
+        type_ => || ffi::test_button_get_type(),
+    }
+}
+
+impl Button {
+    #[doc(alias = "clicked")]
+    pub fn connect_clicked<F: Fn(&Self, &str, &Widget) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn clicked_trampoline<F: Fn(&Button, &str, &Widget) + 'static>(this: *mut ffi::TestButton, label: *mut libc::c_char, parent: *mut ffi::TestWidget, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(&from_glib_borrow(this), &glib::GString::from_glib_borrow(label), &from_glib_borrow(parent))
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"clicked\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(clicked_trampoline::<F> as *const ())), Box_::into_raw(f))
+        }
+    }
+
+    pub fn emit_clicked(&self, label: &str, parent: &Widget) {
+        self.emit_by_name::<()>("clicked", &[&label, &parent]);
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Trait to implement in subclasses of [`Button`], overriding its virtual functions.
+pub trait ButtonImpl: ButtonImplExt + super::widget::WidgetImpl {
+    // rustdoc-stripper-ignore-next
+    /// Default handler of the `clicked` signal, run when it's emitted.
+    fn clicked(&self, button: &Self::Type, label: &str, parent: &Widget) {
+        self.parent_clicked(button, label, parent)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Calls of the parent implementations of the virtual functions of [`Button`].
+pub trait ButtonImplExt: ObjectSubclass {
+    fn parent_clicked(&self, button: &Self::Type, label: &str, parent: &Widget);
+}
+
+impl<T: ButtonImpl> ButtonImplExt for T {
+    fn parent_clicked(&self, button: &Self::Type, label: &str, parent: &Widget) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestButtonClass;
+            if let Some(f) = (*parent_class).clicked {
+                f(button.unsafe_cast_ref::<Button>().to_glib_none().0, label.to_glib_none().0, parent.to_glib_none().0)
+            }
+        }
+    }
+}
+
+unsafe impl<T: ButtonImpl> IsSubclassable<T> for Button {
+    fn class_init(class: &mut glib::Class<Self>) {
+        Self::parent_class_init::<T>(class);
+
+        let klass = class.as_mut();
+        klass.clicked = Some(button_clicked::<T>);
+    }
+}
+
+unsafe extern "C" fn button_clicked<T: ButtonImpl>(this: *mut ffi::TestButton, label: *const libc::c_char, parent: *mut ffi::TestWidget) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    imp.clicked(from_glib_borrow::<_, Button>(this).unsafe_cast_ref(), &glib::GString::from_glib_borrow(label), &from_glib_borrow(parent))
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Button")
+    }
+}
@@ -0,0 +1,119 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/subclass
+// DO NOT EDIT
+
+use crate::Widget;
+use glib::object::Cast;
+use glib::object::IsA;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestEditable")]
+    pub struct Editable(Interface<ffi::TestEditable, ffi::TestEditableInterface>) @requires Widget;
+
+    match fn {
+        type_ => || ffi::test_editable_get_type(),
+    }
+}
+
+impl Editable {
+        pub const NONE: Option<&'static Editable> = None;
+    
+}
+
+pub trait EditableExt: 'static {
+    #[doc(alias = "changed")]
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId;
+}
+
+impl<O: IsA<Editable>> EditableExt for O {
+    fn connect_changed<F: Fn(&Self) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn changed_trampoline<P: IsA<Editable>, F: Fn(&P) + 'static>(this: *mut ffi::TestEditable, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(Editable::from_glib_borrow(this).unsafe_cast_ref())
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"changed\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(changed_trampoline::<Self, F> as *const ())), Box_::into_raw(f))
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Trait to implement in subclasses of [`Editable`], overriding its virtual functions.
+pub trait EditableImpl: EditableImplExt + ObjectImpl {
+    // rustdoc-stripper-ignore-next
+    /// Default handler of the `changed` signal, run when it's emitted.
+    fn changed(&self, editable: &Self::Type) {
+        self.parent_changed(editable)
+    }
+
+    fn get_max_length(&self, editable: &Self::Type) -> u32 {
+        self.parent_get_max_length(editable)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Calls of the parent implementations of the virtual functions of [`Editable`].
+pub trait EditableImplExt: ObjectSubclass {
+    fn parent_changed(&self, editable: &Self::Type);
+    fn parent_get_max_length(&self, editable: &Self::Type) -> u32;
+}
+
+impl<T: EditableImpl> EditableImplExt for T {
+    fn parent_changed(&self, editable: &Self::Type) {
+        unsafe {
+            let data = T::type_data();
+            let parent_iface = data.as_ref().parent_interface::<Editable>() as *const ffi::TestEditableInterface;
+            if let Some(f) = (*parent_iface).changed {
+                f(editable.unsafe_cast_ref::<Editable>().to_glib_none().0)
+            }
+        }
+    }
+
+    fn parent_get_max_length(&self, editable: &Self::Type) -> u32 {
+        unsafe {
+            let data = T::type_data();
+            let parent_iface = data.as_ref().parent_interface::<Editable>() as *const ffi::TestEditableInterface;
+            let f = (*parent_iface).get_max_length.unwrap_or_else(|| crate::panic_missing_parent_vfunc("get_max_length"));
+            f(editable.unsafe_cast_ref::<Editable>().to_glib_none().0)
+        }
+    }
+}
+
+unsafe impl<T: EditableImpl> IsImplementable<T> for Editable
+where
+    <T as ObjectSubclass>::Type: IsA<glib::Object>,
+{
+    fn interface_init(iface: &mut glib::Interface<Self>) {
+        let iface = iface.as_mut();
+        iface.changed = Some(editable_changed::<T>);
+        iface.get_max_length = Some(editable_get_max_length::<T>);
+    }
+}
+
+unsafe extern "C" fn editable_changed<T: EditableImpl>(this: *mut ffi::TestEditable) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    imp.changed(from_glib_borrow::<_, Editable>(this).unsafe_cast_ref())
+}
+
+unsafe extern "C" fn editable_get_max_length<T: EditableImpl>(this: *mut ffi::TestEditable) -> libc::c_uint {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    imp.get_max_length(from_glib_borrow::<_, Editable>(this).unsafe_cast_ref())
+}
+
+impl fmt::Display for Editable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Editable")
+    }
+}
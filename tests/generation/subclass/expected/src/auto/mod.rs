@@ -0,0 +1,29 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/subclass
+// DO NOT EDIT
+
+mod button;
+pub use self::button::Button;
+
+mod editable;
+pub use self::editable::Editable;
+
+mod widget;
+pub use self::widget::Widget;
+
+mod panics;
+#[allow(unused_imports)]
+pub(crate) use self::panics::*;
+
+#[doc(hidden)]
+pub mod traits {
+    pub use super::editable::EditableExt;
+    pub use super::widget::WidgetExt;
+}
+#[doc(hidden)]
+pub mod subclass {
+    pub use super::button::{ButtonImpl, ButtonImplExt};
+    pub use super::editable::{EditableImpl, EditableImplExt};
+    pub use super::widget::{WidgetImpl, WidgetImplExt};
+}
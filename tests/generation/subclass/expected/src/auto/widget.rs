@@ -0,0 +1,117 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/subclass
+// DO NOT EDIT
+
+use glib::object::Cast;
+use glib::object::IsA;
+use glib::signal::connect_raw;
+use glib::signal::SignalHandlerId;
+use glib::subclass::prelude::*;
+use glib::translate::*;
+use std::boxed::Box as Box_;
+use std::fmt;
+use std::mem::transmute;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget, ffi::TestWidgetClass>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+        pub const NONE: Option<&'static Widget> = None;
+    
+}
+
+pub trait WidgetExt: 'static {
+    #[doc(alias = "resized")]
+    fn connect_resized<F: Fn(&Self, i32, i32) + 'static>(&self, f: F) -> SignalHandlerId;
+}
+
+impl<O: IsA<Widget>> WidgetExt for O {
+    fn connect_resized<F: Fn(&Self, i32, i32) + 'static>(&self, f: F) -> SignalHandlerId {
+        unsafe extern "C" fn resized_trampoline<P: IsA<Widget>, F: Fn(&P, i32, i32) + 'static>(this: *mut ffi::TestWidget, width: libc::c_int, height: libc::c_int, f: glib::ffi::gpointer) {
+            let f: &F = &*(f as *const F);
+            f(Widget::from_glib_borrow(this).unsafe_cast_ref(), width, height)
+        }
+        unsafe {
+            let f: Box_<F> = Box_::new(f);
+            connect_raw(self.as_ptr() as *mut _, b"resized\0".as_ptr() as *const _,
+                Some(transmute::<_, unsafe extern "C" fn()>(resized_trampoline::<Self, F> as *const ())), Box_::into_raw(f))
+        }
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Trait to implement in subclasses of [`Widget`], overriding its virtual functions.
+pub trait WidgetImpl: WidgetImplExt + ObjectImpl {
+    // rustdoc-stripper-ignore-next
+    /// Default handler of the `resized` signal, run when it's emitted.
+    fn resized(&self, widget: &Self::Type, width: i32, height: i32) {
+        self.parent_resized(widget, width, height)
+    }
+
+    fn activate(&self, widget: &Self::Type) -> bool {
+        self.parent_activate(widget)
+    }
+}
+
+// rustdoc-stripper-ignore-next
+/// Calls of the parent implementations of the virtual functions of [`Widget`].
+pub trait WidgetImplExt: ObjectSubclass {
+    fn parent_resized(&self, widget: &Self::Type, width: i32, height: i32);
+    fn parent_activate(&self, widget: &Self::Type) -> bool;
+}
+
+impl<T: WidgetImpl> WidgetImplExt for T {
+    fn parent_resized(&self, widget: &Self::Type, width: i32, height: i32) {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestWidgetClass;
+            if let Some(f) = (*parent_class).resized {
+                f(widget.unsafe_cast_ref::<Widget>().to_glib_none().0, width, height)
+            }
+        }
+    }
+
+    fn parent_activate(&self, widget: &Self::Type) -> bool {
+        unsafe {
+            let data = T::type_data();
+            let parent_class = data.as_ref().parent_class() as *const ffi::TestWidgetClass;
+            let f = (*parent_class).activate.unwrap_or_else(|| crate::panic_missing_parent_vfunc("activate"));
+            from_glib(f(widget.unsafe_cast_ref::<Widget>().to_glib_none().0))
+        }
+    }
+}
+
+unsafe impl<T: WidgetImpl> IsSubclassable<T> for Widget {
+    fn class_init(class: &mut glib::Class<Self>) {
+        Self::parent_class_init::<T>(class);
+
+        let klass = class.as_mut();
+        klass.resized = Some(widget_resized::<T>);
+        klass.activate = Some(widget_activate::<T>);
+    }
+}
+
+unsafe extern "C" fn widget_resized<T: WidgetImpl>(this: *mut ffi::TestWidget, width: libc::c_int, height: libc::c_int) {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    imp.resized(from_glib_borrow::<_, Widget>(this).unsafe_cast_ref(), width, height)
+}
+
+unsafe extern "C" fn widget_activate<T: WidgetImpl>(this: *mut ffi::TestWidget) -> glib::ffi::gboolean {
+    let instance = &*(this as *mut T::Instance);
+    let imp = instance.imp();
+    imp.activate(from_glib_borrow::<_, Widget>(this).unsafe_cast_ref()).into_glib()
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}
@@ -0,0 +1,132 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/unions
+// DO NOT EDIT
+
+#![allow(non_camel_case_types, non_upper_case_globals, non_snake_case)]
+#![allow(clippy::approx_constant, clippy::type_complexity, clippy::unreadable_literal, clippy::upper_case_acronyms)]
+#![cfg_attr(feature = "dox", feature(doc_cfg))]
+
+
+#[allow(unused_imports)]
+use libc::{c_int, c_char, c_uchar, c_float, c_uint, c_double,
+    c_short, c_ushort, c_long, c_ulong,
+    c_void, size_t, ssize_t, intptr_t, uintptr_t, time_t, FILE};
+
+#[allow(unused_imports)]
+use glib::{gboolean, gconstpointer, gpointer, GType};
+
+// Unions
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union TestValue {
+    pub as_int: c_int,
+    pub as_double: c_double,
+}
+
+impl ::std::fmt::Debug for TestValue {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestValue @ {:p}", self))
+         .field("as_int", unsafe { &self.as_int })
+         .field("as_double", unsafe { &self.as_double })
+         .finish()
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union TestVariable_data {
+    pub pointer: gpointer,
+    pub index: c_uint,
+}
+
+impl ::std::fmt::Debug for TestVariable_data {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestVariable_data @ {:p}", self))
+         .field("pointer", unsafe { &self.pointer })
+         .field("index", unsafe { &self.index })
+         .finish()
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub union TestVariable_u2 {
+    pub flags: c_uint,
+    pub range: TestVariable_u2_range,
+}
+
+impl ::std::fmt::Debug for TestVariable_u2 {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestVariable_u2 @ {:p}", self))
+         .field("flags", unsafe { &self.flags })
+         .field("range", unsafe { &self.range })
+         .finish()
+    }
+}
+
+// Records
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TestPacket {
+    pub size: size_t,
+    /// Flexible array member, its elements follow the struct in memory
+    pub data: [u8; 0],
+}
+
+impl ::std::fmt::Debug for TestPacket {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestPacket @ {:p}", self))
+         .field("size", &self.size)
+         .finish()
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TestVariable {
+    pub name: *mut c_char,
+    pub value: TestValue,
+    pub data: TestVariable_data,
+    pub u2: TestVariable_u2,
+}
+
+impl ::std::fmt::Debug for TestVariable {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestVariable @ {:p}", self))
+         .field("name", &self.name)
+         .field("value", &self.value)
+         .field("data", &self.data)
+         .field("u2", &self.u2)
+         .finish()
+    }
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct TestVariable_u2_range {
+    pub start: c_int,
+    pub end: c_int,
+}
+
+impl ::std::fmt::Debug for TestVariable_u2_range {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.debug_struct(&format!("TestVariable_u2_range @ {:p}", self))
+         .field("start", &self.start)
+         .field("end", &self.end)
+         .finish()
+    }
+}
+
+#[link(name = "test-1.0")]
+extern "C" {
+
+    //=========================================================================
+    // TestValue
+    //=========================================================================
+    pub fn test_value_get_type() -> GType;
+    pub fn test_value_copy(value: *const TestValue) -> *mut TestValue;
+    pub fn test_value_free(value: *mut TestValue);
+    pub fn test_value_is_zero(value: *const TestValue) -> gboolean;
+
+}
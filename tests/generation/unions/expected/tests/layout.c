@@ -0,0 +1,17 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/unions
+// DO NOT EDIT
+
+#include "manual.h"
+#include <stdalign.h>
+#include <stdio.h>
+
+int main() {
+    printf("%s;%zu;%zu\n", "TestPacket", sizeof(TestPacket), alignof(TestPacket));
+    printf("%s;%zu;%zu\n", "TestValue", sizeof(TestValue), alignof(TestValue));
+    printf("%s;%zu;%zu\n", "TestVariable", sizeof(TestVariable), alignof(TestVariable));
+    printf("%s;%zu;%zu\n", "TestVariable_data", sizeof(__typeof__(((TestVariable*)0)->data)), alignof(__typeof__(((TestVariable*)0)->data)));
+    printf("%s;%zu;%zu\n", "TestVariable_u2_range", sizeof(__typeof__(((TestVariable*)0)->range)), alignof(__typeof__(((TestVariable*)0)->range)));
+    return 0;
+}
@@ -0,0 +1,57 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/upcast
+// DO NOT EDIT
+
+use crate::Button;
+use crate::Widget;
+use glib::object::Cast;
+use glib::object::IsA;
+use glib::translate::*;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestBox")]
+    pub struct Box(Object<ffi::TestBox, ffi::TestBoxClass>);
+
+    match fn {
+        type_ => || ffi::test_box_get_type(),
+    }
+}
+
+impl Box {
+    #[doc(alias = "test_box_set_children")]
+    pub fn set_children(&self, children: &[impl IsA<Widget>]) {
+        let n_children = children.len() as u32;
+        unsafe {
+            ffi::test_box_set_children(self.to_glib_none().0, children.iter().map(|p| p.upcast_ref::<Widget>().clone()).collect::<Vec<Widget>>().to_glib_none().0, n_children);
+        }
+    }
+
+    #[doc(alias = "test_box_set_buttons")]
+    pub fn set_buttons(&self, buttons: &[Button]) {
+        unsafe {
+            ffi::test_box_set_buttons(self.to_glib_none().0, buttons.to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "test_box_set_widgets")]
+    pub fn set_widgets(&self, widgets: &[impl IsA<Widget>]) {
+        unsafe {
+            ffi::test_box_set_widgets(self.to_glib_none().0, widgets.iter().map(|p| p.upcast_ref::<Widget>().clone()).collect::<Vec<Widget>>().to_glib_none().0);
+        }
+    }
+
+    #[doc(alias = "test_box_add_list")]
+    pub fn add_list(&self, widgets: &[impl IsA<Widget>]) {
+        unsafe {
+            ffi::test_box_add_list(self.to_glib_none().0, widgets.iter().map(|p| p.upcast_ref::<Widget>().clone()).collect::<Vec<Widget>>().to_glib_none().0);
+        }
+    }
+}
+
+impl fmt::Display for Box {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Box")
+    }
+}
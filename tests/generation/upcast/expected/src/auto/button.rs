@@ -0,0 +1,22 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/upcast
+// DO NOT EDIT
+
+use crate::Widget;
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestButton")]
+    pub struct Button(Object<ffi::TestButton, ffi::TestButtonClass>) @extends Widget;
+
+    match fn {
+        type_ => || ffi::test_button_get_type(),
+    }
+}
+
+impl fmt::Display for Button {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Button")
+    }
+}
@@ -0,0 +1,14 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/upcast
+// DO NOT EDIT
+
+mod box_;
+pub use self::box_::Box;
+
+mod button;
+pub use self::button::Button;
+
+mod widget;
+pub use self::widget::Widget;
+
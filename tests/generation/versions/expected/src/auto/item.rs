@@ -0,0 +1,21 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/versions
+// DO NOT EDIT
+
+use std::fmt;
+
+glib::wrapper! {
+    #[doc(alias = "TestItem")]
+    pub struct Item(Object<ffi::TestItem, ffi::TestItemClass>);
+
+    match fn {
+        type_ => || ffi::test_item_get_type(),
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Item")
+    }
+}
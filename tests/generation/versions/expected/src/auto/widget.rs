@@ -0,0 +1,67 @@
+// Generated by gir (https://github.com/gtk-rs/gir)
+// from tests/generation/girs
+// from tests/generation/versions
+// DO NOT EDIT
+
+use glib::translate::*;
+use std::fmt;
+
+#[cfg(any(feature = "v1_2", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v1_2")))]
+use crate::Item;
+
+#[cfg(any(feature = "v1_4", feature = "dox"))]
+#[cfg_attr(feature = "dox", doc(cfg(feature = "v1_4")))]
+use crate::Mode;
+
+glib::wrapper! {
+    #[doc(alias = "TestWidget")]
+    pub struct Widget(Object<ffi::TestWidget, ffi::TestWidgetClass>);
+
+    match fn {
+        type_ => || ffi::test_widget_get_type(),
+    }
+}
+
+impl Widget {
+    #[cfg(any(feature = "v1_4", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_4")))]
+    #[doc(alias = "test_widget_foreach_mode")]
+    pub fn foreach_mode<P: FnMut(&Mode)>(&self, func: P) {
+        let mut func_data: P = func;
+        unsafe extern "C" fn func_func<P: FnMut(&Mode)>(mode: ffi::TestMode, user_data: glib::ffi::gpointer) {
+            let mode = from_glib_borrow(mode);
+            let callback: *mut P = user_data as *const _ as usize as *mut P;
+            (*callback)(&mode);
+        }
+        let func = Some(func_func::<P> as _);
+        let super_callback0: &mut P = &mut func_data;
+        unsafe {
+            ffi::test_widget_foreach_mode(self.to_glib_none().0, func, super_callback0 as *mut _ as usize as *mut _);
+        }
+    }
+
+    #[cfg(any(feature = "v1_2", feature = "dox"))]
+    #[cfg_attr(feature = "dox", doc(cfg(feature = "v1_2")))]
+    #[doc(alias = "test_widget_get_items")]
+    #[doc(alias = "get_items")]
+    pub fn items(&self) -> Vec<Item> {
+        unsafe {
+            FromGlibPtrContainer::from_glib_container(ffi::test_widget_get_items(self.to_glib_none().0))
+        }
+    }
+
+    #[doc(alias = "test_widget_get_name")]
+    #[doc(alias = "get_name")]
+    pub fn name(&self) -> Option<glib::GString> {
+        unsafe {
+            from_glib_full(ffi::test_widget_get_name(self.to_glib_none().0))
+        }
+    }
+}
+
+impl fmt::Display for Widget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Widget")
+    }
+}